@@ -1,68 +1,98 @@
-use crate::application::commands::utils::get_string_option;
+use crate::application::commands::utils::{
+    TASK_CHANNEL_TYPES, check_task_channel, get_string_option,
+};
+use crate::application::i18n::{self, t, te, tf};
 use crate::application::services::TaskOrchestrator;
-use crate::application::services::timezone_service::TimezoneService;
+use crate::application::services::timezone_service::{TimezoneError, TimezoneService};
 use crate::domain::entities::task::NotificationMethod;
+use crate::domain::value_objects::Language;
+use crate::domain::value_objects::mention::parse_mentions;
 use crate::utils::{ModalStorage, TaskModalMetadata, generate_modal_id};
+use chrono::Weekday;
+use chrono_tz::Tz;
 use serenity::{
     all::{
         ActionRowComponent, CommandInteraction, CommandOptionType, CreateCommand,
-        CreateCommandOption, CreateInteractionResponse, CreateInteractionResponseMessage,
-        InputTextStyle, ModalInteraction,
+        CreateInteractionResponse, CreateInteractionResponseMessage, InputTextStyle,
+        ModalInteraction,
     },
     builder::{CreateActionRow, CreateInputText, CreateModal},
-    prelude::*,
+    http::Http,
 };
 use std::sync::Arc;
 use tracing::error;
 
 pub fn register_add_task_command() -> CreateCommand {
-    CreateCommand::new("add_task")
-        .description("Add a new task")
-        .add_option(
-            CreateCommandOption::new(
-                CommandOptionType::String,
-                "notification_method",
-                "How to notify you when the task is due",
-            )
-            .add_string_choice("Direct Message", "DM")
-            .add_string_choice("Channel Notification", "Channel")
-            .add_string_choice("Both DM and Channel", "Both")
-            .required(true),
-        )
-        .add_option(
-            CreateCommandOption::new(
-                CommandOptionType::String,
-                "task_type",
-                "Task type: single or weekly",
-            )
-            .add_string_choice("Single (specific date/time)", "single")
-            .add_string_choice("Weekly (repeats on specific days)", "weekly")
-            .required(true),
-        )
+    let notification_method = i18n::option(
+        CommandOptionType::String,
+        "notification_method",
+        "metodo_aviso",
+        "How to notify you when the task is due",
+    );
+    let notification_method = [
+        ("Direct Message", "DM"),
+        ("Channel Notification", "Channel"),
+        ("Both DM and Channel", "Both"),
+    ]
+    .iter()
+    .fold(notification_method, |option, (name, value)| {
+        i18n::choice(option, name, value)
+    });
+    let task_type = i18n::option(
+        CommandOptionType::String,
+        "task_type",
+        "tipo_tarea",
+        "Task type: single or weekly",
+    );
+    let task_type = [
+        ("Single (specific date/time)", "single"),
+        ("Weekly (repeats on specific days)", "weekly"),
+    ]
+    .iter()
+    .fold(task_type, |option, (name, value)| {
+        i18n::choice(option, name, value)
+    });
+
+    i18n::command("add_task", "agregar_tarea", "Add a new task")
+        .add_option(notification_method.required(true))
+        .add_option(task_type.required(true))
         .add_option(
-            CreateCommandOption::new(
+            i18n::option(
                 CommandOptionType::Channel,
                 "channel",
+                "canal",
                 "Channel for notifications (required for Channel/Both)",
             )
+            .channel_types(TASK_CHANNEL_TYPES.to_vec())
             .required(false),
         )
         .add_option(
-            CreateCommandOption::new(
+            i18n::option(
                 CommandOptionType::String,
                 "mention",
-                "@mention users/roles (optional)",
+                "mencion",
+                "Users or roles to ping, @everyone and @here need Mention Everyone (optional)",
+            )
+            .required(false),
+        )
+        .add_option(
+            i18n::option(
+                CommandOptionType::String,
+                "timezone",
+                "zona_horaria",
+                "Timezone of the date and time, e.g. America/New_York (optional, yours by default)",
             )
             .required(false),
         )
 }
 
 pub async fn run_add_task(
-    ctx: &Context,
+    http: &Http,
     command: &CommandInteraction,
     _task_orchestrator: &Arc<TaskOrchestrator>,
     timezone_service: &Arc<TimezoneService>,
     modal_storage: &Arc<ModalStorage>,
+    language: Language,
 ) {
     let options = &command.data.options;
 
@@ -70,12 +100,52 @@ pub async fn run_add_task(
     let notification_method = get_string_option(options, 0).unwrap_or("DM".to_string());
     let task_type = get_string_option(options, 1).unwrap_or("single".to_string());
 
-    // Extract channel ID if provided
-    let channel_id = options
-        .get(2)
-        .and_then(|opt| opt.value.as_channel_id().map(|id| id.get()));
+    // Optional options are left out when not given, so look them up by name
+    let option = |name: &str| options.iter().find(|opt| opt.name == name);
+    let channel_id = option("channel").and_then(|opt| opt.value.as_channel_id().map(|id| id.get()));
+    let mention = option("mention")
+        .and_then(|opt| opt.value.as_str())
+        .unwrap_or_default();
+    let mentions = match parse_mentions(mention) {
+        Ok(mentions) => mentions,
+        Err(reason) => {
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::default()
+                    .content(format!(
+                        "{}\n\n{}",
+                        t(language, "❌ **Invalid mention**"),
+                        te(language, &reason)
+                    ))
+                    .ephemeral(true),
+            );
+            if let Err(err) = command.create_response(http, response).await {
+                error!("Failed to send mention validation error: {}", err);
+            }
+            return;
+        }
+    };
 
-    let mention = get_string_option(options, 3).unwrap_or_default();
+    let timezone = match option("timezone").and_then(|opt| opt.value.as_str()) {
+        Some(query) => match timezone_service.resolve_timezone(query) {
+            Ok(timezone) => Some(timezone),
+            Err(reason) => {
+                let response = CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::default()
+                        .content(format!(
+                            "{}\n\n{}",
+                            t(language, "❌ **Invalid timezone**"),
+                            te(language, &reason)
+                        ))
+                        .ephemeral(true),
+                );
+                if let Err(err) = command.create_response(http, response).await {
+                    error!("Failed to send timezone validation error: {}", err);
+                }
+                return;
+            }
+        },
+        None => None,
+    };
 
     // Validate channel requirement for Channel/Both notification methods - NOW STRICTER
     let requires_channel = matches!(notification_method.as_str(), "Channel" | "Both");
@@ -83,107 +153,118 @@ pub async fn run_add_task(
         if channel_id.is_none() {
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content("❌ **Channel required**\n\nFor 'Channel' or 'Both DM and Channel' notification methods, you must specify a channel")
+                    .content(t(language, "❌ **Channel required**\n\nFor 'Channel' or 'Both DM and Channel' notification methods, you must specify a channel"))
                     .ephemeral(true),
             );
-            if let Err(err) = command.create_response(&ctx.http, response).await {
+            if let Err(err) = command.create_response(http, response).await {
                 error!("Failed to send channel requirement error: {}", err);
             }
             return;
         }
     } else {
-        // For DM-only, channel should not be specified
+        // For DM-only, channel and mentions should not be specified
         if channel_id.is_some() {
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content("❌ **Invalid channel selection**\n\nFor 'Direct Message' notification method, you can't specify a channel")
+                    .content(t(language, "❌ **Invalid channel selection**\n\nFor 'Direct Message' notification method, you can't specify a channel"))
                     .ephemeral(true),
             );
-            if let Err(err) = command.create_response(&ctx.http, response).await {
+            if let Err(err) = command.create_response(http, response).await {
                 error!("Failed to send channel validation error: {}", err);
             }
             return;
         } // <-- ESTA ERA LA LLAVE QUE FALTABA
+        if !mentions.is_empty() {
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::default()
+                    .content(t(language, "❌ **Invalid mention**\n\nDirect messages can't ping anyone, pick a channel notification method to use mentions"))
+                    .ephemeral(true),
+            );
+            if let Err(err) = command.create_response(http, response).await {
+                error!("Failed to send mention validation error: {}", err);
+            }
+            return;
+        }
+    }
+
+    // Catch channels the bot or the user can't post to now, instead of when the task fires
+    if let Some(channel_id) = channel_id {
+        let guild_id = command.guild_id.map(|id| id.get()).unwrap_or_default();
+        if let Err(reason) =
+            check_task_channel(http, guild_id, channel_id, command.user.id.get(), &mentions).await
+        {
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::default()
+                    .content(format!(
+                        "{}\n\n{}",
+                        t(language, "❌ **Can't use this channel**"),
+                        te(language, &reason)
+                    ))
+                    .ephemeral(true),
+            );
+            if let Err(err) = command.create_response(http, response).await {
+                error!("Failed to send channel permission error: {}", err);
+            }
+            return;
+        }
     }
 
     // get user's timezone to display current time
     let user_id = command.user.id.get();
 
-    // Get user's date format for dynamic placeholders
-    let date_format_placeholder = match timezone_service
-        .get_user_date_format_placeholder(user_id)
+    // The zone the task will be pinned to, or the user's own, server defaults included
+    let guild_id = command.guild_id.map(|id| id.get()).unwrap_or_default();
+    let placeholder_timezone = match timezone_service
+        .resolve_task_timezone(user_id, guild_id, timezone.clone(), requires_channel)
         .await
     {
-        Ok(placeholder) => placeholder,
-        Err(_) => "YYYY-MM-DD", // Default fallback
-    };
-
-    let user_timezone = match timezone_service.get_user_timezone(user_id).await {
         Ok(Some(tz)) => tz,
-        _ => "UTC".to_string(),
+        Ok(None) => match timezone_service.get_user_timezone(user_id).await {
+            Ok(Some(tz)) => tz,
+            _ => "UTC".to_string(),
+        },
+        Err(_) => "UTC".to_string(),
     };
-
-    // Get current time in user's timezone for placeholders
-    let current_time_info = match timezone_service.get_current_time_for_timezone(&user_timezone) {
-        Ok(time_string) => {
-            // Parsear el string para extraer fecha y hora por separado
-            let parts: Vec<&str> = time_string.split_whitespace().collect();
-            if parts.len() == 2 {
-                let date_part = parts[0].to_string();
-                let time_part = parts[1].to_string();
-                Some((date_part, time_part))
-            } else {
-                None
-            }
-        }
-        Err(_) => None,
+    // Current date and time in the task's timezone for placeholders, written the user's way
+    let formatter = timezone_service
+        .formatter_for(user_id, Some(guild_id))
+        .await
+        .with_language(language);
+    let formatter = match placeholder_timezone.parse::<Tz>() {
+        Ok(zone) => formatter.in_zone(zone),
+        Err(_) => formatter,
     };
+    let now = timezone_service.now();
 
     // Create modal inputs
-    let title_input = CreateInputText::new(InputTextStyle::Short, "Title", "task_title")
-        .required(true)
-        .placeholder("Enter a title for your task");
+    let example = |value: &str| tf(language, "Example: {value}", &[("value", &value)]);
+    let title_input =
+        CreateInputText::new(InputTextStyle::Short, t(language, "Title"), "task_title")
+            .required(true)
+            .placeholder(t(language, "Enter a title for your task"));
 
     let date_days_input = if task_type == "weekly" {
-        CreateInputText::new(InputTextStyle::Short, "Days", "days")
+        let weekdays = formatter.weekdays(&[Weekday::Mon, Weekday::Wed, Weekday::Fri]);
+        CreateInputText::new(InputTextStyle::Short, t(language, "Days"), "days")
             .required(true)
-            .placeholder("Example: Mon,Wed,Fri")
+            .placeholder(example(&weekdays.replace(", ", ",")))
     } else {
-        // Use dynamic date placeholder based on user's format - FIXED
-        let date_placeholder = if let Some((date_part, _)) = &current_time_info {
-            // Use the actual current date from the timezone service (which now respects format)
-            format!("Example: {}", date_part)
-        } else {
-            // Fallback to format examples based on user's preference
-            match date_format_placeholder {
-                "DD-MM-YYYY" => "Example: 27-11-2025".to_string(),
-                "MM-DD-YYYY" => "Example: 11-27-2025".to_string(),
-                "YYYY-MM-DD" | _ => "Example: 2025-11-27".to_string(),
-            }
-        };
-
-        CreateInputText::new(InputTextStyle::Short, "Date", "date")
+        CreateInputText::new(InputTextStyle::Short, t(language, "Date"), "date")
             .required(true)
-            .placeholder(date_placeholder)
+            .placeholder(example(&formatter.date(now)))
     };
 
-    let time_placeholder = if let Some((_, time_part)) = &current_time_info {
-        format!("Example: {}", time_part)
-    } else {
-        "Example: 15:30".to_string()
-    };
-
-    let time_input = CreateInputText::new(InputTextStyle::Short, "Time", "time")
+    let time_input = CreateInputText::new(InputTextStyle::Short, t(language, "Time"), "time")
         .required(true)
-        .placeholder(time_placeholder);
+        .placeholder(example(&formatter.time(now)));
 
     let description_input = CreateInputText::new(
         InputTextStyle::Paragraph,
-        "Task Description (optional)",
+        t(language, "Task Description (optional)"),
         "task_description",
     )
     .required(false)
-    .placeholder("Add more details about your task...");
+    .placeholder(t(language, "Add more details about your task..."));
 
     // Generate a unique short ID for the modal
     let modal_id = generate_modal_id();
@@ -193,22 +274,24 @@ pub async fn run_add_task(
         task_type.clone(),
         notification_method.clone(),
         channel_id,
-        if mention.is_empty() { None } else { Some(mention.clone()) },
+        mentions,
+        timezone,
     );
     
     modal_storage.store(modal_id.clone(), metadata).await;
 
     let modal_custom_id = modal_id;
 
-    let modal = CreateModal::new(&modal_custom_id, "📅 Create New Task").components(vec![
-        CreateActionRow::InputText(title_input),
-        CreateActionRow::InputText(date_days_input),
-        CreateActionRow::InputText(time_input),
-        CreateActionRow::InputText(description_input),
-    ]);
+    let modal =
+        CreateModal::new(&modal_custom_id, t(language, "📅 Create New Task")).components(vec![
+            CreateActionRow::InputText(title_input),
+            CreateActionRow::InputText(date_days_input),
+            CreateActionRow::InputText(time_input),
+            CreateActionRow::InputText(description_input),
+        ]);
 
     if let Err(err) = command
-        .create_response(&ctx.http, CreateInteractionResponse::Modal(modal))
+        .create_response(http, CreateInteractionResponse::Modal(modal))
         .await
     {
         error!("Failed to show modal: {}", err);
@@ -217,11 +300,12 @@ pub async fn run_add_task(
 
 /// Process the modal input and create the task using TaskService
 pub async fn process_task_modal_input(
-    ctx: &Context,
+    http: &Http,
     modal: &ModalInteraction,
     task_orchestrator: &Arc<TaskOrchestrator>,
     timezone_service: &Arc<TimezoneService>,
     modal_storage: &Arc<ModalStorage>,
+    language: Language,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Retrieve metadata from storage using the modal custom_id
     let metadata = match modal_storage.retrieve(&modal.data.custom_id).await {
@@ -229,10 +313,10 @@ pub async fn process_task_modal_input(
         None => {
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content("❌ Modal session expired. Please try again.")
+                    .content(t(language, "❌ Modal session expired. Please try again."))
                     .ephemeral(true),
             );
-            modal.create_response(&ctx.http, response).await?;
+            modal.create_response(http, response).await?;
             return Ok(());
         }
     };
@@ -240,7 +324,8 @@ pub async fn process_task_modal_input(
     let task_type = metadata.task_type.as_str();
     let notification_method_str = metadata.notification_method.as_str();
     let channel_id = metadata.channel_id;
-    let mention = metadata.mention;
+    let mentions = metadata.mentions;
+    let timezone = metadata.timezone;
 
     // Extract inputs from the modal (4 fields: title, date/days, time, description)
     let title = modal
@@ -322,38 +407,49 @@ pub async fn process_task_modal_input(
     let user_id = modal.user.id.get();
     let guild_id = modal.guild_id.map(|g| g.get()).unwrap_or(0);
 
-    // validate that the user has timezone configured
-    match timezone_service.get_user_timezone(user_id).await {
-        Ok(Some(_)) => {
-            // user has timezone configured, proceed normally
-        }
-        Ok(None) => {
+    // Server tasks and members without a timezone go by the server's default one
+    let is_channel_notification = matches!(
+        notification_method,
+        NotificationMethod::Channel | NotificationMethod::Both
+    );
+    let timezone = match timezone_service
+        .resolve_task_timezone(user_id, guild_id, timezone, is_channel_notification)
+        .await
+    {
+        Ok(timezone) => timezone,
+        Err(TimezoneError::NotFound) => {
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content("❌ **First, setup your timezone**\n\nUse the `/timezone` command to set your location before creating tasks")
+                    .content(t(language, "❌ **First, setup your timezone**\n\nUse the `/timezone` command to set your location before creating tasks, or ask an admin to set a server default with `/server_settings`"))
                     .ephemeral(true),
             );
-            modal.create_response(&ctx.http, response).await?;
+            modal.create_response(http, response).await?;
             return Ok(());
         }
         Err(e) => {
             error!("Error getting user timezone: {:?}", e);
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content("❌ Error verifying timezone")
+                    .content(t(language, "❌ Error verifying timezone"))
                     .ephemeral(true),
             );
-            modal.create_response(&ctx.http, response).await?;
+            modal.create_response(http, response).await?;
             return Ok(());
         }
-    }
+    };
 
     // Save values for response message before they're moved
-    let is_channel_notification = matches!(
-        notification_method,
-        NotificationMethod::Channel | NotificationMethod::Both
-    );
-    let has_mention = mention.is_some();
+    let has_mention = !mentions.is_empty();
+    let timezone_note = timezone
+        .as_ref()
+        .map(|timezone| {
+            tf(
+                language,
+                "\n🌍 Times of this task are in **{timezone}**",
+                &[("timezone", timezone)],
+            )
+        })
+        .unwrap_or_default();
 
     // delegate to TaskOrchestrator for business logic
     match task_orchestrator
@@ -366,42 +462,36 @@ pub async fn process_task_modal_input(
             notification_method,
             datetime_input,
             channel_id,
-            mention,
+            mentions,
+            timezone,
         )
         .await
     {
         Ok(_task_id) => {
-            let response_content = if is_channel_notification && has_mention {
-                format!(
-                    "✅ Task **{}** created successfully with mention in specified channel!",
-                    title
-                )
+            let template = if is_channel_notification && has_mention {
+                "✅ Task **{title}** created successfully with mention in specified channel!"
             } else if is_channel_notification {
-                format!(
-                    "✅ Task **{}** created successfully! Notifications will be sent to the specified channel",
-                    title
-                )
+                "✅ Task **{title}** created successfully! Notifications will be sent to the specified channel"
             } else {
-                format!(
-                    "✅ Task **{}** created successfully! You will receive DM notifications",
-                    title
-                )
+                "✅ Task **{title}** created successfully! You will receive DM notifications"
             };
+            let response_content = tf(language, template, &[("title", &title)]);
 
             let response = CreateInteractionResponse::Message(
-                CreateInteractionResponseMessage::default().content(response_content),
+                CreateInteractionResponseMessage::default()
+                    .content(format!("{}{}", response_content, timezone_note)),
             );
-            modal.create_response(&ctx.http, response).await?;
+            modal.create_response(http, response).await?;
         }
         Err(error) => {
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content(format!("❌ {}", error))
+                    .content(format!("❌ {}", te(language, &error)))
                     .ephemeral(true),
             );
-            modal.create_response(&ctx.http, response).await?;
+            modal.create_response(http, response).await?;
         }
     }
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,13 +1,16 @@
+use crate::application::commands::utils::check_task_channel;
+use crate::application::i18n::{self, t, te, tf};
 use crate::application::services::task_orchestrator::TaskOrchestrator;
 use crate::application::services::task_service::TaskService;
+use crate::application::services::time_formatter::discord_timestamp_with_relative;
 use crate::application::services::timezone_service::TimezoneService;
 use crate::domain::entities::task::Recurrence;
-use crate::domain::value_objects::weekday_format::WeekdayFormat;
-use chrono::{Timelike, Utc};
+use crate::domain::value_objects::Language;
+use chrono::Weekday;
 use serenity::all::{
     ActionRowComponent, CommandInteraction, ComponentInteraction, ComponentInteractionDataKind,
-    Context, CreateActionRow, CreateCommand, CreateEmbed, CreateInteractionResponse,
-    CreateInteractionResponseMessage, InputTextStyle, ModalInteraction,
+    CreateActionRow, CreateCommand, CreateEmbed, CreateInteractionResponse,
+    CreateInteractionResponseMessage, Http, InputTextStyle, ModalInteraction,
 };
 use serenity::builder::{
     CreateInputText, CreateModal, CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption,
@@ -16,145 +19,41 @@ use std::sync::Arc;
 
 /// Register /edit_task command
 pub fn register_edit_task_command() -> CreateCommand {
-    CreateCommand::new("edit_task").description("Edit your task")
-}
-
-/// Extract time part from local time string (HH:MM from "YYYY-MM-DD HH:MM")
-fn extract_time_part(local_time: &str, hour: u8, minute: u8) -> String {
-    local_time
-        .split_whitespace()
-        .nth(1)
-        .map(|s| s.to_string())
-        .unwrap_or_else(|| format!("{:02}:{:02}", hour, minute))
-}
-
-/// Format UTC time to local time string and extract time part
-fn format_utc_time_to_local_time(
-    timezone_service: &TimezoneService,
-    utc_time: chrono::DateTime<Utc>,
-    user_timezone: &str,
-    hour: u8,
-    minute: u8,
-) -> String {
-    match timezone_service.format_from_utc_with_timezone(utc_time, user_timezone) {
-        Ok(local_time) => extract_time_part(&local_time, hour, minute),
-        Err(_) => format!("{:02}:{:02}", hour, minute),
-    }
-}
-
-/// Create UTC time from hour and minute
-fn create_utc_time(hour: u8, minute: u8) -> chrono::DateTime<Utc> {
-    Utc::now()
-        .with_hour(hour as u32)
-        .and_then(|t| t.with_minute(minute as u32))
-        .and_then(|t| t.with_second(0))
-        .unwrap()
-}
-
-/// Format days for display (weekly tasks)
-fn format_days_for_display(days: &[chrono::Weekday]) -> String {
-    days.iter()
-        .map(|d| d.to_short_en())
-        .collect::<Vec<_>>()
-        .join(",")
-}
-
-/// Format date for display (single tasks) using user's preferred format - now async
-async fn format_date_for_display(
-    task: &crate::domain::entities::task::Task,
-    timezone_service: &TimezoneService,
-    user_timezone: &str,
-    user_id: u64,
-) -> String {
-    if let Some(dt) = task.scheduled_time {
-        // Use the new method that respects user's date format preference
-        match timezone_service.format_from_utc_for_user(dt, user_id).await {
-            Ok(local_time) => local_time
-                .split_whitespace()
-                .next()
-                .unwrap_or("")
-                .to_string(),
-            Err(_) => {
-                // Fallback to old method if new one fails
-                match timezone_service.format_from_utc_with_timezone(dt, user_timezone) {
-                    Ok(local_time) => local_time
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or("")
-                        .to_string(),
-                    Err(_) => dt.format("%Y-%m-%d").to_string(),
-                }
-            }
-        }
-    } else {
-        "Date missing".to_string()
-    }
-}
-
-/// Format task date for final display using user's preferred format
-async fn format_task_date(
-    task: &crate::domain::entities::task::Task,
-    timezone_service: &TimezoneService,
-    user_timezone: &str,
-    user_id: u64,
-) -> String {
-    if let Some(Recurrence::Weekly { days, hour, minute }) = &task.recurrence {
-        let days_str = format_days_for_display(days);
-
-        let utc_time = create_utc_time(*hour, *minute);
-        let time_part = format_utc_time_to_local_time(
-            timezone_service,
-            utc_time,
-            user_timezone,
-            *hour,
-            *minute,
-        );
-
-        format!("{} at {}", days_str, time_part)
-    } else if let Some(dt) = task.scheduled_time {
-        // Use the new method that respects user's date format preference
-        match timezone_service.format_from_utc_for_user(dt, user_id).await {
-            Ok(local_time) => local_time,
-            Err(_) => {
-                // Fallback to old method if new one fails
-                match timezone_service.format_from_utc_with_timezone(dt, user_timezone) {
-                    Ok(local_time) => local_time,
-                    Err(_) => dt.format("%Y-%m-%d %H:%M").to_string(),
-                }
-            }
-        }
-    } else {
-        "Date missing".to_string()
-    }
+    i18n::command("edit_task", "editar_tarea", "Edit your task")
 }
 
 /// Run /edit_task, show select menus for single and weekly tasks
 pub async fn run_edit_task(
-    ctx: &Context,
+    http: &Http,
     command: &CommandInteraction,
     task_service: &Arc<TaskService>,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     let user_id = command.user.id.get();
 
-    // verify user's timezone first
-    let user_timezone = match timezone_service.get_user_timezone(user_id).await {
-        Ok(Some(tz)) => tz,
-        Ok(None) => {
-            let _ = command
-                .create_response(
-                    &ctx.http,
-                    CreateInteractionResponse::Message(
-                        CreateInteractionResponseMessage::new()
-                            .content("❌ **First, setup your timezone**\n\nUse `/timezone` to set your location before editing tasks")
-                            .ephemeral(true),
-                    ),
-                )
-                .await;
-            return;
-        }
-        Err(_) => "UTC".to_string(),
-    };
+    // verify user's timezone first, the server's default one stands in for it
+    let guild_id = command.guild_id.map(|id| id.get());
+    if let Ok(None) = timezone_service
+        .get_effective_timezone(user_id, guild_id)
+        .await
+    {
+        let _ = command
+            .create_response(
+                http,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(t(language, "❌ **First, setup your timezone**\n\nUse `/timezone` to set your location before editing tasks"))
+                        .ephemeral(true),
+                ),
+            )
+            .await;
+        return;
+    }
+    let formatter = timezone_service
+        .formatter_for(user_id, guild_id)
+        .await
+        .with_language(language);
 
     // delegate to TaskService for business logic
     let (single_tasks, weekly_tasks) = task_service.get_user_tasks_for_editing(user_id).await;
@@ -162,10 +61,10 @@ pub async fn run_edit_task(
     if single_tasks.is_empty() && weekly_tasks.is_empty() {
         let _ = command
             .create_response(
-                &ctx.http,
+                http,
                 CreateInteractionResponse::Message(
                     CreateInteractionResponseMessage::default()
-                        .content("You don't have any task to edit"),
+                        .content(t(language, "You don't have any task to edit")),
                 ),
             )
             .await;
@@ -184,33 +83,17 @@ pub async fn run_edit_task(
                 task.title.clone()
             };
 
-            let label = if let Some(dt) = task.scheduled_time {
-                match timezone_service
-                    .format_from_utc_for_user(dt, user_id)
-                    .await
-                {
-                    Ok(local_time) => {
-                        format!("#{}: {} (Single on {})", task.id, display_title, local_time)
-                    }
-                    Err(_) => {
-                        match timezone_service.format_from_utc_with_timezone(dt, &user_timezone) {
-                            Ok(local_time) => {
-                                format!(
-                                    "#{}: {} (Single on {})",
-                                    task.id, display_title, local_time
-                                )
-                            }
-                            Err(_) => format!(
-                                "#{}: {} (Single on {})",
-                                task.id,
-                                display_title,
-                                dt.format("%Y-%m-%d %H:%M")
-                            ),
-                        }
-                    }
-                }
-            } else {
-                format!("#{}: {}", task.id, display_title)
+            let label = match task.scheduled_time {
+                Some(dt) => tf(
+                    language,
+                    "#{id}: {title} (Single on {date})",
+                    &[
+                        ("id", &task.id),
+                        ("title", &display_title),
+                        ("date", &formatter.for_task(task).date_time(dt)),
+                    ],
+                ),
+                None => format!("#{}: {}", task.id, display_title),
             };
             formatted_tasks.push((task.id, label));
         }
@@ -222,7 +105,7 @@ pub async fn run_edit_task(
 
         let select =
             CreateSelectMenu::new("edit_menu_single", CreateSelectMenuKind::String { options })
-                .placeholder("Single tasks")
+                .placeholder(t(language, "Single tasks"))
                 .min_values(1)
                 .max_values(1);
 
@@ -239,29 +122,27 @@ pub async fn run_edit_task(
                     task.title.clone()
                 };
 
-                let label =
-                    if let Some(Recurrence::Weekly { days, hour, minute }) = &task.recurrence {
-                        let days_str = days
-                            .iter()
-                            .map(|d| d.to_short_en())
-                            .collect::<Vec<_>>()
-                            .join(",");
-
-                        let time_part = format_utc_time_to_local_time(
-                            timezone_service,
-                            create_utc_time(*hour, *minute),
-                            &user_timezone,
-                            *hour,
-                            *minute,
-                        );
-
-                        format!(
-                            "#{}: {} (Weekly on {} at {})",
-                            task.id, display_title, days_str, time_part
-                        )
-                    } else {
-                        format!("#{}: {}", task.id, display_title)
-                    };
+                let label = if let Some(Recurrence::Weekly { days, .. }) = &task.recurrence {
+                    let days_str = formatter.weekdays(days);
+
+                    let time_part = formatter
+                        .for_task(task)
+                        .recurrence_time(task)
+                        .unwrap_or_default();
+
+                    tf(
+                        language,
+                        "#{id}: {title} (Weekly on {days} at {time})",
+                        &[
+                            ("id", &task.id),
+                            ("title", &display_title),
+                            ("days", &days_str),
+                            ("time", &time_part),
+                        ],
+                    )
+                } else {
+                    format!("#{}: {}", task.id, display_title)
+                };
 
                 CreateSelectMenuOption::new(label, task.id.to_string())
             })
@@ -269,7 +150,7 @@ pub async fn run_edit_task(
 
         let select =
             CreateSelectMenu::new("edit_menu_weekly", CreateSelectMenuKind::String { options })
-                .placeholder("Weekly tasks")
+                .placeholder(t(language, "Weekly tasks"))
                 .min_values(1)
                 .max_values(1);
 
@@ -278,10 +159,10 @@ pub async fn run_edit_task(
 
     let _ = command
         .create_response(
-            &ctx.http,
+            http,
             CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content("Select a task to edit:")
+                    .content(t(language, "Select a task to edit:"))
                     .components(components)
                     .ephemeral(true),
             ),
@@ -291,10 +172,11 @@ pub async fn run_edit_task(
 
 /// Show modal to edit selected task
 pub async fn handle_edit_select(
-    ctx: &Context,
+    http: &Http,
     interaction: &ComponentInteraction,
     task_service: &Arc<TaskService>,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     let selected_id = match &interaction.data.kind {
         ComponentInteractionDataKind::StringSelect { values } => {
@@ -304,10 +186,10 @@ pub async fn handle_edit_select(
                     Err(_) => {
                         let _ = interaction
                             .create_response(
-                                &ctx.http,
+                                http,
                                 CreateInteractionResponse::Message(
                                     CreateInteractionResponseMessage::default()
-                                        .content("❌ Invalid selection."),
+                                        .content(t(language, "❌ Invalid selection.")),
                                 ),
                             )
                             .await;
@@ -324,15 +206,20 @@ pub async fn handle_edit_select(
     let user_id = interaction.user.id.get();
 
     // get user's timezone for placeholder
-    let user_timezone = match timezone_service.get_user_timezone(user_id).await {
+    let user_timezone = match timezone_service
+        .get_effective_timezone(user_id, interaction.guild_id.map(|id| id.get()))
+        .await
+    {
         Ok(Some(tz)) => tz,
         Ok(None) => {
             let _ = interaction
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
-                        CreateInteractionResponseMessage::default()
-                            .content("❌ Please set your timezone first with `/timezone`"),
+                        CreateInteractionResponseMessage::default().content(t(
+                            language,
+                            "❌ Please set your timezone first with `/timezone`",
+                        )),
                     ),
                 )
                 .await;
@@ -340,21 +227,26 @@ pub async fn handle_edit_select(
         }
         Err(_) => "UTC".to_string(),
     };
+    let formatter = timezone_service
+        .formatter_for(user_id, interaction.guild_id.map(|id| id.get()))
+        .await
+        .with_language(language);
 
     // delegate to TaskService for business logic
     let task = match task_service
         .get_task_for_editing(selected_id, user_id)
         .await
     {
-        Some(t) => t,
+        Some(task) => task,
         None => {
             let _ = interaction
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
-                        CreateInteractionResponseMessage::default().content(
+                        CreateInteractionResponseMessage::default().content(t(
+                            language,
                             "❌ Couldn't find the task or you don't have permission to edit it.",
-                        ),
+                        )),
                     ),
                 )
                 .await;
@@ -362,19 +254,20 @@ pub async fn handle_edit_select(
         }
     };
 
-    let title_input = CreateInputText::new(InputTextStyle::Short, "New title", "new_title")
-        .placeholder(&task.title)
-        .required(false);
+    let title_input =
+        CreateInputText::new(InputTextStyle::Short, t(language, "New title"), "new_title")
+            .placeholder(&task.title)
+            .required(false);
 
     let description_input = CreateInputText::new(
         InputTextStyle::Paragraph,
-        "New description (optional)",
+        t(language, "New description (optional)"),
         "new_description",
     )
     .placeholder(
         task.description
             .as_deref()
-            .unwrap_or("Add task description..."),
+            .unwrap_or(t(language, "Add task description...")),
     )
     .required(false);
 
@@ -383,70 +276,86 @@ pub async fn handle_edit_select(
 
     let (date_days_placeholder, date_days_label) = if is_weekly {
         if let Some(Recurrence::Weekly { days, .. }) = &task.recurrence {
-            (format_days_for_display(days), "New days")
+            (formatter.weekdays(days), "New days")
         } else {
-            ("Mon,Wed,Fri".to_string(), "New days")
+            (
+                formatter.weekdays(&[Weekday::Mon, Weekday::Wed, Weekday::Fri]),
+                "New days",
+            )
         }
     } else {
-        // Now this is async, so we need to await it
-        let placeholder =
-            format_date_for_display(&task, timezone_service, &user_timezone, user_id).await;
+        let placeholder = task
+            .scheduled_time
+            .map_or(t(language, "Date missing").to_string(), |dt| {
+                formatter.for_task(&task).date(dt)
+            });
         (placeholder, "New date")
     };
 
-    let date_days_input =
-        CreateInputText::new(InputTextStyle::Short, date_days_label, "new_date_days")
-            .placeholder(&date_days_placeholder)
-            .required(false);
+    let date_days_input = CreateInputText::new(
+        InputTextStyle::Short,
+        t(language, date_days_label),
+        "new_date_days",
+    )
+    .placeholder(&date_days_placeholder)
+    .required(false);
 
     // Create time input with current time placeholder
-    let time_placeholder = if let Some(Recurrence::Weekly { hour, minute, .. }) = &task.recurrence {
-        // Para weekly tasks: usar el mismo approach que single tasks
-        let utc_time = create_utc_time(*hour, *minute);
-        extract_time_part(
-            &timezone_service
-                .format_from_utc_with_timezone(utc_time, &user_timezone)
-                .unwrap_or_else(|_| format!("2024-01-01 {:02}:{:02}", hour, minute)), // fecha dummy, solo nos importa la hora
-            *hour,
-            *minute,
-        )
-    } else if let Some(dt) = task.scheduled_time {
-        // Para single tasks (ya funciona correctamente)
-        extract_time_part(
-            &timezone_service
-                .format_from_utc_with_timezone(dt, &user_timezone)
-                .unwrap_or_else(|_| dt.format("%Y-%m-%d %H:%M").to_string()),
-            dt.hour() as u8,
-            dt.minute() as u8,
-        )
+    let time_placeholder = if task.recurrence.is_some() {
+        formatter.for_task(&task).recurrence_time(&task)
     } else {
-        "15:30".to_string()
-    };
+        task.scheduled_time
+            .map(|dt| formatter.for_task(&task).time(dt))
+    }
+    .unwrap_or_else(|| "15:30".to_string());
 
-    let time_input = CreateInputText::new(InputTextStyle::Short, "New time", "new_time")
-        .placeholder(&time_placeholder)
-        .required(false);
+    let time_input =
+        CreateInputText::new(InputTextStyle::Short, t(language, "New time"), "new_time")
+            .placeholder(&time_placeholder)
+            .required(false);
+
+    let timezone_placeholder = match &task.timezone {
+        Some(timezone) => tf(
+            language,
+            "{timezone}, or \"default\" to use yours",
+            &[("timezone", timezone)],
+        ),
+        None => tf(
+            language,
+            "Yours ({timezone}), or e.g. America/New_York",
+            &[("timezone", &user_timezone)],
+        ),
+    };
+    let timezone_input = CreateInputText::new(
+        InputTextStyle::Short,
+        t(language, "Timezone"),
+        "new_timezone",
+    )
+    .placeholder(timezone_placeholder)
+    .required(false);
 
     let modal_id = format!("edit_task_modal_{}", task.id);
 
-    let modal = CreateModal::new(&modal_id, "Edit task").components(vec![
+    let modal = CreateModal::new(&modal_id, t(language, "Edit task")).components(vec![
         CreateActionRow::InputText(title_input),
         CreateActionRow::InputText(description_input),
         CreateActionRow::InputText(date_days_input),
         CreateActionRow::InputText(time_input),
+        CreateActionRow::InputText(timezone_input),
     ]);
 
     let _ = interaction
-        .create_response(&ctx.http, CreateInteractionResponse::Modal(modal))
+        .create_response(http, CreateInteractionResponse::Modal(modal))
         .await;
 }
 
 /// process modal submission to edit the task
 pub async fn process_edit_task_modal(
-    ctx: &Context,
+    http: &Http,
     modal: &ModalInteraction,
     task_orchestrator: &Arc<TaskOrchestrator>,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     if !modal.data.custom_id.starts_with("edit_task_modal_") {
         return Ok(());
@@ -466,6 +375,7 @@ pub async fn process_edit_task_modal(
     let mut new_description: Option<String> = None;
     let mut new_date_days_input: Option<String> = None;
     let mut new_time_input: Option<String> = None;
+    let mut new_timezone_input: Option<String> = None;
 
     for row in &modal.data.components {
         for c in &row.components {
@@ -501,25 +411,81 @@ pub async fn process_edit_task_modal(
                             }
                         }
                     }
+                    "new_timezone" => {
+                        if let Some(timezone_str) = &input.value
+                            && !timezone_str.trim().is_empty()
+                        {
+                            new_timezone_input = Some(timezone_str.clone());
+                        }
+                    }
                     _ => {}
                 }
             }
         }
     }
 
+    // "default" goes back to the owner's timezone
+    let new_timezone = match new_timezone_input.as_deref().map(str::trim) {
+        None => None,
+        Some(input) if input.eq_ignore_ascii_case("default") => Some(None),
+        Some(input) => match timezone_service.resolve_timezone(input) {
+            Ok(timezone) => Some(Some(timezone)),
+            Err(reason) => {
+                let _ = modal
+                    .create_response(
+                        http,
+                        CreateInteractionResponse::Message(
+                            CreateInteractionResponseMessage::default().content(tf(
+                                language,
+                                "❌ Error editing task: {reason}",
+                                &[("reason", &te(language, &reason))],
+                            )),
+                        ),
+                    )
+                    .await;
+                return Ok(());
+            }
+        },
+    };
+
     // determine if it's a weekly task by checking the original task
     let is_weekly_task = if let Some(original_task) = task_orchestrator
         .get_task_for_editing(task_id, user_id)
         .await
     {
+        // The channel may have changed since the task was created
+        if let Some(channel_id) = original_task.channel_id
+            && let Err(reason) = check_task_channel(
+                http,
+                original_task.guild_id,
+                channel_id,
+                user_id,
+                &original_task.mentions,
+            )
+            .await
+        {
+            let _ = modal
+                .create_response(
+                    http,
+                    CreateInteractionResponse::Message(
+                        CreateInteractionResponseMessage::default().content(tf(
+                            language,
+                            "❌ Error editing task: {reason}",
+                            &[("reason", &te(language, &reason))],
+                        )),
+                    ),
+                )
+                .await;
+            return Ok(());
+        }
         original_task.recurrence.is_some()
     } else {
         let _ = modal
             .create_response(
-                ctx,
+                http,
                 CreateInteractionResponse::Message(
                     CreateInteractionResponseMessage::default()
-                        .content("❌ Couldn't find the task."),
+                        .content(t(language, "❌ Couldn't find the task.")),
                 ),
             )
             .await;
@@ -547,30 +513,51 @@ pub async fn process_edit_task_modal(
             new_title,
             new_description,
             new_datetime_input,
+            new_timezone,
             is_weekly_task,
         )
         .await
     {
         Ok(updated_task) => {
-            let user_timezone = match timezone_service.get_user_timezone(user_id).await {
+            let user_timezone = match timezone_service
+                .get_effective_timezone(user_id, Some(updated_task.guild_id))
+                .await
+            {
                 Ok(Some(tz)) => tz,
                 _ => "UTC".to_string(),
             };
 
-            let date_str =
-                format_task_date(&updated_task, timezone_service, &user_timezone, user_id).await;
+            // Single dates as timestamp markup, recurrences written out in the task's zone
+            let date_str = match (&updated_task.recurrence, updated_task.scheduled_time) {
+                (None, Some(dt)) => discord_timestamp_with_relative(dt),
+                _ => timezone_service
+                    .formatter_for(user_id, Some(updated_task.guild_id))
+                    .await
+                    .with_language(language)
+                    .for_task(&updated_task)
+                    .schedule(&updated_task),
+            };
 
             let embed = CreateEmbed::new()
-                .title("Task Updated Successfully")
-                .description(format!("Task **#{}** has been updated", updated_task.id))
-                .field("Title", &updated_task.title, false)
-                .field("Date", &date_str, false)
+                .title(t(language, "Task Updated Successfully"))
+                .description(tf(
+                    language,
+                    "Task **#{id}** has been updated",
+                    &[("id", &updated_task.id)],
+                ))
+                .field(t(language, "Title"), &updated_task.title, false)
+                .field(t(language, "Date"), &date_str, false)
+                .field(
+                    t(language, "Timezone"),
+                    updated_task.timezone.as_deref().unwrap_or(&user_timezone),
+                    false,
+                )
                 .color(serenity::model::colour::Colour::DARK_GREEN);
 
             // Agregar campo de descripción solo si existe
             let embed = if let Some(desc) = &updated_task.description {
                 if !desc.trim().is_empty() {
-                    embed.field("Description", desc, false)
+                    embed.field(t(language, "Description"), desc, false)
                 } else {
                     embed
                 }
@@ -580,7 +567,7 @@ pub async fn process_edit_task_modal(
 
             let _ = modal
                 .create_response(
-                    ctx,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::default().embed(embed),
                     ),
@@ -590,10 +577,13 @@ pub async fn process_edit_task_modal(
         Err(error) => {
             let _ = modal
                 .create_response(
-                    ctx,
+                    http,
                     CreateInteractionResponse::Message(
-                        CreateInteractionResponseMessage::default()
-                            .content(format!("❌ Error editing task: {}", error)),
+                        CreateInteractionResponseMessage::default().content(tf(
+                            language,
+                            "❌ Error editing task: {reason}",
+                            &[("reason", &te(language, &error))],
+                        )),
                     ),
                 )
                 .await;
@@ -1,22 +1,36 @@
+use crate::application::i18n::{self, t};
+use crate::domain::value_objects::Language;
 use serenity::all::{
     CommandInteraction, CreateCommand, CreateInteractionResponse, CreateInteractionResponseMessage,
+    Http,
 };
-use serenity::prelude::*;
 use tracing::{error};
 
+/// One line per command, translated on their own
+const COMMAND_LINES: [&str; 12] = [
+    "`/add_task` - Create a Single or Weekly task (Single tasks are removed after notification, Weekly task are automatically rescheduled)",
+    "`/list_tasks` - List all your tasks",
+    "`/remove_task` - Remove specific tasks or all of them",
+    "`/edit_task` - Edit a task by selecting it",
+    "`/timezone` - Set your current timezone based on your country, city or state",
+    "`/preferences` - Choose your language, date format, 12 or 24-hour clock and first day of the week",
+    "`/time` - Show the current local time of other members",
+    "`/working_hours` - Set the hours you can be booked in by `/meeting_finder`",
+    "`/meeting_finder` - Find times this week that fit everyone's working hours and schedule a meeting",
+    "`/server_settings` - Set the server's default timezone, date format and language (admins)",
+    "`/my_data` - Get a copy of everything the bot stores about you, or delete it",
+    "`/help` - Show this message",
+];
+
 pub fn register_help_command() -> CreateCommand {
-    CreateCommand::new("help").description("Show available commands")
+    i18n::command("help", "ayuda", "Show available commands")
 }
 
-pub async fn run_help_command(ctx: &Context, command: &CommandInteraction) {
-    let content = "\
-**Available Commands:**\n\
-`/add_task` - Create a Single or Weekly task (Single tasks are removed after notification, Weekly task are automatically rescheduled) \n\
-`/list_tasks` - List all your tasks\n\
-`/remove_task` - Remove specific tasks or all of them\n\
-`/edit_task` - Edit a task by selecting it\n\
-`/timezone` - Set your current timezone based on your country, city or state\n\
-`/help` - Show this message";
+pub async fn run_help_command(http: &Http, command: &CommandInteraction, language: Language) {
+    let content = std::iter::once(t(language, "**Available Commands:**"))
+        .chain(COMMAND_LINES.iter().map(|line| t(language, line)))
+        .collect::<Vec<_>>()
+        .join("\n");
 
     let builder = CreateInteractionResponse::Message(
         CreateInteractionResponseMessage::default()
@@ -24,7 +38,7 @@ pub async fn run_help_command(ctx: &Context, command: &CommandInteraction) {
             .ephemeral(false),
     );
 
-    if let Err(err) = command.create_response(&ctx.http, builder).await {
+    if let Err(err) = command.create_response(http, builder).await {
         error!("Error executing /help: {:?}", err);
     }
 }
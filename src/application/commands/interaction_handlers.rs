@@ -2,49 +2,55 @@ use crate::application::services::TaskOrchestrator;
 use crate::application::services::notification_service::NotificationService;
 use crate::application::services::task_service::TaskService;
 use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::value_objects::{Language, TimezoneChangeMode};
 use crate::utils::ModalStorage;
+use serenity::http::Http;
 use serenity::model::prelude::*;
-use serenity::prelude::*;
 use std::sync::Arc;
 use tracing::{error, info};
 
 /// Handle slash commands
 pub async fn handle_command(
-    ctx: &Context,
+    http: &Http,
     interaction: &Interaction,
     task_service: &Arc<TaskService>,
     task_orchestrator: &Arc<TaskOrchestrator>,
     _notification_service: &Arc<NotificationService>,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     if let Some(command) = interaction.clone().command() {
         match command.data.name.as_str() {
             "list_tasks" => {
                 crate::application::commands::list_tasks::run_list_tasks(
-                    ctx,
+                    http,
                     &command,
                     task_service,
                     timezone_service,
+                    language,
                 )
                 .await;
             }
             "remove_task" => {
                 crate::application::commands::remove_task::run_remove_task(
-                    ctx,
+                    http,
                     &command,
                     task_orchestrator,
+                    language,
                 )
                 .await;
             }
             "help" => {
-                crate::application::commands::help::run_help_command(ctx, &command).await;
+                crate::application::commands::help::run_help_command(http, &command, language)
+                    .await;
             }
             "edit_task" => {
                 crate::application::commands::edit_task::run_edit_task(
-                    ctx,
+                    http,
                     &command,
                     task_service,
                     timezone_service,
+                    language,
                 )
                 .await;
             }
@@ -55,11 +61,12 @@ pub async fn handle_command(
 
 /// Handle components (buttons, select menus)
 pub async fn handle_component(
-    ctx: &Context,
+    http: &Http,
     interaction: &Interaction,
     task_service: &Arc<TaskService>,
     task_orchestrator: &Arc<TaskOrchestrator>,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     if let Some(component) = interaction.clone().message_component() {
         let custom_id = component.data.custom_id.as_str();
@@ -68,10 +75,34 @@ pub async fn handle_component(
         if custom_id.starts_with("timezone_confirm:") {
             let timezone_id = &custom_id["timezone_confirm:".len()..];
             crate::application::commands::timezone::handle_timezone_confirm(
-                ctx,
+                http,
                 &component,
                 timezone_id,
                 timezone_service,
+                task_orchestrator,
+                language,
+            )
+            .await;
+            return;
+        }
+
+        let change_mode = if let Some(timezone_id) = custom_id.strip_prefix("timezone_keep_local:")
+        {
+            Some((timezone_id, TimezoneChangeMode::KeepLocalTime))
+        } else {
+            custom_id
+                .strip_prefix("timezone_keep_absolute:")
+                .map(|timezone_id| (timezone_id, TimezoneChangeMode::KeepAbsoluteTime))
+        };
+        if let Some((timezone_id, mode)) = change_mode {
+            crate::application::commands::timezone::apply_timezone_change(
+                http,
+                &component,
+                timezone_id,
+                mode,
+                timezone_service,
+                task_orchestrator,
+                language,
             )
             .await;
             return;
@@ -80,16 +111,19 @@ pub async fn handle_component(
         match custom_id {
             "timezone_select" => {
                 crate::application::commands::timezone::handle_timezone_select(
-                    ctx,
+                    http,
                     &component,
                     timezone_service,
+                    language,
                 )
                 .await;
                 return;
             }
             "timezone_cancel" => {
-                crate::application::commands::timezone::handle_timezone_cancel(ctx, &component)
-                    .await;
+                crate::application::commands::timezone::handle_timezone_cancel(
+                    http, &component, language,
+                )
+                .await;
                 return;
             }
             _ => {} // continue with other handlers
@@ -106,9 +140,10 @@ pub async fn handle_component(
 
         if remove_ids.contains(&custom_id) {
             crate::application::commands::remove_task::handle_remove_select(
-                ctx,
+                http,
                 &component,
                 task_orchestrator,
+                language,
             )
             .await;
             return;
@@ -118,10 +153,11 @@ pub async fn handle_component(
         let edit_ids = ["edit_menu_single", "edit_menu_weekly"];
         if edit_ids.contains(&custom_id) {
             crate::application::commands::edit_task::handle_edit_select(
-                ctx,
+                http,
                 &component,
                 task_service,
                 timezone_service,
+                language,
             )
             .await;
             return;
@@ -131,21 +167,23 @@ pub async fn handle_component(
 
 /// Handles modal submissions
 pub async fn handle_modal(
-    ctx: &Context,
+    http: &Http,
     interaction: &Interaction,
     task_orchestrator: &Arc<TaskOrchestrator>,
     timezone_service: &Arc<TimezoneService>,
     modal_storage: &Arc<ModalStorage>,
+    language: Language,
 ) {
     if let Some(modal) = interaction.clone().modal_submit() {
         let custom_id = modal.data.custom_id.as_str();
 
         if custom_id.starts_with("edit_task_modal_") {
             crate::application::commands::edit_task::process_edit_task_modal(
-                ctx,
+                http,
                 &modal,
                 task_orchestrator,
                 timezone_service,
+                language,
             )
             .await
             .unwrap_or_else(|err| {
@@ -153,11 +191,12 @@ pub async fn handle_modal(
             });
         } else if custom_id.starts_with("add_task_modal") {
             crate::application::commands::add_task::process_task_modal_input(
-                ctx,
+                http,
                 &modal,
                 task_orchestrator,
                 timezone_service,
                 modal_storage,
+                language,
             )
             .await
             .unwrap_or_else(|err| {
@@ -1,38 +1,50 @@
+use crate::application::i18n::{self, tf};
 use crate::application::services::task_service::TaskService;
 use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::value_objects::Language;
 use serenity::builder::{
     CreateCommand, CreateInteractionResponse, CreateInteractionResponseMessage,
 };
+use serenity::http::Http;
 use serenity::model::prelude::*;
-use serenity::prelude::*;
 use std::sync::Arc;
 use tracing::error;
 
 pub fn register_list_tasks_command() -> CreateCommand {
-    CreateCommand::new("list_tasks").description("📋 Show your current tasks")
+    i18n::command("list_tasks", "listar_tareas", "📋 Show your current tasks")
 }
 
 pub async fn run_list_tasks(
-    ctx: &Context,
+    http: &Http,
     command: &CommandInteraction,
     task_service: &Arc<TaskService>,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     let user_id: u64 = command.user.id.into();
     let user_mention = command.user.mention();
 
     let embed_response = task_service
-        .get_user_tasks_embed(user_id, timezone_service.clone())
+        .get_user_tasks_embed(
+            user_id,
+            command.guild_id.map(|id| id.get()),
+            timezone_service.clone(),
+            language,
+        )
         .await;
 
     let builder = CreateInteractionResponse::Message(
         CreateInteractionResponseMessage::default()
-            .content(format!("These are your tasks, {} \n", user_mention))
+            .content(tf(
+                language,
+                "These are your tasks, {user}\n",
+                &[("user", &user_mention)],
+            ))
             .add_embed(embed_response)
             .ephemeral(false),
     );
 
-    if let Err(e) = command.create_response(&ctx.http, builder).await {
+    if let Err(e) = command.create_response(http, builder).await {
         error!("Failed to send list_tasks response: {}", e);
     }
 }
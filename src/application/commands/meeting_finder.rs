@@ -0,0 +1,527 @@
+use crate::application::commands::utils::check_task_channel;
+use crate::application::i18n::{self, t, te, tf};
+use crate::application::services::TaskOrchestrator;
+use crate::application::services::time_formatter::discord_timestamp_with_relative;
+use crate::application::services::timezone_service::{TimezoneError, TimezoneService};
+use crate::domain::entities::task::NotificationMethod;
+use crate::domain::value_objects::mention::{format_mentions, parse_user_mentions};
+use crate::domain::value_objects::{Language, MentionTarget, WorkingHours, find_meeting_windows};
+use crate::utils::{MeetingProposal, ModalStorage};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, ComponentInteraction, ComponentInteractionDataKind,
+    CreateActionRow, CreateAllowedMentions, CreateCommand, CreateEmbed, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption, Http, UserId,
+};
+use serenity::model::colour::Colour;
+use std::sync::Arc;
+use tracing::error;
+
+/// Prefix of the slot menu's custom_id, followed by the proposal key
+pub const MEETING_SLOT_PREFIX: &str = "meeting_slot:";
+
+/// How far ahead slots are searched
+const SEARCH_DAYS: i64 = 7;
+/// People in one meeting, the caller included
+const MAX_PARTICIPANTS: usize = 10;
+/// Discord's limit of options in a select menu
+const MAX_SLOTS: usize = 25;
+
+const NO_TIMEZONE: &str =
+    "❌ Set your timezone with `/timezone` first, working hours are local to it";
+
+pub fn register_working_hours_command() -> CreateCommand {
+    i18n::command(
+        "working_hours",
+        "horario_laboral",
+        "Set the hours /meeting_finder can book you in, local to your timezone",
+    )
+    .add_option(
+        i18n::option(
+            CommandOptionType::String,
+            "hours",
+            "horas",
+            "e.g. 09:00-17:00, or 'default' to go back to 09:00-17:00 (shows yours if empty)",
+        )
+        .required(false),
+    )
+}
+
+pub fn register_meeting_finder_command() -> CreateCommand {
+    i18n::command(
+        "meeting_finder",
+        "buscar_reunion",
+        "Find times this week that fit everyone's working hours",
+    )
+    .add_option(
+        i18n::option(
+            CommandOptionType::String,
+            "members",
+            "miembros",
+            "Who to meet with, e.g. @Ana @Bob",
+        )
+        .required(true),
+    )
+    .add_option(
+        i18n::option(
+            CommandOptionType::Integer,
+            "duration",
+            "duracion",
+            "Length of the meeting in minutes (60 by default)",
+        )
+        .min_int_value(15)
+        .max_int_value(480)
+        .required(false),
+    )
+    .add_option(
+        i18n::option(
+            CommandOptionType::String,
+            "title",
+            "titulo",
+            "Title of the reminder (\"Meeting\" by default)",
+        )
+        .max_length(100)
+        .required(false),
+    )
+}
+
+pub async fn run_working_hours(
+    http: &Http,
+    command: &CommandInteraction,
+    timezone_service: &Arc<TimezoneService>,
+    language: Language,
+) {
+    let user_id = command.user.id.get();
+    let hours = command
+        .data
+        .options
+        .iter()
+        .find(|opt| opt.name == "hours")
+        .and_then(|opt| opt.value.as_str());
+
+    let content = match hours {
+        None => match timezone_service.get_user_preferences(user_id).await {
+            Ok(Some(prefs)) => tf(
+                language,
+                "🕘 Your working hours are **{hours}** ({timezone})",
+                &[
+                    ("hours", &prefs.working_hours.unwrap_or_default()),
+                    ("timezone", &prefs.timezone),
+                ],
+            ),
+            Ok(None) => t(language, NO_TIMEZONE).to_string(),
+            Err(e) => {
+                error!("Error getting preferences of user {}: {:?}", user_id, e);
+                t(language, "❌ Error reading your working hours").to_string()
+            }
+        },
+        Some(input) => {
+            let working_hours = if input.trim().eq_ignore_ascii_case("default") {
+                Ok(None)
+            } else {
+                input.parse::<WorkingHours>().map(Some)
+            };
+            match working_hours {
+                Ok(working_hours) => match timezone_service
+                    .set_user_working_hours(user_id, working_hours)
+                    .await
+                {
+                    Ok(()) => tf(
+                        language,
+                        "✅ Your working hours are now **{hours}**",
+                        &[("hours", &working_hours.unwrap_or_default())],
+                    ),
+                    Err(TimezoneError::NotFound) => t(language, NO_TIMEZONE).to_string(),
+                    Err(e) => {
+                        error!("Error saving working hours of user {}: {:?}", user_id, e);
+                        t(language, "❌ Error saving your working hours").to_string()
+                    }
+                },
+                Err(reason) => format!(
+                    "{}\n\n{}",
+                    t(language, "❌ **Invalid working hours**"),
+                    te(language, &reason)
+                ),
+            }
+        }
+    };
+
+    respond(
+        http,
+        command,
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    )
+    .await;
+}
+
+pub async fn run_meeting_finder(
+    http: &Http,
+    command: &CommandInteraction,
+    timezone_service: &Arc<TimezoneService>,
+    meeting_storage: &Arc<ModalStorage<MeetingProposal>>,
+    language: Language,
+) {
+    let Some(guild_id) = command.guild_id else {
+        respond_error(
+            http,
+            command,
+            t(language, "This command only works in a server"),
+        )
+        .await;
+        return;
+    };
+    let owner_id = command.user.id.get();
+    let option = |name: &str| command.data.options.iter().find(|opt| opt.name == name);
+
+    let members = option("members")
+        .and_then(|opt| opt.value.as_str())
+        .unwrap_or_default();
+    let mut participants = vec![owner_id];
+    match parse_user_mentions(members) {
+        Ok(user_ids) => {
+            for user_id in user_ids {
+                if !participants.contains(&user_id) {
+                    participants.push(user_id);
+                }
+            }
+        }
+        Err(reason) => {
+            respond_error(
+                http,
+                command,
+                &format!(
+                    "{}\n\n{}",
+                    t(language, "**Invalid members**"),
+                    te(language, &reason)
+                ),
+            )
+            .await;
+            return;
+        }
+    }
+    if participants.len() > MAX_PARTICIPANTS {
+        respond_error(
+            http,
+            command,
+            &tf(
+                language,
+                "A meeting can have at most {count} people",
+                &[("count", &MAX_PARTICIPANTS)],
+            ),
+        )
+        .await;
+        return;
+    }
+    let duration = Duration::minutes(
+        option("duration")
+            .and_then(|opt| opt.value.as_i64())
+            .unwrap_or(60),
+    );
+    let title = option("title")
+        .and_then(|opt| opt.value.as_str())
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .unwrap_or(t(language, "Meeting"))
+        .to_string();
+
+    // Everyone needs a timezone, working hours fall back to the default
+    let mut schedules = Vec::new();
+    let mut lines = Vec::new();
+    let mut without_timezone = Vec::new();
+    for user_id in &participants {
+        let prefs = timezone_service
+            .get_user_preferences(*user_id)
+            .await
+            .unwrap_or_else(|e| {
+                error!("Error getting preferences of user {}: {:?}", user_id, e);
+                None
+            });
+        match prefs.and_then(|prefs| Some((prefs.timezone.parse::<Tz>().ok()?, prefs))) {
+            Some((zone, prefs)) => {
+                let hours = prefs.working_hours.unwrap_or_default();
+                lines.push(format!("<@{}> · `{}` · {}", user_id, zone.name(), hours));
+                schedules.push((zone, hours));
+            }
+            None => without_timezone.push(MentionTarget::User(*user_id)),
+        }
+    }
+    if !without_timezone.is_empty() {
+        respond_error(
+            http,
+            command,
+            &tf(
+                language,
+                "{members} need to set a timezone with `/timezone` first",
+                &[("members", &format_mentions(&without_timezone))],
+            ),
+        )
+        .await;
+        return;
+    }
+
+    let windows = find_meeting_windows(&schedules, timezone_service.now(), duration, SEARCH_DAYS);
+    if windows.is_empty() {
+        respond_error(
+            http,
+            command,
+            &tf(
+                language,
+                "No {minutes}-minute slot fits everyone's working hours in the next {days} days",
+                &[("minutes", &duration.num_minutes()), ("days", &SEARCH_DAYS)],
+            ),
+        )
+        .await;
+        return;
+    }
+
+    // Slots are shown in the caller's timezone, who is the first participant
+    let owner_zone = schedules[0].0;
+    let formatter = timezone_service
+        .formatter_for(owner_id, Some(guild_id.get()))
+        .await
+        .with_language(language)
+        .in_zone(owner_zone);
+    let options: Vec<CreateSelectMenuOption> = windows
+        .iter()
+        .take(MAX_SLOTS)
+        .map(|window| {
+            let day = window.start.with_timezone(&owner_zone);
+            CreateSelectMenuOption::new(
+                format!(
+                    "{} {} {} {}-{}",
+                    t(language, &day.format("%a").to_string()),
+                    day.format("%d"),
+                    t(language, &day.format("%b").to_string()),
+                    formatter.time(window.start),
+                    formatter.time(window.end)
+                ),
+                window.start.timestamp().to_string(),
+            )
+        })
+        .collect();
+
+    let proposal_key = command.id.to_string();
+    meeting_storage
+        .store(
+            proposal_key.clone(),
+            MeetingProposal {
+                owner_id,
+                guild_id: guild_id.get(),
+                channel_id: command.channel_id.get(),
+                participants,
+                title: title.clone(),
+            },
+        )
+        .await;
+
+    let embed = CreateEmbed::new()
+        .title(format!("📅 {}", title))
+        .description(tf(
+            language,
+            "Times where a {minutes}-minute meeting fits everyone, in your timezone (`{timezone}`). \
+             The meeting starts at the beginning of the slot you pick\n\n{members}",
+            &[
+                ("minutes", &duration.num_minutes()),
+                ("timezone", &owner_zone.name()),
+                ("members", &lines.join("\n")),
+            ],
+        ))
+        .color(Colour::BLUE);
+    let menu = CreateSelectMenu::new(
+        format!("{}{}", MEETING_SLOT_PREFIX, proposal_key),
+        CreateSelectMenuKind::String { options },
+    )
+    .placeholder(t(language, "Pick a slot"));
+
+    respond(
+        http,
+        command,
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .components(vec![CreateActionRow::SelectMenu(menu)])
+            .ephemeral(true),
+    )
+    .await;
+}
+
+/// A slot was picked: create one channel task mentioning every participant
+pub async fn handle_meeting_slot(
+    http: &Http,
+    interaction: &ComponentInteraction,
+    task_orchestrator: &Arc<TaskOrchestrator>,
+    timezone_service: &Arc<TimezoneService>,
+    meeting_storage: &Arc<ModalStorage<MeetingProposal>>,
+    language: Language,
+) {
+    let proposal_key = &interaction.data.custom_id[MEETING_SLOT_PREFIX.len()..];
+    let start = match &interaction.data.kind {
+        ComponentInteractionDataKind::StringSelect { values } => values
+            .first()
+            .and_then(|value| value.parse::<i64>().ok())
+            .and_then(|timestamp| DateTime::<Utc>::from_timestamp(timestamp, 0)),
+        _ => None,
+    };
+    let Some(start) = start else {
+        respond_component(http, interaction, t(language, "❌ Invalid slot")).await;
+        return;
+    };
+    if start <= timezone_service.now() {
+        respond_component(
+            http,
+            interaction,
+            t(
+                language,
+                "❌ That slot has already started, run `/meeting_finder` again",
+            ),
+        )
+        .await;
+        return;
+    }
+
+    let Some(proposal) = meeting_storage.retrieve(proposal_key).await else {
+        respond_component(
+            http,
+            interaction,
+            t(
+                language,
+                "❌ These slots expired or were already used, run `/meeting_finder` again",
+            ),
+        )
+        .await;
+        return;
+    };
+
+    let mentions: Vec<MentionTarget> = proposal
+        .participants
+        .iter()
+        .filter(|user_id| **user_id != proposal.owner_id)
+        .map(|user_id| MentionTarget::User(*user_id))
+        .collect();
+    if let Err(reason) = check_task_channel(
+        http,
+        proposal.guild_id,
+        proposal.channel_id,
+        proposal.owner_id,
+        &mentions,
+    )
+    .await
+    {
+        // Taken out so a double click can't book twice, back in so another pick can be tried
+        meeting_storage
+            .store(proposal_key.to_string(), proposal)
+            .await;
+        respond_component(http, interaction, &format!("❌ {}", te(language, &reason))).await;
+        return;
+    }
+
+    let attendees = proposal
+        .participants
+        .iter()
+        .map(|user_id| MentionTarget::User(*user_id))
+        .collect::<Vec<_>>();
+    let result = task_orchestrator
+        .create_and_schedule_single_task(
+            proposal.owner_id,
+            proposal.guild_id,
+            proposal.title.clone(),
+            tf(
+                language,
+                "Meeting with {members}",
+                &[("members", &format_mentions(&attendees))],
+            ),
+            start,
+            NotificationMethod::Channel,
+            Some(proposal.channel_id),
+            mentions,
+            None,
+        )
+        .await;
+
+    match result {
+        Ok(task_id) => {
+            let when = discord_timestamp_with_relative(start);
+            let content = tf(
+                language,
+                "📅 <@{owner}> scheduled **{title}** (task #{id}) for {when} with {members}, \
+                 everyone is reminded here when it starts",
+                &[
+                    ("owner", &proposal.owner_id),
+                    ("title", &proposal.title),
+                    ("id", &task_id),
+                    ("when", &when),
+                    ("members", &format_mentions(&attendees)),
+                ],
+            );
+            // Tell the participants they were booked
+            let allowed_mentions = CreateAllowedMentions::new().users(
+                proposal
+                    .participants
+                    .iter()
+                    .map(|user_id| UserId::new(*user_id)),
+            );
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .allowed_mentions(allowed_mentions),
+            );
+            if let Err(e) = interaction.create_response(http, response).await {
+                error!("Error announcing meeting: {:?}", e);
+            }
+        }
+        Err(e) => {
+            meeting_storage
+                .store(proposal_key.to_string(), proposal)
+                .await;
+            respond_component(
+                http,
+                interaction,
+                &tf(
+                    language,
+                    "❌ Couldn't create the meeting: {reason}",
+                    &[("reason", &te(language, &e))],
+                ),
+            )
+            .await;
+        }
+    }
+}
+
+async fn respond(
+    http: &Http,
+    command: &CommandInteraction,
+    message: CreateInteractionResponseMessage,
+) {
+    if let Err(e) = command
+        .create_response(http, CreateInteractionResponse::Message(message))
+        .await
+    {
+        error!("Error executing /{}: {:?}", command.data.name, e);
+    }
+}
+
+async fn respond_error(http: &Http, command: &CommandInteraction, reason: &str) {
+    respond(
+        http,
+        command,
+        CreateInteractionResponseMessage::new()
+            .content(format!("❌ {}", reason))
+            .allowed_mentions(CreateAllowedMentions::new())
+            .ephemeral(true),
+    )
+    .await;
+}
+
+async fn respond_component(http: &Http, interaction: &ComponentInteraction, content: &str) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+    if let Err(e) = interaction.create_response(http, response).await {
+        error!("Error answering meeting slot pick: {:?}", e);
+    }
+}
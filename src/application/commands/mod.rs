@@ -3,13 +3,19 @@ pub mod edit_task;
 pub mod help;
 pub mod interaction_handlers;
 pub mod list_tasks;
+pub mod meeting_finder;
+pub mod my_data;
+pub mod preferences;
 pub mod remove_task;
+pub mod server_settings;
 pub mod timezone;
 pub mod utils;
+pub mod world_clock;
 pub mod stats;
 
 pub use add_task::register_add_task_command;
 pub use help::register_help_command;
 pub use list_tasks::register_list_tasks_command;
+pub use my_data::register_my_data_command;
 pub use remove_task::register_remove_task_command;
 pub use stats::register_stats_command;
\ No newline at end of file
@@ -0,0 +1,190 @@
+use crate::application::i18n::{self, t, te, tf};
+use crate::application::services::user_data_service::UserDataService;
+use crate::domain::value_objects::{Language, UserError};
+use serenity::all::{
+    ButtonStyle, CommandDataOptionValue, CommandInteraction, CommandOptionType,
+    ComponentInteraction, CreateActionRow, CreateAttachment, CreateButton, CreateCommand,
+    CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage,
+    EditInteractionResponse, Http,
+};
+use std::sync::Arc;
+use tracing::error;
+
+pub const CONFIRM_DELETE_ID: &str = "my_data_delete_confirm";
+pub const CANCEL_DELETE_ID: &str = "my_data_delete_cancel";
+
+pub fn register_my_data_command() -> CreateCommand {
+    i18n::command(
+        "my_data",
+        "mis_datos",
+        "Get or delete everything the bot stores about you",
+    )
+    .add_option(i18n::option(
+        CommandOptionType::SubCommand,
+        "export",
+        "exportar",
+        "Receive a JSON file with all your data by DM",
+    ))
+    .add_option(i18n::option(
+        CommandOptionType::SubCommand,
+        "delete",
+        "eliminar",
+        "Delete all your tasks, preferences and history",
+    ))
+}
+
+pub async fn run_my_data(
+    http: &Http,
+    command: &CommandInteraction,
+    user_data_service: &Arc<UserDataService>,
+    language: Language,
+) {
+    let subcommand = command
+        .data
+        .options
+        .first()
+        .filter(|option| matches!(option.value, CommandDataOptionValue::SubCommand(_)))
+        .map(|option| option.name.as_str());
+
+    match subcommand {
+        Some("export") => run_export(http, command, user_data_service, language).await,
+        Some("delete") => {
+            let confirm = CreateButton::new(CONFIRM_DELETE_ID)
+                .label(t(language, "🗑️ Delete everything"))
+                .style(ButtonStyle::Danger);
+            let cancel = CreateButton::new(CANCEL_DELETE_ID)
+                .label(t(language, "❌ Cancel"))
+                .style(ButtonStyle::Secondary);
+
+            let _ = command
+                .create_response(
+                    http,
+                    CreateInteractionResponse::Message(
+                        CreateInteractionResponseMessage::new()
+                            .content(t(
+                                language,
+                                "⚠️ This deletes all your tasks, preferences, scheduled \
+                                 notifications and history. It can't be undone.",
+                            ))
+                            .components(vec![CreateActionRow::Buttons(vec![confirm, cancel])])
+                            .ephemeral(true),
+                    ),
+                )
+                .await;
+        }
+        _ => {
+            let _ = command
+                .create_response(
+                    http,
+                    CreateInteractionResponse::Message(
+                        CreateInteractionResponseMessage::new()
+                            .content(t(language, "❌ Use `/my_data export` or `/my_data delete`"))
+                            .ephemeral(true),
+                    ),
+                )
+                .await;
+        }
+    }
+}
+
+async fn run_export(
+    http: &Http,
+    command: &CommandInteraction,
+    user_data_service: &Arc<UserDataService>,
+    language: Language,
+) {
+    // Collecting the data and opening the DM can take longer than Discord's 3s reply window
+    if let Err(e) = command.defer_ephemeral(http).await {
+        error!("Failed to defer /my_data export: {}", e);
+        return;
+    }
+
+    let user_id = command.user.id.get();
+    let content = match send_export(http, command, user_data_service, language).await {
+        Ok(()) => t(language, "📬 I sent you a DM with your data").to_string(),
+        Err(e) => {
+            error!("Failed to export data of user {}: {}", user_id, e);
+            format!("❌ {}", te(language, &e))
+        }
+    };
+
+    let _ = command
+        .edit_response(http, EditInteractionResponse::new().content(content))
+        .await;
+}
+
+async fn send_export(
+    http: &Http,
+    command: &CommandInteraction,
+    user_data_service: &Arc<UserDataService>,
+    language: Language,
+) -> Result<(), UserError> {
+    let user_id = command.user.id.get();
+    let json = user_data_service.export(user_id).await?.to_json()?;
+
+    let dm = command
+        .user
+        .create_dm_channel(http)
+        .await
+        .map_err(|_| "Couldn't open a DM with you, check your privacy settings")?;
+
+    let message = CreateMessage::new()
+        .content(t(language, "Here is everything the bot stores about you"))
+        .add_file(CreateAttachment::bytes(
+            json.into_bytes(),
+            format!("my_data_{}.json", user_id),
+        ));
+
+    dm.send_message(http, message)
+        .await
+        .map_err(|_| "Couldn't send you a DM, check your privacy settings")?;
+    Ok(())
+}
+
+/// Handles the confirmation buttons of `/my_data delete`
+pub async fn handle_my_data_component(
+    http: &Http,
+    interaction: &ComponentInteraction,
+    user_data_service: &Arc<UserDataService>,
+    language: Language,
+) {
+    let content = match interaction.data.custom_id.as_str() {
+        CONFIRM_DELETE_ID => match user_data_service.delete(interaction.user.id.get()).await {
+            Ok(deleted) => tf(
+                language,
+                "✅ Your data was deleted ({tasks} tasks, {entries} scheduled notifications{preferences})",
+                &[
+                    ("tasks", &deleted.tasks),
+                    ("entries", &deleted.scheduler_entries),
+                    (
+                        "preferences",
+                        &if deleted.preferences {
+                            t(language, ", your preferences")
+                        } else {
+                            ""
+                        },
+                    ),
+                ],
+            ),
+            Err(e) => {
+                error!(
+                    "Failed to delete data of user {}: {}",
+                    interaction.user.id, e
+                );
+                format!("❌ {}", te(language, &e))
+            }
+        },
+        _ => t(language, "❌ Operation cancelled").to_string(),
+    };
+
+    let _ = interaction
+        .create_response(
+            http,
+            CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .components(vec![]),
+            ),
+        )
+        .await;
+}
@@ -0,0 +1,279 @@
+use crate::application::commands::utils::date_format::get_date_format_description;
+use crate::application::i18n::{self, t, te, tf};
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::value_objects::weekday_format::WeekdayFormat;
+use crate::domain::value_objects::{ClockFormat, Language};
+use chrono::Weekday;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, CreateCommand, CreateEmbed, CreateInteractionResponse,
+    CreateInteractionResponseMessage, Http,
+};
+use serenity::model::colour::Colour;
+use std::sync::Arc;
+use tracing::error;
+
+/// Date format option value that goes back to the one of the user's timezone
+const FROM_TIMEZONE: &str = "auto";
+/// Language option value that goes back to the language of the user's Discord client
+const FROM_DISCORD: &str = "discord";
+
+pub fn register_preferences_command() -> CreateCommand {
+    let date_format = i18n::option(
+        CommandOptionType::String,
+        "date_format",
+        "formato_fecha",
+        "Order of day, month and year",
+    )
+    .add_string_choice("YYYY-MM-DD", "YMD")
+    .add_string_choice("DD-MM-YYYY", "DMY")
+    .add_string_choice("MM-DD-YYYY", "MDY");
+    let clock = i18n::option(
+        CommandOptionType::String,
+        "clock",
+        "reloj",
+        "12 or 24-hour clock",
+    );
+    let first_day = i18n::option(
+        CommandOptionType::String,
+        "first_day",
+        "primer_dia",
+        "First day of the week",
+    );
+    let language = i18n::option(
+        CommandOptionType::String,
+        "language",
+        "idioma",
+        "Language of the bot's replies",
+    )
+    .add_string_choice("English", "en")
+    .add_string_choice("Español", "es");
+
+    i18n::command(
+        "preferences",
+        "preferencias",
+        "Choose how dates and times are written and read for you",
+    )
+    .add_option(i18n::choice(date_format, "From my timezone", FROM_TIMEZONE).required(false))
+    .add_option(
+        [ClockFormat::H24, ClockFormat::H12]
+            .iter()
+            .fold(clock, |option, clock| {
+                i18n::choice(option, &clock.to_string(), clock.code())
+            })
+            .required(false),
+    )
+    .add_option(
+        [("Monday", "Mon"), ("Sunday", "Sun"), ("Saturday", "Sat")]
+            .iter()
+            .fold(first_day, |option, (name, value)| {
+                i18n::choice(option, name, value)
+            })
+            .required(false),
+    )
+    .add_option(i18n::choice(language, "Discord's language", FROM_DISCORD).required(false))
+}
+
+/// Run /preferences, without options it shows the current preferences
+pub async fn run_preferences(
+    http: &Http,
+    command: &CommandInteraction,
+    timezone_service: &Arc<TimezoneService>,
+    language: Language,
+) {
+    let user_id = command.user.id.get();
+    let mut preferences = match timezone_service.get_user_preferences(user_id).await {
+        Ok(Some(preferences)) => preferences,
+        Ok(None) => {
+            respond(
+                http,
+                command,
+                t(
+                    language,
+                    "❌ Set your timezone with `/timezone` first, your preferences are stored with it",
+                ),
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Error getting preferences of user {}: {:?}", user_id, e);
+            respond(
+                http,
+                command,
+                t(language, "❌ Error reading your preferences"),
+            )
+            .await;
+            return;
+        }
+    };
+
+    let option = |name: &str| {
+        command
+            .data
+            .options
+            .iter()
+            .find(|opt| opt.name == name)
+            .and_then(|opt| opt.value.as_str())
+    };
+    let is_update = ["date_format", "clock", "first_day", "language"]
+        .iter()
+        .any(|name| option(name).is_some());
+
+    if let Some(date_format) = option("date_format") {
+        preferences.date_format_chosen = date_format != FROM_TIMEZONE;
+        if preferences.date_format_chosen {
+            preferences.date_format = Some(date_format.to_string());
+        }
+    }
+    if let Some(clock) = option("clock") {
+        match clock.parse::<ClockFormat>() {
+            Ok(clock) => preferences.clock_format = clock,
+            Err(reason) => {
+                respond(http, command, &format!("❌ {}", te(language, &reason))).await;
+                return;
+            }
+        }
+    }
+    if let Some(day) = option("first_day") {
+        preferences.first_weekday = match <Weekday as WeekdayFormat>::from_str(day) {
+            Some(Weekday::Mon) => None,
+            Some(day) => Some(day),
+            None => {
+                respond(
+                    http,
+                    command,
+                    &tf(language, "❌ Unknown day '{day}'", &[("day", &day)]),
+                )
+                .await;
+                return;
+            }
+        };
+    }
+    if let Some(code) = option("language") {
+        preferences.language = match code {
+            FROM_DISCORD => None,
+            code => match code.parse::<Language>() {
+                Ok(code) => Some(code),
+                Err(reason) => {
+                    respond(http, command, &format!("❌ {}", te(language, &reason))).await;
+                    return;
+                }
+            },
+        };
+    }
+
+    if is_update {
+        if let Err(e) = timezone_service
+            .save_user_preferences(preferences.clone())
+            .await
+        {
+            error!("Error saving preferences of user {}: {:?}", user_id, e);
+            respond(
+                http,
+                command,
+                t(language, "❌ Error saving your preferences"),
+            )
+            .await;
+            return;
+        }
+        // Re-read to show the date format inferred from the timezone
+        if let Ok(Some(saved)) = timezone_service.get_user_preferences(user_id).await {
+            preferences = saved;
+        }
+    }
+
+    // A new language preference applies to this very reply
+    let language = if is_update {
+        timezone_service
+            .language_for(
+                user_id,
+                command.guild_id.map(|id| id.get()),
+                Some(&command.locale),
+            )
+            .await
+    } else {
+        language
+    };
+    let formatter = timezone_service
+        .formatter_for(user_id, None)
+        .await
+        .with_language(language);
+    let date_format = preferences
+        .date_format
+        .as_deref()
+        .map(get_date_format_description)
+        .unwrap_or(get_date_format_description("YMD"));
+    let embed = CreateEmbed::new()
+        .title(t(
+            language,
+            if is_update {
+                "✅ Preferences updated"
+            } else {
+                "⚙️ Your preferences"
+            },
+        ))
+        .description(tf(
+            language,
+            "Now in {timezone}: **{now}**",
+            &[
+                ("timezone", &preferences.timezone),
+                ("now", &formatter.date_time(timezone_service.now())),
+            ],
+        ))
+        .field(
+            t(language, "Date format"),
+            if preferences.date_format_chosen {
+                t(language, date_format).to_string()
+            } else {
+                tf(
+                    language,
+                    "{format} (from your timezone)",
+                    &[("format", &t(language, date_format))],
+                )
+            },
+            false,
+        )
+        .field(
+            t(language, "Clock"),
+            t(language, &preferences.clock_format.to_string()),
+            false,
+        )
+        .field(
+            t(language, "First day of the week"),
+            t(language, &preferences.week_start().to_string()),
+            false,
+        )
+        .field(
+            t(language, "Language"),
+            match preferences.language {
+                Some(chosen) => chosen.to_string(),
+                None => tf(
+                    language,
+                    "{language} (from Discord)",
+                    &[("language", &language)],
+                ),
+            },
+            false,
+        )
+        .color(Colour::BLUE);
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .ephemeral(true),
+    );
+    if let Err(e) = command.create_response(http, response).await {
+        error!("Error executing /preferences: {:?}", e);
+    }
+}
+
+async fn respond(http: &Http, command: &CommandInteraction, content: &str) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+    if let Err(e) = command.create_response(http, response).await {
+        error!("Error executing /preferences: {:?}", e);
+    }
+}
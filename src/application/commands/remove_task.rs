@@ -1,19 +1,22 @@
+use crate::application::i18n::{self, t, te, tf};
 use crate::application::services::TaskOrchestrator;
+use crate::domain::value_objects::Language;
 use serenity::all::{
-    ButtonStyle, CommandInteraction, ComponentInteraction, Context, CreateActionRow, CreateButton,
+    ButtonStyle, CommandInteraction, ComponentInteraction, CreateActionRow, CreateButton,
     CreateCommand, CreateInteractionResponse, CreateInteractionResponseMessage, CreateSelectMenu,
-    CreateSelectMenuKind, CreateSelectMenuOption,
+    CreateSelectMenuKind, CreateSelectMenuOption, Http,
 };
 use std::sync::Arc;
 
 pub fn register_remove_task_command() -> CreateCommand {
-    CreateCommand::new("remove_task").description("Task removal")
+    i18n::command("remove_task", "eliminar_tarea", "Task removal")
 }
 
 pub async fn run_remove_task(
-    ctx: &Context,
+    http: &Http,
     command: &CommandInteraction,
     task_orchestrator: &Arc<TaskOrchestrator>,
+    language: Language,
 ) {
     let user_id = command.user.id.get();
 
@@ -36,7 +39,7 @@ pub async fn run_remove_task(
                     "remove_menu_single",
                     CreateSelectMenuKind::String { options },
                 )
-                .placeholder("Single tasks")
+                .placeholder(t(language, "Single tasks"))
                 .min_values(1)
                 .max_values(1);
 
@@ -57,7 +60,7 @@ pub async fn run_remove_task(
                     "remove_menu_weekly",
                     CreateSelectMenuKind::String { options },
                 )
-                .placeholder("Weekly tasks")
+                .placeholder(t(language, "Weekly tasks"))
                 .min_values(1)
                 .max_values(1);
 
@@ -66,16 +69,16 @@ pub async fn run_remove_task(
 
             // remove all button
             let remove_all_button = CreateButton::new("remove_all_button")
-                .label("🗑️ Delete all tasks")
+                .label(t(language, "🗑️ Delete all tasks"))
                 .style(ButtonStyle::Danger);
             components.push(CreateActionRow::Buttons(vec![remove_all_button]));
 
             let _ = command
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::default()
-                            .content("Select a task to delete:")
+                            .content(t(language, "Select a task to delete:"))
                             .components(components)
                             .ephemeral(true),
                     ),
@@ -85,10 +88,10 @@ pub async fn run_remove_task(
         Err(error_message) => {
             let _ = command
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::default()
-                            .content(error_message)
+                            .content(te(language, &error_message))
                             .ephemeral(true),
                     ),
                 )
@@ -99,9 +102,10 @@ pub async fn run_remove_task(
 
 // Handler for component interactions
 pub async fn handle_remove_select(
-    ctx: &Context,
+    http: &Http,
     interaction: &ComponentInteraction,
     task_orchestrator: &Arc<TaskOrchestrator>,
+    language: Language,
 ) {
     use serenity::all::{
         ComponentInteractionDataKind, CreateActionRow, CreateButton, CreateInteractionResponse,
@@ -118,11 +122,14 @@ pub async fn handle_remove_select(
                         // delegate to TaskOrchestrator for business logic
                         match task_orchestrator.remove_user_task(task_id, user_id).await {
                             Ok(Some(removed_task)) => {
-                                let content =
-                                    format!("✅ Task **{}** deleted.", removed_task.title);
+                                let content = tf(
+                                    language,
+                                    "✅ Task **{title}** deleted.",
+                                    &[("title", &removed_task.title)],
+                                );
                                 let _ = interaction
                                     .create_response(
-                                        &ctx.http,
+                                        http,
                                         CreateInteractionResponse::Message(
                                             CreateInteractionResponseMessage::default()
                                                 .content(content)
@@ -132,10 +139,14 @@ pub async fn handle_remove_select(
                                     .await;
                             }
                             Ok(None) => {
-                                let content = format!("❌ Couldn't find task #{}.", task_id);
+                                let content = tf(
+                                    language,
+                                    "❌ Couldn't find task #{id}.",
+                                    &[("id", &task_id)],
+                                );
                                 let _ = interaction
                                     .create_response(
-                                        &ctx.http,
+                                        http,
                                         CreateInteractionResponse::Message(
                                             CreateInteractionResponseMessage::default()
                                                 .content(content)
@@ -147,10 +158,10 @@ pub async fn handle_remove_select(
                             Err(error) => {
                                 let _ = interaction
                                     .create_response(
-                                        &ctx.http,
+                                        http,
                                         CreateInteractionResponse::Message(
                                             CreateInteractionResponseMessage::default()
-                                                .content(format!("❌ {}", error))
+                                                .content(format!("❌ {}", te(language, &error)))
                                                 .ephemeral(true),
                                         ),
                                     )
@@ -161,10 +172,13 @@ pub async fn handle_remove_select(
                     Err(_) => {
                         let _ = interaction
                             .create_response(
-                                &ctx.http,
+                                http,
                                 CreateInteractionResponse::Message(
                                     CreateInteractionResponseMessage::default()
-                                        .content("❌ Invalid selection (couldn't parse task ID)")
+                                        .content(t(
+                                            language,
+                                            "❌ Invalid selection (couldn't parse task ID)",
+                                        ))
                                         .ephemeral(true),
                                 ),
                             )
@@ -177,7 +191,7 @@ pub async fn handle_remove_select(
         ComponentInteractionDataKind::Button { .. } => match interaction.data.custom_id.as_str() {
             "remove_all_button" => {
                 let confirm_yes = CreateButton::new("confirm_remove_all_yes")
-                    .label("✅ Yes")
+                    .label(t(language, "✅ Yes"))
                     .style(ButtonStyle::Danger);
                 let confirm_no = CreateButton::new("confirm_remove_all_no")
                     .label("❌ No")
@@ -187,10 +201,13 @@ pub async fn handle_remove_select(
 
                 let _ = interaction
                     .create_response(
-                        &ctx.http,
+                        http,
                         CreateInteractionResponse::Message(
                             CreateInteractionResponseMessage::default()
-                                .content("⚠️ Are you sure you want to delete all your tasks?")
+                                .content(t(
+                                    language,
+                                    "⚠️ Are you sure you want to delete all your tasks?",
+                                ))
                                 .components(rows)
                                 .ephemeral(true),
                         ),
@@ -203,10 +220,14 @@ pub async fn handle_remove_select(
                     Ok(count) => {
                         let _ = interaction
                             .create_response(
-                                &ctx.http,
+                                http,
                                 CreateInteractionResponse::Message(
                                     CreateInteractionResponseMessage::default()
-                                        .content(format!("✅ {} tasks deleted successfully", count))
+                                        .content(tf(
+                                            language,
+                                            "✅ {count} tasks deleted successfully",
+                                            &[("count", &count)],
+                                        ))
                                         .components(vec![])
                                         .ephemeral(false),
                                 ),
@@ -216,10 +237,10 @@ pub async fn handle_remove_select(
                     Err(error) => {
                         let _ = interaction
                             .create_response(
-                                &ctx.http,
+                                http,
                                 CreateInteractionResponse::Message(
                                     CreateInteractionResponseMessage::default()
-                                        .content(format!("Error: {}", error))
+                                        .content(format!("Error: {}", te(language, &error)))
                                         .components(vec![])
                                         .ephemeral(true),
                                 ),
@@ -231,10 +252,10 @@ pub async fn handle_remove_select(
             "confirm_remove_all_no" => {
                 let _ = interaction
                     .create_response(
-                        &ctx.http,
+                        http,
                         CreateInteractionResponse::Message(
                             CreateInteractionResponseMessage::default()
-                                .content("❌ Operation cancelled")
+                                .content(t(language, "❌ Operation cancelled"))
                                 .components(vec![])
                                 .ephemeral(true),
                         ),
@@ -247,10 +268,10 @@ pub async fn handle_remove_select(
         _ => {
             let _ = interaction
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::default()
-                            .content("❌ Interaction type not handled")
+                            .content(t(language, "❌ Interaction type not handled"))
                             .ephemeral(true),
                     ),
                 )
@@ -0,0 +1,238 @@
+use crate::application::commands::utils::date_format::get_date_format_description;
+use crate::application::i18n::{self, t, te};
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::entities::guild_settings::GuildSettings;
+use crate::domain::value_objects::Language;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, CreateCommand, CreateEmbed, CreateInteractionResponse,
+    CreateInteractionResponseMessage, Http, Permissions,
+};
+use serenity::model::colour::Colour;
+use std::sync::Arc;
+use tracing::error;
+
+/// Option value that clears a setting
+const UNSET: &str = "default";
+
+pub fn register_server_settings_command() -> CreateCommand {
+    i18n::command(
+        "server_settings",
+        "ajustes_servidor",
+        "Server defaults for channel tasks and members without a timezone",
+    )
+    .default_member_permissions(Permissions::MANAGE_GUILD)
+    .add_option(
+        i18n::option(
+            CommandOptionType::String,
+            "timezone",
+            "zona_horaria",
+            "Default timezone, e.g. Europe/Madrid, or 'default' to clear it",
+        )
+        .required(false),
+    )
+    .add_option(
+        i18n::choice(
+            i18n::option(
+                CommandOptionType::String,
+                "date_format",
+                "formato_fecha",
+                "Default date order for members without their own",
+            )
+            .add_string_choice("YYYY-MM-DD", "YMD")
+            .add_string_choice("DD-MM-YYYY", "DMY")
+            .add_string_choice("MM-DD-YYYY", "MDY"),
+            "Not set",
+            UNSET,
+        )
+        .required(false),
+    )
+    .add_option(
+        i18n::choice(
+            i18n::option(
+                CommandOptionType::String,
+                "language",
+                "idioma",
+                "Default language of the bot's replies",
+            )
+            .add_string_choice("English", "en")
+            .add_string_choice("Español", "es"),
+            "Not set",
+            UNSET,
+        )
+        .required(false),
+    )
+}
+
+/// Run /server_settings, without options it shows the current settings
+pub async fn run_server_settings(
+    http: &Http,
+    command: &CommandInteraction,
+    timezone_service: &Arc<TimezoneService>,
+    language: Language,
+) {
+    let Some(guild_id) = command.guild_id.map(|id| id.get()) else {
+        respond(
+            http,
+            command,
+            t(language, "❌ This command only works in a server"),
+        )
+        .await;
+        return;
+    };
+
+    // Discord hides the command from other members, but server admins can override that
+    let can_manage = command
+        .member
+        .as_ref()
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.manage_guild());
+    if !can_manage {
+        respond(
+            http,
+            command,
+            t(
+                language,
+                "❌ You need the **Manage Server** permission to change server settings",
+            ),
+        )
+        .await;
+        return;
+    }
+
+    let mut settings = match timezone_service.get_guild_settings(guild_id).await {
+        Ok(settings) => {
+            settings.unwrap_or_else(|| GuildSettings::new(guild_id, timezone_service.now()))
+        }
+        Err(e) => {
+            error!("Error getting settings of guild {}: {:?}", guild_id, e);
+            respond(
+                http,
+                command,
+                t(language, "❌ Error reading the server settings"),
+            )
+            .await;
+            return;
+        }
+    };
+
+    let option = |name: &str| {
+        command
+            .data
+            .options
+            .iter()
+            .find(|opt| opt.name == name)
+            .and_then(|opt| opt.value.as_str())
+            .map(str::trim)
+    };
+    let is_update = ["timezone", "date_format", "language"]
+        .iter()
+        .any(|name| option(name).is_some());
+
+    if let Some(query) = option("timezone") {
+        settings.timezone = if query.eq_ignore_ascii_case(UNSET) {
+            None
+        } else {
+            match timezone_service.resolve_timezone(query) {
+                Ok(timezone) => Some(timezone),
+                Err(reason) => {
+                    respond(
+                        http,
+                        command,
+                        &format!(
+                            "{}\n\n{}",
+                            t(language, "❌ **Invalid timezone**"),
+                            te(language, &reason)
+                        ),
+                    )
+                    .await;
+                    return;
+                }
+            }
+        };
+    }
+    if let Some(date_format) = option("date_format") {
+        settings.date_format = (date_format != UNSET).then(|| date_format.to_string());
+    }
+    if let Some(code) = option("language") {
+        settings.language = match code {
+            UNSET => None,
+            code => match code.parse::<Language>() {
+                Ok(code) => Some(code),
+                Err(reason) => {
+                    respond(http, command, &format!("❌ {}", te(language, &reason))).await;
+                    return;
+                }
+            },
+        };
+    }
+
+    if is_update && let Err(e) = timezone_service.save_guild_settings(settings.clone()).await {
+        error!("Error saving settings of guild {}: {:?}", guild_id, e);
+        respond(
+            http,
+            command,
+            t(language, "❌ Error saving the server settings"),
+        )
+        .await;
+        return;
+    }
+
+    let not_set = || t(language, "*not set*").to_string();
+    let embed = CreateEmbed::new()
+        .title(t(
+            language,
+            if is_update {
+                "✅ Server settings updated"
+            } else {
+                "⚙️ Server settings"
+            },
+        ))
+        .description(t(
+            language,
+            "Channel tasks use the server timezone, and members who never ran `/timezone` \
+             get these defaults",
+        ))
+        .field(
+            t(language, "Timezone"),
+            settings.timezone.clone().unwrap_or_else(not_set),
+            false,
+        )
+        .field(
+            t(language, "Date format"),
+            settings
+                .date_format
+                .as_deref()
+                .map(|format| t(language, get_date_format_description(format)).to_string())
+                .unwrap_or_else(not_set),
+            false,
+        )
+        .field(
+            t(language, "Language"),
+            settings
+                .language
+                .map(|language| language.to_string())
+                .unwrap_or_else(not_set),
+            false,
+        )
+        .color(Colour::BLUE);
+
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .ephemeral(true),
+    );
+    if let Err(e) = command.create_response(http, response).await {
+        error!("Error executing /server_settings: {:?}", e);
+    }
+}
+
+async fn respond(http: &Http, command: &CommandInteraction, content: &str) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+    if let Err(e) = command.create_response(http, response).await {
+        error!("Error executing /server_settings: {:?}", e);
+    }
+}
@@ -1,33 +1,45 @@
+use crate::application::i18n::{self, t};
 use crate::application::services::task_service::TaskService;
+use crate::domain::value_objects::Language;
 use serenity::builder::{
     CreateCommand, CreateInteractionResponse, CreateInteractionResponseMessage,
 };
+use serenity::cache::Cache;
+use serenity::http::Http;
 use serenity::model::prelude::*;
-use serenity::prelude::*;
 use std::sync::Arc;
 use tracing::{error};
 
 pub fn register_stats_command() -> CreateCommand {
-    CreateCommand::new("stats")
-        .description("📊 Show bot statistics (creator only)")
-        .dm_permission(false) // No permitir en DMs
-        .default_member_permissions(Permissions::empty()) // Sin permisos especiales por defecto
+    i18n::command(
+        "stats",
+        "estadisticas",
+        "📊 Show bot statistics (creator only)",
+    )
+    .dm_permission(false) // No permitir en DMs
+    .default_member_permissions(Permissions::empty()) // Sin permisos especiales por defecto
 }
 
 pub async fn run_stats(
-    ctx: &Context,
+    http: &Http,
+    cache: &Cache,
     command: &CommandInteraction,
     task_service: &Arc<TaskService>,
+    creator_id: Option<u64>,
+    language: Language,
 ) {
     // Verify that the user is the bot creator in the test server
-    if !is_authorized_user(&command.user) {
+    if !is_authorized_user(&command.user, creator_id) {
         let builder = CreateInteractionResponse::Message(
             CreateInteractionResponseMessage::default()
-                .content("❌ You are not authorized to use this command.")
+                .content(t(
+                    language,
+                    "❌ You are not authorized to use this command.",
+                ))
                 .ephemeral(true),
         );
         
-        if let Err(e) = command.create_response(&ctx.http, builder).await {
+        if let Err(e) = command.create_response(http, builder).await {
             error!("Failed to send stats unauthorized response: {}", e);
         }
         return;
@@ -40,10 +52,10 @@ pub async fn run_stats(
             error!("Failed to get total tasks count: {}", e);
             let builder = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::default()
-                    .content("❌ Failed to retrieve statistics.")
+                    .content(t(language, "❌ Failed to retrieve statistics."))
                     .ephemeral(true),
             );
-            if let Err(e) = command.create_response(&ctx.http, builder).await {
+            if let Err(e) = command.create_response(http, builder).await {
                 error!("Failed to send stats error response: {}", e);
             }
             return;
@@ -51,30 +63,32 @@ pub async fn run_stats(
     };
 
     // Get the number of servers using Serenity's cache
-    let server_count = ctx.cache.guilds().len();
+    let server_count = cache.guilds().len();
 
     // Create response embed
     let embed = CreateInteractionResponseMessage::default()
         .embed(
             serenity::builder::CreateEmbed::default()
-                .title("Statistics")
-                .description("Private statistics")
-                .field("All tasks", format!("{}", total_tasks), true)
-                .field("Servers registered", format!("{}", server_count), true)
+                .title(t(language, "Statistics"))
+                .description(t(language, "Private statistics"))
+                .field(t(language, "All tasks"), format!("{}", total_tasks), true)
+                .field(
+                    t(language, "Servers registered"),
+                    format!("{}", server_count),
+                    true,
+                )
                 .color(0x00FF00) // Green
         )
         .ephemeral(true); // Visible only to the user who executed the command
 
     let builder = CreateInteractionResponse::Message(embed);
 
-    if let Err(e) = command.create_response(&ctx.http, builder).await {
+    if let Err(e) = command.create_response(http, builder).await {
         error!("Failed to send stats response: {}", e);
     }
 }
 
 /// Verifies if the user is authorized (only the bot creator)
-fn is_authorized_user(user: &User) -> bool {
-    const CREATOR_ID: u64 = 300869447475003393;
-    
-    user.id == CREATOR_ID
+fn is_authorized_user(user: &User, creator_id: Option<u64>) -> bool {
+    creator_id == Some(user.id.get())
 }
\ No newline at end of file
@@ -1,35 +1,43 @@
 use crate::application::commands::utils::date_format::{
     get_inferred_date_format_info, get_user_date_format_info,
 };
+use crate::application::i18n::{self, t, tf};
+use crate::application::services::TaskOrchestrator;
+use crate::application::services::task_orchestrator::TimezoneChangePreview;
 use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::value_objects::{Language, TimezoneChangeMode};
 use serenity::builder::CreateEmbedFooter;
 use serenity::builder::{
     CreateActionRow, CreateButton, CreateEmbed, CreateInteractionResponse,
     CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuOption,
 };
+use serenity::http::Http;
 use serenity::model::application::CommandInteraction;
 use serenity::model::colour::Colour;
 use serenity::prelude::*;
 use std::sync::Arc;
 use tracing::error;
 
+/// Affected tasks listed in the timezone change preview
+const PREVIEW_LIMIT: usize = 10;
+
 pub fn register_timezone_command() -> serenity::builder::CreateCommand {
-    serenity::builder::CreateCommand::new("timezone")
-        .description("Set your time zone for tasks")
-        .add_option(
-            serenity::builder::CreateCommandOption::new(
-                serenity::model::application::CommandOptionType::String,
-                "location",
-                "Your country, city or state (e.g. Argentina, New York, Madrid)",
-            )
-            .required(true),
+    i18n::command("timezone", "zona_horaria", "Set your time zone for tasks").add_option(
+        i18n::option(
+            serenity::model::application::CommandOptionType::String,
+            "location",
+            "ubicacion",
+            "Your city, country, state or zone (e.g. Argentina, Nueva York, Madrid, CET)",
         )
+        .required(true),
+    )
 }
 
 pub async fn run_timezone_command(
-    ctx: &Context,
+    http: &Http,
     command: &CommandInteraction,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     let user_id = command.user.id.get();
     let location = match crate::application::commands::utils::get_string::get_string_option(
@@ -40,10 +48,13 @@ pub async fn run_timezone_command(
         None => {
             let _ = command
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::new()
-                            .content("❌ You must provide a location (country, city or state)")
+                            .content(t(
+                                language,
+                                "❌ You must provide a location (country, city or state)",
+                            ))
                             .ephemeral(true),
                     ),
                 )
@@ -58,12 +69,13 @@ pub async fn run_timezone_command(
     if search_results.is_empty() {
         let _ = command
             .create_response(
-                &ctx.http,
+                http,
                 CreateInteractionResponse::Message(
                     CreateInteractionResponseMessage::new()
-                        .content(format!(
-                            "❌ No time zones found for '{}'. Try a more specific name",
-                            location
+                        .content(tf(
+                            language,
+                            "❌ No time zones found for '{location}'. Try a more specific name",
+                            &[("location", &location)],
                         ))
                         .ephemeral(true),
                 ),
@@ -74,56 +86,67 @@ pub async fn run_timezone_command(
 
     // if there are multiple results, show selection
     if search_results.len() > 1 {
-        show_timezone_selection(ctx, command, user_id, search_results, timezone_service).await;
+        show_timezone_selection(
+            http,
+            command,
+            user_id,
+            search_results,
+            timezone_service,
+            language,
+        )
+        .await;
     } else {
         // if there is only one result, show confirmation directly
         let timezone_info = search_results[0];
-        show_timezone_confirmation(ctx, command, user_id, timezone_info, timezone_service).await;
+        show_timezone_confirmation(
+            http,
+            command,
+            user_id,
+            timezone_info,
+            timezone_service,
+            language,
+        )
+        .await;
     }
 }
 
 // Show timezone selection menu when there are multiple results
 async fn show_timezone_selection(
-    ctx: &Context,
+    http: &Http,
     command: &CommandInteraction,
     _user_id: u64,
     timezones: Vec<&crate::infrastructure::timezone::timezone_manager::TimezoneInfo>,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     let mut options = Vec::new();
 
-    for (_index, tz_info) in timezones.iter().enumerate() {
-        let current_time = match timezone_service.get_current_time_for_timezone(&tz_info.utc[0]) {
+    for tz_info in timezones.iter() {
+        let current_time = match timezone_service.get_current_time_for_timezone(&tz_info.id) {
             Ok(time) => time,
-            Err(_) => "Error al obtener hora".to_string(),
+            Err(_) => t(language, "Error obtaining the time").to_string(),
         };
 
-        let label = if tz_info.utc.len() == 1 {
-            format!("{} - {}", tz_info.text, current_time)
-        } else {
-            format!("{} - {}", tz_info.value, current_time)
-        };
+        let mut label = format!("{} - {}", tz_info.text, current_time);
+        if label.chars().count() > 100 {
+            label = label.chars().take(100).collect();
+        }
 
-        let mut description = if tz_info.utc.len() == 1 {
-            tz_info.utc[0].clone()
+        let description = if tz_info.abbreviations.is_empty() {
+            tz_info.id.clone()
         } else {
-            tz_info.utc[0..2].join(", ") + "..."
+            format!("{} ({})", tz_info.id, tz_info.abbreviations.join("/"))
         };
 
-        if description.len() > 50 {
-            description.truncate(50);
-        }
-
-        let timezone_id = &tz_info.utc[0];
         options
-            .push(CreateSelectMenuOption::new(label, timezone_id.clone()).description(description));
+            .push(CreateSelectMenuOption::new(label, tz_info.id.clone()).description(description));
     }
 
     let select_menu = CreateSelectMenu::new(
         "timezone_select",
         serenity::builder::CreateSelectMenuKind::String { options },
     )
-    .placeholder("Select your timezone")
+    .placeholder(t(language, "Select your timezone"))
     .min_values(1)
     .max_values(1);
 
@@ -131,10 +154,10 @@ async fn show_timezone_selection(
 
     let _ = command
         .create_response(
-            &ctx.http,
+            http,
             CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::new()
-                    .content("🔍 **Select your timezone:**")
+                    .content(t(language, "🔍 **Select your timezone:**"))
                     .components(vec![action_row])
                     .ephemeral(true),
             ),
@@ -144,23 +167,24 @@ async fn show_timezone_selection(
 
 /// Manage /timezone confirmation when there is a single result
 async fn show_timezone_confirmation(
-    ctx: &Context,
+    http: &Http,
     command: &CommandInteraction,
     _user_id: u64,
     timezone_info: &crate::infrastructure::timezone::timezone_manager::TimezoneInfo,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
-    let timezone_id = &timezone_info.utc[0];
+    let timezone_id = &timezone_info.id;
     let current_time = match timezone_service.get_current_time_for_timezone(timezone_id) {
         Ok(time) => time,
         Err(e) => {
             error!("Error getting current time: {:?}", e);
             let _ = command
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::new()
-                            .content("❌ Error obtaining current time")
+                            .content(t(language, "❌ Error obtaining current time"))
                             .ephemeral(true),
                     ),
                 )
@@ -173,30 +197,26 @@ async fn show_timezone_confirmation(
     let (_inferred_format, format_description) =
         get_inferred_date_format_info(timezone_service, timezone_id);
 
-    let embed = CreateEmbed::new()
-        .title("🕐 Timezone confirmation")
-        .description(format!(
-            "**Selected timezone:** {}\n**Current time:** `{}`\n**Date format:** {}\n\nIs this your correct local time?",
-            timezone_info.text, current_time, format_description
-        ))
-        .colour(Colour::DARK_GREEN)
-        .footer(CreateEmbedFooter::new(
-            "Date format will be automatically set based on your location. You can change it later if needed.",
-        ));
+    let embed = confirmation_embed(
+        &timezone_info.text,
+        &current_time,
+        format_description,
+        language,
+    );
 
     let accept_button = CreateButton::new(format!("timezone_confirm:{}", timezone_id))
-        .label("✅ Yes, it's correct")
+        .label(t(language, "✅ Yes, it's correct"))
         .style(serenity::all::ButtonStyle::Success);
 
     let cancel_button = CreateButton::new("timezone_cancel")
-        .label("❌ Cancel")
+        .label(t(language, "❌ Cancel"))
         .style(serenity::all::ButtonStyle::Danger);
 
     let action_row = CreateActionRow::Buttons(vec![accept_button, cancel_button]);
 
     let _ = command
         .create_response(
-            &ctx.http,
+            http,
             CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::new()
                     .embed(embed)
@@ -209,9 +229,10 @@ async fn show_timezone_confirmation(
 
 /// Manage /timezone when there is multiple results
 pub async fn handle_timezone_select(
-    ctx: &Context,
+    http: &Http,
     interaction: &serenity::model::application::ComponentInteraction,
     timezone_service: &Arc<TimezoneService>,
+    language: Language,
 ) {
     // get the selected timezone_id
     let timezone_id = match &interaction.data.kind {
@@ -221,10 +242,10 @@ pub async fn handle_timezone_select(
             } else {
                 let _ = interaction
                     .create_response(
-                        &ctx.http,
+                        http,
                         CreateInteractionResponse::Message(
                             CreateInteractionResponseMessage::new()
-                                .content("❌ No selection was found")
+                                .content(t(language, "❌ No selection was found"))
                                 .ephemeral(true),
                         ),
                     )
@@ -235,10 +256,10 @@ pub async fn handle_timezone_select(
         _ => {
             let _ = interaction
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::new()
-                            .content("❌ Interaction type unvalid")
+                            .content(t(language, "❌ Interaction type unvalid"))
                             .ephemeral(true),
                     ),
                 )
@@ -255,10 +276,10 @@ pub async fn handle_timezone_select(
             if search_results.is_empty() {
                 let _ = interaction
                     .create_response(
-                        &ctx.http,
+                        http,
                         CreateInteractionResponse::Message(
                             CreateInteractionResponseMessage::new()
-                                .content("❌ Timezone not found")
+                                .content(t(language, "❌ Timezone not found"))
                                 .ephemeral(true),
                         ),
                     )
@@ -276,10 +297,10 @@ pub async fn handle_timezone_select(
             error!("Error getting current time: {:?}", e);
             let _ = interaction
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::new()
-                            .content("❌ Error obtaining current time")
+                            .content(t(language, "❌ Error obtaining current time"))
                             .ephemeral(true),
                     ),
                 )
@@ -292,30 +313,26 @@ pub async fn handle_timezone_select(
     let (_inferred_format, format_description) =
         get_inferred_date_format_info(timezone_service, &timezone_id);
 
-    let embed = CreateEmbed::new()
-        .title("🕐 Timezone confirmation")
-        .description(format!(
-            "**Selected timezone:** {}\n**Current time:** `{}`\n**Date format:** {}\n\nIs this your correct local time?",
-            timezone_info.text, current_time, format_description
-        ))
-        .colour(Colour::DARK_GREEN)
-        .footer(CreateEmbedFooter::new(
-            "Date format will be automatically set based on your location. You can change it later if needed.",
-        ));
+    let embed = confirmation_embed(
+        &timezone_info.text,
+        &current_time,
+        format_description,
+        language,
+    );
 
     let accept_button = CreateButton::new(format!("timezone_confirm:{}", timezone_id))
-        .label("✅ Yes, it's correct")
+        .label(t(language, "✅ Yes, it's correct"))
         .style(serenity::all::ButtonStyle::Success);
 
     let cancel_button = CreateButton::new("timezone_cancel")
-        .label("❌ Cancel")
+        .label(t(language, "❌ Cancel"))
         .style(serenity::all::ButtonStyle::Danger);
 
     let action_row = CreateActionRow::Buttons(vec![accept_button, cancel_button]);
 
     let _ = interaction
         .create_response(
-            &ctx.http,
+            http,
             CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::new()
                     .embed(embed)
@@ -326,44 +343,219 @@ pub async fn handle_timezone_select(
         .await;
 }
 
+/// Asks whether `timezone` shows the right local time, before saving it
+fn confirmation_embed(
+    timezone: &str,
+    current_time: &str,
+    format_description: &str,
+    language: Language,
+) -> CreateEmbed {
+    CreateEmbed::new()
+        .title(t(language, "🕐 Timezone confirmation"))
+        .description(tf(
+            language,
+            "**Selected timezone:** {timezone}\n**Current time:** `{time}`\n**Date format:** {date_format}\n\nIs this your correct local time?",
+            &[
+                ("timezone", &timezone),
+                ("time", &current_time),
+                ("date_format", &t(language, format_description)),
+            ],
+        ))
+        .colour(Colour::DARK_GREEN)
+        .footer(CreateEmbedFooter::new(t(
+            language,
+            "Date format will be automatically set based on your location. You can change it later if needed.",
+        )))
+}
+
 pub async fn handle_timezone_confirm(
-    ctx: &Context,
+    http: &Http,
     interaction: &serenity::model::application::ComponentInteraction,
     timezone_id: &str,
     timezone_service: &Arc<TimezoneService>,
+    task_orchestrator: &Arc<TaskOrchestrator>,
+    language: Language,
 ) {
     let user_id = interaction.user.id.get();
 
-    match timezone_service
-        .set_user_timezone(user_id, timezone_id)
+    let preview = match task_orchestrator
+        .preview_timezone_change(user_id, timezone_id)
         .await
     {
-        Ok(()) => {
+        Ok(preview) => preview,
+        Err(e) => {
+            error!("Error previewing timezone change: {}", e);
+            respond_error(http, interaction, t(language, "❌ Error setting time zone")).await;
+            return;
+        }
+    };
+
+    // Nothing to re-anchor, the timezone is simply set
+    if preview.is_empty() {
+        apply_timezone_change(
+            http,
+            interaction,
+            timezone_id,
+            TimezoneChangeMode::KeepAbsoluteTime,
+            timezone_service,
+            task_orchestrator,
+            language,
+        )
+        .await;
+        return;
+    }
+
+    show_timezone_change_preview(http, interaction, timezone_id, &preview, language).await;
+}
+
+/// Ask whether the tasks that follow the user's timezone keep their local or absolute time
+async fn show_timezone_change_preview(
+    http: &Http,
+    interaction: &serenity::model::application::ComponentInteraction,
+    timezone_id: &str,
+    preview: &[TimezoneChangePreview],
+    language: Language,
+) {
+    let mut lines: Vec<String> = preview
+        .iter()
+        .take(PREVIEW_LIMIT)
+        .map(|task| {
+            tf(
+                language,
+                "• **{title}** (#{id}): `{current}` → `{moved}` with absolute times",
+                &[
+                    ("title", &task.title),
+                    ("id", &task.task_id),
+                    ("current", &task.current),
+                    ("moved", &task.keeping_absolute),
+                ],
+            )
+        })
+        .collect();
+    if preview.len() > PREVIEW_LIMIT {
+        lines.push(tf(
+            language,
+            "…and {count} more",
+            &[("count", &(preview.len() - PREVIEW_LIMIT))],
+        ));
+    }
+
+    let embed = CreateEmbed::new()
+        .title(t(language, "🌍 What should happen to your tasks?"))
+        .description(tf(
+            language,
+            "Moving to `{timezone}` affects {count} of your tasks:\n{tasks}",
+            &[
+                ("timezone", &timezone_id),
+                ("count", &preview.len()),
+                ("tasks", &lines.join("\n")),
+            ],
+        ))
+        .field(
+            t(language, "🕘 Keep local time"),
+            t(
+                language,
+                "Tasks stay at the same time on your clock, so they fire at another moment",
+            ),
+            false,
+        )
+        .field(
+            t(language, "⏱️ Keep absolute time"),
+            t(
+                language,
+                "Tasks fire at the same moment, which is another time on your clock",
+            ),
+            false,
+        )
+        .color(Colour::ORANGE)
+        .footer(CreateEmbedFooter::new(t(
+            language,
+            "Tasks with their own timezone are not affected",
+        )));
+
+    let keep_local = CreateButton::new(format!("timezone_keep_local:{}", timezone_id))
+        .label(t(language, "Keep local time"))
+        .style(serenity::model::application::ButtonStyle::Success);
+    let keep_absolute = CreateButton::new(format!("timezone_keep_absolute:{}", timezone_id))
+        .label(t(language, "Keep absolute time"))
+        .style(serenity::model::application::ButtonStyle::Primary);
+    let cancel_button = CreateButton::new("timezone_cancel")
+        .label(t(language, "Cancel"))
+        .style(serenity::model::application::ButtonStyle::Danger);
+
+    let _ = interaction
+        .create_response(
+            http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(vec![CreateActionRow::Buttons(vec![
+                        keep_local,
+                        keep_absolute,
+                        cancel_button,
+                    ])])
+                    .ephemeral(true),
+            ),
+        )
+        .await;
+}
+
+/// Set the timezone and move the user's tasks according to `mode`, from the preview buttons
+pub async fn apply_timezone_change(
+    http: &Http,
+    interaction: &serenity::model::application::ComponentInteraction,
+    timezone_id: &str,
+    mode: TimezoneChangeMode,
+    timezone_service: &Arc<TimezoneService>,
+    task_orchestrator: &Arc<TaskOrchestrator>,
+    language: Language,
+) {
+    let user_id = interaction.user.id.get();
+
+    match task_orchestrator
+        .change_user_timezone(user_id, timezone_id, mode)
+        .await
+    {
+        Ok(moved) => {
             let current_time = match timezone_service.get_current_time_for_user(user_id).await {
                 Ok(time) => time,
-                Err(_) => "Error obtaining hour".to_string(),
+                Err(_) => t(language, "Error obtaining the time").to_string(),
             };
 
             // Use utility function to get user's date format info
-            let date_format_info = get_user_date_format_info(timezone_service, user_id).await;
-
-            let embed = CreateEmbed::new()
-                .title("✅ Timezone set up correctly!")
-                .description(format!(
-                    "Timezone configured successfully for {}!",
-                    interaction.user.mention()
+            let date_format_info =
+                get_user_date_format_info(timezone_service, user_id, language).await;
+
+            let mut embed = CreateEmbed::new()
+                .title(t(language, "✅ Timezone set up correctly!"))
+                .description(tf(
+                    language,
+                    "Timezone configured successfully for {user}!",
+                    &[("user", &interaction.user.mention())],
                 ))
-                .field("Timezone", format!("`{}`", timezone_id), true)
-                .field("Current Time", format!("`{}`", current_time), true)
-                .field("Date Format", date_format_info, false)
+                .field(t(language, "Timezone"), format!("`{}`", timezone_id), true)
+                .field(t(language, "Current Time"), format!("`{}`", current_time), true)
+                .field(t(language, "Date Format"), date_format_info, false)
                 .color(serenity::model::colour::Colour::DARK_GREEN)
-                .footer(CreateEmbedFooter::new(
+                .footer(CreateEmbedFooter::new(t(
+                    language,
                     "When creating tasks, the date field will now show the format familiar to your region",
-                ));
+                )));
+            if moved > 0 {
+                embed = embed.field(
+                    t(language, "Tasks"),
+                    tf(
+                        language,
+                        "{count} tasks keep their local time",
+                        &[("count", &moved)],
+                    ),
+                    false,
+                );
+            }
 
             let _ = interaction
                 .create_response(
-                    &ctx.http,
+                    http,
                     CreateInteractionResponse::Message(
                         CreateInteractionResponseMessage::new()
                             .embed(embed)
@@ -373,31 +565,48 @@ pub async fn handle_timezone_confirm(
                 .await;
         }
         Err(e) => {
-            error!("Error setting timezone: {:?}", e);
-            let _ = interaction
-                .create_response(
-                    &ctx.http,
-                    CreateInteractionResponse::Message(
-                        CreateInteractionResponseMessage::new()
-                            .content("❌ Error setting time zone")
-                            .ephemeral(true),
-                    ),
-                )
-                .await;
+            error!("Error setting timezone: {}", e);
+            respond_error(
+                http,
+                interaction,
+                t(
+                    language,
+                    "❌ Error setting time zone, your tasks were not changed",
+                ),
+            )
+            .await;
         }
     }
 }
 
+async fn respond_error(
+    http: &Http,
+    interaction: &serenity::model::application::ComponentInteraction,
+    message: &str,
+) {
+    let _ = interaction
+        .create_response(
+            http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(message)
+                    .ephemeral(true),
+            ),
+        )
+        .await;
+}
+
 pub async fn handle_timezone_cancel(
-    ctx: &Context,
+    http: &Http,
     interaction: &serenity::model::application::ComponentInteraction,
+    language: Language,
 ) {
     let _ = interaction
         .create_response(
-            &ctx.http,
+            http,
             CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::new()
-                    .content("❌ Time zone setting canceled")
+                    .content(t(language, "❌ Time zone setting canceled"))
                     .ephemeral(false),
             ),
         )
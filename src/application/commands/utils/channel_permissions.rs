@@ -0,0 +1,171 @@
+use crate::domain::value_objects::{MentionTarget, UserError};
+use crate::domain::value_objects::mention::format_mentions;
+use serenity::all::{Channel, ChannelId, ChannelType, GuildId, Permissions, RoleId, UserId};
+use serenity::http::Http;
+use tracing::warn;
+
+/// What the bot needs in a channel to post task notifications
+const BOT_PERMISSIONS: Permissions = Permissions::VIEW_CHANNEL
+    .union(Permissions::SEND_MESSAGES)
+    .union(Permissions::EMBED_LINKS);
+
+/// What the task owner needs there, so the bot can't be used to post where they can't
+const USER_PERMISSIONS: Permissions = Permissions::VIEW_CHANNEL.union(Permissions::SEND_MESSAGES);
+
+/// Channel types a task can be posted to, also the only ones offered by the `channel` options
+pub const TASK_CHANNEL_TYPES: [ChannelType; 2] = [ChannelType::Text, ChannelType::News];
+
+/// Check that `channel_id` is a text channel of `guild_id` where both the bot and `user_id` can
+/// post task notifications pinging `mentions`. The error names the missing permissions
+pub async fn check_task_channel(
+    http: &Http,
+    guild_id: u64,
+    channel_id: u64,
+    user_id: u64,
+    mentions: &[MentionTarget],
+) -> Result<(), UserError> {
+    let channel_mention = format!("<#{}>", channel_id);
+    let channel = match http.get_channel(ChannelId::new(channel_id)).await {
+        Ok(Channel::Guild(channel)) => channel,
+        Ok(_) => {
+            return Err(
+                UserError::new("{channel} is not a server channel").with("channel", channel_mention),
+            );
+        }
+        // Discord answers 403 for channels the bot can't see
+        Err(e) => {
+            warn!("Failed to fetch channel {}: {}", channel_id, e);
+            return Err(UserError::new(
+                "I can't access {channel}, I need the **View Channel** permission there",
+            )
+            .with("channel", channel_mention));
+        }
+    };
+
+    if channel.guild_id.get() != guild_id {
+        return Err(
+            UserError::new("{channel} belongs to another server").with("channel", channel_mention),
+        );
+    }
+    if !TASK_CHANNEL_TYPES.contains(&channel.kind) {
+        return Err(UserError::new(
+            "{channel} is not a text channel, pick a text or announcement channel",
+        )
+        .with("channel", channel_mention));
+    }
+
+    let guild_id = GuildId::new(guild_id);
+    let (guild, bot) = match tokio::try_join!(http.get_guild(guild_id), http.get_current_user()) {
+        Ok(found) => found,
+        Err(e) => {
+            warn!(
+                "Failed to fetch guild {} for a permission check: {}",
+                guild_id, e
+            );
+            return Err("Couldn't check the channel permissions, try again later".into());
+        }
+    };
+    let (bot_member, user_member) = match tokio::try_join!(
+        http.get_member(guild_id, bot.id),
+        http.get_member(guild_id, UserId::new(user_id))
+    ) {
+        Ok(members) => members,
+        Err(e) => {
+            warn!(
+                "Failed to fetch members of guild {} for a permission check: {}",
+                guild_id, e
+            );
+            return Err("Couldn't check the channel permissions, try again later".into());
+        }
+    };
+
+    let bot_permissions = guild.user_permissions_in(&channel, &bot_member);
+    let user_permissions = guild.user_permissions_in(&channel, &user_member);
+
+    let bot_missing = missing_permissions(bot_permissions, BOT_PERMISSIONS);
+    if !bot_missing.is_empty() {
+        return Err(
+            UserError::new("I'm missing the **{permissions}** permission in {channel}")
+                .with("permissions", bot_missing.join(", "))
+                .with("channel", channel_mention),
+        );
+    }
+
+    let user_missing = missing_permissions(user_permissions, USER_PERMISSIONS);
+    if !user_missing.is_empty() {
+        return Err(UserError::new(
+            "You need the **{permissions}** permission in {channel} to send task notifications there",
+        )
+        .with("permissions", user_missing.join(", "))
+        .with("channel", channel_mention));
+    }
+
+    // Pings that need Mention Everyone: broadcasts and roles not open to everyone. Discord would
+    // silently drop them when the task fires
+    let mut restricted = Vec::new();
+    for target in mentions {
+        match target {
+            MentionTarget::Role(id) => match guild.roles.get(&RoleId::new(*id)) {
+                Some(role) if role.mentionable => {}
+                Some(_) => restricted.push(*target),
+                None => {
+                    return Err(
+                        UserError::new("{role} is not a role of this server").with("role", target),
+                    );
+                }
+            },
+            target if target.is_broadcast() => restricted.push(*target),
+            _ => {}
+        }
+    }
+    if restricted.is_empty() {
+        return Ok(());
+    }
+
+    let required = Permissions::MENTION_EVERYONE;
+    let bot_missing = missing_permissions(bot_permissions, required);
+    if !bot_missing.is_empty() {
+        return Err(UserError::new(
+            "I'm missing the **{permissions}** permission in {channel} to ping {mentions}",
+        )
+        .with("permissions", bot_missing.join(", "))
+        .with("channel", channel_mention)
+        .with("mentions", format_mentions(&restricted)));
+    }
+    let user_missing = missing_permissions(user_permissions, required);
+    if !user_missing.is_empty() {
+        return Err(UserError::new(
+            "You need the **{permissions}** permission in {channel} to ping {mentions}",
+        )
+        .with("permissions", user_missing.join(", "))
+        .with("channel", channel_mention)
+        .with("mentions", format_mentions(&restricted)));
+    }
+
+    Ok(())
+}
+
+/// Names of the `required` permissions absent from `granted`, e.g. "Send Messages"
+fn missing_permissions(granted: Permissions, required: Permissions) -> Vec<&'static str> {
+    (required - granted).get_permission_names()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_only_the_missing_permissions() {
+        assert!(
+            missing_permissions(
+                Permissions::ADMINISTRATOR | BOT_PERMISSIONS,
+                BOT_PERMISSIONS
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            missing_permissions(Permissions::VIEW_CHANNEL, BOT_PERMISSIONS),
+            vec!["Embed Links", "Send Messages"]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 /// Utility functions for date format handling in commands
+use crate::application::i18n::t;
 use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::value_objects::Language;
 use std::sync::Arc;
 
 /// Get a human-readable description of a date format
@@ -29,12 +31,13 @@ pub fn get_inferred_date_format_info(
 pub async fn get_user_date_format_info(
     timezone_service: &Arc<TimezoneService>,
     user_id: u64,
+    language: Language,
 ) -> String {
     match timezone_service.get_user_date_format(user_id).await {
         Ok(Some(format)) => {
-            let format_desc = get_date_format_description(&format);
+            let format_desc = t(language, get_date_format_description(&format));
             format!("`{}` - {}", format, format_desc)
         }
-        _ => "Automatically set based on your location".to_string(),
+        _ => t(language, "Automatically set based on your location").to_string(),
     }
 }
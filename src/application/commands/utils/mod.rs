@@ -1,4 +1,5 @@
+pub mod channel_permissions;
 pub mod date_format;
 pub mod get_string;
-pub mod time_remaining;
+pub use channel_permissions::{TASK_CHANNEL_TYPES, check_task_channel};
 pub use get_string::get_string_option;
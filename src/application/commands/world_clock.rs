@@ -0,0 +1,147 @@
+use crate::application::i18n::{self, t, te, tf};
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::value_objects::Language;
+use crate::domain::value_objects::mention::parse_user_mentions;
+use chrono::Offset;
+use chrono_tz::Tz;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, CreateAllowedMentions, CreateCommand, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, Http,
+};
+use serenity::model::colour::Colour;
+use std::sync::Arc;
+use tracing::error;
+
+/// Members listed at most, one embed line each
+const MAX_MEMBERS: usize = 25;
+
+pub fn register_time_command() -> CreateCommand {
+    i18n::command(
+        "time",
+        "hora",
+        "Show the current local time of other members",
+    )
+    .add_option(
+        i18n::option(
+            CommandOptionType::String,
+            "members",
+            "miembros",
+            "Members to show, e.g. @Ana @Bob (yourself if empty)",
+        )
+        .required(false),
+    )
+}
+
+pub async fn run_time_command(
+    http: &Http,
+    command: &CommandInteraction,
+    timezone_service: &Arc<TimezoneService>,
+    language: Language,
+) {
+    let members = command
+        .data
+        .options
+        .iter()
+        .find(|opt| opt.name == "members")
+        .and_then(|opt| opt.value.as_str())
+        .unwrap_or_default();
+    let mut user_ids = match parse_user_mentions(members) {
+        Ok(user_ids) => user_ids,
+        Err(reason) => {
+            respond(
+                http,
+                command,
+                CreateInteractionResponseMessage::new()
+                    .content(format!(
+                        "{}\n\n{}",
+                        t(language, "❌ **Invalid members**"),
+                        te(language, &reason)
+                    ))
+                    .ephemeral(true),
+            )
+            .await;
+            return;
+        }
+    };
+    if user_ids.is_empty() {
+        user_ids.push(command.user.id.get());
+    }
+    user_ids.truncate(MAX_MEMBERS);
+
+    let now = timezone_service.now();
+    // Hours are written with the caller's clock
+    let formatter = timezone_service
+        .formatter_for(command.user.id.get(), command.guild_id.map(|id| id.get()))
+        .await;
+    // (UTC offset in seconds, line), members without a timezone go last
+    let mut lines = Vec::new();
+    for user_id in user_ids {
+        let timezone = match timezone_service.get_user_timezone(user_id).await {
+            Ok(timezone) => timezone,
+            Err(e) => {
+                error!("Error getting timezone of user {}: {:?}", user_id, e);
+                None
+            }
+        };
+
+        let line = match timezone.as_deref().and_then(|tz| tz.parse::<Tz>().ok()) {
+            Some(zone) => {
+                let local = now.with_timezone(&zone);
+                (
+                    local.offset().fix().local_minus_utc(),
+                    format!(
+                        "<@{}> · **{}** {} · `{}`",
+                        user_id,
+                        formatter.in_zone(zone).time(now),
+                        t(language, &local.format("%a").to_string()),
+                        zone.name()
+                    ),
+                )
+            }
+            None => (
+                i32::MAX,
+                tf(
+                    language,
+                    "<@{user}> · no timezone set",
+                    &[("user", &user_id)],
+                ),
+            ),
+        };
+        lines.push(line);
+    }
+    lines.sort_by_key(|(offset, _)| *offset);
+
+    let embed = CreateEmbed::new()
+        .title(t(language, "🕐 World clock"))
+        .description(
+            lines
+                .into_iter()
+                .map(|(_, line)| line)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+        .color(Colour::BLUE);
+
+    // Listing people shouldn't ping them
+    respond(
+        http,
+        command,
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .allowed_mentions(CreateAllowedMentions::new()),
+    )
+    .await;
+}
+
+async fn respond(
+    http: &Http,
+    command: &CommandInteraction,
+    message: CreateInteractionResponseMessage,
+) {
+    if let Err(e) = command
+        .create_response(http, CreateInteractionResponse::Message(message))
+        .await
+    {
+        error!("Error executing /time: {:?}", e);
+    }
+}
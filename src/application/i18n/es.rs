@@ -0,0 +1,966 @@
+//! Spanish texts, keyed by the English text used in the code
+
+pub(super) static MESSAGES: &[(&str, &str)] = &[
+    // Shared
+    ("Title", "Título"),
+    // /help
+    ("**Available Commands:**", "**Comandos disponibles:**"),
+    (
+        "`/add_task` - Create a Single or Weekly task (Single tasks are removed after notification, Weekly task are automatically rescheduled)",
+        "`/agregar_tarea` - Crea una tarea única o semanal (las únicas se borran después de avisar, las semanales se reprograman solas)",
+    ),
+    (
+        "`/list_tasks` - List all your tasks",
+        "`/listar_tareas` - Lista todas tus tareas",
+    ),
+    (
+        "`/remove_task` - Remove specific tasks or all of them",
+        "`/eliminar_tarea` - Elimina algunas tareas o todas",
+    ),
+    (
+        "`/edit_task` - Edit a task by selecting it",
+        "`/editar_tarea` - Edita una tarea eligiéndola",
+    ),
+    (
+        "`/timezone` - Set your current timezone based on your country, city or state",
+        "`/zona_horaria` - Configura tu zona horaria según tu país, ciudad o estado",
+    ),
+    (
+        "`/preferences` - Choose your language, date format, 12 or 24-hour clock and first day of the week",
+        "`/preferencias` - Elige tu idioma, formato de fecha, reloj de 12 o 24 horas y primer día de la semana",
+    ),
+    (
+        "`/time` - Show the current local time of other members",
+        "`/hora` - Muestra la hora local de otros miembros",
+    ),
+    (
+        "`/working_hours` - Set the hours you can be booked in by `/meeting_finder`",
+        "`/horario_laboral` - Configura las horas en las que `/buscar_reunion` puede agendarte",
+    ),
+    (
+        "`/meeting_finder` - Find times this week that fit everyone's working hours and schedule a meeting",
+        "`/buscar_reunion` - Busca horarios de esta semana que encajen con el horario de todos y agenda una reunión",
+    ),
+    (
+        "`/server_settings` - Set the server's default timezone, date format and language (admins)",
+        "`/ajustes_servidor` - Configura la zona horaria, el formato de fecha y el idioma por defecto del servidor (admins)",
+    ),
+    (
+        "`/my_data` - Get a copy of everything the bot stores about you, or delete it",
+        "`/mis_datos` - Recibe una copia de todo lo que el bot guarda sobre ti, o bórralo",
+    ),
+    (
+        "`/help` - Show this message",
+        "`/ayuda` - Muestra este mensaje",
+    ),
+    (
+        "Show available commands",
+        "Muestra los comandos disponibles",
+    ),
+    // Days and schedules
+    ("Mon", "Lun"),
+    ("Tue", "Mar"),
+    ("Wed", "Mié"),
+    ("Thu", "Jue"),
+    ("Fri", "Vie"),
+    ("Sat", "Sáb"),
+    ("Sun", "Dom"),
+    ("Every {days} at {time}", "Cada {days} a las {time}"),
+    (
+        "Every {interval} days at {time}",
+        "Cada {interval} días a las {time}",
+    ),
+    ("Not scheduled", "Sin programar"),
+    // /list_tasks
+    ("📝 Your Tasks", "📝 Tus tareas"),
+    (
+        "You don't have any tasks yet!",
+        "¡Todavía no tienes tareas!",
+    ),
+    (
+        "Use /add_task to create your first task",
+        "Usa /agregar_tarea para crear tu primera tarea",
+    ),
+    ("❌ Timezone Required", "❌ Falta la zona horaria"),
+    (
+        "Please set your timezone first using `/timezone` to see task times correctly",
+        "Configura primero tu zona horaria con `/zona_horaria` para ver bien las horas de tus tareas",
+    ),
+    (
+        "This ensures all times are displayed in your local timezone",
+        "Así todas las horas se muestran en tu zona horaria",
+    ),
+    (
+        "\n• You have 1 task in total",
+        "\n• Tienes 1 tarea en total",
+    ),
+    (
+        "\n• You have {count} tasks in total",
+        "\n• Tienes {count} tareas en total",
+    ),
+    (
+        "\n• Times are in this server's default timezone, use `/timezone` to set your own",
+        "\n• Las horas están en la zona horaria por defecto del servidor, usa `/zona_horaria` para configurar la tuya",
+    ),
+    (
+        "**Time remaining:** {remaining}\n",
+        "**Tiempo restante:** {remaining}\n",
+    ),
+    (
+        "▬▬▬▬▬ **Single Tasks ({count})** ▬▬▬▬▬\n\u{200B}",
+        "▬▬▬▬▬ **Tareas únicas ({count})** ▬▬▬▬▬\n\u{200B}",
+    ),
+    ("**Next:** {next}\n", "**Próxima:** {next}\n"),
+    (
+        "▬▬▬▬▬ **Weekly Tasks ({count})** ▬▬▬▬▬\n\u{200B}",
+        "▬▬▬▬▬ **Tareas semanales ({count})** ▬▬▬▬▬\n\u{200B}",
+    ),
+    (
+        "\n\u{200B}\nTimezone: {timezone} | Date format: {date_format} | Current time: {now}",
+        "\n\u{200B}\nZona horaria: {timezone} | Formato de fecha: {date_format} | Hora actual: {now}",
+    ),
+    ("**Description:**\n", "**Descripción:**\n"),
+    ("*(no description)*\n", "*(sin descripción)*\n"),
+    (
+        "**Timezone:** {timezone} ({time} there)\n",
+        "**Zona horaria:** {timezone} ({time} allí)\n",
+    ),
+    (
+        "**Timezone:** {timezone}\n",
+        "**Zona horaria:** {timezone}\n",
+    ),
+    ("**Mentions:** {mentions}\n", "**Menciones:** {mentions}\n"),
+    ("**Channel:** {channel}\n", "**Canal:** {channel}\n"),
+    (
+        "These are your tasks, {user}\n",
+        "Estas son tus tareas, {user}\n",
+    ),
+    (
+        "📋 Show your current tasks",
+        "📋 Muestra tus tareas actuales",
+    ),
+    (
+        "Your task is ready! {mentions}",
+        "¡Tu tarea está lista! {mentions}",
+    ),
+    ("Task ID", "ID de tarea"),
+    ("Type", "Tipo"),
+    ("Recurring", "Recurrente"),
+    ("One-time", "Única"),
+    ("_(no description)_", "_(sin descripción)_"),
+    (
+        "📦 Task #{id} is paused because {reason}, it comes back if the bot is added again. Here is a copy",
+        "📦 La tarea #{id} está en pausa porque {reason}, volverá si el bot se agrega de nuevo. Aquí tienes una copia",
+    ),
+    (
+        "ℹ️ These tasks are active again because the bot was added back to their server:\n{titles}",
+        "ℹ️ Estas tareas vuelven a estar activas porque el bot se agregó de nuevo a su servidor:\n{titles}",
+    ),
+    (
+        "ℹ️ These tasks will be sent to you by DM from now on because {reason}:\n{titles}",
+        "ℹ️ Desde ahora recibirás estas tareas por MD porque {reason}:\n{titles}",
+    ),
+    (
+        "ℹ️ These tasks were deleted because {reason}:\n{titles}",
+        "ℹ️ Estas tareas se eliminaron porque {reason}:\n{titles}",
+    ),
+    (
+        "the bot was removed from the server they belong to",
+        "el bot fue expulsado del servidor al que pertenecen",
+    ),
+    (
+        "you left the server they belong to",
+        "saliste del servidor al que pertenecen",
+    ),
+    (
+        "the channel they were posted in was deleted",
+        "se eliminó el canal en el que se publicaban",
+    ),
+    (
+        "⏳ You're going too fast, try again in {seconds} seconds",
+        "⏳ Vas demasiado rápido, vuelve a intentarlo en {seconds} segundos",
+    ),
+    ("Shut up", "Callate puta"),
+    ("Okay", "Bueno"),
+    ("Okay...", "bueno..."),
+    ("Action failed: {error}", "Error en la acción: {error}"),
+    (
+        "They aren't in any voice channel",
+        "Pero no está en ningún canal puta",
+    ),
+    ("Couldn't kick them: {error}", "Error al kickear: {error}"),
+    (
+        "Mention the user or use a known alias",
+        "Menciona al usuario o usa un alias conocido",
+    ),
+    ("Not sure what you mean", "Que homosexual"),
+    ("{name} kicked.", "{name} kickeado."),
+    (
+        "Couldn't kick {name}: {error}",
+        "Error al kickear a {name}: {error}",
+    ),
+    (
+        "I couldn't find the user: {name}",
+        "No encontré al usuario: {name}",
+    ),
+    ("Can I kick {name}?", "Puedo kickear a {name}?"),
+    ("Yes", "Sí"),
+    ("Task removal", "Eliminación de tareas"),
+    ("Single tasks", "Tareas únicas"),
+    ("Weekly tasks", "Tareas semanales"),
+    ("🗑️ Delete all tasks", "🗑️ Eliminar todas las tareas"),
+    ("Select a task to delete:", "Elige una tarea para eliminar:"),
+    (
+        "✅ Task **{title}** deleted.",
+        "✅ Tarea **{title}** eliminada.",
+    ),
+    (
+        "❌ Couldn't find task #{id}.",
+        "❌ No se encontró la tarea #{id}.",
+    ),
+    (
+        "❌ Invalid selection (couldn't parse task ID)",
+        "❌ Selección no válida (no se pudo leer el ID de la tarea)",
+    ),
+    ("✅ Yes", "✅ Sí"),
+    (
+        "⚠️ Are you sure you want to delete all your tasks?",
+        "⚠️ ¿Seguro que quieres eliminar todas tus tareas?",
+    ),
+    (
+        "✅ {count} tasks deleted successfully",
+        "✅ {count} tareas eliminadas correctamente",
+    ),
+    ("❌ Operation cancelled", "❌ Operación cancelada"),
+    (
+        "❌ Interaction type not handled",
+        "❌ Tipo de interacción no soportado",
+    ),
+    (
+        "You don't have any task to delete",
+        "No tienes ninguna tarea para eliminar",
+    ),
+    (
+        "You don't have permission to delete this task",
+        "No tienes permiso para eliminar esta tarea",
+    ),
+    (
+        "Cannot create a task in the past",
+        "No se puede crear una tarea en el pasado",
+    ),
+    (
+        "Cannot create a weekly task in the past",
+        "No se puede crear una tarea semanal en el pasado",
+    ),
+    (
+        "Task title cannot be empty",
+        "El título de la tarea no puede estar vacío",
+    ),
+    (
+        "At least one day must be specified for weekly task",
+        "Las tareas semanales necesitan al menos un día",
+    ),
+    ("Invalid time specified", "Hora no válida"),
+    (
+        "You cannot schedule a task in the past",
+        "No puedes programar una tarea en el pasado",
+    ),
+    (
+        "Task not found or you don't have permission to edit it",
+        "La tarea no existe o no tienes permiso para editarla",
+    ),
+    (
+        "Enter the date and time again to change the task's timezone",
+        "Vuelve a escribir la fecha y la hora para cambiar la zona horaria de la tarea",
+    ),
+    (
+        "User has no timezone configured",
+        "No tienes una zona horaria configurada",
+    ),
+    ("Invalid hour", "Hora no válida"),
+    ("Invalid minute", "Minuto no válido"),
+    ("Invalid time values", "Valores de hora no válidos"),
+    (
+        "Working hours must start before they end",
+        "El horario laboral debe empezar antes de terminar",
+    ),
+    (
+        "DD-MM-YYYY (e.g., 27-11-2025)",
+        "DD-MM-AAAA (p. ej., 27-11-2025)",
+    ),
+    (
+        "MM-DD-YYYY (e.g., 11-27-2025)",
+        "MM-DD-AAAA (p. ej., 11-27-2025)",
+    ),
+    (
+        "YYYY-MM-DD (e.g., 2025-11-27)",
+        "AAAA-MM-DD (p. ej., 2025-11-27)",
+    ),
+    (
+        "Automatically set based on your location",
+        "Elegido automáticamente según tu ubicación",
+    ),
+    (
+        "Set your time zone for tasks",
+        "Configura tu zona horaria para las tareas",
+    ),
+    (
+        "Your city, country, state or zone (e.g. Argentina, Nueva York, Madrid, CET)",
+        "Tu ciudad, país, estado o zona (p. ej. Argentina, Nueva York, Madrid, CET)",
+    ),
+    (
+        "❌ You must provide a location (country, city or state)",
+        "❌ Tienes que indicar una ubicación (país, ciudad o estado)",
+    ),
+    (
+        "❌ No time zones found for '{location}'. Try a more specific name",
+        "❌ No se encontraron zonas horarias para '{location}'. Prueba con un nombre más específico",
+    ),
+    ("Error obtaining the time", "Error al obtener la hora"),
+    ("Select your timezone", "Elige tu zona horaria"),
+    (
+        "🔍 **Select your timezone:**",
+        "🔍 **Elige tu zona horaria:**",
+    ),
+    (
+        "❌ Error obtaining current time",
+        "❌ Error al obtener la hora actual",
+    ),
+    (
+        "🕐 Timezone confirmation",
+        "🕐 Confirmación de zona horaria",
+    ),
+    (
+        "**Selected timezone:** {timezone}\n**Current time:** `{time}`\n**Date format:** {date_format}\n\nIs this your correct local time?",
+        "**Zona horaria elegida:** {timezone}\n**Hora actual:** `{time}`\n**Formato de fecha:** {date_format}\n\n¿Es tu hora local correcta?",
+    ),
+    (
+        "Date format will be automatically set based on your location. You can change it later if needed.",
+        "El formato de fecha se elegirá automáticamente según tu ubicación. Puedes cambiarlo más tarde si lo necesitas.",
+    ),
+    ("✅ Yes, it's correct", "✅ Sí, es correcta"),
+    ("❌ Cancel", "❌ Cancelar"),
+    ("Cancel", "Cancelar"),
+    (
+        "❌ No selection was found",
+        "❌ No se encontró ninguna selección",
+    ),
+    (
+        "❌ Interaction type unvalid",
+        "❌ Tipo de interacción no válido",
+    ),
+    ("❌ Timezone not found", "❌ Zona horaria no encontrada"),
+    (
+        "❌ Error setting time zone",
+        "❌ Error al configurar la zona horaria",
+    ),
+    (
+        "• **{title}** (#{id}): `{current}` → `{moved}` with absolute times",
+        "• **{title}** (#{id}): `{current}` → `{moved}` con horas absolutas",
+    ),
+    ("…and {count} more", "…y {count} más"),
+    (
+        "🌍 What should happen to your tasks?",
+        "🌍 ¿Qué hacemos con tus tareas?",
+    ),
+    (
+        "Moving to `{timezone}` affects {count} of your tasks:\n{tasks}",
+        "Cambiar a `{timezone}` afecta a {count} de tus tareas:\n{tasks}",
+    ),
+    ("🕘 Keep local time", "🕘 Mantener la hora local"),
+    (
+        "Tasks stay at the same time on your clock, so they fire at another moment",
+        "Las tareas siguen a la misma hora en tu reloj, así que suenan en otro momento",
+    ),
+    ("⏱️ Keep absolute time", "⏱️ Mantener la hora absoluta"),
+    (
+        "Tasks fire at the same moment, which is another time on your clock",
+        "Las tareas suenan en el mismo momento, que es otra hora en tu reloj",
+    ),
+    (
+        "Tasks with their own timezone are not affected",
+        "Las tareas con su propia zona horaria no cambian",
+    ),
+    ("Keep local time", "Mantener la hora local"),
+    ("Keep absolute time", "Mantener la hora absoluta"),
+    (
+        "✅ Timezone set up correctly!",
+        "✅ ¡Zona horaria configurada!",
+    ),
+    (
+        "Timezone configured successfully for {user}!",
+        "¡Zona horaria configurada correctamente para {user}!",
+    ),
+    ("Timezone", "Zona horaria"),
+    ("Current Time", "Hora actual"),
+    ("Date Format", "Formato de fecha"),
+    (
+        "When creating tasks, the date field will now show the format familiar to your region",
+        "Al crear tareas, el campo de fecha mostrará el formato habitual de tu región",
+    ),
+    ("Tasks", "Tareas"),
+    (
+        "{count} tasks keep their local time",
+        "{count} tareas mantienen su hora local",
+    ),
+    (
+        "❌ Error setting time zone, your tasks were not changed",
+        "❌ Error al configurar la zona horaria, tus tareas no cambiaron",
+    ),
+    (
+        "❌ Time zone setting canceled",
+        "❌ Configuración de zona horaria cancelada",
+    ),
+    (
+        "Show the current local time of other members",
+        "Muestra la hora local actual de otros miembros",
+    ),
+    (
+        "Members to show, e.g. @Ana @Bob (yourself if empty)",
+        "Miembros a mostrar, p. ej. @Ana @Bob (tú si lo dejas vacío)",
+    ),
+    ("❌ **Invalid members**", "❌ **Miembros no válidos**"),
+    (
+        "<@{user}> · no timezone set",
+        "<@{user}> · sin zona horaria",
+    ),
+    ("🕐 World clock", "🕐 Reloj mundial"),
+    (
+        "📊 Show bot statistics (creator only)",
+        "📊 Muestra las estadísticas del bot (solo el creador)",
+    ),
+    (
+        "❌ You are not authorized to use this command.",
+        "❌ No tienes permiso para usar este comando.",
+    ),
+    (
+        "❌ Failed to retrieve statistics.",
+        "❌ No se pudieron obtener las estadísticas.",
+    ),
+    ("Statistics", "Estadísticas"),
+    ("Private statistics", "Estadísticas privadas"),
+    ("All tasks", "Todas las tareas"),
+    ("Servers registered", "Servidores registrados"),
+    ("Jan", "ene"),
+    ("Feb", "feb"),
+    ("Mar", "mar"),
+    ("Apr", "abr"),
+    ("May", "may"),
+    ("Jun", "jun"),
+    ("Jul", "jul"),
+    ("Aug", "ago"),
+    ("Sep", "sep"),
+    ("Oct", "oct"),
+    ("Nov", "nov"),
+    ("Dec", "dic"),
+    (
+        "Set the hours /meeting_finder can book you in, local to your timezone",
+        "Elige las horas en las que /meeting_finder puede citarte, en tu zona horaria",
+    ),
+    (
+        "e.g. 09:00-17:00, or 'default' to go back to 09:00-17:00 (shows yours if empty)",
+        "p. ej. 09:00-17:00, o 'default' para volver a 09:00-17:00 (muestra el tuyo si lo dejas vacío)",
+    ),
+    (
+        "Find times this week that fit everyone's working hours",
+        "Busca horas esta semana que encajen en el horario laboral de todos",
+    ),
+    (
+        "Who to meet with, e.g. @Ana @Bob",
+        "Con quién reunirte, p. ej. @Ana @Bob",
+    ),
+    (
+        "Length of the meeting in minutes (60 by default)",
+        "Duración de la reunión en minutos (60 por defecto)",
+    ),
+    (
+        "Title of the reminder (\"Meeting\" by default)",
+        "Título del recordatorio (\"Reunión\" por defecto)",
+    ),
+    (
+        "❌ Set your timezone with `/timezone` first, working hours are local to it",
+        "❌ Configura primero tu zona horaria con `/timezone`, el horario laboral va en ella",
+    ),
+    (
+        "🕘 Your working hours are **{hours}** ({timezone})",
+        "🕘 Tu horario laboral es **{hours}** ({timezone})",
+    ),
+    (
+        "❌ Error reading your working hours",
+        "❌ Error al leer tu horario laboral",
+    ),
+    (
+        "✅ Your working hours are now **{hours}**",
+        "✅ Tu horario laboral ahora es **{hours}**",
+    ),
+    (
+        "❌ Error saving your working hours",
+        "❌ Error al guardar tu horario laboral",
+    ),
+    (
+        "❌ **Invalid working hours**",
+        "❌ **Horario laboral no válido**",
+    ),
+    (
+        "This command only works in a server",
+        "Este comando solo funciona en un servidor",
+    ),
+    ("**Invalid members**", "**Miembros no válidos**"),
+    (
+        "A meeting can have at most {count} people",
+        "Una reunión puede tener como mucho {count} personas",
+    ),
+    ("Meeting", "Reunión"),
+    (
+        "{members} need to set a timezone with `/timezone` first",
+        "{members} tienen que configurar primero una zona horaria con `/timezone`",
+    ),
+    (
+        "No {minutes}-minute slot fits everyone's working hours in the next {days} days",
+        "Ningún hueco de {minutes} minutos encaja en el horario laboral de todos en los próximos {days} días",
+    ),
+    (
+        "Times where a {minutes}-minute meeting fits everyone, in your timezone (`{timezone}`). The meeting starts at the beginning of the slot you pick\n\n{members}",
+        "Horas en las que una reunión de {minutes} minutos encaja para todos, en tu zona horaria (`{timezone}`). La reunión empieza al principio del hueco que elijas\n\n{members}",
+    ),
+    ("Pick a slot", "Elige un hueco"),
+    ("❌ Invalid slot", "❌ Hueco no válido"),
+    (
+        "❌ That slot has already started, run `/meeting_finder` again",
+        "❌ Ese hueco ya empezó, vuelve a usar `/meeting_finder`",
+    ),
+    (
+        "❌ These slots expired or were already used, run `/meeting_finder` again",
+        "❌ Estos huecos caducaron o ya se usaron, vuelve a usar `/meeting_finder`",
+    ),
+    ("Meeting with {members}", "Reunión con {members}"),
+    (
+        "📅 <@{owner}> scheduled **{title}** (task #{id}) for {when} with {members}, everyone is reminded here when it starts",
+        "📅 <@{owner}> programó **{title}** (tarea #{id}) para {when} con {members}, se avisará a todos aquí cuando empiece",
+    ),
+    (
+        "❌ Couldn't create the meeting: {reason}",
+        "❌ No se pudo crear la reunión: {reason}",
+    ),
+    (
+        "Server defaults for channel tasks and members without a timezone",
+        "Valores del servidor para tareas de canal y miembros sin zona horaria",
+    ),
+    (
+        "Default timezone, e.g. Europe/Madrid, or 'default' to clear it",
+        "Zona horaria por defecto, p. ej. Europe/Madrid, o 'default' para quitarla",
+    ),
+    (
+        "Default date order for members without their own",
+        "Orden de fecha por defecto para miembros sin uno propio",
+    ),
+    ("Not set", "Sin configurar"),
+    (
+        "Default language of the bot's replies",
+        "Idioma por defecto de las respuestas del bot",
+    ),
+    (
+        "❌ This command only works in a server",
+        "❌ Este comando solo funciona en un servidor",
+    ),
+    (
+        "❌ You need the **Manage Server** permission to change server settings",
+        "❌ Necesitas el permiso **Gestionar servidor** para cambiar los ajustes del servidor",
+    ),
+    (
+        "❌ Error reading the server settings",
+        "❌ Error al leer los ajustes del servidor",
+    ),
+    ("❌ **Invalid timezone**", "❌ **Zona horaria no válida**"),
+    (
+        "❌ Error saving the server settings",
+        "❌ Error al guardar los ajustes del servidor",
+    ),
+    ("*not set*", "*sin configurar*"),
+    (
+        "✅ Server settings updated",
+        "✅ Ajustes del servidor actualizados",
+    ),
+    ("⚙️ Server settings", "⚙️ Ajustes del servidor"),
+    (
+        "Channel tasks use the server timezone, and members who never ran `/timezone` get these defaults",
+        "Las tareas de canal usan la zona horaria del servidor, y los miembros que nunca usaron `/timezone` reciben estos valores",
+    ),
+    ("Date format", "Formato de fecha"),
+    ("Language", "Idioma"),
+    (
+        "Choose how dates and times are written and read for you",
+        "Elige cómo se escriben y se leen para ti las fechas y horas",
+    ),
+    ("Order of day, month and year", "Orden de día, mes y año"),
+    ("From my timezone", "Según mi zona horaria"),
+    ("12 or 24-hour clock", "Reloj de 12 o 24 horas"),
+    ("24-hour (15:30)", "24 horas (15:30)"),
+    ("12-hour (3:30 PM)", "12 horas (3:30 PM)"),
+    ("First day of the week", "Primer día de la semana"),
+    ("Monday", "Lunes"),
+    ("Sunday", "Domingo"),
+    ("Saturday", "Sábado"),
+    (
+        "Language of the bot's replies",
+        "Idioma de las respuestas del bot",
+    ),
+    ("Discord's language", "El idioma de Discord"),
+    (
+        "❌ Set your timezone with `/timezone` first, your preferences are stored with it",
+        "❌ Configura primero tu zona horaria con `/timezone`, tus preferencias se guardan con ella",
+    ),
+    (
+        "❌ Error reading your preferences",
+        "❌ Error al leer tus preferencias",
+    ),
+    ("❌ Unknown day '{day}'", "❌ Día desconocido '{day}'"),
+    (
+        "❌ Error saving your preferences",
+        "❌ Error al guardar tus preferencias",
+    ),
+    ("✅ Preferences updated", "✅ Preferencias actualizadas"),
+    ("⚙️ Your preferences", "⚙️ Tus preferencias"),
+    (
+        "Now in {timezone}: **{now}**",
+        "Ahora en {timezone}: **{now}**",
+    ),
+    (
+        "{format} (from your timezone)",
+        "{format} (según tu zona horaria)",
+    ),
+    ("Clock", "Reloj"),
+    ("{language} (from Discord)", "{language} (de Discord)"),
+    (
+        "Use the 24-hour clock (e.g. 15:30), or pick the 12-hour clock in `/preferences`",
+        "Usa el reloj de 24 horas (p. ej. 15:30), o elige el de 12 horas en `/preferences`",
+    ),
+    (
+        "Get or delete everything the bot stores about you",
+        "Descarga o elimina todo lo que el bot guarda sobre ti",
+    ),
+    (
+        "Receive a JSON file with all your data by DM",
+        "Recibe por MD un archivo JSON con todos tus datos",
+    ),
+    (
+        "Delete all your tasks, preferences and history",
+        "Elimina todas tus tareas, preferencias e historial",
+    ),
+    ("🗑️ Delete everything", "🗑️ Eliminar todo"),
+    (
+        "⚠️ This deletes all your tasks, preferences, scheduled notifications and history. It can't be undone.",
+        "⚠️ Esto elimina todas tus tareas, preferencias, notificaciones programadas e historial. No se puede deshacer.",
+    ),
+    (
+        "❌ Use `/my_data export` or `/my_data delete`",
+        "❌ Usa `/my_data export` o `/my_data delete`",
+    ),
+    (
+        "📬 I sent you a DM with your data",
+        "📬 Te envié un MD con tus datos",
+    ),
+    (
+        "Couldn't open a DM with you, check your privacy settings",
+        "No pude abrir un MD contigo, revisa tu configuración de privacidad",
+    ),
+    (
+        "Couldn't send you a DM, check your privacy settings",
+        "No pude enviarte un MD, revisa tu configuración de privacidad",
+    ),
+    (
+        "Here is everything the bot stores about you",
+        "Esto es todo lo que el bot guarda sobre ti",
+    ),
+    (
+        "✅ Your data was deleted ({tasks} tasks, {entries} scheduled notifications{preferences})",
+        "✅ Se eliminaron tus datos ({tasks} tareas, {entries} notificaciones programadas{preferences})",
+    ),
+    (", your preferences", ", tus preferencias"),
+    ("Add a new task", "Agrega una tarea nueva"),
+    (
+        "How to notify you when the task is due",
+        "Cómo avisarte cuando llegue la tarea",
+    ),
+    ("Direct Message", "Mensaje directo"),
+    ("Channel Notification", "Aviso en un canal"),
+    ("Both DM and Channel", "MD y canal"),
+    (
+        "Task type: single or weekly",
+        "Tipo de tarea: única o semanal",
+    ),
+    (
+        "Single (specific date/time)",
+        "Única (fecha y hora concretas)",
+    ),
+    (
+        "Weekly (repeats on specific days)",
+        "Semanal (se repite ciertos días)",
+    ),
+    (
+        "Channel for notifications (required for Channel/Both)",
+        "Canal de los avisos (obligatorio para Canal/Ambos)",
+    ),
+    (
+        "Users or roles to ping, @everyone and @here need Mention Everyone (optional)",
+        "Usuarios o roles a mencionar, @everyone y @here necesitan Mencionar a todos (opcional)",
+    ),
+    (
+        "Timezone of the date and time, e.g. America/New_York (optional, yours by default)",
+        "Zona horaria de la fecha y la hora, p. ej. America/New_York (opcional, la tuya por defecto)",
+    ),
+    ("❌ **Invalid mention**", "❌ **Mención no válida**"),
+    (
+        "❌ **Channel required**\n\nFor 'Channel' or 'Both DM and Channel' notification methods, you must specify a channel",
+        "❌ **Falta el canal**\n\nPara avisos en 'Canal' o 'MD y canal' tienes que indicar un canal",
+    ),
+    (
+        "❌ **Invalid channel selection**\n\nFor 'Direct Message' notification method, you can't specify a channel",
+        "❌ **Canal no válido**\n\nCon avisos por 'Mensaje directo' no puedes indicar un canal",
+    ),
+    (
+        "❌ **Invalid mention**\n\nDirect messages can't ping anyone, pick a channel notification method to use mentions",
+        "❌ **Mención no válida**\n\nLos mensajes directos no mencionan a nadie, elige un aviso en canal para usar menciones",
+    ),
+    (
+        "❌ **Can't use this channel**",
+        "❌ **No se puede usar este canal**",
+    ),
+    ("Example: {value}", "Ejemplo: {value}"),
+    (
+        "Enter a title for your task",
+        "Escribe un título para tu tarea",
+    ),
+    ("Days", "Días"),
+    ("Date", "Fecha"),
+    ("Time", "Hora"),
+    (
+        "Task Description (optional)",
+        "Descripción de la tarea (opcional)",
+    ),
+    (
+        "Add more details about your task...",
+        "Agrega más detalles sobre tu tarea...",
+    ),
+    ("📅 Create New Task", "📅 Crear tarea nueva"),
+    (
+        "❌ Modal session expired. Please try again.",
+        "❌ El formulario caducó. Vuelve a intentarlo.",
+    ),
+    (
+        "❌ **First, setup your timezone**\n\nUse the `/timezone` command to set your location before creating tasks, or ask an admin to set a server default with `/server_settings`",
+        "❌ **Primero configura tu zona horaria**\n\nUsa el comando `/timezone` para indicar tu ubicación antes de crear tareas, o pide a un admin que ponga una por defecto con `/server_settings`",
+    ),
+    (
+        "❌ Error verifying timezone",
+        "❌ Error al verificar la zona horaria",
+    ),
+    (
+        "\n🌍 Times of this task are in **{timezone}**",
+        "\n🌍 Las horas de esta tarea están en **{timezone}**",
+    ),
+    (
+        "✅ Task **{title}** created successfully with mention in specified channel!",
+        "✅ ¡Tarea **{title}** creada con mención en el canal indicado!",
+    ),
+    (
+        "✅ Task **{title}** created successfully! Notifications will be sent to the specified channel",
+        "✅ ¡Tarea **{title}** creada! Los avisos se enviarán al canal indicado",
+    ),
+    (
+        "✅ Task **{title}** created successfully! You will receive DM notifications",
+        "✅ ¡Tarea **{title}** creada! Recibirás los avisos por MD",
+    ),
+    ("Edit your task", "Edita tu tarea"),
+    (
+        "❌ **First, setup your timezone**\n\nUse `/timezone` to set your location before editing tasks",
+        "❌ **Primero configura tu zona horaria**\n\nUsa `/timezone` para indicar tu ubicación antes de editar tareas",
+    ),
+    (
+        "You don't have any task to edit",
+        "No tienes ninguna tarea para editar",
+    ),
+    (
+        "#{id}: {title} (Single on {date})",
+        "#{id}: {title} (Única el {date})",
+    ),
+    (
+        "#{id}: {title} (Weekly on {days} at {time})",
+        "#{id}: {title} (Semanal los {days} a las {time})",
+    ),
+    ("Select a task to edit:", "Elige una tarea para editar:"),
+    ("❌ Invalid selection.", "❌ Selección no válida."),
+    (
+        "❌ Please set your timezone first with `/timezone`",
+        "❌ Configura primero tu zona horaria con `/timezone`",
+    ),
+    (
+        "❌ Couldn't find the task or you don't have permission to edit it.",
+        "❌ No se encontró la tarea o no tienes permiso para editarla.",
+    ),
+    ("New title", "Nuevo título"),
+    ("New description (optional)", "Nueva descripción (opcional)"),
+    ("Add task description...", "Agrega una descripción..."),
+    ("New days", "Nuevos días"),
+    ("New date", "Nueva fecha"),
+    ("Date missing", "Sin fecha"),
+    ("New time", "Nueva hora"),
+    (
+        "{timezone}, or \"default\" to use yours",
+        "{timezone}, o \"default\" para usar la tuya",
+    ),
+    (
+        "Yours ({timezone}), or e.g. America/New_York",
+        "La tuya ({timezone}), o p. ej. America/New_York",
+    ),
+    ("Edit task", "Editar tarea"),
+    (
+        "❌ Error editing task: {reason}",
+        "❌ Error al editar la tarea: {reason}",
+    ),
+    ("❌ Couldn't find the task.", "❌ No se encontró la tarea."),
+    ("Task Updated Successfully", "Tarea actualizada"),
+    (
+        "Task **#{id}** has been updated",
+        "La tarea **#{id}** se actualizó",
+    ),
+    ("Description", "Descripción"),
+    (
+        "You already have {count} tasks, the limit is {limit}. Remove some with /remove_task first",
+        "Ya tienes {count} tareas, el límite es {limit}. Elimina algunas con /remove_task primero",
+    ),
+    (
+        "You already have {count} recurring tasks, the limit is {limit}",
+        "Ya tienes {count} tareas recurrentes, el límite es {limit}",
+    ),
+    (
+        "This server already has {count} tasks, the limit is {limit}",
+        "Este servidor ya tiene {count} tareas, el límite es {limit}",
+    ),
+    (
+        "Recurring tasks can repeat at most every {hours} hours",
+        "Las tareas recurrentes pueden repetirse como mucho cada {hours} horas",
+    ),
+    (
+        "{channel} is not a server channel",
+        "{channel} no es un canal del servidor",
+    ),
+    (
+        "I can't access {channel}, I need the **View Channel** permission there",
+        "No puedo acceder a {channel}, necesito el permiso **View Channel** ahí",
+    ),
+    (
+        "{channel} belongs to another server",
+        "{channel} pertenece a otro servidor",
+    ),
+    (
+        "{channel} is not a text channel, pick a text or announcement channel",
+        "{channel} no es un canal de texto, elige un canal de texto o de anuncios",
+    ),
+    (
+        "Couldn't check the channel permissions, try again later",
+        "No se pudieron comprobar los permisos del canal, inténtalo más tarde",
+    ),
+    (
+        "I'm missing the **{permissions}** permission in {channel}",
+        "Me falta el permiso **{permissions}** en {channel}",
+    ),
+    (
+        "You need the **{permissions}** permission in {channel} to send task notifications there",
+        "Necesitas el permiso **{permissions}** en {channel} para enviar notificaciones de tareas ahí",
+    ),
+    (
+        "{role} is not a role of this server",
+        "{role} no es un rol de este servidor",
+    ),
+    (
+        "I'm missing the **{permissions}** permission in {channel} to ping {mentions}",
+        "Me falta el permiso **{permissions}** en {channel} para mencionar a {mentions}",
+    ),
+    (
+        "You need the **{permissions}** permission in {channel} to ping {mentions}",
+        "Necesitas el permiso **{permissions}** en {channel} para mencionar a {mentions}",
+    ),
+    (
+        "`{text}` is not a mention, pick users or roles from the @ suggestions",
+        "`{text}` no es una mención, elige usuarios o roles de las sugerencias de @",
+    ),
+    (
+        "{mention} is not a member, mention people one by one",
+        "{mention} no es un miembro, menciona a las personas una por una",
+    ),
+    (
+        "`{query}` is not a timezone I know",
+        "`{query}` no es una zona horaria que conozca",
+    ),
+    (
+        "`{query}` matches several timezones, use one of: {candidates}",
+        "`{query}` coincide con varias zonas horarias, usa una de: {candidates}",
+    ),
+    (
+        "Failed to parse date/time. Use format: {date} HH:MM",
+        "No se pudo leer la fecha/hora. Usa el formato: {date} HH:MM",
+    ),
+    (
+        "Failed to parse date/time. Use format: {date} HH:MM or 3:30 PM",
+        "No se pudo leer la fecha/hora. Usa el formato: {date} HH:MM o 3:30 PM",
+    ),
+    (
+        "Invalid format. Use: days HH:MM",
+        "Formato no válido. Usa: días HH:MM",
+    ),
+    (
+        "Invalid format. Use: days HH:MM or 3:30 PM",
+        "Formato no válido. Usa: días HH:MM o 3:30 PM",
+    ),
+    ("Invalid day: {day}", "Día no válido: {day}"),
+    (
+        "Invalid time format. Use HH:MM",
+        "Formato de hora no válido. Usa HH:MM",
+    ),
+    (
+        "Invalid time format. Use HH:MM or 3:30 PM",
+        "Formato de hora no válido. Usa HH:MM o 3:30 PM",
+    ),
+    ("Invalid recurrence type", "Tipo de recurrencia no válido"),
+    (
+        "Could not calculate first occurrence",
+        "No se pudo calcular la primera repetición",
+    ),
+    (
+        "Unknown clock '{clock}', expected '24h' or '12h'",
+        "Reloj '{clock}' desconocido, se esperaba '24h' o '12h'",
+    ),
+    (
+        "Unknown language '{language}', expected 'en' or 'es'",
+        "Idioma '{language}' desconocido, se esperaba 'en' o 'es'",
+    ),
+    (
+        "'{range}' is not a time range, use HH:MM-HH:MM (e.g. 09:00-17:00)",
+        "'{range}' no es un rango horario, usa HH:MM-HH:MM (p. ej. 09:00-17:00)",
+    ),
+    (
+        "Failed to serialize data: {reason}",
+        "No se pudieron preparar los datos: {reason}",
+    ),
+    (
+        "Failed to read preferences: {reason}",
+        "No se pudieron leer las preferencias: {reason}",
+    ),
+    (
+        "Failed to read scheduler: {reason}",
+        "No se pudo leer el planificador: {reason}",
+    ),
+    (
+        "Failed to read delivery history: {reason}",
+        "No se pudo leer el historial de envíos: {reason}",
+    ),
+    (
+        "Failed to purge scheduler entries: {reason}",
+        "No se pudieron borrar las entradas del planificador: {reason}",
+    ),
+    (
+        "Failed to delete preferences: {reason}",
+        "No se pudieron borrar las preferencias: {reason}",
+    ),
+];
@@ -0,0 +1,132 @@
+//! Message catalogue. Texts are written in English in the code and looked up by that text, so
+//! a missing translation falls back to English. `{name}` placeholders are filled by `tf`
+mod es;
+
+use crate::domain::value_objects::{Language, UserError, fill_placeholders};
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+/// Discord locales the Spanish catalogue is offered in
+pub const SPANISH_LOCALES: [&str; 2] = ["es-ES", "es-419"];
+
+static SPANISH: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| es::MESSAGES.iter().copied().collect());
+
+/// `text` in `language`, or `text` itself when it has no translation
+pub fn t(language: Language, text: &str) -> &str {
+    match language {
+        Language::En => text,
+        Language::Es => SPANISH.get(text).copied().unwrap_or(text),
+    }
+}
+
+/// Translates `template` and fills its `{name}` placeholders from `args`
+pub fn tf(language: Language, template: &str, args: &[(&str, &(dyn Display + Sync))]) -> String {
+    fill_placeholders(t(language, template), args)
+}
+
+/// `error` in `language`, its placeholders filled with the values it carries
+pub fn te(language: Language, error: &UserError) -> String {
+    fill_placeholders(t(language, error.template()), error.args())
+}
+
+/// Slash command with its Spanish name, the description is translated from the catalogue
+pub fn command(name: &str, name_es: &str, description: &str) -> CreateCommand {
+    SPANISH_LOCALES.iter().fold(
+        CreateCommand::new(name).description(description),
+        |command, locale| {
+            command
+                .name_localized(*locale, name_es)
+                .description_localized(*locale, t(Language::Es, description))
+        },
+    )
+}
+
+/// Command option with its Spanish name, the description is translated from the catalogue
+pub fn option(
+    kind: CommandOptionType,
+    name: &str,
+    name_es: &str,
+    description: &str,
+) -> CreateCommandOption {
+    SPANISH_LOCALES.iter().fold(
+        CreateCommandOption::new(kind, name, description),
+        |option, locale| {
+            option
+                .name_localized(*locale, name_es)
+                .description_localized(*locale, t(Language::Es, description))
+        },
+    )
+}
+
+/// Adds a string choice whose name is translated from the catalogue
+pub fn choice(option: CreateCommandOption, name: &str, value: &str) -> CreateCommandOption {
+    let spanish = t(Language::Es, name);
+    option.add_string_choice_localized(
+        name,
+        value,
+        SPANISH_LOCALES.iter().map(|locale| (*locale, spanish)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn placeholders(text: &str) -> HashSet<&str> {
+        text.split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn spanish_entries_are_unique_and_keep_their_placeholders() {
+        let mut seen = HashSet::new();
+        for (english, spanish) in es::MESSAGES {
+            assert!(seen.insert(english), "duplicated entry: {}", english);
+            assert_eq!(
+                placeholders(english),
+                placeholders(spanish),
+                "placeholders differ: {}",
+                english
+            );
+        }
+    }
+
+    #[test]
+    fn falls_back_to_english_and_fills_placeholders() {
+        assert_eq!(t(Language::En, "Title"), "Title");
+        assert_eq!(t(Language::Es, "Title"), "Título");
+        assert_eq!(
+            t(Language::Es, "not in the catalogue"),
+            "not in the catalogue"
+        );
+        assert_eq!(
+            tf(
+                Language::Es,
+                "✅ Task **{title}** deleted.",
+                &[("title", &"Standup")]
+            ),
+            "✅ Tarea **Standup** eliminada."
+        );
+        let error = UserError::new("Invalid day: {day}").with("day", "Lun");
+        assert_eq!(te(Language::Es, &error), "Día no válido: Lun");
+        assert_eq!(te(Language::En, &error), "Invalid day: Lun");
+    }
+
+    #[test]
+    fn values_holding_placeholders_are_kept_as_they_are() {
+        assert_eq!(
+            tf(
+                Language::En,
+                "✅ Task **{title}** deleted.",
+                &[("title", &"{x}"), ("x", &"oops")]
+            ),
+            "✅ Task **{x}** deleted."
+        );
+    }
+}
@@ -1,2 +1,3 @@
 pub mod commands;
+pub mod i18n;
 pub mod services;
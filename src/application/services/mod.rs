@@ -1,7 +1,10 @@
 pub mod geo_mapping_service;
 pub mod notification_service;
+pub mod task_lifecycle_service;
 pub mod task_orchestrator;
 pub mod task_service;
+pub mod time_formatter;
 pub mod timezone_service;
+pub mod user_data_service;
 
 pub use task_orchestrator::TaskOrchestrator;
@@ -1,32 +1,63 @@
+use crate::application::i18n::{t, tf};
+use crate::application::services::time_formatter::discord_timestamp_with_relative;
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::NotificationSink;
 use crate::domain::entities::scheduled_task::ScheduledTask;
 use crate::domain::entities::task::{NotificationMethod, Task};
-use chrono::Local;
-use serenity::builder::{CreateEmbed, CreateMessage};
-use serenity::model::colour::Color;
-use serenity::model::id::{ChannelId, UserId};
-use serenity::prelude::Context;
+use crate::domain::notification_sink::{MessageEmbed, OutgoingMessage};
+use crate::domain::value_objects::mention::format_mentions;
+use crate::domain::value_objects::{Language, MentionTarget};
+use std::sync::Arc;
 
 #[derive(Clone)]
-pub struct NotificationService;
+pub struct NotificationService {
+    sink: Arc<dyn NotificationSink>,
+    languages: Option<Arc<TimezoneService>>, // None sends everything in English
+}
 
 impl NotificationService {
-    pub fn new() -> Self {
-        Self
+    pub fn new(sink: Arc<dyn NotificationSink>) -> Self {
+        Self {
+            sink,
+            languages: None,
+        }
+    }
+
+    /// Write notifications in each user's or guild's language, as stored with their preferences
+    pub fn with_languages(mut self, timezone_service: Arc<TimezoneService>) -> Self {
+        self.languages = Some(timezone_service);
+        self
+    }
+
+    /// Language of DMs to `user_id` about a task of `guild_id`
+    pub async fn language_of(&self, user_id: u64, guild_id: u64) -> Language {
+        match &self.languages {
+            Some(languages) => languages.language_for(user_id, Some(guild_id), None).await,
+            None => Language::default(),
+        }
+    }
+
+    /// Language of messages posted in `guild_id`, read by all its members
+    async fn guild_language(&self, guild_id: u64) -> Language {
+        match &self.languages {
+            Some(languages) => languages.guild_language(guild_id).await,
+            None => Language::default(),
+        }
     }
 
     /// Sends a notification for a task according to its NotificationMethod.
     /// For Channel/Both, uses the task-specific channel_id
-    pub async fn send_task_notification(&self, task: &Task, ctx: &Context) -> Result<(), String> {
+    pub async fn send_task_notification(&self, task: &Task) -> Result<(), String> {
         match task.notification_method {
             NotificationMethod::DM => {
-                self.send_dm(task, ctx).await?;
+                self.send_dm(task).await?;
             }
             NotificationMethod::Channel => {
-                self.send_channel_with_task_channel(task, ctx).await?;
+                self.send_channel_with_task_channel(task).await?;
             }
             NotificationMethod::Both => {
-                self.send_dm(task, ctx).await?;
-                self.send_channel_with_task_channel(task, ctx).await?;
+                self.send_dm(task).await?;
+                self.send_channel_with_task_channel(task).await?;
             }
         }
         Ok(())
@@ -36,7 +67,6 @@ impl NotificationService {
     pub async fn send_task_notification_from_scheduled(
         &self,
         scheduled_task: &ScheduledTask,
-        ctx: &Context,
         task_orchestrator: &crate::application::services::task_orchestrator::TaskOrchestrator,
     ) -> Result<(), String> {
         // Fetch the full task details including description and channel_id
@@ -59,41 +89,31 @@ impl NotificationService {
                 recurrence: None,
                 notification_method: scheduled_task.notification_method.clone(),
                 channel_id: None, // No channel_id in fallback
-                mention: scheduled_task.mention.clone(),
+                mentions: scheduled_task.mentions.clone(),
+                timezone: None,
             }
         };
 
         // Send notification using task-specific channel
-        self.send_task_notification(&notification_task, ctx).await
+        self.send_task_notification(&notification_task).await
     }
 
     /// Send a direct message to the user with an embed
-    pub async fn send_dm(&self, task: &Task, ctx: &Context) -> Result<(), String> {
-        let user_id = UserId::from(task.user_id);
-
-        let embed = self.create_task_embed(task);
-
-        let dm_channel = user_id
-            .create_dm_channel(&ctx.http)
-            .await
-            .map_err(|e| format!("Failed to create DM channel for user {}: {}", user_id, e))?;
-
-        let msg = CreateMessage::new().embed(embed);
+    pub async fn send_dm(&self, task: &Task) -> Result<(), String> {
+        let language = self.language_of(task.user_id, task.guild_id).await;
+        let msg = OutgoingMessage {
+            content: None,
+            embed: Some(self.create_task_embed(task, language)),
+            allowed_mentions: Vec::new(),
+        };
 
-        dm_channel
-            .send_message(&ctx.http, msg)
-            .await
-            .map_err(|e| format!("Failed to send DM to user {}: {}", user_id, e))?;
+        self.sink.send_dm(task.user_id, msg).await?;
 
         Ok(())
     }
 
     /// Send a message to the task-specific channel with an embed
-    pub async fn send_channel_with_task_channel(
-        &self,
-        task: &Task,
-        ctx: &Context,
-    ) -> Result<(), String> {
+    pub async fn send_channel_with_task_channel(&self, task: &Task) -> Result<(), String> {
         let channel_id = task.channel_id.ok_or_else(|| {
             format!(
                 "Task {} has no channel_id configured for channel notification.",
@@ -101,62 +121,94 @@ impl NotificationService {
             )
         })?;
 
-        let channel = ChannelId::new(channel_id);
-
-        // Create notification message with mention based on task configuration
-        let notification_content = if let Some(mention) = &task.mention {
-            // Use the specified mention(s) instead of the task creator
-            format!("Your task is ready! {}", mention)
+        // Ping the chosen targets, or the task creator when there are none
+        let targets = if task.mentions.is_empty() {
+            vec![MentionTarget::User(task.user_id)]
         } else {
-            // Fallback to mentioning the task creator
-            let user_mention = format!("<@{}>", task.user_id);
-            format!("Your task is ready! {}", user_mention)
+            task.mentions.clone()
         };
 
-        let embed = self.create_task_embed(task);
-        let msg = CreateMessage::new()
-            .content(notification_content)
-            .embed(embed);
+        let language = self.guild_language(task.guild_id).await;
+        let msg = OutgoingMessage {
+            content: Some(tf(
+                language,
+                "Your task is ready! {mentions}",
+                &[("mentions", &format_mentions(&targets))],
+            )),
+            embed: Some(self.create_task_embed(task, language)),
+            allowed_mentions: targets,
+        };
 
-        channel
-            .send_message(&ctx.http, msg)
+        self.sink
+            .send_to_channel(channel_id, msg)
             .await
             .map_err(|e| format!("Failed to send channel message for task {}: {}", task.id, e))?;
 
         Ok(())
     }
 
+    /// Send the user a plain direct message, e.g. to explain what happened to their tasks
+    pub async fn send_notice(&self, user_id: u64, content: String) -> Result<(), String> {
+        let msg = OutgoingMessage {
+            content: Some(content),
+            embed: None,
+            allowed_mentions: Vec::new(),
+        };
+
+        self.sink.send_dm(user_id, msg).await?;
+
+        Ok(())
+    }
+
+    /// Send the owner a copy of a task with a note on top, for tasks the bot is about to drop
+    pub async fn send_task_copy(&self, task: &Task, note: String) -> Result<(), String> {
+        let language = self.language_of(task.user_id, task.guild_id).await;
+        let msg = OutgoingMessage {
+            content: Some(note),
+            embed: Some(self.create_task_embed(task, language)),
+            allowed_mentions: Vec::new(),
+        };
+
+        self.sink.send_dm(task.user_id, msg).await?;
+
+        Ok(())
+    }
+
     /// Create a rich embed for task notifications
-    fn create_task_embed(&self, task: &Task) -> CreateEmbed {
+    fn create_task_embed(&self, task: &Task, language: Language) -> MessageEmbed {
         let task_type = if task.recurrence.is_some() {
-            "Recurring"
+            t(language, "Recurring")
         } else {
-            "One-time"
+            t(language, "One-time")
         };
 
         let description = if let Some(desc) = &task.description {
             if !desc.trim().is_empty() {
                 format!("{}", desc)
             } else {
-                "_(no description)_".to_string()
+                t(language, "_(no description)_").to_string()
             }
         } else {
-            "_(no description)_".to_string()
+            t(language, "_(no description)_").to_string()
         };
 
-        let mut embed = CreateEmbed::new()
-            .title(format!("{}", task.title))
-            .color(Color::from_rgb(66, 135, 245))
-            .description(description)
-            .field("\u{2800}", "\u{200B}", false) // Espaciador
-            .field("Task ID", format!("#{}", task.id), true)
-            .field("Type", task_type, true);
+        let mut embed = MessageEmbed {
+            title: Some(task.title.clone()),
+            description: Some(description),
+            color: Some(0x4287F5),
+            ..Default::default()
+        }
+        .field("\u{2800}", "\u{200B}", false) // Espaciador
+        .field(t(language, "Task ID"), format!("#{}", task.id), true)
+        .field(t(language, "Type"), task_type, true);
 
+        // Rendered by each reader's client in their own timezone
         if let Some(scheduled_time) = task.scheduled_time {
-            let local_time = scheduled_time.with_timezone(&Local);
-            let formatted = local_time.format("%A, %d - %B - %Y at %H:%M").to_string();
-
-            embed = embed.field("\u{2800}", format!("> {}", formatted), false);
+            embed = embed.field(
+                "\u{2800}",
+                format!("> {}", discord_timestamp_with_relative(scheduled_time)),
+                false,
+            );
         }
 
         embed
@@ -0,0 +1,230 @@
+use crate::application::i18n::{t, tf};
+use crate::application::services::TaskOrchestrator;
+use crate::application::services::notification_service::NotificationService;
+use crate::domain::entities::task::{NotificationMethod, Task};
+use crate::domain::value_objects::{OrphanedTaskPolicies, OrphanedTaskPolicy};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// What happened to the tasks affected by a guild, member or channel going away
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrphanedTasksOutcome {
+    pub removed: usize,
+    pub archived: usize,
+    pub moved_to_dm: usize,
+}
+
+/// Applies the configured `OrphanedTaskPolicies` when a task's guild, channel or owner membership
+/// disappears, so those tasks stop firing into errors. Owners are told by DM when Discord allows it
+pub struct TaskLifecycleService {
+    task_orchestrator: Arc<TaskOrchestrator>,
+    notification_service: Arc<NotificationService>,
+    policies: OrphanedTaskPolicies,
+}
+
+impl TaskLifecycleService {
+    pub fn new(
+        task_orchestrator: Arc<TaskOrchestrator>,
+        notification_service: Arc<NotificationService>,
+        policies: OrphanedTaskPolicies,
+    ) -> Self {
+        Self {
+            task_orchestrator,
+            notification_service,
+            policies,
+        }
+    }
+
+    /// The bot was kicked from `guild_id` or the guild was deleted
+    pub async fn on_guild_removed(&self, guild_id: u64) -> OrphanedTasksOutcome {
+        let tasks = self.affected_tasks(|task| task.guild_id == guild_id).await;
+        self.apply(
+            self.policies.guild_removed,
+            tasks,
+            "the bot was removed from the server they belong to",
+        )
+        .await
+    }
+
+    /// The bot is in `guild_id` (again): tasks archived when it was removed go back to the
+    /// schedule and their owners are told. Returns how many were restored
+    pub async fn on_guild_available(&self, guild_id: u64) -> usize {
+        let archived: Vec<Task> = self
+            .task_orchestrator
+            .get_archived_tasks()
+            .await
+            .into_iter()
+            .filter(|task| task.guild_id == guild_id)
+            .collect();
+
+        let mut restored: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+        for task in archived {
+            match self.task_orchestrator.restore_task(task.id).await {
+                Ok(task) => restored
+                    .entry(task.user_id)
+                    .or_default()
+                    .push(format!("• **{}** (#{})", task.title, task.id)),
+                Err(e) => warn!("Failed to restore archived task #{}: {}", task.id, e),
+            }
+        }
+
+        let count = restored.values().map(Vec::len).sum();
+        for (user_id, titles) in restored {
+            let language = self
+                .notification_service
+                .language_of(user_id, guild_id)
+                .await;
+            let notice = tf(
+                language,
+                "ℹ️ These tasks are active again because the bot was added back to their server:\n{titles}",
+                &[("titles", &titles.join("\n"))],
+            );
+            if let Err(e) = self.notification_service.send_notice(user_id, notice).await {
+                warn!("Couldn't tell user {} about their tasks: {}", user_id, e);
+            }
+        }
+
+        if count > 0 {
+            info!("Restored {} archived task(s) of guild {}", count, guild_id);
+        }
+        count
+    }
+
+    /// `user_id` left `guild_id`, their tasks there can no longer mention or reach them in it
+    pub async fn on_member_left(&self, guild_id: u64, user_id: u64) -> OrphanedTasksOutcome {
+        let tasks = self
+            .affected_tasks(|task| task.guild_id == guild_id && task.user_id == user_id)
+            .await;
+        self.apply(
+            self.policies.member_left,
+            tasks,
+            "you left the server they belong to",
+        )
+        .await
+    }
+
+    /// A channel used by `Channel`/`Both` tasks was deleted
+    pub async fn on_channel_deleted(&self, channel_id: u64) -> OrphanedTasksOutcome {
+        let tasks = self
+            .affected_tasks(|task| task.channel_id == Some(channel_id))
+            .await;
+        self.apply(
+            self.policies.channel_deleted,
+            tasks,
+            "the channel they were posted in was deleted",
+        )
+        .await
+    }
+
+    async fn affected_tasks(&self, affected: impl Fn(&Task) -> bool) -> Vec<Task> {
+        self.task_orchestrator
+            .get_all_tasks()
+            .await
+            .into_iter()
+            .filter(|task| affected(task))
+            .collect()
+    }
+
+    async fn apply(
+        &self,
+        policy: OrphanedTaskPolicy,
+        tasks: Vec<Task>,
+        reason: &str,
+    ) -> OrphanedTasksOutcome {
+        let mut outcome = OrphanedTasksOutcome::default();
+        // Guild and titles per owner, so each owner gets a single notice
+        let mut handled: BTreeMap<u64, (u64, Vec<String>)> = BTreeMap::new();
+
+        for task in tasks {
+            let result = match policy {
+                // DM-only tasks keep working where they are
+                OrphanedTaskPolicy::MoveToDm if uses_channel(&task) => self
+                    .task_orchestrator
+                    .move_task_to_dm(task.id)
+                    .await
+                    .map(|_| outcome.moved_to_dm += 1),
+                OrphanedTaskPolicy::MoveToDm => continue,
+                OrphanedTaskPolicy::Archive => {
+                    let language = self
+                        .notification_service
+                        .language_of(task.user_id, task.guild_id)
+                        .await;
+                    let note = tf(
+                        language,
+                        "📦 Task #{id} is paused because {reason}, it comes back if the bot is added again. Here is a copy",
+                        &[("id", &task.id), ("reason", &t(language, reason))],
+                    );
+                    if let Err(e) = self.notification_service.send_task_copy(&task, note).await {
+                        warn!(
+                            "Couldn't send a copy of task #{} to its owner: {}",
+                            task.id, e
+                        );
+                    }
+                    self.task_orchestrator
+                        .archive_task(task.id)
+                        .await
+                        .map(|_| outcome.archived += 1)
+                }
+                OrphanedTaskPolicy::Delete => {
+                    self.remove(&task).await.map(|_| outcome.removed += 1)
+                }
+            };
+
+            match result {
+                Ok(()) => handled
+                    .entry(task.user_id)
+                    .or_insert_with(|| (task.guild_id, Vec::new()))
+                    .1
+                    .push(format!("• **{}** (#{})", task.title, task.id)),
+                Err(e) => warn!("Failed to handle orphaned task #{}: {}", task.id, e),
+            }
+        }
+
+        // Archived tasks were already announced one by one along with their copy
+        if policy != OrphanedTaskPolicy::Archive {
+            for (user_id, (guild_id, titles)) in handled {
+                let language = self
+                    .notification_service
+                    .language_of(user_id, guild_id)
+                    .await;
+                let template = match policy {
+                    OrphanedTaskPolicy::MoveToDm => {
+                        "ℹ️ These tasks will be sent to you by DM from now on because {reason}:\n{titles}"
+                    }
+                    _ => "ℹ️ These tasks were deleted because {reason}:\n{titles}",
+                };
+                let notice = tf(
+                    language,
+                    template,
+                    &[
+                        ("reason", &t(language, reason)),
+                        ("titles", &titles.join("\n")),
+                    ],
+                );
+                if let Err(e) = self.notification_service.send_notice(user_id, notice).await {
+                    warn!("Couldn't tell user {} about their tasks: {}", user_id, e);
+                }
+            }
+        }
+
+        if outcome != OrphanedTasksOutcome::default() {
+            info!(
+                "Orphaned tasks ({}): {} removed, {} archived, {} moved to DM",
+                reason, outcome.removed, outcome.archived, outcome.moved_to_dm
+            );
+        }
+        outcome
+    }
+
+    async fn remove(&self, task: &Task) -> Result<(), String> {
+        self.task_orchestrator
+            .remove_user_task(task.id, task.user_id)
+            .await?;
+        Ok(())
+    }
+}
+
+fn uses_channel(task: &Task) -> bool {
+    task.channel_id.is_some() || !matches!(task.notification_method, NotificationMethod::DM)
+}
@@ -3,8 +3,43 @@ use crate::application::services::timezone_service::TimezoneService;
 use crate::domain::Recurrence;
 use crate::domain::entities::scheduled_task::ScheduledTask;
 use crate::domain::entities::task::{NotificationMethod, Task};
-use crate::domain::repositories::task_scheduler_repository::TaskSchedulerRepository;
+use crate::domain::repositories::task_scheduler_repository::{
+    SchedulerError, TaskSchedulerRepository,
+};
+use crate::domain::value_objects::{MentionTarget, TimezoneChangeMode, UserError};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::{Span, info, warn};
+
+/// Differences between the tasks table and the scheduler queue
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScheduleReport {
+    /// Tasks with a scheduled time but no live queue entry
+    pub missing: Vec<u64>,
+    /// Queue entries whose task no longer exists
+    pub orphaned: Vec<u64>,
+    /// Queue entries due at a different time than their task
+    pub mismatched: Vec<u64>,
+}
+
+impl ScheduleReport {
+    pub fn is_consistent(&self) -> bool {
+        self.missing.is_empty() && self.orphaned.is_empty() && self.mismatched.is_empty()
+    }
+}
+
+/// How a task reads before and after its owner changes timezone, for the /timezone preview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimezoneChangePreview {
+    pub task_id: u64,
+    pub title: String,
+    /// Local schedule in the current timezone, also the one kept with `KeepLocalTime`
+    pub current: String,
+    /// Local schedule in the new timezone with `KeepAbsoluteTime`
+    pub keeping_absolute: String,
+}
 
 #[derive(Clone)]
 pub struct TaskOrchestrator {
@@ -38,11 +73,18 @@ impl TaskOrchestrator {
         notification_method: NotificationMethod,
         input_str: String,
         channel_id: Option<u64>, // NEW: Channel ID for task-specific notifications
-        mention: Option<String>,
-    ) -> Result<u64, String> {
+        mentions: Vec<MentionTarget>,
+        timezone: Option<String>, // The owner's timezone when None
+    ) -> Result<u64, UserError> {
         let (scheduled_time, recurrence) = self
             .timezone_service
-            .parse_task_input(&input_str, task_type, user_id)
+            .parse_task_input(
+                &input_str,
+                task_type,
+                user_id,
+                guild_id,
+                timezone.as_deref(),
+            )
             .await?;
 
         let task_id = match task_type {
@@ -55,7 +97,8 @@ impl TaskOrchestrator {
                     scheduled_time.unwrap(),
                     notification_method,
                     channel_id, // NEW: Pass channel_id
-                    mention,
+                    mentions,
+                    timezone,
                 )
                 .await?
             }
@@ -71,14 +114,15 @@ impl TaskOrchestrator {
                         minute,
                         notification_method,
                         channel_id, // NEW: Pass channel_id
-                        mention,
+                        mentions,
+                        timezone,
                     )
                     .await?
                 } else {
-                    return Err("Invalid recurrence type".to_string());
+                    return Err("Invalid recurrence type".into());
                 }
             }
-            _ => return Err(format!("Unknown task type: {}", task_type)),
+            _ => return Err(format!("Unknown task type: {}", task_type).into()),
         };
 
         Ok(task_id)
@@ -93,8 +137,9 @@ impl TaskOrchestrator {
         scheduled_time: chrono::DateTime<chrono::Utc>,
         notification_method: NotificationMethod,
         channel_id: Option<u64>, // NEW: Channel ID parameter
-        mention: Option<String>,
-    ) -> Result<u64, String> {
+        mentions: Vec<MentionTarget>,
+        timezone: Option<String>,
+    ) -> Result<u64, UserError> {
         // delegate to task service
         let task_id = self
             .task_service
@@ -106,7 +151,8 @@ impl TaskOrchestrator {
                 scheduled_time,
                 notification_method,
                 channel_id, // NEW: Pass channel_id
-                mention,
+                mentions,
+                timezone,
             )
             .await?;
 
@@ -126,8 +172,9 @@ impl TaskOrchestrator {
         minute: u8,
         notification_method: NotificationMethod,
         channel_id: Option<u64>, // NEW: Channel ID parameter
-        mention: Option<String>,
-    ) -> Result<u64, String> {
+        mentions: Vec<MentionTarget>,
+        timezone: Option<String>,
+    ) -> Result<u64, UserError> {
         // delegate to task service
         let task_id = self
             .task_service
@@ -141,7 +188,8 @@ impl TaskOrchestrator {
                 minute,
                 notification_method,
                 channel_id, // NEW: Pass channel_id
-                mention,
+                mentions,
+                timezone,
             )
             .await?;
 
@@ -155,13 +203,13 @@ impl TaskOrchestrator {
     /// Handle task after notification (remove single tasks / reschedule recurring tasks)
     pub async fn handle_post_notification_task(&self, task: &Task) -> Result<(), String> {
         if task.recurrence.is_none() {
-            // Single task - only remove from repository (already popped from scheduler during notification)
+            // Single task - only remove from repository (scheduler entry already completed during notification)
             self.task_service
                 .remove_user_task(task.id, task.user_id)
                 .await?;
         } else {
             // recurring task (weekly) - reschedule for next occurrence
-            if let Some(next_time) = task.next_occurrence() {
+            if let Some(next_time) = task.next_occurrence(self.task_service.clock.now()) {
                 self.task_service
                     .task_repo
                     .update_task_time(task.id, next_time)
@@ -212,34 +260,6 @@ impl TaskOrchestrator {
         Ok(())
     }
 
-    /// Get the next pending task from the scheduler (for priority queue scheduler)
-    pub async fn peek_next_scheduled_task(
-        &self,
-    ) -> Result<
-        Option<ScheduledTask>,
-        crate::domain::repositories::task_scheduler_repository::SchedulerError,
-    > {
-        self.task_scheduler.peek_next_task().await
-    }
-
-    /// Remove and return the next pending task from the scheduler
-    pub async fn pop_next_scheduled_task(
-        &self,
-    ) -> Result<
-        Option<ScheduledTask>,
-        crate::domain::repositories::task_scheduler_repository::SchedulerError,
-    > {
-        self.task_scheduler.pop_next_task().await
-    }
-
-    /// Add a scheduled task to the scheduler (used for retries)
-    pub async fn add_scheduled_task(
-        &self,
-        task: ScheduledTask,
-    ) -> Result<(), crate::domain::repositories::task_scheduler_repository::SchedulerError> {
-        self.task_scheduler.add_scheduled_task(task).await
-    }
-
     // === USER-INITIATED TASK REMOVAL ===
 
     /// Remove a task initiated by user (removes from both repository and scheduler)
@@ -247,7 +267,9 @@ impl TaskOrchestrator {
         &self,
         task_id: u64,
         user_id: u64,
-    ) -> Result<Option<crate::domain::entities::task::Task>, String> {
+    ) -> Result<Option<crate::domain::entities::task::Task>, UserError> {
+        Span::current().record("task_id", task_id);
+
         // First remove from repository (includes permission check)
         let removed_task = self.task_service.remove_user_task(task_id, user_id).await?;
 
@@ -263,7 +285,7 @@ impl TaskOrchestrator {
     }
 
     /// Remove all tasks for a user (removes from both repository and scheduler)
-    pub async fn remove_all_user_tasks(&self, user_id: u64) -> Result<usize, String> {
+    pub async fn remove_all_user_tasks(&self, user_id: u64) -> Result<usize, UserError> {
         // Get all user tasks before removal to know which scheduler entries to remove
         let user_tasks = self.task_service.get_user_tasks(user_id).await;
 
@@ -283,12 +305,25 @@ impl TaskOrchestrator {
         Ok(removed_count)
     }
 
+    /// Get all tasks of a user (delegated to task service)
+    pub async fn get_user_tasks(&self, user_id: u64) -> Vec<Task> {
+        self.task_service.get_user_tasks(user_id).await
+    }
+
+    /// Get every task of every user (delegated to task service)
+    pub async fn get_all_tasks(&self) -> Vec<Task> {
+        self.task_service.get_all_tasks_for_scheduling().await
+    }
+
     /// Get task by ID (delegated to task service)
     pub async fn get_task_by_id(&self, task_id: u64) -> Option<Task> {
         self.task_service.get_task_by_id(task_id).await
     }
 
     async fn schedule_existing_task(&self, task_id: u64) -> Result<(), String> {
+        // Tie the surrounding interaction span to the task so its delivery can be traced back
+        Span::current().record("task_id", task_id);
+
         if let Some(task) = self.task_service.get_task_by_id(task_id).await {
             if let Some(scheduled_time) = task.scheduled_time {
                 let scheduled_task = ScheduledTask::new(task_id, scheduled_time, &task);
@@ -296,6 +331,7 @@ impl TaskOrchestrator {
                     .add_scheduled_task(scheduled_task)
                     .await
                     .map_err(|e| format!("Failed to schedule task: {:?}", e))?;
+                info!("Task #{} scheduled for {}", task_id, scheduled_time);
             }
         }
         Ok(())
@@ -317,11 +353,12 @@ impl TaskOrchestrator {
             Vec<crate::domain::entities::task::Task>,
             Vec<crate::domain::entities::task::Task>,
         ),
-        String,
+        UserError,
     > {
         self.task_service.get_user_tasks_for_removal(user_id).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn edit_and_reschedule_task(
         &self,
         task_id: u64,
@@ -329,8 +366,11 @@ impl TaskOrchestrator {
         new_title: Option<String>,
         new_description: Option<String>,
         new_datetime_input: Option<String>,
+        new_timezone: Option<Option<String>>,
         is_weekly_task: bool,
-    ) -> Result<Task, String> {
+    ) -> Result<Task, UserError> {
+        Span::current().record("task_id", task_id);
+
         // execute editing in taskservice
         let edited_task = self
             .task_service
@@ -340,6 +380,7 @@ impl TaskOrchestrator {
                 new_title,
                 new_description,
                 new_datetime_input,
+                new_timezone,
                 is_weekly_task,
                 self.timezone_service.clone(),
             )
@@ -358,8 +399,302 @@ impl TaskOrchestrator {
                 .add_scheduled_task(scheduled_task)
                 .await
                 .map_err(|e| format!("Failed to reschedule: {:?}", e))?;
+            info!("Task #{} rescheduled for {}", task_id, scheduled_time);
         }
 
         Ok(edited_task)
     }
+
+    // === TIMEZONE CHANGE ORCHESTRATION ===
+
+    /// Tasks of `user_id` a move to `new_timezone` would shift, empty when the user has no
+    /// timezone yet or keeps the same one
+    pub async fn preview_timezone_change(
+        &self,
+        user_id: u64,
+        new_timezone: &str,
+    ) -> Result<Vec<TimezoneChangePreview>, String> {
+        let Some((from, to)) = self.timezone_change_zones(user_id, new_timezone).await? else {
+            return Ok(Vec::new());
+        };
+        let formatter = self.timezone_service.formatter_for(user_id, None).await;
+        let (current, moved) = (formatter.in_zone(from), formatter.in_zone(to));
+
+        Ok(self
+            .tasks_following_owner_timezone(user_id)
+            .await
+            .iter()
+            .map(|task| TimezoneChangePreview {
+                task_id: task.id,
+                title: task.title.clone(),
+                current: current.schedule(task),
+                keeping_absolute: moved.schedule(task),
+            })
+            .collect())
+    }
+
+    /// Move `user_id` to `new_timezone`. With `KeepLocalTime` their tasks are re-anchored to
+    /// the new zone in the same transaction that saves the preference. Returns how many tasks
+    /// were moved
+    pub async fn change_user_timezone(
+        &self,
+        user_id: u64,
+        new_timezone: &str,
+        mode: TimezoneChangeMode,
+    ) -> Result<usize, String> {
+        let zones = match mode {
+            TimezoneChangeMode::KeepLocalTime => {
+                self.timezone_change_zones(user_id, new_timezone).await?
+            }
+            TimezoneChangeMode::KeepAbsoluteTime => None,
+        };
+
+        let mut moved = Vec::new();
+        if let Some((from, to)) = zones {
+            let now = self.task_service.clock.now();
+            for task in self.tasks_following_owner_timezone(user_id).await {
+                let (scheduled_time, recurrence) = task.reanchored(from, to, now);
+                moved.push(Task {
+                    scheduled_time,
+                    recurrence,
+                    ..task
+                });
+            }
+        }
+
+        let schedules = moved
+            .iter()
+            .map(|task| (task.id, task.scheduled_time, task.recurrence.clone()))
+            .collect();
+        self.timezone_service
+            .set_user_timezone_with_schedules(user_id, new_timezone, schedules)
+            .await
+            .map_err(|e| format!("Failed to save timezone: {:?}", e))?;
+
+        // Only queued once committed, the scheduler never runs ahead of the database
+        for task in &moved {
+            let result = match task.scheduled_time {
+                Some(scheduled_time) => {
+                    self.task_scheduler
+                        .add_scheduled_task(ScheduledTask::new(task.id, scheduled_time, task))
+                        .await
+                }
+                None => self.task_scheduler.remove_task(task.id).await,
+            };
+            // The stored task is right, an admin `check --repair` fixes the queue later
+            if let Err(e) = result {
+                warn!(
+                    "Failed to re-queue task #{} in its new timezone: {:?}",
+                    task.id, e
+                );
+            }
+        }
+
+        info!(
+            "User {} moved to {}, {} tasks kept their local time",
+            user_id,
+            new_timezone,
+            moved.len()
+        );
+        Ok(moved.len())
+    }
+
+    /// Current and new zone of `user_id`, `None` when there is nothing to re-anchor
+    async fn timezone_change_zones(
+        &self,
+        user_id: u64,
+        new_timezone: &str,
+    ) -> Result<Option<(Tz, Tz)>, String> {
+        let current = self
+            .timezone_service
+            .get_user_timezone(user_id)
+            .await
+            .map_err(|e| format!("Error getting timezone: {:?}", e))?;
+        let Some(current) = current.filter(|current| current != new_timezone) else {
+            return Ok(None);
+        };
+
+        let from = current
+            .parse::<Tz>()
+            .map_err(|_| format!("Unknown timezone: {}", current))?;
+        let to = new_timezone
+            .parse::<Tz>()
+            .map_err(|_| format!("Unknown timezone: {}", new_timezone))?;
+        Ok(Some((from, to)))
+    }
+
+    /// Tasks whose times follow their owner's timezone, i.e. not pinned to their own
+    async fn tasks_following_owner_timezone(&self, user_id: u64) -> Vec<Task> {
+        self.task_service
+            .get_user_tasks(user_id)
+            .await
+            .into_iter()
+            .filter(|task| task.timezone.is_none())
+            .collect()
+    }
+
+    // === ADMIN ORCHESTRATION ===
+
+    /// Move a task and its queue entry to `new_time` (admin tooling, no ownership check)
+    pub async fn reschedule_task(
+        &self,
+        task_id: u64,
+        new_time: DateTime<Utc>,
+    ) -> Result<Task, String> {
+        let mut task = self
+            .task_service
+            .get_task_by_id(task_id)
+            .await
+            .ok_or_else(|| format!("Task #{} not found", task_id))?;
+
+        self.task_service
+            .task_repo
+            .update_task_time(task_id, new_time)
+            .await?;
+        task.scheduled_time = Some(new_time);
+
+        self.task_scheduler
+            .add_scheduled_task(ScheduledTask::new(task_id, new_time, &task))
+            .await
+            .map_err(|e| format!("Failed to reschedule task in scheduler: {:?}", e))?;
+        info!("Task #{} rescheduled for {}", task_id, new_time);
+
+        Ok(task)
+    }
+
+    /// Switch a task to DM delivery, keeping its schedule (its channel or guild is gone)
+    pub async fn move_task_to_dm(&self, task_id: u64) -> Result<Task, String> {
+        let task = self.task_service.task_repo.move_to_dm(task_id).await?;
+
+        if let Some(scheduled_time) = task.scheduled_time {
+            self.task_scheduler
+                .add_scheduled_task(ScheduledTask::new(task_id, scheduled_time, &task))
+                .await
+                .map_err(|e| format!("Failed to update task in scheduler: {:?}", e))?;
+        }
+        info!("Task #{} moved to DM delivery", task_id);
+
+        Ok(task)
+    }
+
+    /// Take a task out of the schedule but keep it stored, `restore_task` brings it back
+    pub async fn archive_task(&self, task_id: u64) -> Result<Task, String> {
+        let task = self
+            .task_service
+            .task_repo
+            .set_archived(task_id, true)
+            .await?;
+
+        match self.task_scheduler.remove_task(task_id).await {
+            Ok(()) | Err(SchedulerError::TaskNotFound) => {}
+            Err(e) => return Err(format!("Failed to remove task from scheduler: {:?}", e)),
+        }
+        info!("Task #{} archived", task_id);
+
+        Ok(task)
+    }
+
+    /// Queue an archived task again. Recurring tasks skip the occurrences missed meanwhile,
+    /// single ones are delivered right away if their time already passed
+    pub async fn restore_task(&self, task_id: u64) -> Result<Task, String> {
+        let mut task = self
+            .task_service
+            .task_repo
+            .set_archived(task_id, false)
+            .await?;
+
+        let now = self.task_service.clock.now();
+        if task.recurrence.is_some()
+            && task.scheduled_time.is_some_and(|time| time < now)
+            && let Some(next_time) = task.next_occurrence(now)
+        {
+            self.task_service
+                .task_repo
+                .update_task_time(task_id, next_time)
+                .await?;
+            task.scheduled_time = Some(next_time);
+        }
+
+        if let Some(scheduled_time) = task.scheduled_time {
+            self.task_scheduler
+                .add_scheduled_task(ScheduledTask::new(task_id, scheduled_time, &task))
+                .await
+                .map_err(|e| format!("Failed to queue restored task: {:?}", e))?;
+        }
+        info!("Task #{} restored", task_id);
+
+        Ok(task)
+    }
+
+    /// Archived tasks of every user
+    pub async fn get_archived_tasks(&self) -> Vec<Task> {
+        self.task_service.task_repo.list_archived().await
+    }
+
+    /// Make a task due now so the next scheduler poll delivers it
+    pub async fn fire_task_now(&self, task_id: u64) -> Result<Task, String> {
+        let now = self.task_service.clock.now();
+        self.reschedule_task(task_id, now).await
+    }
+
+    /// Compare every task with the scheduler queue without changing anything
+    pub async fn check_schedule(&self) -> Result<ScheduleReport, String> {
+        let tasks = self.task_service.get_all_tasks_for_scheduling().await;
+        let queued: HashMap<u64, ScheduledTask> = self
+            .task_scheduler
+            .list_pending()
+            .await
+            .map_err(|e| format!("Failed to read scheduler queue: {:?}", e))?
+            .into_iter()
+            .map(|entry| (entry.task_id, entry))
+            .collect();
+
+        let mut report = ScheduleReport::default();
+        for task in &tasks {
+            let Some(scheduled_time) = task.scheduled_time else {
+                continue;
+            };
+            match queued.get(&task.id) {
+                None => report.missing.push(task.id),
+                Some(entry) if entry.scheduled_time.timestamp() != scheduled_time.timestamp() => {
+                    report.mismatched.push(task.id)
+                }
+                Some(_) => {}
+            }
+        }
+
+        report.orphaned = queued
+            .keys()
+            .filter(|task_id| !tasks.iter().any(|task| task.id == **task_id))
+            .copied()
+            .collect();
+        report.orphaned.sort_unstable();
+
+        Ok(report)
+    }
+
+    /// Re-queue missing or mismatched tasks at their stored time and drop orphaned entries
+    pub async fn repair_schedule(&self) -> Result<ScheduleReport, String> {
+        let report = self.check_schedule().await?;
+
+        for task_id in report.missing.iter().chain(&report.mismatched) {
+            if let Some(task) = self.task_service.get_task_by_id(*task_id).await
+                && let Some(scheduled_time) = task.scheduled_time
+            {
+                self.task_scheduler
+                    .add_scheduled_task(ScheduledTask::new(task.id, scheduled_time, &task))
+                    .await
+                    .map_err(|e| format!("Failed to re-queue task #{}: {:?}", task.id, e))?;
+            }
+        }
+
+        for task_id in &report.orphaned {
+            self.task_scheduler
+                .remove_task(*task_id)
+                .await
+                .map_err(|e| format!("Failed to drop queue entry #{}: {:?}", task_id, e))?;
+        }
+
+        Ok(report)
+    }
 }
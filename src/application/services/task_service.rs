@@ -1,9 +1,16 @@
+use crate::application::i18n::{t, tf};
 use crate::application::services::notification_service::NotificationService;
+use crate::application::services::time_formatter::{
+    TimeFormatter, TimestampStyle, discord_timestamp, discord_timestamp_with_relative,
+};
 use crate::application::services::timezone_service::TimezoneService;
-use crate::domain::entities::task::{NotificationMethod, Recurrence, Task};
+use crate::domain::Clock;
+use crate::domain::entities::task::{NotificationMethod, Recurrence, Task, next_weekly_time};
 use crate::domain::repositories::TaskRepository;
-use crate::domain::value_objects::weekday_format::WeekdayFormat;
-use chrono::{DateTime, Datelike, Duration, Timelike, Utc, Weekday};
+use crate::domain::value_objects::mention::format_mentions;
+use crate::domain::value_objects::{Language, MentionTarget, TaskLimits, UserError};
+use chrono::{DateTime, Utc, Weekday};
+use chrono_tz::Tz;
 use serenity::builder::{CreateEmbed, CreateEmbedFooter};
 use serenity::model::colour::Color;
 use std::sync::Arc;
@@ -15,6 +22,8 @@ pub struct TaskService {
     #[allow(dead_code)]
     notification_service: Arc<NotificationService>,
     timezone_service: Arc<TimezoneService>,
+    pub(crate) clock: Arc<dyn Clock>,
+    limits: TaskLimits,
 }
 
 impl TaskService {
@@ -22,14 +31,34 @@ impl TaskService {
         task_repo: Arc<dyn TaskRepository>,
         notification_service: Arc<NotificationService>,
         timezone_service: Arc<TimezoneService>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             task_repo,
             notification_service,
             timezone_service,
+            clock,
+            limits: TaskLimits::default(),
         }
     }
 
+    pub fn with_limits(mut self, limits: TaskLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Refuse a new task once the user or guild reached its quota
+    async fn check_limits(
+        &self,
+        user_id: u64,
+        guild_id: u64,
+        recurrence: Option<&Recurrence>,
+    ) -> Result<(), UserError> {
+        let existing = self.task_repo.list_tasks().await;
+        self.limits
+            .check_new_task(&existing, user_id, guild_id, recurrence)
+    }
+
     // === TASK CREATION BUSINESS LOGIC ===
 
     pub async fn create_single_task(
@@ -41,14 +70,15 @@ impl TaskService {
         scheduled_time: DateTime<Utc>,
         notification_method: NotificationMethod,
         channel_id: Option<u64>, // NEW: Specific channel for this task
-        mention: Option<String>,
-    ) -> Result<u64, String> {
-        if scheduled_time < Utc::now() {
-            return Err("Cannot create a task in the past".to_string());
+        mentions: Vec<MentionTarget>,
+        timezone: Option<String>,
+    ) -> Result<u64, UserError> {
+        if scheduled_time < self.clock.now() {
+            return Err("Cannot create a task in the past".into());
         }
 
         if title.trim().is_empty() {
-            return Err("Task title cannot be empty".to_string());
+            return Err("Task title cannot be empty".into());
         }
 
         let task = Task::new(
@@ -61,14 +91,18 @@ impl TaskService {
             None,
             notification_method,
             channel_id, // NEW: Pass channel_id
-            mention,
-        );
+            mentions,
+        )
+        .with_timezone(timezone);
 
         // Validate channel requirement
         task.validate_channel_requirement()?;
 
+        self.check_limits(user_id, guild_id, task.recurrence.as_ref())
+            .await?;
+
         // persist
-        self.task_repo.add_task(task).await
+        Ok(self.task_repo.add_task(task).await?)
     }
 
     pub async fn create_weekly_task(
@@ -82,27 +116,28 @@ impl TaskService {
         minute: u8,
         notification_method: NotificationMethod,
         channel_id: Option<u64>, // NEW: Specific channel for this task
-        mention: Option<String>,
-    ) -> Result<u64, String> {
+        mentions: Vec<MentionTarget>,
+        timezone: Option<String>,
+    ) -> Result<u64, UserError> {
         if title.trim().is_empty() {
-            return Err("Task title cannot be empty".to_string());
+            return Err("Task title cannot be empty".into());
         }
 
         if days.is_empty() {
-            return Err("At least one day must be specified for weekly task".to_string());
+            return Err("At least one day must be specified for weekly task".into());
         }
 
         if hour > 23 || minute > 59 {
-            return Err("Invalid time specified".to_string());
+            return Err("Invalid time specified".into());
         }
 
         // put first occurrence
         let first_time = self
-            .calculate_first_occurrence(&days, hour, minute)
-            .ok_or("Could not calculate first occurrence".to_string())?;
+            .calculate_first_occurrence(&days, hour, minute, timezone.as_deref())
+            .ok_or("Could not calculate first occurrence")?;
 
-        if first_time < Utc::now() {
-            return Err("Cannot create a weekly task in the past".to_string());
+        if first_time < self.clock.now() {
+            return Err("Cannot create a weekly task in the past".into());
         }
 
         // create entity
@@ -117,45 +152,32 @@ impl TaskService {
             recurrence,
             notification_method,
             channel_id, // NEW: Pass channel_id
-            mention,
-        );
+            mentions,
+        )
+        .with_timezone(timezone);
 
         // Validate channel requirement
         task.validate_channel_requirement()?;
 
+        self.check_limits(user_id, guild_id, task.recurrence.as_ref())
+            .await?;
+
         // persist
-        self.task_repo.add_task(task).await
+        Ok(self.task_repo.add_task(task).await?)
     }
 
+    /// First occurrence of a weekly schedule, local to `timezone` or in UTC when None
     fn calculate_first_occurrence(
         &self,
         days: &[Weekday],
         hour: u8,
         minute: u8,
+        timezone: Option<&str>,
     ) -> Option<DateTime<Utc>> {
-        let now = Utc::now();
-        // create "today at the specified time"
-        let today_at_time = now
-            .with_hour(hour as u32)
-            .and_then(|t| t.with_minute(minute as u32))
-            .and_then(|t| t.with_second(0))
-            .unwrap();
-
-        let mut candidate = today_at_time;
-
-        // if today's time has already passed, start from tomorrow
-        if candidate <= now {
-            candidate = candidate + Duration::days(1);
-        }
-
-        // find the next matching day
-        for _ in 0..7 {
-            if days.contains(&candidate.weekday()) {
-                return Some(candidate);
-            }
-            candidate = candidate + Duration::days(1);
-        }
-        None
+        let zone = timezone
+            .and_then(|timezone| timezone.parse().ok())
+            .unwrap_or(Tz::UTC);
+        next_weekly_time(days, hour, minute, zone, self.clock.now())
     }
 
     pub async fn get_user_tasks(&self, user_id: u64) -> Vec<Task> {
@@ -173,7 +195,7 @@ impl TaskService {
         &self,
         task_id: u64,
         user_id: u64,
-    ) -> Result<Option<Task>, String> {
+    ) -> Result<Option<Task>, UserError> {
         // verify that the task belongs to the user
         let tasks = self.task_repo.list_tasks().await;
         if let Some(task) = tasks.into_iter().find(|t| t.id == task_id) {
@@ -185,12 +207,12 @@ impl TaskService {
                     return Ok(None);
                 }
             } else {
-                return Err("You don't have permission to delete this task".to_string());
+                return Err("You don't have permission to delete this task".into());
             }
         }
         Ok(None)
     }
-    pub async fn remove_all_user_tasks(&self, user_id: u64) -> Result<usize, String> {
+    pub async fn remove_all_user_tasks(&self, user_id: u64) -> Result<usize, UserError> {
         let count = self.task_repo.remove_all_by_user(user_id).await;
         Ok(count)
     }
@@ -198,11 +220,11 @@ impl TaskService {
     pub async fn get_user_tasks_for_removal(
         &self,
         user_id: u64,
-    ) -> Result<(Vec<Task>, Vec<Task>), String> {
+    ) -> Result<(Vec<Task>, Vec<Task>), UserError> {
         let tasks = self.get_user_tasks(user_id).await;
 
         if tasks.is_empty() {
-            return Err("You don't have any task to delete".to_string());
+            return Err("You don't have any task to delete".into());
         }
 
         let single_tasks: Vec<Task> = tasks
@@ -222,169 +244,77 @@ impl TaskService {
 
     // === LIST TASKS BUSINESS LOGIC ===
 
-    // Used for correct time formatting - now async
-    async fn format_recurrence_for_display_with_timezone(
-        &self,
-        recurrence: &Option<Recurrence>,
-        timezone_service: &TimezoneService,
-        user_id: u64,
-    ) -> String {
-        match recurrence {
-            Some(Recurrence::Weekly { days, hour, minute }) => {
-                // format the days of the week
-                let days_str = days
-                    .iter()
-                    .map(|d| d.to_short_en().to_string())
-                    .collect::<Vec<_>>()
-                    .join(", ");
-
-                // convert UTC to local time
-                let utc_time = Utc::now()
-                    .with_hour(*hour as u32)
-                    .and_then(|t| t.with_minute(*minute as u32))
-                    .and_then(|t| t.with_second(0))
-                    .unwrap();
-
-                let local_time_str = match timezone_service
-                    .format_from_utc_for_user(utc_time, user_id)
-                    .await
-                {
-                    Ok(local_time) => {
-                        // extract only time (HH:MM) from the formatted string
-                        if let Some(time_part) = local_time.split_whitespace().nth(1) {
-                            time_part.to_string()
-                        } else {
-                            format!("{:02}:{:02}", hour, minute)
-                        }
-                    }
-                    Err(_) => {
-                        // Fallback to old method
-                        match timezone_service.get_user_timezone(user_id).await {
-                            Ok(Some(user_timezone)) => {
-                                match timezone_service
-                                    .format_from_utc_with_timezone(utc_time, &user_timezone)
-                                {
-                                    Ok(local_time) => {
-                                        if let Some(time_part) =
-                                            local_time.split_whitespace().nth(1)
-                                        {
-                                            time_part.to_string()
-                                        } else {
-                                            format!("{:02}:{:02}", hour, minute)
-                                        }
-                                    }
-                                    Err(_) => format!("{:02}:{:02}", hour, minute),
-                                }
-                            }
-                            _ => format!("{:02}:{:02}", hour, minute),
-                        }
-                    }
-                };
-
-                format!("Every {} at {}", days_str, local_time_str)
-            }
-            Some(Recurrence::EveryXDays {
-                interval,
-                hour,
-                minute,
-            }) => {
-                let utc_time = Utc::now()
-                    .with_hour(*hour as u32)
-                    .and_then(|t| t.with_minute(*minute as u32))
-                    .and_then(|t| t.with_second(0))
-                    .unwrap();
-
-                let local_time_str = match timezone_service
-                    .format_from_utc_for_user(utc_time, user_id)
-                    .await
-                {
-                    Ok(local_time) => {
-                        if let Some(time_part) = local_time.split_whitespace().nth(1) {
-                            time_part.to_string()
-                        } else {
-                            format!("{:02}:{:02}", hour, minute)
-                        }
-                    }
-                    Err(_) => {
-                        // Fallback to old method
-                        match timezone_service.get_user_timezone(user_id).await {
-                            Ok(Some(user_timezone)) => {
-                                match timezone_service
-                                    .format_from_utc_with_timezone(utc_time, &user_timezone)
-                                {
-                                    Ok(local_time) => {
-                                        if let Some(time_part) =
-                                            local_time.split_whitespace().nth(1)
-                                        {
-                                            time_part.to_string()
-                                        } else {
-                                            format!("{:02}:{:02}", hour, minute)
-                                        }
-                                    }
-                                    Err(_) => format!("{:02}:{:02}", hour, minute),
-                                }
-                            }
-                            _ => format!("{:02}:{:02}", hour, minute),
-                        }
-                    }
-                };
-
-                format!("Every {} days at {}", interval, local_time_str)
-            }
-            None => "Not recurring".to_string(),
-        }
-    }
-
-    /// Get user tasks to display in /list_tasks
+    /// Get user tasks to display in /list_tasks, members without a timezone see them in the
+    /// guild's default one
     pub async fn get_user_tasks_embed(
         &self,
         user_id: u64,
+        guild_id: Option<u64>,
         timezone_service: Arc<TimezoneService>,
+        language: Language,
     ) -> CreateEmbed {
         let tasks = self.get_user_tasks(user_id).await;
 
         if tasks.is_empty() {
             return CreateEmbed::default()
-                .title("📝 Your Tasks")
-                .description("You don't have any tasks yet!")
+                .title(t(language, "📝 Your Tasks"))
+                .description(t(language, "You don't have any tasks yet!"))
                 .color(Color::DARK_GREY)
-                .footer(CreateEmbedFooter::new(
+                .footer(CreateEmbedFooter::new(t(
+                    language,
                     "Use /add_task to create your first task",
-                ));
+                )));
         }
 
-        // Get user's timezone and format preferences
-        let user_timezone = match timezone_service.get_user_timezone(user_id).await {
+        // Get user's timezone and format preferences, or the server's defaults
+        let has_own_timezone = matches!(
+            timezone_service.get_user_timezone(user_id).await,
+            Ok(Some(_))
+        );
+        let user_timezone = match timezone_service
+            .get_effective_timezone(user_id, guild_id)
+            .await
+        {
             Ok(Some(tz)) => tz,
             Ok(None) => {
                 return CreateEmbed::default()
-            .title("❌ Timezone Required")
-            .description("Please set your timezone first using `/timezone` to see task times correctly")
+            .title(t(language, "❌ Timezone Required"))
+            .description(t(language, "Please set your timezone first using `/timezone` to see task times correctly"))
             .color(Color::RED)
-            .footer(CreateEmbedFooter::new(
+            .footer(CreateEmbedFooter::new(t(
+                language,
                 "This ensures all times are displayed in your local timezone",
-            ));
+            )));
             }
             Err(_) => "UTC".to_string(),
         };
-
-        // Get user's date format for display
-        let user_date_format = match timezone_service.get_user_date_format(user_id).await {
-            Ok(Some(format)) => format,
-            _ => "YMD".to_string(), // Default to YMD
-        };
+        let formatter = timezone_service
+            .formatter_for(user_id, guild_id)
+            .await
+            .with_language(language);
 
         // separate tasks
         let (single_tasks, recurrent_tasks) = self.separate_tasks_by_type(&tasks);
         let total_tasks = single_tasks.len() + recurrent_tasks.len();
 
+        let mut description = if total_tasks == 1 {
+            t(language, "\n• You have 1 task in total").to_string()
+        } else {
+            tf(
+                language,
+                "\n• You have {count} tasks in total",
+                &[("count", &total_tasks)],
+            )
+        };
+        if !has_own_timezone {
+            description.push_str(t(
+                language,
+                "\n• Times are in this server's default timezone, use `/timezone` to set your own",
+            ));
+        }
         let mut embed = CreateEmbed::default()
-            .title("📝 Your Tasks")
-            .description(format!(
-                "\n• You have {} task{} in total",
-                total_tasks,
-                if total_tasks != 1 { "s" } else { "" }
-            ))
+            .title(t(language, "📝 Your Tasks"))
+            .description(description)
             .color(Color::BLUE);
 
         embed = embed.field("\n", "\n", false);
@@ -394,187 +324,89 @@ impl TaskService {
             let mut single_tasks_field = String::new();
 
             for task in &single_tasks {
-                let scheduled_str =
-                    task.scheduled_time
-                        .map_or("⏰ Not scheduled".to_string(), |utc_dt| {
-                            // Use a closure to handle the async operation
-                            let timezone_service = timezone_service.clone();
-                            let user_timezone = user_timezone.clone();
-
-                            // We'll format this separately since we can't use await in the closure
-                            // For now, use the synchronous method as fallback
-                            match timezone_service
-                                .format_from_utc_with_timezone(utc_dt, &user_timezone)
-                            {
-                                Ok(local_time) => format!("> {}", local_time),
-                                Err(_) => format!("> {} (UTC)", utc_dt.format("%Y-%m-%d %H:%M")),
-                            }
-                        });
-
                 single_tasks_field.push_str(&format!("#{} - __**{}**__\n\n", task.id, task.title));
+                push_description(&mut single_tasks_field, task, language);
 
-                // DESCRIPTION con label
-                match &task.description {
-                    Some(desc) if !desc.trim().is_empty() => {
-                        single_tasks_field.push_str(&format!("**Description:**\n{}\n", desc));
-                    }
-                    _ => {
-                        single_tasks_field.push_str("**Description:**\n*(no description)*\n");
-                    }
-                }
-
-                // ESPACIO entre Description y Schedule
-                single_tasks_field.push_str("\n");
-
-                // SCHEDULE (para single tasks)
+                // SCHEDULE, shown by Discord in the reader's own timezone
+                let scheduled_str = task.scheduled_time.map_or_else(
+                    || format!("⏰ {}", t(language, "Not scheduled")),
+                    |utc_dt| format!("> {}", discord_timestamp(utc_dt, TimestampStyle::Full)),
+                );
                 single_tasks_field.push_str(&format!("{}\n", scheduled_str));
-
-                // MENTIONS
-                if let Some(mention) = &task.mention {
-                    if !mention.trim().is_empty() {
-                        single_tasks_field.push_str(&format!("**Mentions:** {}\n", mention));
-                    } else {
-                        single_tasks_field
-                            .push_str(&format!("**Mentions:** <@{}>\n", task.user_id));
-                    }
-                } else {
-                    single_tasks_field.push_str(&format!("**Mentions:** <@{}>\n", task.user_id));
-                }
-
-                // CHANNEL
-                if let Some(channel_id) = task.channel_id {
-                    single_tasks_field.push_str(&format!("**Channel:** <#{}>\n", channel_id));
-                }
+                push_task_details(&mut single_tasks_field, task, &formatter, language);
 
                 // TIME REMAINING al final
                 if let Some(scheduled_time) = task.scheduled_time {
-                    let time_remaining =
-                        crate::application::commands::utils::time_remaining::format_time_remaining(
-                            scheduled_time,
-                        );
-                    single_tasks_field
-                        .push_str(&format!("**Time remaining:** {}\n", time_remaining));
+                    single_tasks_field.push_str(&tf(
+                        language,
+                        "**Time remaining:** {remaining}\n",
+                        &[(
+                            "remaining",
+                            &discord_timestamp(scheduled_time, TimestampStyle::Relative),
+                        )],
+                    ));
                 }
 
-                single_tasks_field.push_str("\n");
+                single_tasks_field.push('\n');
             }
 
             embed = embed.field(
-                format!(
-                    "▬▬▬▬▬ **Single Tasks ({})** ▬▬▬▬▬\n\u{200B}",
-                    single_tasks.len()
+                tf(
+                    language,
+                    "▬▬▬▬▬ **Single Tasks ({count})** ▬▬▬▬▬\n\u{200B}",
+                    &[("count", &single_tasks.len())],
                 ),
                 single_tasks_field,
                 false,
             );
         }
 
-        // Weekly tasks - need to collect all recurrence strings first
+        // Weekly tasks
         if !recurrent_tasks.is_empty() {
             let mut recurrent_tasks_field = String::new();
 
-            // Collect all recurrence strings first
-            let mut recurrence_strings = Vec::new();
             for task in &recurrent_tasks {
-                let recurrence_str = self
-                    .format_recurrence_for_display_with_timezone(
-                        &task.recurrence,
-                        &timezone_service,
-                        user_id,
-                    )
-                    .await;
-                recurrence_strings.push((task.id, recurrence_str));
-            }
-
-            // Now build the field content
-            for (task_index, task) in recurrent_tasks.iter().enumerate() {
-                let recurrence_str = &recurrence_strings[task_index].1;
-
                 recurrent_tasks_field
                     .push_str(&format!("#{} - __**{}**__\n\n", task.id, task.title));
-
-                // DESCRIPTION con label
-                match &task.description {
-                    Some(desc) if !desc.trim().is_empty() => {
-                        recurrent_tasks_field.push_str(&format!("**Description:**\n{}\n", desc));
-                    }
-                    _ => {
-                        recurrent_tasks_field.push_str("**Description:**\n*(no description)*\n");
-                    }
-                }
-
-                // ESPACIO entre Description y Schedule
-                recurrent_tasks_field.push_str("\n");
+                push_description(&mut recurrent_tasks_field, task, language);
 
                 // SCHEDULE (para weekly tasks)
-                recurrent_tasks_field.push_str(&format!("{}\n", recurrence_str));
-
-                // MENTIONS
-                if let Some(mention) = &task.mention {
-                    if !mention.trim().is_empty() {
-                        recurrent_tasks_field.push_str(&format!("**Mentions:** {}\n", mention));
-                    } else {
-                        recurrent_tasks_field
-                            .push_str(&format!("**Mentions:** <@{}>\n", task.user_id));
-                    }
-                } else {
-                    recurrent_tasks_field.push_str(&format!("**Mentions:** <@{}>\n", task.user_id));
-                }
-
-                // CHANNEL
-                if let Some(channel_id) = task.channel_id {
-                    recurrent_tasks_field.push_str(&format!("**Channel:** <#{}>\n", channel_id));
-                }
-
-                // TIME REMAINING al final
-                if let Some(next_occurrence) = task.next_occurrence() {
-                    let time_remaining =
-                        crate::application::commands::utils::time_remaining::format_time_remaining(
-                            next_occurrence,
-                        );
-                    recurrent_tasks_field
-                        .push_str(&format!("**Time remaining:** {}\n", time_remaining));
+                recurrent_tasks_field
+                    .push_str(&format!("{}\n", formatter.for_task(task).schedule(task)));
+                push_task_details(&mut recurrent_tasks_field, task, &formatter, language);
+
+                // NEXT OCCURRENCE al final
+                if let Some(next_occurrence) = task.next_occurrence(self.clock.now()) {
+                    recurrent_tasks_field.push_str(&tf(
+                        language,
+                        "**Next:** {next}\n",
+                        &[("next", &discord_timestamp_with_relative(next_occurrence))],
+                    ));
                 }
 
-                recurrent_tasks_field.push_str("\n");
+                recurrent_tasks_field.push('\n');
             }
 
             embed = embed.field("\n", "", false).field(
-                format!(
-                    "▬▬▬▬▬ **Weekly Tasks ({})** ▬▬▬▬▬\n\u{200B}",
-                    recurrent_tasks.len()
+                tf(
+                    language,
+                    "▬▬▬▬▬ **Weekly Tasks ({count})** ▬▬▬▬▬\n\u{200B}",
+                    &[("count", &recurrent_tasks.len())],
                 ),
                 recurrent_tasks_field,
                 false,
             );
         }
 
-        // Footer with localized current time
-        let now = Utc::now();
-        let local_time_str = match timezone_service
-            .format_from_utc_for_user(now, user_id)
-            .await
-        {
-            Ok(local_time) => local_time,
-            Err(_) => {
-                // Fallback to old method
-                match timezone_service.format_from_utc_with_timezone(now, &user_timezone) {
-                    Ok(local_time) => local_time,
-                    Err(_) => now.format("%Y-%m-%d %H:%M").to_string() + " (UTC)",
-                }
-            }
-        };
-
-        // Format description based on user's date format preference
-        let format_description = match user_date_format.as_str() {
-            "DMY" => "DD-MM-YYYY",
-            "MDY" => "MM-DD-YYYY",
-            "YMD" | _ => "YYYY-MM-DD",
-        };
-
-        embed = embed.footer(CreateEmbedFooter::new(format!(
-            "\n\u{200B}\nTimezone: {} | Date format: {} | Current time: {}",
-            user_timezone, format_description, local_time_str
+        // Footers don't render timestamp markup, so the current time is written out
+        embed = embed.footer(CreateEmbedFooter::new(tf(
+            language,
+            "\n\u{200B}\nTimezone: {timezone} | Date format: {date_format} | Current time: {now}",
+            &[
+                ("timezone", &user_timezone),
+                ("date_format", &formatter.date_format_label()),
+                ("now", &formatter.date_time(self.clock.now())),
+            ],
         )));
 
         embed
@@ -628,6 +460,7 @@ impl TaskService {
             .find(|t| t.id == task_id && t.user_id == user_id)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn edit_task(
         &self,
         task_id: u64,
@@ -635,35 +468,53 @@ impl TaskService {
         new_title: Option<String>,
         new_description: Option<String>,
         new_datetime_input: Option<String>,
+        new_timezone: Option<Option<String>>,
         is_weekly_task: bool,
         timezone_service: Arc<TimezoneService>,
-    ) -> Result<Task, String> {
+    ) -> Result<Task, UserError> {
         // validate task exists and belongs to user
         let current_task = self
             .get_task_for_editing(task_id, user_id)
             .await
-            .ok_or_else(|| "Task not found or you don't have permission to edit it".to_string())?;
+            .ok_or("Task not found or you don't have permission to edit it")?;
 
+        // The stored times only make sense in the zone they were given in
+        let new_timezone = new_timezone.filter(|timezone| *timezone != current_task.timezone);
+        if new_timezone.is_some() && new_datetime_input.is_none() {
+            return Err("Enter the date and time again to change the task's timezone".into());
+        }
+        let timezone = new_timezone
+            .clone()
+            .unwrap_or_else(|| current_task.timezone.clone());
+
+        // Tasks created under looser limits can still be edited as long as the schedule stays
+        let schedule_changed = new_datetime_input.is_some();
         let (new_scheduled_time, new_recurrence) = if let Some(datetime_input) = new_datetime_input
         {
             let task_type = if is_weekly_task { "weekly" } else { "single" };
             let (scheduled_time, recurrence) = timezone_service
-                .parse_task_input(&datetime_input, task_type, user_id)
+                .parse_task_input(
+                    &datetime_input,
+                    task_type,
+                    user_id,
+                    current_task.guild_id,
+                    timezone.as_deref(),
+                )
                 .await?;
 
             // calculate first ocurrence for weekly tasks
             if is_weekly_task {
                 if let Some(Recurrence::Weekly { days, hour, minute }) = recurrence {
                     let first_time = self
-                        .calculate_first_occurrence(&days, hour, minute)
-                        .ok_or("Could not calculate first occurrence".to_string())?;
+                        .calculate_first_occurrence(&days, hour, minute, timezone.as_deref())
+                        .ok_or("Could not calculate first occurrence")?;
 
                     (
                         Some(first_time),
                         Some(Recurrence::Weekly { days, hour, minute }),
                     )
                 } else {
-                    return Err("Invalid recurrence type".to_string());
+                    return Err("Invalid recurrence type".into());
                 }
             } else {
                 (scheduled_time, recurrence)
@@ -672,10 +523,14 @@ impl TaskService {
             (current_task.scheduled_time, current_task.recurrence)
         };
 
+        if schedule_changed && let Some(recurrence) = &new_recurrence {
+            self.limits.check_recurrence(recurrence)?;
+        }
+
         // validates title is not empty if a new one is provided
         if let Some(ref title) = new_title {
             if title.trim().is_empty() {
-                return Err("Task title cannot be empty".to_string());
+                return Err("Task title cannot be empty".into());
             }
         }
 
@@ -686,7 +541,8 @@ impl TaskService {
             None => None,             // don't change existing description
         };
 
-        self.task_repo
+        Ok(self
+            .task_repo
             .edit_task(
                 task_id,
                 new_title,
@@ -694,8 +550,9 @@ impl TaskService {
                 new_scheduled_time,
                 new_recurrence,
                 None,
+                new_timezone,
             )
-            .await
+            .await?)
     }
 
     // === SCHEDULER BUSINESS LOGIC ===
@@ -714,3 +571,64 @@ impl TaskService {
             .find(|task| task.id == task_id)
     }
 }
+
+/// Who a task's channel notification pings, the owner when no mentions were picked
+fn mentions_label(task: &Task) -> String {
+    if task.mentions.is_empty() {
+        MentionTarget::User(task.user_id).to_string()
+    } else {
+        format_mentions(&task.mentions)
+    }
+}
+
+/// Description with its label, or a placeholder when the task has none
+fn push_description(field: &mut String, task: &Task, language: Language) {
+    field.push_str(t(language, "**Description:**\n"));
+    match &task.description {
+        Some(desc) if !desc.trim().is_empty() => field.push_str(&format!("{}\n", desc)),
+        _ => field.push_str(t(language, "*(no description)*\n")),
+    }
+    // ESPACIO entre Description y Schedule
+    field.push('\n');
+}
+
+/// Timezone, mentions and channel lines of a task in /list_tasks
+fn push_task_details(
+    field: &mut String,
+    task: &Task,
+    formatter: &TimeFormatter,
+    language: Language,
+) {
+    if let Some(timezone) = &task.timezone {
+        // Single dates also written out in the task's zone, the markup shows the reader's
+        match (task.recurrence.is_none(), task.scheduled_time) {
+            (true, Some(at)) => field.push_str(&tf(
+                language,
+                "**Timezone:** {timezone} ({time} there)\n",
+                &[
+                    ("timezone", timezone),
+                    ("time", &formatter.for_task(task).date_time(at)),
+                ],
+            )),
+            _ => field.push_str(&tf(
+                language,
+                "**Timezone:** {timezone}\n",
+                &[("timezone", timezone)],
+            )),
+        }
+    }
+
+    field.push_str(&tf(
+        language,
+        "**Mentions:** {mentions}\n",
+        &[("mentions", &mentions_label(task))],
+    ));
+
+    if let Some(channel_id) = task.channel_id {
+        field.push_str(&tf(
+            language,
+            "**Channel:** {channel}\n",
+            &[("channel", &format!("<#{}>", channel_id))],
+        ));
+    }
+}
@@ -0,0 +1,248 @@
+use crate::application::i18n::{t, tf};
+use crate::domain::entities::task::{Recurrence, Task};
+use crate::domain::entities::user_preferences::UserPreferences;
+use crate::domain::value_objects::weekday_format::WeekdayFormat;
+use crate::domain::value_objects::{ClockFormat, Language};
+use chrono::{DateTime, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// How Discord renders a `<t:unix:style>` timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampStyle {
+    /// "Monday, 6 January 2025 12:00"
+    Full,
+    /// "in 2 hours"
+    Relative,
+}
+
+/// Discord timestamp markup, every reader sees it in their own client's timezone and language
+pub fn discord_timestamp(at: DateTime<Utc>, style: TimestampStyle) -> String {
+    let style = match style {
+        TimestampStyle::Full => 'F',
+        TimestampStyle::Relative => 'R',
+    };
+    format!("<t:{}:{}>", at.timestamp(), style)
+}
+
+/// Full date and time followed by how far away it is, for message content and embed fields
+pub fn discord_timestamp_with_relative(at: DateTime<Utc>) -> String {
+    format!(
+        "{} ({})",
+        discord_timestamp(at, TimestampStyle::Full),
+        discord_timestamp(at, TimestampStyle::Relative)
+    )
+}
+
+/// Writes times out for one reader, in their timezone, date format, clock and language. Used where
+/// Discord doesn't render timestamp markup: select menus, modal placeholders, embed titles and footers
+#[derive(Debug, Clone)]
+pub struct TimeFormatter {
+    zone: Tz,
+    date_pattern: &'static str,
+    clock: ClockFormat,
+    week_start: Weekday,
+    language: Language,
+}
+
+impl TimeFormatter {
+    /// `date_format` is "YMD", "DMY" or "MDY", anything else reads as "YMD"
+    pub fn new(zone: Tz, date_format: &str) -> Self {
+        let date_pattern = match date_format {
+            "DMY" => "%d-%m-%Y",
+            "MDY" => "%m-%d-%Y",
+            _ => "%Y-%m-%d",
+        };
+        Self {
+            zone,
+            date_pattern,
+            clock: ClockFormat::default(),
+            week_start: Weekday::Mon,
+            language: Language::default(),
+        }
+    }
+
+    /// Writes day names and recurrences in `language`
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Takes the clock and first day of the week the user chose in /preferences
+    pub fn with_preferences(mut self, preferences: &UserPreferences) -> Self {
+        self.clock = preferences.clock_format;
+        self.week_start = preferences.week_start();
+        self
+    }
+
+    pub fn zone(&self) -> Tz {
+        self.zone
+    }
+
+    /// Same reader, another timezone
+    pub fn in_zone(&self, zone: Tz) -> Self {
+        Self {
+            zone,
+            ..self.clone()
+        }
+    }
+
+    /// The same reader looking at `task`: tasks with their own timezone are shown in it
+    pub fn for_task(&self, task: &Task) -> Self {
+        match task
+            .timezone
+            .as_deref()
+            .and_then(|tz| tz.parse::<Tz>().ok())
+        {
+            Some(zone) => self.in_zone(zone),
+            None => self.clone(),
+        }
+    }
+
+    /// The date order as users type it, e.g. "DD-MM-YYYY"
+    pub fn date_format_label(&self) -> &'static str {
+        match self.date_pattern {
+            "%d-%m-%Y" => "DD-MM-YYYY",
+            "%m-%d-%Y" => "MM-DD-YYYY",
+            _ => "YYYY-MM-DD",
+        }
+    }
+
+    /// e.g. "06-01-2025"
+    pub fn date(&self, at: DateTime<Utc>) -> String {
+        at.with_timezone(&self.zone)
+            .format(self.date_pattern)
+            .to_string()
+    }
+
+    /// e.g. "09:00" or "9:00 AM"
+    pub fn time(&self, at: DateTime<Utc>) -> String {
+        self.clock.format(at.with_timezone(&self.zone).time())
+    }
+
+    /// e.g. "06-01-2025 09:00"
+    pub fn date_time(&self, at: DateTime<Utc>) -> String {
+        format!("{} {}", self.date(at), self.time(at))
+    }
+
+    /// Local time a recurring task fires at. Hours of tasks without a timezone are UTC, so they
+    /// are read on the next occurrence (or the day they were converted on) to get the offset
+    pub fn recurrence_time(&self, task: &Task) -> Option<String> {
+        let (hour, minute) = match task.recurrence.as_ref()? {
+            Recurrence::Weekly { hour, minute, .. } => (*hour, *minute),
+            Recurrence::EveryXDays { hour, minute, .. } => (*hour, *minute),
+        };
+        if task.timezone.is_some() {
+            let time = NaiveTime::from_hms_opt(hour as u32, minute as u32, 0)?;
+            return Some(self.clock.format(time));
+        }
+
+        let at = task.scheduled_time.or_else(|| {
+            Utc.with_ymd_and_hms(1970, 1, 1, hour as u32, minute as u32, 0)
+                .single()
+        })?;
+        Some(self.time(at))
+    }
+
+    /// e.g. "Sun, Mon, Wed", starting on the reader's first day of the week
+    pub fn weekdays(&self, days: &[Weekday]) -> String {
+        let mut days = days.to_vec();
+        days.sort_by_key(|day| day.days_since(self.week_start));
+        days.iter()
+            .map(|d| t(self.language, d.to_short_en()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// e.g. "Every Mon, Wed at 09:00", `None` for tasks that don't repeat
+    pub fn recurrence(&self, task: &Task) -> Option<String> {
+        let time = self.recurrence_time(task)?;
+        match task.recurrence.as_ref()? {
+            Recurrence::Weekly { days, .. } => Some(tf(
+                self.language,
+                "Every {days} at {time}",
+                &[("days", &self.weekdays(days)), ("time", &time)],
+            )),
+            Recurrence::EveryXDays { interval, .. } => Some(tf(
+                self.language,
+                "Every {interval} days at {time}",
+                &[("interval", interval), ("time", &time)],
+            )),
+        }
+    }
+
+    /// The recurrence of a repeating task, or the date and time of a single one
+    pub fn schedule(&self, task: &Task) -> String {
+        match (self.recurrence(task), task.scheduled_time) {
+            (Some(recurrence), _) => recurrence,
+            (None, Some(at)) => self.date_time(at),
+            (None, None) => t(self.language, "Not scheduled").to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+
+    #[test]
+    fn renders_discord_markup() {
+        let at = Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap();
+        assert_eq!(
+            discord_timestamp_with_relative(at),
+            "<t:1736164800:F> (<t:1736164800:R>)"
+        );
+    }
+
+    #[test]
+    fn writes_times_in_the_readers_zone_and_format() {
+        let madrid = TimeFormatter::new("Europe/Madrid".parse().unwrap(), "DMY");
+        let at = Utc.with_ymd_and_hms(2025, 1, 6, 23, 30, 0).unwrap();
+        assert_eq!(madrid.date_time(at), "07-01-2025 00:30");
+
+        // Weekly hours are UTC unless the task has its own zone
+        let weekly = Recurrence::Weekly {
+            days: vec![Weekday::Mon, Weekday::Wed],
+            hour: 8,
+            minute: 0,
+        };
+        let next = Utc.with_ymd_and_hms(2025, 1, 8, 8, 0, 0).unwrap();
+        let mut task = Task::for_test(42, "Standup").scheduled_at(next).recurring(weekly);
+        assert_eq!(madrid.schedule(&task), "Every Mon, Wed at 09:00");
+
+        task.timezone = Some("America/New_York".to_string());
+        assert_eq!(madrid.for_task(&task).zone().name(), "America/New_York");
+        assert_eq!(madrid.schedule(&task), "Every Mon, Wed at 08:00");
+    }
+
+    #[test]
+    fn follows_the_readers_clock_and_week() {
+        let mut preferences = UserPreferences::new_with_format(
+            42,
+            "America/New_York".to_string(),
+            Some("MDY".to_string()),
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        );
+        preferences.clock_format = ClockFormat::H12;
+        preferences.first_weekday = Some(Weekday::Sun);
+        let formatter = TimeFormatter::new("America/New_York".parse().unwrap(), "MDY")
+            .with_preferences(&preferences);
+
+        let at = Utc.with_ymd_and_hms(2025, 1, 6, 20, 30, 0).unwrap();
+        assert_eq!(formatter.date_time(at), "01-06-2025 3:30 PM");
+
+        let mut task = Task::for_test(42, "Standup")
+            .scheduled_at(at)
+            .recurring(Recurrence::Weekly {
+                days: vec![Weekday::Mon, Weekday::Sun],
+                hour: 15,
+                minute: 30,
+            });
+        task.timezone = Some("America/New_York".to_string());
+        assert_eq!(formatter.schedule(&task), "Every Sun, Mon at 3:30 PM");
+        assert_eq!(
+            formatter.with_language(Language::Es).schedule(&task),
+            "Cada Dom, Lun a las 3:30 PM"
+        );
+    }
+}
@@ -1,12 +1,17 @@
 use super::geo_mapping_service::GeoMappingService;
+use super::time_formatter::TimeFormatter;
+use crate::domain::Clock;
+use crate::domain::entities::guild_settings::GuildSettings;
 use crate::domain::entities::task::Recurrence;
 use crate::domain::entities::user_preferences::UserPreferences;
+use crate::domain::repositories::GuildSettingsRepository;
 use crate::domain::repositories::user_preferences_repository::{
     RepositoryError, UserPreferencesRepository,
 };
 use crate::domain::value_objects::weekday_format::WeekdayFormat;
+use crate::domain::value_objects::{ClockFormat, Language, UserError, WorkingHours};
 use crate::infrastructure::timezone::timezone_manager::{TimezoneInfo, TimezoneManager};
-use chrono::{DateTime, FixedOffset, LocalResult, TimeZone, Timelike, Utc, Weekday};
+use chrono::{DateTime, LocalResult, TimeZone, Timelike, Utc, Weekday};
 use std::sync::Arc;
 
 #[allow(dead_code)]
@@ -22,24 +27,69 @@ pub type Result<T> = std::result::Result<T, TimezoneError>;
 
 pub struct TimezoneService {
     user_prefs_repo: Arc<dyn UserPreferencesRepository>,
+    guild_settings_repo: Option<Arc<dyn GuildSettingsRepository>>, // None disables /server_settings
     timezone_manager: Arc<TimezoneManager>,
     geo_mapping_service: GeoMappingService,
+    clock: Arc<dyn Clock>,
 }
 
 impl TimezoneService {
     pub fn new(
         user_prefs_repo: Arc<dyn UserPreferencesRepository>,
         timezone_manager: Arc<TimezoneManager>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             user_prefs_repo,
+            guild_settings_repo: None,
             timezone_manager,
             geo_mapping_service: GeoMappingService::new(),
+            clock,
         }
     }
 
+    pub fn with_guild_settings(
+        mut self,
+        guild_settings_repo: Arc<dyn GuildSettingsRepository>,
+    ) -> Self {
+        self.guild_settings_repo = Some(guild_settings_repo);
+        self
+    }
+
+    /// Current time according to the injected clock
+    pub fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
     /// Set the time zone for a user and infer date format automatically
     pub async fn set_user_timezone(&self, user_id: u64, timezone_str: &str) -> Result<()> {
+        let preferences = self.preferences_in_timezone(user_id, timezone_str).await?;
+        self.user_prefs_repo
+            .save(&preferences)
+            .await
+            .map_err(TimezoneError::RepositoryError)
+    }
+
+    /// Like `set_user_timezone`, writing the new task schedules in the same transaction
+    pub async fn set_user_timezone_with_schedules(
+        &self,
+        user_id: u64,
+        timezone_str: &str,
+        schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+    ) -> Result<()> {
+        let preferences = self.preferences_in_timezone(user_id, timezone_str).await?;
+        self.user_prefs_repo
+            .save_with_task_schedules(&preferences, schedules)
+            .await
+            .map_err(TimezoneError::RepositoryError)
+    }
+
+    /// Stored preferences of `user_id` (or new ones) moved to `timezone_str`
+    async fn preferences_in_timezone(
+        &self,
+        user_id: u64,
+        timezone_str: &str,
+    ) -> Result<UserPreferences> {
         if !self.timezone_manager.validate_timezone(timezone_str) {
             return Err(TimezoneError::InvalidTimezone(format!(
                 "Invalid timezone: {timezone_str}"
@@ -56,6 +106,7 @@ impl TimezoneService {
                 prefs.update_timezone_and_format(
                     timezone_str.to_string(),
                     date_format.map(|s| s.to_string()),
+                    self.clock.now(),
                 );
                 prefs
             }
@@ -63,9 +114,44 @@ impl TimezoneService {
                 user_id,
                 timezone_str.to_string(),
                 date_format.map(|s| s.to_string()),
+                self.clock.now(),
             ),
             Err(e) => return Err(TimezoneError::RepositoryError(e)),
         };
+        Ok(preferences)
+    }
+
+    /// Set the working hours /meeting_finder uses, `None` goes back to the default. The user
+    /// needs a timezone first, the hours are local to it
+    pub async fn set_user_working_hours(
+        &self,
+        user_id: u64,
+        working_hours: Option<WorkingHours>,
+    ) -> Result<()> {
+        let mut preferences = self
+            .user_prefs_repo
+            .get(user_id)
+            .await
+            .map_err(TimezoneError::RepositoryError)?
+            .ok_or(TimezoneError::NotFound)?;
+        preferences.update_working_hours(working_hours, self.clock.now());
+
+        self.user_prefs_repo
+            .save(&preferences)
+            .await
+            .map_err(TimezoneError::RepositoryError)
+    }
+
+    /// Saves the display preferences changed in /preferences. A date format that wasn't chosen
+    /// goes back to the one of the user's timezone
+    pub async fn save_user_preferences(&self, mut preferences: UserPreferences) -> Result<()> {
+        if !preferences.date_format_chosen {
+            preferences.date_format = self
+                .geo_mapping_service
+                .infer_date_format_from_timezone(&preferences.timezone)
+                .map(|s| s.to_string());
+        }
+        preferences.updated_at = self.clock.now();
 
         self.user_prefs_repo
             .save(&preferences)
@@ -99,12 +185,142 @@ impl TimezoneService {
             .map_err(TimezoneError::RepositoryError)
     }
 
-    /// Gets the date format placeholder for a user (for UI display)
-    pub async fn get_user_date_format_placeholder(&self, user_id: u64) -> Result<&'static str> {
-        match self.user_prefs_repo.get(user_id).await {
-            Ok(Some(prefs)) => Ok(prefs.get_date_format_placeholder()),
-            Ok(None) => Ok("YYYY-MM-DD"), // Default placeholder
-            Err(e) => Err(TimezoneError::RepositoryError(e)),
+    /// Gets the defaults admins set for a guild, `None` when there are none
+    pub async fn get_guild_settings(&self, guild_id: u64) -> Result<Option<GuildSettings>> {
+        match &self.guild_settings_repo {
+            Some(repo) => repo
+                .get(guild_id)
+                .await
+                .map_err(TimezoneError::RepositoryError),
+            None => Ok(None),
+        }
+    }
+
+    /// Store a guild's defaults, dropping them altogether once nothing is set
+    pub async fn save_guild_settings(&self, mut settings: GuildSettings) -> Result<()> {
+        let repo = self.guild_settings_repo.as_ref().ok_or_else(|| {
+            TimezoneError::RepositoryError(RepositoryError::StorageError(
+                "Server settings are not available".to_string(),
+            ))
+        })?;
+        if let Some(timezone) = &settings.timezone
+            && !self.timezone_manager.validate_timezone(timezone)
+        {
+            return Err(TimezoneError::InvalidTimezone(format!(
+                "Invalid timezone: {timezone}"
+            )));
+        }
+
+        if settings.is_empty() {
+            return match repo.delete(settings.guild_id).await {
+                Ok(()) | Err(RepositoryError::NotFound) => Ok(()),
+                Err(e) => Err(TimezoneError::RepositoryError(e)),
+            };
+        }
+        settings.updated_at = self.clock.now();
+        repo.save(&settings)
+            .await
+            .map_err(TimezoneError::RepositoryError)
+    }
+
+    /// The user's timezone, or the guild's default when they never set one
+    pub async fn get_effective_timezone(
+        &self,
+        user_id: u64,
+        guild_id: Option<u64>,
+    ) -> Result<Option<String>> {
+        if let Some(timezone) = self.get_user_timezone(user_id).await? {
+            return Ok(Some(timezone));
+        }
+        match guild_id {
+            Some(guild_id) => Ok(self
+                .get_guild_settings(guild_id)
+                .await?
+                .and_then(|settings| settings.timezone)),
+            None => Ok(None),
+        }
+    }
+
+    /// Language to answer `user_id` in: the one chosen in /preferences, else their Discord
+    /// client's `locale` when the bot speaks it, else the guild's default, else English
+    pub async fn language_for(
+        &self,
+        user_id: u64,
+        guild_id: Option<u64>,
+        locale: Option<&str>,
+    ) -> Language {
+        if let Ok(Some(language)) = self
+            .get_user_preferences(user_id)
+            .await
+            .map(|prefs| prefs.and_then(|prefs| prefs.language))
+        {
+            return language;
+        }
+        if let Some(language) = locale.and_then(|locale| locale.parse().ok()) {
+            return language;
+        }
+        match guild_id {
+            Some(guild_id) => self.guild_language(guild_id).await,
+            None => Language::default(),
+        }
+    }
+
+    /// The guild's default language, English when none was set
+    pub async fn guild_language(&self, guild_id: u64) -> Language {
+        self.get_guild_settings(guild_id)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|settings| settings.language)
+            .unwrap_or_default()
+    }
+
+    /// The user's date format, or the guild's default when they have no preferences
+    pub async fn get_effective_date_format(
+        &self,
+        user_id: u64,
+        guild_id: Option<u64>,
+    ) -> Result<Option<String>> {
+        if let Some(prefs) = self.get_user_preferences(user_id).await? {
+            return Ok(prefs.date_format);
+        }
+        match guild_id {
+            Some(guild_id) => Ok(self
+                .get_guild_settings(guild_id)
+                .await?
+                .and_then(|settings| settings.date_format)),
+            None => Ok(None),
+        }
+    }
+
+    /// Timezone a new task is pinned to: the one chosen for it, else the guild's default for
+    /// server tasks or when the owner has no timezone. `None` follows the owner's timezone, and
+    /// `NotFound` means there is no timezone to go by at all
+    pub async fn resolve_task_timezone(
+        &self,
+        user_id: u64,
+        guild_id: u64,
+        explicit: Option<String>,
+        server_task: bool,
+    ) -> Result<Option<String>> {
+        if explicit.is_some() {
+            return Ok(explicit);
+        }
+
+        let user_timezone = self.get_user_timezone(user_id).await?;
+        if server_task || user_timezone.is_none() {
+            let guild_timezone = self
+                .get_guild_settings(guild_id)
+                .await?
+                .and_then(|settings| settings.timezone);
+            if guild_timezone.is_some() {
+                return Ok(guild_timezone);
+            }
+        }
+
+        match user_timezone {
+            Some(_) => Ok(None),
+            None => Err(TimezoneError::NotFound),
         }
     }
 
@@ -130,10 +346,7 @@ impl TimezoneService {
                 TimezoneError::InvalidTimezone(format!("Timezone not found: {timezone}"))
             })?;
 
-        let offset = FixedOffset::east_opt((tz_info.offset * 3600.0) as i32)
-            .ok_or_else(|| TimezoneError::TimeConversionError("Invalid offset".to_string()))?;
-
-        let local_datetime_with_offset = offset.from_local_datetime(&naive_datetime);
+        let local_datetime_with_offset = tz_info.tz.from_local_datetime(&naive_datetime);
 
         match local_datetime_with_offset {
             LocalResult::Single(datetime) => Ok(datetime.with_timezone(&Utc)),
@@ -146,71 +359,42 @@ impl TimezoneService {
         }
     }
 
-    /// Convert UTC date to a specific timezone with proper date formatting
-    pub fn format_from_utc_with_timezone(
-        &self,
-        utc_datetime: DateTime<Utc>,
-        timezone: &str,
-    ) -> Result<String> {
-        let tz_info = self
+    /// Writes times in `timezone` with `date_format`, inferred from the zone when `None`.
+    /// Unknown zones fall back to UTC
+    pub fn formatter(&self, timezone: &str, date_format: Option<&str>) -> TimeFormatter {
+        let zone = self
             .timezone_manager
             .get_timezone_info(timezone)
-            .ok_or_else(|| {
-                TimezoneError::InvalidTimezone(format!("Timezone not found: {timezone}"))
-            })?;
-
-        let offset = FixedOffset::east_opt((tz_info.offset * 3600.0) as i32)
-            .ok_or_else(|| TimezoneError::TimeConversionError("Invalid offset".to_string()))?;
-
-        let local_datetime = utc_datetime.with_timezone(&offset);
-
-        // Infer date format from timezone for proper display
-        let date_format = self
-            .geo_mapping_service
-            .infer_date_format_from_timezone(timezone)
+            .map_or(chrono_tz::UTC, |tz_info| tz_info.tz);
+        let date_format = date_format
+            .or_else(|| {
+                self.geo_mapping_service
+                    .infer_date_format_from_timezone(timezone)
+            })
             .unwrap_or("YMD");
 
-        let format_pattern = match date_format {
-            "DMY" => "%d-%m-%Y %H:%M",
-            "MDY" => "%m-%d-%Y %H:%M",
-            "YMD" | _ => "%Y-%m-%d %H:%M",
-        };
-
-        Ok(local_datetime.format(format_pattern).to_string())
+        TimeFormatter::new(zone, date_format)
     }
 
-    /// Format a UTC datetime using the user's preferred date format
-    pub async fn format_from_utc_for_user(
-        &self,
-        utc_datetime: DateTime<Utc>,
-        user_id: u64,
-    ) -> Result<String> {
-        let prefs = self
-            .get_user_preferences(user_id)
-            .await?
-            .ok_or(TimezoneError::NotFound)?;
-
-        let tz_info = self
-            .timezone_manager
-            .get_timezone_info(&prefs.timezone)
-            .ok_or_else(|| {
-                TimezoneError::InvalidTimezone(format!("Timezone not found: {}", prefs.timezone))
-            })?;
-
-        let offset = FixedOffset::east_opt((tz_info.offset * 3600.0) as i32)
-            .ok_or_else(|| TimezoneError::TimeConversionError("Invalid offset".to_string()))?;
-
-        let local_datetime = utc_datetime.with_timezone(&offset);
-
-        // Format based on user's preferred date format
-        let format_pattern = match prefs.date_format.as_deref() {
-            Some("DMY") => "%d-%m-%Y %H:%M",
-            Some("MDY") => "%m-%d-%Y %H:%M",
-            Some("YMD") | None => "%Y-%m-%d %H:%M", // Default to YMD
-            _ => "%Y-%m-%d %H:%M",                  // Fallback
-        };
+    /// Writes times for a user: their timezone and date format, or the guild's defaults, or UTC
+    pub async fn formatter_for(&self, user_id: u64, guild_id: Option<u64>) -> TimeFormatter {
+        let timezone = self
+            .get_effective_timezone(user_id, guild_id)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| "UTC".to_string());
+        let date_format = self
+            .get_effective_date_format(user_id, guild_id)
+            .await
+            .ok()
+            .flatten();
 
-        Ok(local_datetime.format(format_pattern).to_string())
+        let formatter = self.formatter(&timezone, date_format.as_deref());
+        match self.get_user_preferences(user_id).await {
+            Ok(Some(prefs)) => formatter.with_preferences(&prefs),
+            _ => formatter,
+        }
     }
 
     /// Gets the current time in the user's timezone with proper date formatting
@@ -220,136 +404,125 @@ impl TimezoneService {
             .await?
             .ok_or(TimezoneError::NotFound)?;
 
-        let now_utc = Utc::now();
-        self.format_from_utc_with_timezone_and_format(
-            now_utc,
-            &prefs.timezone,
-            prefs.date_format.as_deref(),
-        )
+        Ok(self
+            .formatter(&prefs.timezone, prefs.date_format.as_deref())
+            .with_preferences(&prefs)
+            .date_time(self.clock.now()))
     }
 
     /// Gets the current time in a specific timezone with proper date formatting
     pub fn get_current_time_for_timezone(&self, timezone: &str) -> Result<String> {
-        let now_utc = Utc::now();
-        self.format_from_utc_with_timezone(now_utc, timezone)
-    }
-
-    /// Helper function to format UTC datetime with explicit date format
-    fn format_from_utc_with_timezone_and_format(
-        &self,
-        utc_datetime: DateTime<Utc>,
-        timezone: &str,
-        date_format: Option<&str>,
-    ) -> Result<String> {
-        let tz_info = self
-            .timezone_manager
-            .get_timezone_info(timezone)
-            .ok_or_else(|| {
-                TimezoneError::InvalidTimezone(format!("Timezone not found: {timezone}"))
-            })?;
-
-        let offset = FixedOffset::east_opt((tz_info.offset * 3600.0) as i32)
-            .ok_or_else(|| TimezoneError::TimeConversionError("Invalid offset".to_string()))?;
-
-        let local_datetime = utc_datetime.with_timezone(&offset);
-
-        // Use provided date format or infer from timezone
-        let format_pattern = match date_format {
-            Some("DMY") => "%d-%m-%Y %H:%M",
-            Some("MDY") => "%m-%d-%Y %H:%M",
-            Some("YMD") => "%Y-%m-%d %H:%M",
-            None => {
-                // Infer format from timezone if not provided
-                let inferred_format = self
-                    .geo_mapping_service
-                    .infer_date_format_from_timezone(timezone)
-                    .unwrap_or("YMD");
-                match inferred_format {
-                    "DMY" => "%d-%m-%Y %H:%M",
-                    "MDY" => "%m-%d-%Y %H:%M",
-                    "YMD" | _ => "%Y-%m-%d %H:%M",
-                }
-            }
-            _ => "%Y-%m-%d %H:%M", // Fallback
-        };
-
-        Ok(local_datetime.format(format_pattern).to_string())
+        if self.timezone_manager.get_timezone_info(timezone).is_none() {
+            return Err(TimezoneError::InvalidTimezone(format!(
+                "Timezone not found: {timezone}"
+            )));
+        }
+        Ok(self.formatter(timezone, None).date_time(self.clock.now()))
     }
 
-    /// Search time zones by city, country, etc with geographic mapping
+    /// Search time zones by city, country, state, zone name or abbreviation
     pub fn search_timezones(&self, query: &str) -> Vec<&TimezoneInfo> {
-        // First, try the timezone_manager search (handles special cases like US, Canada)
-        let manager_results = self.timezone_manager.search_timezones(query);
-        
-        // If timezone_manager returns results, use them
-        if !manager_results.is_empty() {
-            return manager_results;
-        }
-        
-        // Otherwise, try geo_mapping_service for direct country/city matches
-        if let Some(timezone_id) = self.geo_mapping_service.search_geo_mapping(query) {
-            if let Some(tz_info) = self.timezone_manager.get_timezone_info(timezone_id) {
-                return vec![tz_info];
-            }
-        }
-        
-        // If nothing found, return empty
-        vec![]
+        self.timezone_manager.search_timezones(query)
     }
 
-    /// Get timezone information by exact name
+    /// Get timezone information by IANA name or alias
     pub fn get_timezone_info(&self, timezone: &str) -> Option<&TimezoneInfo> {
         self.timezone_manager.get_timezone_info(timezone)
     }
 
-    /// Parses and validates the entry of a task (single or weekly) based on the user's time zone
+    /// The IANA name meant by a zone name, alias or search that finds a single zone,
+    /// e.g. "new york" -> "America/New_York". The error lists the candidates
+    pub fn resolve_timezone(&self, query: &str) -> std::result::Result<String, UserError> {
+        if let Some(tz_info) = self.timezone_manager.get_timezone_info(query.trim()) {
+            return Ok(tz_info.id.clone());
+        }
+
+        match self.timezone_manager.search_timezones(query).as_slice() {
+            [] => Err(UserError::new("`{query}` is not a timezone I know").with("query", query.trim())),
+            [tz_info] => Ok(tz_info.id.clone()),
+            candidates => Err(UserError::new(
+                "`{query}` matches several timezones, use one of: {candidates}",
+            )
+            .with("query", query.trim())
+            .with(
+                "candidates",
+                candidates
+                    .iter()
+                    .take(5)
+                    .map(|tz_info| format!("`{}`", tz_info.id))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )),
+        }
+    }
+
+    /// Parses and validates the entry of a task (single or weekly) in `task_timezone`, or the
+    /// user's time zone when the task has none. Weekly times stay local to `task_timezone`,
+    /// otherwise they are converted to UTC
     pub async fn parse_task_input(
         &self,
         input_str: &str,
         task_type: &str,
         user_id: u64,
-    ) -> std::result::Result<(Option<DateTime<Utc>>, Option<Recurrence>), String> {
-        let user_timezone = self
-            .get_user_timezone(user_id)
-            .await
-            .map_err(|e| format!("Error getting timezone: {e:?}"))?
-            .ok_or("User has no timezone configured".to_string())?;
+        guild_id: u64,
+        task_timezone: Option<&str>,
+    ) -> std::result::Result<(Option<DateTime<Utc>>, Option<Recurrence>), UserError> {
+        let timezone = match task_timezone {
+            Some(timezone) => timezone.to_string(),
+            None => self
+                .get_user_timezone(user_id)
+                .await
+                .map_err(|e| format!("Error getting timezone: {e:?}"))?
+                .ok_or("User has no timezone configured".to_string())?,
+        };
+        let timezone = timezone.as_str();
 
         // Get user's date format to parse the input correctly
         let user_date_format = self
-            .get_user_date_format(user_id)
+            .get_effective_date_format(user_id, Some(guild_id))
             .await
             .map_err(|e| format!("Error getting date format: {e:?}"))?
             .unwrap_or("YMD".to_string());
+        let clock = self
+            .get_user_preferences(user_id)
+            .await
+            .map_err(|e| format!("Error getting preferences: {e:?}"))?
+            .map(|prefs| prefs.clock_format)
+            .unwrap_or_default();
 
         match task_type {
             "single" => {
                 // Determine the format pattern based on user's date format
-                let format_pattern = match user_date_format.as_str() {
-                    "DMY" => "%d-%m-%Y %H:%M",
-                    "MDY" => "%m-%d-%Y %H:%M",
-                    "YMD" | _ => "%Y-%m-%d %H:%M",
+                let (format_pattern, format_hint) = match user_date_format.as_str() {
+                    "DMY" => ("%d-%m-%Y", "DD-MM-YYYY"),
+                    "MDY" => ("%m-%d-%Y", "MM-DD-YYYY"),
+                    _ => ("%Y-%m-%d", "YYYY-MM-DD"),
+                };
+                let parse_error = || {
+                    UserError::new(match clock {
+                        ClockFormat::H24 => "Failed to parse date/time. Use format: {date} HH:MM",
+                        ClockFormat::H12 => {
+                            "Failed to parse date/time. Use format: {date} HH:MM or 3:30 PM"
+                        }
+                    })
+                    .with("date", format_hint)
                 };
 
-                let naive_dt = chrono::NaiveDateTime::parse_from_str(input_str, format_pattern)
-                    .map_err(|_| {
-                        format!(
-                            "Failed to parse date/time. Use format: {}",
-                            match user_date_format.as_str() {
-                                "DMY" => "DD-MM-YYYY HH:MM",
-                                "MDY" => "MM-DD-YYYY HH:MM",
-                                "YMD" | _ => "YYYY-MM-DD HH:MM",
-                            }
-                        )
-                    })?;
+                let (date_str, time_str) = Self::split_time(input_str).ok_or_else(parse_error)?;
+                let date = chrono::NaiveDate::parse_from_str(date_str, format_pattern)
+                    .map_err(|_| parse_error())?;
+                let (hour, minute) = clock.parse_time(&time_str)?;
+                let naive_dt = date
+                    .and_hms_opt(hour as u32, minute as u32, 0)
+                    .ok_or_else(parse_error)?;
 
                 // Convert to the standard format for further processing
                 let standard_format_str = naive_dt.format("%Y-%m-%d %H:%M").to_string();
                 let utc_datetime = self
-                    .parse_to_utc_with_timezone(&standard_format_str, &user_timezone)
+                    .parse_to_utc_with_timezone(&standard_format_str, timezone)
                     .map_err(|e| format!("Error processing date/time: {e:?}"))?;
 
-                let is_future = utc_datetime > Utc::now();
+                let is_future = utc_datetime > self.clock.now();
 
                 if !is_future {
                     return Err("You cannot schedule a task in the past".into());
@@ -358,12 +531,16 @@ impl TimezoneService {
                 Ok((Some(utc_datetime), None))
             }
             "weekly" => {
-                let (days, hour, minute) = Self::parse_weekly_input(input_str)?;
+                let (days, hour, minute) = Self::parse_weekly_input(input_str, clock)?;
+
+                if task_timezone.is_some() {
+                    return Ok((None, Some(Recurrence::Weekly { days, hour, minute })));
+                }
 
                 let time_str = format!("{hour:02}:{minute:02}");
                 let local_datetime_str = format!("1970-01-01 {time_str}");
                 let utc_datetime = self
-                    .parse_to_utc_with_timezone(&local_datetime_str, &user_timezone)
+                    .parse_to_utc_with_timezone(&local_datetime_str, timezone)
                     .map_err(|e| format!("Error processing time: {e:?}"))?;
 
                 let recurrence = Recurrence::Weekly {
@@ -374,26 +551,48 @@ impl TimezoneService {
 
                 Ok((None, Some(recurrence)))
             }
-            _ => Err(format!("Unknown task type: {task_type}")),
+            _ => Err(format!("Unknown task type: {task_type}").into()),
         }
     }
 
     /// Parse weekly input string into weekdays, hour, and minute
-    fn parse_weekly_input(input_str: &str) -> std::result::Result<(Vec<Weekday>, u8, u8), String> {
-        let input = input_str.trim();
-        let last_space = input.rfind(' ').ok_or("Invalid format. Use: days HH:MM")?;
-        let days_str = &input[..last_space];
-        let time_str = &input[last_space + 1..];
+    fn parse_weekly_input(
+        input_str: &str,
+        clock: ClockFormat,
+    ) -> std::result::Result<(Vec<Weekday>, u8, u8), UserError> {
+        let (days_str, time_str) = Self::split_time(input_str).ok_or(match clock {
+            ClockFormat::H24 => "Invalid format. Use: days HH:MM",
+            ClockFormat::H12 => "Invalid format. Use: days HH:MM or 3:30 PM",
+        })?;
 
         let days = Self::parse_days(days_str)?;
 
-        let (hour, minute) = Self::parse_time(time_str)?;
+        let (hour, minute) = Self::parse_time(&time_str, clock)?;
 
         Ok((days, hour, minute))
     }
 
+    /// Splits "<date or days> <time>" at the last space, keeping a separate AM/PM with the time
+    fn split_time(input_str: &str) -> Option<(&str, String)> {
+        let input = input_str.trim();
+        let lower = input.to_ascii_lowercase();
+        let meridiem_len = if lower.ends_with("am") || lower.ends_with("pm") {
+            2
+        } else {
+            0
+        };
+        let (body, meridiem) = input.split_at(input.len() - meridiem_len);
+        let body = body.trim_end();
+
+        let last_space = body.rfind(' ')?;
+        Some((
+            body[..last_space].trim_end(),
+            format!("{}{}", &body[last_space + 1..], meridiem),
+        ))
+    }
+
     /// Parse days string into Weekday enums with intelligent parsing
-    fn parse_days(days_str: &str) -> std::result::Result<Vec<Weekday>, String> {
+    fn parse_days(days_str: &str) -> std::result::Result<Vec<Weekday>, UserError> {
         let mut days = Vec::new();
 
         for day_str in days_str.split(',') {
@@ -401,7 +600,7 @@ impl TimezoneService {
             if let Some(weekday) = Weekday::from_str(day_clean) {
                 days.push(weekday);
             } else {
-                return Err(format!("Invalid day: {}", day_str));
+                return Err(UserError::new("Invalid day: {day}").with("day", day_str));
             }
         }
 
@@ -409,25 +608,9 @@ impl TimezoneService {
         Ok(days)
     }
 
-    /// Parse time string into hour and minute
-    fn parse_time(time_str: &str) -> std::result::Result<(u8, u8), String> {
-        let parts: Vec<&str> = time_str.split(':').collect();
-        if parts.len() != 2 {
-            return Err("Invalid time format. Use HH:MM".to_string());
-        }
-
-        let hour = parts[0]
-            .parse::<u8>()
-            .map_err(|_| "Invalid hour".to_string())?;
-        let minute = parts[1]
-            .parse::<u8>()
-            .map_err(|_| "Invalid minute".to_string())?;
-
-        if hour > 23 || minute > 59 {
-            return Err("Invalid time values".to_string());
-        }
-
-        Ok((hour, minute))
+    /// Parse time string into hour and minute, `3:30 PM` is accepted with the 12-hour clock
+    fn parse_time(time_str: &str, clock: ClockFormat) -> std::result::Result<(u8, u8), UserError> {
+        clock.parse_time(time_str)
     }
 
     /// Infer date format from a timezone string
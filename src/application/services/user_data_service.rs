@@ -0,0 +1,186 @@
+use crate::application::services::TaskOrchestrator;
+use crate::domain::entities::scheduled_task::{DeliveryRecord, ScheduledTask};
+use crate::domain::entities::task::Task;
+use crate::domain::entities::user_preferences::UserPreferences;
+use crate::domain::repositories::task_scheduler_repository::TaskSchedulerRepository;
+use crate::domain::repositories::user_preferences_repository::{
+    RepositoryError, UserPreferencesRepository,
+};
+use crate::domain::value_objects::UserError;
+use crate::domain::{Clock, PersonalDataStore};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::info;
+
+/// Bumped whenever the layout of `UserDataExport` changes
+pub const USER_DATA_FORMAT_VERSION: u32 = 1;
+
+/// Everything the bot stores about one user, sent to them by `/my_data export`
+#[derive(Debug, Serialize)]
+pub struct UserDataExport {
+    pub format_version: u32,
+    pub user_id: u64,
+    pub exported_at: DateTime<Utc>,
+    pub tasks: Vec<Task>,
+    pub preferences: Option<UserPreferences>,
+    /// Live scheduler entries of the user's tasks
+    pub scheduled: Vec<ScheduledTask>,
+    /// Recently delivered notifications (kept about a week)
+    pub deliveries: Vec<DeliveryRecord>,
+    /// Sections from other `PersonalDataStore`s, by name
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+impl UserDataExport {
+    pub fn to_json(&self) -> Result<String, UserError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| UserError::new("Failed to serialize data: {reason}").with("reason", e))
+    }
+}
+
+/// What `/my_data delete` removed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserDataDeletion {
+    pub tasks: usize,
+    pub scheduler_entries: usize,
+    pub preferences: bool,
+    /// Records removed from other `PersonalDataStore`s
+    pub other: usize,
+}
+
+/// Export and erasure of a user's data across tasks, scheduler, preferences and extra stores
+pub struct UserDataService {
+    task_orchestrator: Arc<TaskOrchestrator>,
+    preferences_repo: Arc<dyn UserPreferencesRepository>,
+    task_scheduler: Arc<dyn TaskSchedulerRepository>,
+    stores: Vec<Arc<dyn PersonalDataStore>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl UserDataService {
+    pub fn new(
+        task_orchestrator: Arc<TaskOrchestrator>,
+        preferences_repo: Arc<dyn UserPreferencesRepository>,
+        task_scheduler: Arc<dyn TaskSchedulerRepository>,
+        stores: Vec<Arc<dyn PersonalDataStore>>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            task_orchestrator,
+            preferences_repo,
+            task_scheduler,
+            stores,
+            clock,
+        }
+    }
+
+    pub async fn export(&self, user_id: u64) -> Result<UserDataExport, UserError> {
+        let tasks = self.user_tasks(user_id).await;
+        let task_ids: Vec<u64> = tasks.iter().map(|task| task.id).collect();
+
+        let preferences = self
+            .preferences_repo
+            .get(user_id)
+            .await
+            .map_err(|e| UserError::new("Failed to read preferences: {reason}").with("reason", e))?;
+
+        let scheduled = self
+            .task_scheduler
+            .list_pending()
+            .await
+            .map_err(|e| UserError::new("Failed to read scheduler: {reason}").with("reason", e))?
+            .into_iter()
+            .filter(|entry| entry.user_id == user_id)
+            .collect();
+
+        let deliveries = self
+            .task_scheduler
+            .list_deliveries(&task_ids)
+            .await
+            .map_err(|e| {
+                UserError::new("Failed to read delivery history: {reason}").with("reason", e)
+            })?;
+
+        let mut other = BTreeMap::new();
+        for store in &self.stores {
+            if let Some(section) = store.export_user(user_id).await? {
+                other.insert(store.name().to_string(), section);
+            }
+        }
+
+        Ok(UserDataExport {
+            format_version: USER_DATA_FORMAT_VERSION,
+            user_id,
+            exported_at: self.clock.now(),
+            tasks,
+            preferences,
+            scheduled,
+            deliveries,
+            other,
+        })
+    }
+
+    /// Remove every record tied to `user_id`. Scheduler entries are purged for good instead of
+    /// being left as tombstones, since they carry the user's id and task titles
+    pub async fn delete(&self, user_id: u64) -> Result<UserDataDeletion, UserError> {
+        let task_ids: Vec<u64> = self
+            .user_tasks(user_id)
+            .await
+            .iter()
+            .map(|task| task.id)
+            .collect();
+
+        let tasks = self
+            .task_orchestrator
+            .remove_all_user_tasks(user_id)
+            .await?;
+
+        let scheduler_entries = self
+            .task_scheduler
+            .purge_user(user_id, &task_ids)
+            .await
+            .map_err(|e| {
+                UserError::new("Failed to purge scheduler entries: {reason}").with("reason", e)
+            })?;
+
+        let preferences = match self.preferences_repo.delete(user_id).await {
+            Ok(()) => true,
+            Err(RepositoryError::NotFound) => false,
+            Err(e) => {
+                return Err(UserError::new("Failed to delete preferences: {reason}").with("reason", e));
+            }
+        };
+
+        let mut other = 0;
+        for store in &self.stores {
+            other += store.erase_user(user_id).await?;
+        }
+
+        info!(
+            "Erased data of user {}: {} tasks, {} scheduler entries, preferences: {}, {} other records",
+            user_id, tasks, scheduler_entries, preferences, other
+        );
+
+        Ok(UserDataDeletion {
+            tasks,
+            scheduler_entries,
+            preferences,
+            other,
+        })
+    }
+
+    /// Every task of `user_id`, archived ones included
+    async fn user_tasks(&self, user_id: u64) -> Vec<Task> {
+        let mut tasks = self.task_orchestrator.get_user_tasks(user_id).await;
+        tasks.extend(
+            self.task_orchestrator
+                .get_archived_tasks()
+                .await
+                .into_iter()
+                .filter(|task| task.user_id == user_id),
+        );
+        tasks
+    }
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+use daily::infrastructure::admin_cli::{AdminCli, run_admin};
+use daily::infrastructure::config::AppConfig;
+use dotenvy::dotenv;
+use std::process::ExitCode;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv().ok();
+    let cli = AdminCli::parse();
+
+    // Logs go to stderr so `export` can be piped; quiet unless RUST_LOG asks for more
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+        )
+        .init();
+
+    let result = match AppConfig::load() {
+        Ok(config) => run_admin(config, cli).await,
+        Err(e) => Err(e.into()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("daily-admin: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use tokio::sync::watch;
+
+/// Source of the current time, injected so scheduling logic can run against simulated time
+#[async_trait]
+pub trait Clock: Send + Sync {
+    /// Current instant in UTC
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Wait until `now()` reaches `deadline` (returns immediately if it already has)
+    async fn sleep_until(&self, deadline: DateTime<Utc>);
+
+    /// Wait for `duration` of clock time
+    async fn sleep(&self, duration: Duration) {
+        self.sleep_until(self.now() + duration).await;
+    }
+}
+
+/// Wall clock backed by `Utc::now()` and tokio timers
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    async fn sleep_until(&self, deadline: DateTime<Utc>) {
+        if let Ok(remaining) = (deadline - Utc::now()).to_std() {
+            tokio::time::sleep(remaining).await;
+        }
+    }
+}
+
+/// Clock that only moves when told to, sleepers wake up once time is advanced past their deadline
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct ManualClock {
+    now: watch::Sender<DateTime<Utc>>,
+}
+
+#[allow(dead_code)]
+impl ManualClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: watch::Sender::new(start),
+        }
+    }
+
+    /// Jump to an absolute instant
+    pub fn set(&self, instant: DateTime<Utc>) {
+        self.now.send_replace(instant);
+    }
+
+    /// Move time forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        self.now.send_modify(|now| *now += duration);
+    }
+}
+
+#[async_trait]
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.borrow()
+    }
+
+    async fn sleep_until(&self, deadline: DateTime<Utc>) {
+        let mut receiver = self.now.subscribe();
+        // The sender lives as long as self, so wait_for can only fail if the clock is dropped
+        let _ = receiver.wait_for(|now| *now >= deadline).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn manual_clock_only_moves_when_advanced() {
+        let clock = ManualClock::new(start());
+        assert_eq!(clock.now(), start());
+
+        clock.advance(Duration::minutes(90));
+        assert_eq!(clock.now(), start() + Duration::minutes(90));
+
+        clock.set(start());
+        assert_eq!(clock.now(), start());
+    }
+
+    #[tokio::test]
+    async fn manual_clock_sleepers_wake_when_deadline_is_reached() {
+        let clock = std::sync::Arc::new(ManualClock::new(start()));
+
+        let sleeper = {
+            let clock = clock.clone();
+            tokio::spawn(async move { clock.sleep_until(start() + Duration::hours(1)).await })
+        };
+
+        clock.advance(Duration::minutes(30));
+        for _ in 0..5 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!sleeper.is_finished());
+
+        clock.advance(Duration::minutes(30));
+        sleeper.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn sleeping_until_the_past_returns_immediately() {
+        let clock = ManualClock::new(start());
+        clock.sleep_until(start() - Duration::seconds(1)).await;
+        SystemClock.sleep_until(Utc::now() - Duration::seconds(1)).await;
+    }
+}
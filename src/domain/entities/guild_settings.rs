@@ -0,0 +1,34 @@
+use crate::domain::value_objects::Language;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Server-wide defaults set by admins, used for server tasks and members without preferences
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuildSettings {
+    pub guild_id: u64,
+    pub timezone: Option<String>,    // IANA name, None when unset
+    pub date_format: Option<String>, // "YMD", "DMY", or "MDY"
+    pub language: Option<Language>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl GuildSettings {
+    pub fn new(guild_id: u64, now: DateTime<Utc>) -> Self {
+        Self {
+            guild_id,
+            timezone: None,
+            date_format: None,
+            language: None,
+            updated_at: now,
+        }
+    }
+
+    /// Whether nothing is set, so the row can be dropped
+    pub fn is_empty(&self) -> bool {
+        self.timezone.is_none() && self.date_format.is_none() && self.language.is_none()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.guild_id > 0
+    }
+}
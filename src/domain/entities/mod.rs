@@ -1,3 +1,4 @@
+pub mod guild_settings;
 pub mod task;
 pub mod user_preferences;
 pub mod scheduled_task;
@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::cmp::Ordering;
 
 /// Entity representing a task scheduled for notification
 /// Used by the priority queue scheduler for efficient task management
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ScheduledTask {
     pub task_id: u64,
     pub scheduled_time: DateTime<Utc>,
@@ -13,7 +14,7 @@ pub struct ScheduledTask {
     pub notification_method: crate::domain::entities::task::NotificationMethod,
     pub is_recurring: bool,
     pub is_deleted: bool, // For lazy deletion - infrastructure concern but stored in entity
-    pub mention: Option<String>, // Optional @user or @role mention for notifications
+    pub mentions: Vec<crate::domain::value_objects::MentionTarget>, // Who channel notifications ping
 }
 
 impl ScheduledTask {
@@ -31,10 +32,15 @@ impl ScheduledTask {
             notification_method: task.notification_method.clone(),
             is_recurring: task.recurrence.is_some(),
             is_deleted: false, // Always start as not deleted
-            mention: task.mention.clone(), // Copy mention for notifications
+            mentions: task.mentions.clone(), // Copy mentions for notifications
         }
     }
 
+    /// Identifies this occurrence of the task, used to deliver it at most once across instances
+    pub fn delivery_key(&self) -> String {
+        format!("{}:{}", self.task_id, self.scheduled_time.timestamp())
+    }
+
     /// Mark this task as deleted for lazy deletion
     #[allow(dead_code)]
     pub fn mark_deleted(&mut self) {
@@ -48,6 +54,26 @@ impl ScheduledTask {
     }
 }
 
+/// One delivered occurrence of a task, kept for a while so no instance delivers it twice
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeliveryRecord {
+    pub task_id: u64,
+    pub scheduled_time: DateTime<Utc>,
+    pub delivered_at: DateTime<Utc>,
+}
+
+impl DeliveryRecord {
+    /// Rebuild a record from a stored delivery key (see `ScheduledTask::delivery_key`)
+    pub fn from_key(delivery_key: &str, delivered_at: DateTime<Utc>) -> Option<Self> {
+        let (task_id, timestamp) = delivery_key.split_once(':')?;
+        Some(Self {
+            task_id: task_id.parse().ok()?,
+            scheduled_time: DateTime::from_timestamp(timestamp.parse().ok()?, 0)?,
+            delivered_at,
+        })
+    }
+}
+
 // implement ordering for priority queue (earliest times have highest priority)
 impl PartialOrd for ScheduledTask {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -1,4 +1,6 @@
-use chrono::{DateTime, Datelike, Duration, Timelike, Utc, Weekday};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use crate::domain::value_objects::MentionTarget;
 use serde::{Deserialize, Serialize};
 
 // Auxiliary structs for serialization in SQLite repository
@@ -27,7 +29,11 @@ pub struct Task {
     pub recurrence: Option<Recurrence>,
     pub notification_method: NotificationMethod,
     pub channel_id: Option<u64>, // Specific channel for channel notifications
-    pub mention: Option<String>, // Optional @user or @role mention for notifications
+    pub mentions: Vec<MentionTarget>, // Who channel notifications ping, the owner when empty
+    // IANA zone the schedule was given in, recurrence times are local to it. When None the
+    // owner's timezone was used and recurrence times are stored in UTC
+    #[serde(default)]
+    pub timezone: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +57,34 @@ pub enum NotificationMethod {
     Both,
 }
 
+impl Recurrence {
+    /// Shortest gap between two consecutive occurrences
+    pub fn min_interval(&self) -> Duration {
+        match self {
+            Recurrence::Weekly { days, .. } => {
+                let mut offsets: Vec<i64> = days
+                    .iter()
+                    .map(|day| day.num_days_from_monday() as i64)
+                    .collect();
+                offsets.sort_unstable();
+                offsets.dedup();
+
+                // Wrap around from the last day of the week to the first one
+                let wrap = match (offsets.first(), offsets.last()) {
+                    (Some(first), Some(last)) => first + 7 - last,
+                    _ => 7,
+                };
+                let gap = offsets
+                    .windows(2)
+                    .map(|pair| pair[1] - pair[0])
+                    .fold(wrap, i64::min);
+                Duration::days(gap)
+            }
+            Recurrence::EveryXDays { interval, .. } => Duration::days(*interval as i64),
+        }
+    }
+}
+
 impl Task {
     pub fn new(
         id: u64,
@@ -62,7 +96,7 @@ impl Task {
         recurrence: Option<Recurrence>,
         notification_method: NotificationMethod,
         channel_id: Option<u64>,
-        mention: Option<String>,
+        mentions: Vec<MentionTarget>,
     ) -> Self {
         Self {
             id,
@@ -74,7 +108,103 @@ impl Task {
             recurrence,
             notification_method,
             channel_id,
-            mention,
+            mentions,
+            timezone: None,
+        }
+    }
+
+    /// Pin the task to its own timezone instead of its owner's
+    pub fn with_timezone(mut self, timezone: Option<String>) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Zone recurrence times are expressed in
+    pub fn recurrence_zone(&self) -> Tz {
+        self.timezone
+            .as_deref()
+            .and_then(|timezone| timezone.parse().ok())
+            .unwrap_or(Tz::UTC)
+    }
+
+    /// Schedule and recurrence after the owner moves from timezone `from` to `to`, keeping the
+    /// local time. Tasks pinned to their own timezone don't follow the owner's and stay the same
+    pub fn reanchored(
+        &self,
+        from: Tz,
+        to: Tz,
+        now: DateTime<Utc>,
+    ) -> (Option<DateTime<Utc>>, Option<Recurrence>) {
+        if self.timezone.is_some() {
+            return (self.scheduled_time, self.recurrence.clone());
+        }
+
+        // Recurrence times of these tasks are UTC, converted with the offset of 1970-01-01. The
+        // conversion can cross midnight, so the weekdays move by the days it crossed
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let shift = |hour: u8, minute: u8| {
+            at_local_time(Tz::UTC, epoch, hour, minute)
+                .map(|utc| reanchor_time(utc, from, to))
+                .map_or((hour, minute, 0), |utc| {
+                    let days = (utc.date_naive() - epoch).num_days();
+                    (utc.hour() as u8, utc.minute() as u8, days)
+                })
+        };
+        // A due time still ahead of `now` must not move into the past, where the scheduler would
+        // fire it at once
+        let falls_behind = |time: DateTime<Utc>, moved: DateTime<Utc>| time > now && moved <= now;
+
+        match &self.recurrence {
+            Some(Recurrence::Weekly { days, hour, minute }) => {
+                let (hour, minute, offset) = shift(*hour, *minute);
+                let mut days: Vec<Weekday> = days
+                    .iter()
+                    .map(|day| {
+                        let index = day.num_days_from_monday() as i64 + offset;
+                        Weekday::try_from(index.rem_euclid(7) as u8).unwrap()
+                    })
+                    .collect();
+                days.sort_by_key(|day| day.num_days_from_monday());
+                days.dedup();
+                (
+                    next_weekly_time(&days, hour, minute, Tz::UTC, now),
+                    Some(Recurrence::Weekly { days, hour, minute }),
+                )
+            }
+            Some(Recurrence::EveryXDays {
+                interval,
+                hour,
+                minute,
+            }) => {
+                let (hour, minute, _) = shift(*hour, *minute);
+                let step = Duration::days((*interval).max(1) as i64);
+                let scheduled_time = self.scheduled_time.map(|time| {
+                    let mut moved = reanchor_time(time, from, to);
+                    // Skip ahead to the first run after `now` on the same interval
+                    if falls_behind(time, moved) {
+                        while moved <= now {
+                            moved += step;
+                        }
+                    }
+                    moved
+                });
+                (
+                    scheduled_time,
+                    Some(Recurrence::EveryXDays {
+                        interval: *interval,
+                        hour,
+                        minute,
+                    }),
+                )
+            }
+            // Nothing comes after a single task, it keeps its time rather than fire early
+            None => (
+                self.scheduled_time.map(|time| {
+                    let moved = reanchor_time(time, from, to);
+                    if falls_behind(time, moved) { time } else { moved }
+                }),
+                None,
+            ),
         }
     }
 
@@ -97,51 +227,249 @@ impl Task {
         Ok(())
     }
 
-    /// Calculates the next occurrence datetime for a recurring task. Returns `None` if the task is not recurring
-    pub fn next_occurrence(&self) -> Option<DateTime<Utc>> {
+    /// Calculates the next occurrence after `now` for a recurring task. Returns `None` if the task is not recurring
+    pub fn next_occurrence(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let zone = self.recurrence_zone();
         match &self.recurrence {
             Some(Recurrence::Weekly { days, hour, minute }) => {
-                let now = Utc::now();
-                // create today at the specified time
-                let today_at_time = now
-                    .with_hour(*hour as u32)
-                    .and_then(|t| t.with_minute(*minute as u32))
-                    .and_then(|t| t.with_second(0))
-                    .unwrap();
-
-                let mut candidate = today_at_time;
-
-                // if current time already passed, start from tomorrow
-                if candidate <= now {
-                    candidate = candidate + Duration::days(1);
-                }
-
-                // search for next candidate day
-                for _ in 0..7 {
-                    if days.contains(&candidate.weekday()) {
-                        return Some(candidate);
-                    }
-                    candidate = candidate + Duration::days(1);
-                }
-                None
+                next_weekly_time(days, *hour, *minute, zone, now)
             }
             Some(Recurrence::EveryXDays {
                 interval,
                 hour,
                 minute,
             }) => {
-                if let Some(current) = self.scheduled_time {
-                    let next = current + Duration::days(*interval as i64);
-                    return Some(
-                        next.with_hour(*hour as u32)
-                            .and_then(|t| t.with_minute(*minute as u32))
-                            .and_then(|t| t.with_second(0))
-                            .unwrap(),
-                    );
-                }
-                None
+                let current = self.scheduled_time?;
+                let date =
+                    current.with_timezone(&zone).date_naive() + Duration::days(*interval as i64);
+                at_local_time(zone, date, *hour, *minute)
             }
             None => None,
         }
     }
 }
+
+/// Tasks for tests: a DM task in guild 10 without a schedule, other fields are set directly
+#[cfg(test)]
+impl Task {
+    pub fn for_test(user_id: u64, title: &str) -> Self {
+        Self::new(
+            0,
+            user_id,
+            10,
+            title.to_string(),
+            None,
+            None,
+            None,
+            NotificationMethod::DM,
+            None,
+            Vec::new(),
+        )
+    }
+
+    pub fn scheduled_at(mut self, time: DateTime<Utc>) -> Self {
+        self.scheduled_time = Some(time);
+        self
+    }
+
+    pub fn recurring(mut self, recurrence: Recurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+}
+
+/// First of `days` at `hour:minute` local time in `zone` strictly after `now`
+pub fn next_weekly_time(
+    days: &[Weekday],
+    hour: u8,
+    minute: u8,
+    zone: Tz,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let today = now.with_timezone(&zone).date_naive();
+    (0..=7)
+        .map(|offset| today + Duration::days(offset))
+        .filter(|date| days.contains(&date.weekday()))
+        .filter_map(|date| at_local_time(zone, date, hour, minute))
+        .find(|candidate| *candidate > now)
+}
+
+/// The same wall-clock time as `time` in `from`, read in `to`
+fn reanchor_time(time: DateTime<Utc>, from: Tz, to: Tz) -> DateTime<Utc> {
+    let local = time.with_timezone(&from).naive_local();
+    at_local_time(to, local.date(), local.hour() as u8, local.minute() as u8).unwrap_or(time)
+}
+
+/// `hour:minute` on `date` in `zone`. A time skipped by a DST change moves one hour later, a
+/// repeated one takes its first occurrence
+fn at_local_time(zone: Tz, date: NaiveDate, hour: u8, minute: u8) -> Option<DateTime<Utc>> {
+    let time = date.and_hms_opt(hour as u32, minute as u32, 0)?;
+    zone.from_local_datetime(&time)
+        .earliest()
+        .or_else(|| zone.from_local_datetime(&(time + Duration::hours(1))).earliest())
+        .map(|datetime| datetime.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    // Monday 2025-01-06 12:00 UTC
+    fn monday_noon() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn weekly_picks_later_today_when_time_has_not_passed() {
+        let task = Task::for_test(1, "recurring")
+            .recurring(Recurrence::Weekly { days: vec![Weekday::Mon], hour: 18, minute: 30 });
+
+        assert_eq!(
+            task.next_occurrence(monday_noon()),
+            Some(Utc.with_ymd_and_hms(2025, 1, 6, 18, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn weekly_skips_to_next_matching_day_once_time_has_passed() {
+        let task = Task::for_test(1, "recurring")
+            .recurring(Recurrence::Weekly {
+                days: vec![Weekday::Mon, Weekday::Thu],
+                hour: 9,
+                minute: 0,
+            });
+
+        assert_eq!(
+            task.next_occurrence(monday_noon()),
+            Some(Utc.with_ymd_and_hms(2025, 1, 9, 9, 0, 0).unwrap())
+        );
+        // Exactly at the scheduled minute counts as passed
+        let at_time = Utc.with_ymd_and_hms(2025, 1, 9, 9, 0, 0).unwrap();
+        assert_eq!(
+            task.next_occurrence(at_time),
+            Some(Utc.with_ymd_and_hms(2025, 1, 13, 9, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn every_x_days_advances_from_last_scheduled_time() {
+        let task = Task::for_test(1, "recurring")
+            .recurring(Recurrence::EveryXDays { interval: 3, hour: 8, minute: 15 })
+            .scheduled_at(monday_noon());
+
+        assert_eq!(
+            task.next_occurrence(monday_noon()),
+            Some(Utc.with_ymd_and_hms(2025, 1, 9, 8, 15, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn pinned_timezone_keeps_the_local_time_across_dst() {
+        let task = Task::for_test(1, "recurring")
+            .recurring(Recurrence::Weekly { days: vec![Weekday::Mon], hour: 10, minute: 0 })
+        .with_timezone(Some("America/New_York".to_string()));
+
+        // 10:00 EST in winter, 10:00 EDT after the March change
+        assert_eq!(
+            task.next_occurrence(monday_noon()),
+            Some(Utc.with_ymd_and_hms(2025, 1, 6, 15, 0, 0).unwrap())
+        );
+        let march = Utc.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+        assert_eq!(
+            task.next_occurrence(march),
+            Some(Utc.with_ymd_and_hms(2025, 3, 10, 14, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn reanchoring_keeps_the_local_time_in_the_new_zone() {
+        let buenos_aires: Tz = "America/Argentina/Buenos_Aires".parse().unwrap();
+        let madrid: Tz = "Europe/Madrid".parse().unwrap();
+
+        // 09:00 in Buenos Aires, stored as 12:00 UTC
+        let weekly = Task::for_test(1, "recurring")
+            .recurring(Recurrence::Weekly { days: vec![Weekday::Tue], hour: 12, minute: 0 })
+            .scheduled_at(Utc.with_ymd_and_hms(2025, 1, 7, 12, 0, 0).unwrap());
+        let (next, recurrence) = weekly.reanchored(buenos_aires, madrid, monday_noon());
+        assert_eq!(next, Some(Utc.with_ymd_and_hms(2025, 1, 7, 8, 0, 0).unwrap()));
+        assert!(matches!(recurrence, Some(Recurrence::Weekly { hour: 8, minute: 0, .. })));
+
+        let mut single = weekly.clone();
+        single.recurrence = None;
+        single.scheduled_time = Some(Utc.with_ymd_and_hms(2025, 1, 10, 12, 30, 0).unwrap());
+        let (next, recurrence) = single.reanchored(buenos_aires, madrid, monday_noon());
+        assert_eq!(next, Some(Utc.with_ymd_and_hms(2025, 1, 10, 8, 30, 0).unwrap()));
+        assert!(recurrence.is_none());
+
+        // A task with its own timezone doesn't follow its owner
+        let pinned = weekly.clone().with_timezone(Some("Asia/Tokyo".to_string()));
+        let (next, _) = pinned.reanchored(buenos_aires, madrid, monday_noon());
+        assert_eq!(next, pinned.scheduled_time);
+    }
+
+    #[test]
+    fn reanchoring_moves_the_weekdays_when_the_time_crosses_midnight() {
+        let madrid: Tz = "Europe/Madrid".parse().unwrap();
+        let buenos_aires: Tz = "America/Argentina/Buenos_Aires".parse().unwrap();
+
+        // Monday 23:00 UTC is Tuesday 00:00 in Madrid, and Tuesday 00:00 in Buenos Aires is
+        // Tuesday 03:00 UTC
+        let weekly = Task::for_test(1, "recurring")
+            .recurring(Recurrence::Weekly {
+                days: vec![Weekday::Mon, Weekday::Sun],
+                hour: 23,
+                minute: 0,
+            });
+        let (next, recurrence) = weekly.reanchored(madrid, buenos_aires, monday_noon());
+        assert!(matches!(
+            &recurrence,
+            Some(Recurrence::Weekly { days, hour: 3, minute: 0 })
+                if days == &[Weekday::Mon, Weekday::Tue]
+        ));
+        assert_eq!(next, Some(Utc.with_ymd_and_hms(2025, 1, 7, 3, 0, 0).unwrap()));
+
+        // And back again, across midnight the other way
+        let weekly = Task::for_test(1, "recurring").recurring(recurrence.unwrap());
+        let (_, recurrence) = weekly.reanchored(buenos_aires, madrid, monday_noon());
+        assert!(matches!(
+            &recurrence,
+            Some(Recurrence::Weekly { days, hour: 23, minute: 0 })
+                if days == &[Weekday::Mon, Weekday::Sun]
+        ));
+    }
+
+    #[test]
+    fn reanchoring_never_moves_a_pending_time_into_the_past() {
+        let buenos_aires: Tz = "America/Argentina/Buenos_Aires".parse().unwrap();
+        let madrid: Tz = "Europe/Madrid".parse().unwrap();
+        // 14:00 UTC is 11:00 in Buenos Aires, which is 10:00 UTC in Madrid, before noon
+        let due = Utc.with_ymd_and_hms(2025, 1, 6, 14, 0, 0).unwrap();
+
+        let every_two_days = Task::for_test(1, "recurring")
+            .recurring(Recurrence::EveryXDays { interval: 2, hour: 14, minute: 0 })
+            .scheduled_at(due);
+        let mut single = every_two_days.clone();
+        single.recurrence = None;
+        let (next, _) = single.reanchored(buenos_aires, madrid, monday_noon());
+        assert_eq!(next, Some(due));
+
+        let (next, recurrence) = every_two_days.reanchored(buenos_aires, madrid, monday_noon());
+        assert_eq!(next, Some(Utc.with_ymd_and_hms(2025, 1, 8, 10, 0, 0).unwrap()));
+        assert!(matches!(recurrence, Some(Recurrence::EveryXDays { hour: 10, minute: 0, .. })));
+    }
+
+    #[test]
+    fn min_interval_is_the_shortest_gap_between_occurrences() {
+        let weekly = |days: Vec<Weekday>| Recurrence::Weekly { days, hour: 9, minute: 0 };
+
+        assert_eq!(weekly(vec![Weekday::Wed]).min_interval(), Duration::days(7));
+        assert_eq!(weekly(vec![Weekday::Mon, Weekday::Thu]).min_interval(), Duration::days(3));
+        // Sunday to Monday wraps around the week
+        assert_eq!(weekly(vec![Weekday::Sun, Weekday::Wed, Weekday::Mon]).min_interval(), Duration::days(1));
+        assert_eq!(
+            Recurrence::EveryXDays { interval: 2, hour: 9, minute: 0 }.min_interval(),
+            Duration::days(2)
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use chrono::{DateTime, Utc};
+use crate::domain::value_objects::{ClockFormat, Language, WorkingHours};
+use chrono::{DateTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,17 +7,36 @@ pub struct UserPreferences {
     pub user_id: u64,
     pub timezone: String,
     pub date_format: Option<String>, // "YMD", "DMY", or "MDY"
+    #[serde(default)]
+    pub date_format_chosen: bool, // set in /preferences, kept when the timezone changes
+    #[serde(default)]
+    pub clock_format: ClockFormat,
+    #[serde(default)]
+    pub first_weekday: Option<Weekday>, // None for Monday
+    #[serde(default)]
+    pub language: Option<Language>, // None follows the Discord client's language
+    #[serde(default)]
+    pub working_hours: Option<WorkingHours>, // None for WorkingHours::DEFAULT
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl UserPreferences {
-    pub fn new_with_format(user_id: u64, timezone: String, date_format: Option<String>) -> Self {
-        let now = Utc::now();
+    pub fn new_with_format(
+        user_id: u64,
+        timezone: String,
+        date_format: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Self {
         Self {
             user_id,
             timezone,
             date_format,
+            date_format_chosen: false,
+            clock_format: ClockFormat::default(),
+            first_weekday: None,
+            language: None,
+            working_hours: None,
             created_at: now,
             updated_at: now,
         }
@@ -26,10 +46,23 @@ impl UserPreferences {
         &mut self,
         new_timezone: String,
         date_format: Option<String>,
+        now: DateTime<Utc>,
     ) {
         self.timezone = new_timezone;
-        self.date_format = date_format;
-        self.updated_at = Utc::now();
+        if !self.date_format_chosen {
+            self.date_format = date_format;
+        }
+        self.updated_at = now;
+    }
+
+    pub fn update_working_hours(&mut self, working_hours: Option<WorkingHours>, now: DateTime<Utc>) {
+        self.working_hours = working_hours;
+        self.updated_at = now;
+    }
+
+    /// First column of the user's week
+    pub fn week_start(&self) -> Weekday {
+        self.first_weekday.unwrap_or(Weekday::Mon)
     }
 
     pub fn is_valid(&self) -> bool {
@@ -1,7 +1,13 @@
+pub mod clock;
 pub mod entities;
+pub mod notification_sink;
+pub mod personal_data_store;
 pub mod repositories;
 pub mod value_objects;
 
+pub use clock::{Clock, SystemClock};
+pub use notification_sink::NotificationSink;
+pub use personal_data_store::PersonalDataStore;
 pub use entities::task::{NotificationMethod, Recurrence, Task, WeeklyRecurrenceData, EveryXDaysRecurrenceData};
 // Re-exports for scheduler components - used via complex trait bounds
 #[allow(unused_imports)]
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+
+use crate::domain::value_objects::MentionTarget;
+
+/// A single name/value row of an embed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    pub inline: bool,
+}
+
+/// Platform-neutral rich embed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageEmbed {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub color: Option<u32>, // 0xRRGGBB
+    pub fields: Vec<EmbedField>,
+}
+
+impl MessageEmbed {
+    pub fn field(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: bool,
+    ) -> Self {
+        self.fields.push(EmbedField {
+            name: name.into(),
+            value: value.into(),
+            inline,
+        });
+        self
+    }
+}
+
+/// Message to deliver: plain content, an embed, or both
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutgoingMessage {
+    pub content: Option<String>,
+    pub embed: Option<MessageEmbed>,
+    /// Who the content is allowed to ping, nobody when empty
+    pub allowed_mentions: Vec<MentionTarget>,
+}
+
+/// Where a delivered message ended up, needed to edit it later
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SentMessage {
+    pub channel_id: u64,
+    pub message_id: u64,
+}
+
+/// Outbound port for user-facing notifications, keeps delivery logic independent of Discord
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    /// Send a direct message to a user
+    async fn send_dm(&self, user_id: u64, message: OutgoingMessage) -> Result<SentMessage, String>;
+
+    /// Send a message to a guild channel
+    async fn send_to_channel(
+        &self,
+        channel_id: u64,
+        message: OutgoingMessage,
+    ) -> Result<SentMessage, String>;
+
+    /// Replace the content and embed of a previously sent message
+    #[allow(dead_code)]
+    async fn edit_message(&self, sent: SentMessage, message: OutgoingMessage)
+    -> Result<(), String>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+/// Personal data kept outside the task storage (e.g. the server-specific JSON files),
+/// included in `/my_data export` and erased by `/my_data delete`
+#[async_trait]
+pub trait PersonalDataStore: Send + Sync {
+    /// Section name in the export
+    fn name(&self) -> &'static str;
+
+    /// Everything stored about `user_id`, None when there is nothing
+    async fn export_user(&self, user_id: u64) -> Result<Option<serde_json::Value>, String>;
+
+    /// Remove everything stored about `user_id`, returns how many records were removed
+    async fn erase_user(&self, user_id: u64) -> Result<usize, String>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use std::fmt::Debug;
+
+use crate::domain::entities::guild_settings::GuildSettings;
+use crate::domain::repositories::user_preferences_repository::Result;
+
+#[async_trait]
+pub trait GuildSettingsRepository: Send + Sync + Debug {
+    /// Obtain a guild's settings by guild ID
+    async fn get(&self, guild_id: u64) -> Result<Option<GuildSettings>>;
+
+    /// Save or update a guild's settings
+    async fn save(&self, settings: &GuildSettings) -> Result<()>;
+
+    /// Delete a guild's settings by guild ID
+    async fn delete(&self, guild_id: u64) -> Result<()>;
+}
@@ -1,7 +1,9 @@
+pub mod guild_settings_repository;
 pub mod task_repository;
 pub mod user_preferences_repository;
 pub mod task_scheduler_repository;
 
+pub use guild_settings_repository::GuildSettingsRepository;
 pub use task_repository::TaskRepository;
 pub use user_preferences_repository::UserPreferencesRepository;
 // Scheduler components - used in Arc<dyn Trait> and error handling
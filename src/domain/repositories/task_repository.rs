@@ -6,6 +6,7 @@ use async_trait::async_trait;
 pub trait TaskRepository: Send + Sync {
     async fn add_task(&self, task: Task) -> Result<u64, String>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn edit_task(
         &self,
         task_id: u64,
@@ -14,17 +15,35 @@ pub trait TaskRepository: Send + Sync {
         new_scheduled_time: Option<DateTime<Utc>>,
         new_recurrence: Option<Recurrence>,
         new_notification_method: Option<NotificationMethod>,
+        new_timezone: Option<Option<String>>, // Some(None) goes back to the owner's timezone
     ) -> Result<Task, String>;
 
     async fn remove_task(&self, task_id: u64) -> bool;
 
     async fn remove_all_by_user(&self, user_id: u64) -> usize;
 
+    /// Every task that isn't archived
     async fn list_tasks(&self) -> Vec<Task>;
 
+    /// Tasks kept out of the schedule and of `list_tasks` until they are restored
+    async fn list_archived(&self) -> Vec<Task>;
+
+    /// Archive a task or bring it back, returns it as stored
+    async fn set_archived(&self, task_id: u64, archived: bool) -> Result<Task, String>;
+
+    /// Replace the scheduled time and recurrence of several tasks, either all of them change or
+    /// none does (e.g. moving a user's tasks to a new timezone)
+    async fn update_schedules(
+        &self,
+        schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+    ) -> Result<(), String>;
+
     /// Updates only the scheduled time of a task (used for recurring weekly task)
     async fn update_task_time(&self, task_id: u64, new_time: DateTime<Utc>) -> Result<(), String>;
 
+    /// Deliver a task by DM from now on, dropping its channel and mention
+    async fn move_to_dm(&self, task_id: u64) -> Result<Task, String>;
+
     /// Get total count of all tasks in the system (admin only)
     async fn get_total_task_count(&self) -> Result<u64, String>;
 }
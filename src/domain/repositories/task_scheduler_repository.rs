@@ -1,6 +1,8 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::broadcast;
 
-use crate::domain::entities::scheduled_task::ScheduledTask;
+use crate::domain::entities::scheduled_task::{DeliveryRecord, ScheduledTask};
 
 #[derive(Debug)]
 pub enum SchedulerError {
@@ -24,11 +26,48 @@ pub trait TaskSchedulerRepository: Send + Sync {
     /// Add a task to the scheduler
     async fn add_scheduled_task(&self, task: ScheduledTask) -> Result<(), SchedulerError>;
 
-    /// Get the next pending task (without removing it)
-    async fn peek_next_task(&self) -> Result<Option<ScheduledTask>, SchedulerError>;
+    /// Lease the earliest due task to `owner` until `lease_until`, so no other instance sharing
+    /// the storage picks it up. Tasks whose lease expired (crashed owner) can be claimed again
+    async fn claim_due_task(
+        &self,
+        owner: &str,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Option<ScheduledTask>, SchedulerError>;
 
-    /// Remove and return the next pending task
-    async fn pop_next_task(&self) -> Result<Option<ScheduledTask>, SchedulerError>;
+    /// Earliest time at which a pending task can be claimed (its due time, or its lease expiry if later)
+    async fn next_claimable_time(&self) -> Result<Option<DateTime<Utc>>, SchedulerError>;
+
+    /// Give a claimed task back to the queue, due again at `retry_at`
+    /// No-op if `owner` no longer holds the lease
+    async fn release_claim(
+        &self,
+        task_id: u64,
+        owner: &str,
+        retry_at: DateTime<Utc>,
+    ) -> Result<(), SchedulerError>;
+
+    /// Record the task's delivery key and drop the entry if `owner` still holds its lease
+    /// Returns false when the lease was lost (expired and reclaimed, or the task was rescheduled)
+    async fn complete_claim(
+        &self,
+        task: &ScheduledTask,
+        owner: &str,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<bool, SchedulerError>;
+
+    /// Whether an occurrence (see `ScheduledTask::delivery_key`) was already delivered by any instance
+    async fn is_delivered(&self, delivery_key: &str) -> Result<bool, SchedulerError>;
+
+    /// Forget delivery keys recorded before `before`, returns how many were removed
+    async fn prune_deliveries(&self, before: DateTime<Utc>) -> Result<usize, SchedulerError>;
+
+    /// Delivered occurrences of the given tasks that haven't been pruned yet, oldest first
+    async fn list_deliveries(&self, task_ids: &[u64]) -> Result<Vec<DeliveryRecord>, SchedulerError>;
+
+    /// Physically remove every entry of `user_id` (tombstones included) and the delivery keys
+    /// of `task_ids`, returns how many entries were removed
+    async fn purge_user(&self, user_id: u64, task_ids: &[u64]) -> Result<usize, SchedulerError>;
 
     /// Remove a specific task by ID
     async fn remove_task(&self, task_id: u64) -> Result<(), SchedulerError>;
@@ -36,4 +75,25 @@ pub trait TaskSchedulerRepository: Send + Sync {
     /// Check if there are any pending tasks
     #[allow(dead_code)]
     async fn has_pending_tasks(&self) -> Result<bool, SchedulerError>;
+
+    /// Number of live (not removed) entries in the queue
+    async fn pending_count(&self) -> Result<usize, SchedulerError>;
+
+    /// Live entries in delivery order, for inspecting the queue
+    async fn list_pending(&self) -> Result<Vec<ScheduledTask>, SchedulerError>;
+
+    /// Physically remove soft-deleted entries, returns how many tombstones were removed
+    async fn compact(&self) -> Result<usize, SchedulerError>;
+
+    /// Total number of tombstones removed since this repository was created
+    fn tombstones_removed(&self) -> u64;
+
+    /// Get a receiver for wake-up notifications (signalled when new tasks are added)
+    fn subscribe_wakeup(&self) -> broadcast::Receiver<()>;
+
+    /// Whether other processes (bot instances, the admin CLI) write to this storage too. Their
+    /// writes don't reach `subscribe_wakeup`, so the scheduler has to re-poll it
+    fn is_shared(&self) -> bool {
+        false
+    }
 }
@@ -1,6 +1,8 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::fmt::{Debug, Display};
 
+use crate::domain::entities::task::Recurrence;
 use crate::domain::entities::user_preferences::UserPreferences;
 
 #[allow(dead_code)]
@@ -35,7 +37,17 @@ pub trait UserPreferencesRepository: Send + Sync + Debug {
     /// Save or update user's preferences
     async fn save(&self, preferences: &UserPreferences) -> Result<()>;
 
+    /// Save preferences and replace the scheduled time and recurrence of some of the user's
+    /// tasks at once, either everything is written or nothing is (timezone changes)
+    async fn save_with_task_schedules(
+        &self,
+        preferences: &UserPreferences,
+        schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+    ) -> Result<()>;
+
+    /// Every stored preference, ordered by user_id (admin export)
+    async fn list_all(&self) -> Result<Vec<UserPreferences>>;
+
     /// Delete user's preferences by user ID
-    #[allow(dead_code)]
     async fn delete(&self, user_id: u64) -> Result<()>;
 }
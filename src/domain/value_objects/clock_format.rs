@@ -0,0 +1,118 @@
+use crate::domain::value_objects::UserError;
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How a user reads and types hours
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClockFormat {
+    #[default]
+    H24,
+    H12,
+}
+
+impl ClockFormat {
+    /// Short code stored in the database, e.g. `12h`
+    pub fn code(&self) -> &'static str {
+        match self {
+            ClockFormat::H24 => "24h",
+            ClockFormat::H12 => "12h",
+        }
+    }
+
+
+    /// e.g. "15:30" or "3:30 PM"
+    pub fn format(&self, time: NaiveTime) -> String {
+        match self {
+            ClockFormat::H24 => time.format("%H:%M").to_string(),
+            ClockFormat::H12 => time.format("%-I:%M %p").to_string(),
+        }
+    }
+
+    /// Reads `HH:MM`, and with the 12-hour clock also `3pm` and `3:30 PM`
+    pub fn parse_time(&self, input: &str) -> Result<(u8, u8), UserError> {
+        let input = input.trim().to_ascii_lowercase();
+        let (body, pm) = match (input.strip_suffix("am"), input.strip_suffix("pm")) {
+            (Some(body), _) => (body.trim_end(), Some(false)),
+            (_, Some(body)) => (body.trim_end(), Some(true)),
+            _ => (input.as_str(), None),
+        };
+
+        let Some(pm) = pm else {
+            let (hour, minute) = body.split_once(':').ok_or(match self {
+                ClockFormat::H24 => "Invalid time format. Use HH:MM",
+                ClockFormat::H12 => "Invalid time format. Use HH:MM or 3:30 PM",
+            })?;
+            let hour = hour.parse::<u8>().map_err(|_| "Invalid hour")?;
+            let minute = minute.parse::<u8>().map_err(|_| "Invalid minute")?;
+            if hour > 23 || minute > 59 {
+                return Err("Invalid time values".into());
+            }
+            return Ok((hour, minute));
+        };
+
+        if *self == ClockFormat::H24 {
+            return Err(
+                "Use the 24-hour clock (e.g. 15:30), or pick the 12-hour clock in `/preferences`"
+                    .into(),
+            );
+        }
+        let (hour, minute) = body.split_once(':').unwrap_or((body, "0"));
+        let hour = hour.parse::<u8>().map_err(|_| "Invalid hour")?;
+        let minute = minute.parse::<u8>().map_err(|_| "Invalid minute")?;
+        if !(1..=12).contains(&hour) || minute > 59 {
+            return Err("Invalid time values".into());
+        }
+        Ok((hour % 12 + if pm { 12 } else { 0 }, minute))
+    }
+}
+
+/// Accepts the code (`12h`) or the number of hours alone (`12`)
+impl std::str::FromStr for ClockFormat {
+    type Err = UserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().trim_end_matches('h') {
+            "24" => Ok(ClockFormat::H24),
+            "12" => Ok(ClockFormat::H12),
+            _ => Err(
+                UserError::new("Unknown clock '{clock}', expected '24h' or '12h'")
+                    .with("clock", s.trim()),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ClockFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClockFormat::H24 => f.write_str("24-hour (15:30)"),
+            ClockFormat::H12 => f.write_str("12-hour (3:30 PM)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twelve_hour_clock_reads_am_and_pm() {
+        let clock = ClockFormat::H12;
+        assert_eq!(clock.parse_time("3pm"), Ok((15, 0)));
+        assert_eq!(clock.parse_time("3:30 PM"), Ok((15, 30)));
+        assert_eq!(clock.parse_time("12am"), Ok((0, 0)));
+        assert_eq!(clock.parse_time("12:15 pm"), Ok((12, 15)));
+        assert_eq!(clock.parse_time("15:30"), Ok((15, 30)));
+        assert!(clock.parse_time("13pm").is_err());
+        assert_eq!(
+            clock.format(NaiveTime::from_hms_opt(15, 30, 0).unwrap()),
+            "3:30 PM"
+        );
+
+        assert!(ClockFormat::H24.parse_time("3pm").is_err());
+        assert_eq!(ClockFormat::H24.parse_time("09:05"), Ok((9, 5)));
+        assert_eq!("12".parse::<ClockFormat>(), Ok(ClockFormat::H12));
+    }
+}
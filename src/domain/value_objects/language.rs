@@ -0,0 +1,56 @@
+use crate::domain::value_objects::UserError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Language the bot answers in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    En,
+    Es,
+}
+
+impl Language {
+    /// Every language the bot speaks
+    pub const ALL: [Language; 2] = [Language::En, Language::Es];
+
+    /// Short code stored in the database, e.g. `en`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Es => "es",
+        }
+    }
+
+    /// Name shown to users, in the language itself
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::Es => "Español",
+        }
+    }
+}
+
+/// Accepts the code (`es`) or a Discord locale (`es-ES`)
+impl std::str::FromStr for Language {
+    type Err = UserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim().to_lowercase();
+        match code.split(['-', '_']).next().unwrap_or_default() {
+            "en" => Ok(Language::En),
+            "es" => Ok(Language::Es),
+            _ => Err(
+                UserError::new("Unknown language '{language}', expected 'en' or 'es'")
+                    .with("language", s.trim()),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::domain::value_objects::UserError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Someone a channel notification pings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum MentionTarget {
+    User(u64),
+    Role(u64),
+    Everyone,
+    Here,
+}
+
+impl MentionTarget {
+    /// `@everyone` and `@here`, which need the Mention Everyone permission
+    pub fn is_broadcast(&self) -> bool {
+        matches!(self, Self::Everyone | Self::Here)
+    }
+}
+
+/// Discord's message markup, e.g. `<@123>` or `<@&456>`
+impl fmt::Display for MentionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::User(id) => write!(f, "<@{}>", id),
+            Self::Role(id) => write!(f, "<@&{}>", id),
+            Self::Everyone => write!(f, "@everyone"),
+            Self::Here => write!(f, "@here"),
+        }
+    }
+}
+
+/// Parse the `mention` option of /add_task: user and role mentions, `@everyone` or `@here`,
+/// separated by spaces or commas. Anything else is rejected instead of being sent as text
+pub fn parse_mentions(input: &str) -> Result<Vec<MentionTarget>, UserError> {
+    let mut targets = Vec::new();
+
+    for token in input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+    {
+        let mut rest = token;
+        while !rest.is_empty() {
+            let (target, remaining) = next_target(rest).ok_or_else(|| {
+                UserError::new(
+                    "`{text}` is not a mention, pick users or roles from the @ suggestions",
+                )
+                .with("text", token)
+            })?;
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+            rest = remaining;
+        }
+    }
+
+    Ok(targets)
+}
+
+/// Parse a list of member mentions, e.g. `<@1> <@2>`. Roles and broadcasts are rejected
+pub fn parse_user_mentions(input: &str) -> Result<Vec<u64>, UserError> {
+    parse_mentions(input)?
+        .into_iter()
+        .map(|target| match target {
+            MentionTarget::User(id) => Ok(id),
+            other => Err(
+                UserError::new("{mention} is not a member, mention people one by one")
+                    .with("mention", other),
+            ),
+        })
+        .collect()
+}
+
+/// Best-effort parse of mentions stored as free text by older versions, unknown parts are dropped
+pub fn parse_mentions_lenient(input: &str) -> Vec<MentionTarget> {
+    let mut targets = Vec::new();
+
+    for token in input.split(|c: char| c.is_whitespace() || c == ',') {
+        let mut rest = token;
+        while !rest.is_empty() {
+            match next_target(rest) {
+                Some((target, remaining)) => {
+                    if !targets.contains(&target) {
+                        targets.push(target);
+                    }
+                    rest = remaining;
+                }
+                None => break,
+            }
+        }
+    }
+
+    targets
+}
+
+/// Markup of every target, space separated
+pub fn format_mentions(targets: &[MentionTarget]) -> String {
+    targets
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The mention at the start of `input` and what follows it
+fn next_target(input: &str) -> Option<(MentionTarget, &str)> {
+    if let Some(rest) = input.strip_prefix("@everyone") {
+        return Some((MentionTarget::Everyone, rest));
+    }
+    if let Some(rest) = input.strip_prefix("@here") {
+        return Some((MentionTarget::Here, rest));
+    }
+
+    let inner_end = input.find('>')?;
+    let inner = input.strip_prefix("<@")?.get(..inner_end - 2)?;
+    let rest = &input[inner_end + 1..];
+
+    let target = if let Some(id) = inner.strip_prefix('&') {
+        MentionTarget::Role(parse_id(id)?)
+    } else {
+        MentionTarget::User(parse_id(inner.strip_prefix('!').unwrap_or(inner))?)
+    };
+    Some((target, rest))
+}
+
+fn parse_id(id: &str) -> Option<u64> {
+    id.parse().ok().filter(|id| *id != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_users_roles_and_broadcasts() {
+        assert_eq!(
+            parse_mentions("<@1>, <@!2><@&3>  @here @everyone <@1>").unwrap(),
+            vec![
+                MentionTarget::User(1),
+                MentionTarget::User(2),
+                MentionTarget::Role(3),
+                MentionTarget::Here,
+                MentionTarget::Everyone,
+            ]
+        );
+        assert!(parse_mentions("").unwrap().is_empty());
+        assert!(parse_mentions("@john").unwrap_err().to_string().contains("`@john`"));
+        assert!(parse_mentions("<@&abc>").is_err());
+        assert!(parse_mentions("<@1>x").is_err());
+
+        assert_eq!(parse_user_mentions("<@1> <@!2>").unwrap(), vec![1, 2]);
+        assert!(parse_user_mentions("<@1> <@&3>").unwrap_err().to_string().contains("<@&3>"));
+    }
+
+    #[test]
+    fn round_trips_through_markup_and_reads_legacy_text() {
+        let targets = vec![
+            MentionTarget::User(7),
+            MentionTarget::Role(8),
+            MentionTarget::Everyone,
+        ];
+        assert_eq!(format_mentions(&targets), "<@7> <@&8> @everyone");
+        assert_eq!(parse_mentions(&format_mentions(&targets)).unwrap(), targets);
+
+        assert_eq!(
+            parse_mentions_lenient("hey <@7> and team <@&8>!"),
+            vec![MentionTarget::User(7), MentionTarget::Role(8)]
+        );
+    }
+}
@@ -1 +1,18 @@
+pub mod clock_format;
+pub mod language;
+pub mod mention;
+pub mod orphaned_task_policy;
+pub mod task_limits;
+pub mod timezone_change_mode;
+pub mod user_error;
 pub mod weekday_format;
+pub mod working_hours;
+
+pub use clock_format::ClockFormat;
+pub use language::Language;
+pub use mention::MentionTarget;
+pub use orphaned_task_policy::{OrphanedTaskPolicies, OrphanedTaskPolicy};
+pub use task_limits::TaskLimits;
+pub use timezone_change_mode::TimezoneChangeMode;
+pub use user_error::{UserError, fill_placeholders};
+pub use working_hours::{MeetingWindow, WorkingHours, find_meeting_windows};
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+/// What happens to tasks whose guild, channel or owner membership went away
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrphanedTaskPolicy {
+    /// Remove the tasks and tell the owner which ones
+    Delete,
+    /// Pause the tasks until the bot rejoins the guild and send the owner a copy
+    Archive,
+    /// Keep the tasks but deliver them by DM, without channel or mention
+    MoveToDm,
+}
+
+impl std::str::FromStr for OrphanedTaskPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "delete" => Ok(Self::Delete),
+            "archive" => Ok(Self::Archive),
+            "move_to_dm" => Ok(Self::MoveToDm),
+            other => Err(format!(
+                "Unknown policy '{}', expected 'delete', 'archive' or 'move_to_dm'",
+                other
+            )),
+        }
+    }
+}
+
+/// Policy for each event that can orphan a task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrphanedTaskPolicies {
+    /// The bot was removed from the task's guild
+    pub guild_removed: OrphanedTaskPolicy,
+    /// The owner left the task's guild
+    pub member_left: OrphanedTaskPolicy,
+    /// The task's notification channel was deleted
+    pub channel_deleted: OrphanedTaskPolicy,
+}
+
+impl Default for OrphanedTaskPolicies {
+    fn default() -> Self {
+        Self {
+            guild_removed: OrphanedTaskPolicy::Archive,
+            member_left: OrphanedTaskPolicy::MoveToDm,
+            channel_deleted: OrphanedTaskPolicy::MoveToDm,
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::domain::entities::task::{Recurrence, Task};
+use crate::domain::value_objects::UserError;
+use chrono::Duration;
+
+/// Caps on how many tasks users and guilds can keep, checked before a task is created or edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskLimits {
+    pub max_tasks_per_user: usize,
+    pub max_tasks_per_guild: usize,
+    pub max_recurring_tasks_per_user: usize,
+    /// Shortest allowed gap between two occurrences of a recurring task
+    pub min_recurrence_interval: Duration,
+}
+
+impl Default for TaskLimits {
+    fn default() -> Self {
+        Self {
+            max_tasks_per_user: 100,
+            max_tasks_per_guild: 1000,
+            max_recurring_tasks_per_user: 25,
+            min_recurrence_interval: Duration::days(1),
+        }
+    }
+}
+
+impl TaskLimits {
+    /// Whether one more task with `recurrence` fits next to the `existing` ones
+    pub fn check_new_task(
+        &self,
+        existing: &[Task],
+        user_id: u64,
+        guild_id: u64,
+        recurrence: Option<&Recurrence>,
+    ) -> Result<(), UserError> {
+        let user_tasks: Vec<&Task> = existing
+            .iter()
+            .filter(|task| task.user_id == user_id)
+            .collect();
+
+        if user_tasks.len() >= self.max_tasks_per_user {
+            return Err(UserError::new(
+                "You already have {count} tasks, the limit is {limit}. Remove some with /remove_task first",
+            )
+            .with("count", user_tasks.len())
+            .with("limit", self.max_tasks_per_user));
+        }
+
+        if let Some(recurrence) = recurrence {
+            let recurring = user_tasks
+                .iter()
+                .filter(|task| task.recurrence.is_some())
+                .count();
+            if recurring >= self.max_recurring_tasks_per_user {
+                return Err(UserError::new(
+                    "You already have {count} recurring tasks, the limit is {limit}",
+                )
+                .with("count", recurring)
+                .with("limit", self.max_recurring_tasks_per_user));
+            }
+            self.check_recurrence(recurrence)?;
+        }
+
+        let guild_tasks = existing
+            .iter()
+            .filter(|task| task.guild_id == guild_id)
+            .count();
+        if guild_tasks >= self.max_tasks_per_guild {
+            return Err(
+                UserError::new("This server already has {count} tasks, the limit is {limit}")
+                    .with("count", guild_tasks)
+                    .with("limit", self.max_tasks_per_guild),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Whether a recurring task repeats no more often than allowed
+    pub fn check_recurrence(&self, recurrence: &Recurrence) -> Result<(), UserError> {
+        if recurrence.min_interval() < self.min_recurrence_interval {
+            return Err(
+                UserError::new("Recurring tasks can repeat at most every {hours} hours")
+                    .with("hours", self.min_recurrence_interval.num_hours()),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+
+    fn weekly(days: Vec<Weekday>) -> Recurrence {
+        Recurrence::Weekly {
+            days,
+            hour: 9,
+            minute: 0,
+        }
+    }
+
+    #[test]
+    fn each_limit_is_enforced_separately() {
+        let limits = TaskLimits {
+            max_tasks_per_user: 2,
+            max_tasks_per_guild: 3,
+            max_recurring_tasks_per_user: 1,
+            min_recurrence_interval: Duration::days(2),
+        };
+        let existing = vec![
+            Task::for_test(1, "task").recurring(weekly(vec![Weekday::Mon])),
+            Task::for_test(2, "task"),
+        ];
+
+        // Single tasks only count against the totals
+        assert!(limits.check_new_task(&existing, 1, 10, None).is_ok());
+        assert!(
+            limits
+                .check_new_task(&existing, 1, 10, Some(&weekly(vec![Weekday::Tue])))
+                .unwrap_err()
+                .to_string()
+                .contains("recurring")
+        );
+        assert!(
+            limits
+                .check_new_task(
+                    &existing,
+                    2,
+                    10,
+                    Some(&weekly(vec![Weekday::Mon, Weekday::Tue]))
+                )
+                .unwrap_err()
+                .to_string()
+                .contains("every 48 hours")
+        );
+
+        let existing = [existing, vec![Task::for_test(1, "task")]].concat();
+        assert!(
+            limits
+                .check_new_task(&existing, 1, 11, None)
+                .unwrap_err()
+                .to_string()
+                .contains("limit is 2")
+        );
+        assert!(
+            limits
+                .check_new_task(&existing, 3, 10, None)
+                .unwrap_err()
+                .to_string()
+                .contains("server")
+        );
+        assert!(limits.check_new_task(&existing, 3, 11, None).is_ok());
+    }
+}
@@ -0,0 +1,8 @@
+/// What happens to a user's tasks when they move to another timezone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimezoneChangeMode {
+    /// Tasks keep their wall-clock time, so their UTC time moves with the new zone
+    KeepLocalTime,
+    /// Tasks keep firing at the same instant, which reads as another local time
+    KeepAbsoluteTime,
+}
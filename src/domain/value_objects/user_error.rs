@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+use std::fmt;
+
+/// Error shown to a user, kept as its English template and the values of its `{name}`
+/// placeholders so the command replying can translate it. Displays in English
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserError {
+    template: Cow<'static, str>,
+    args: Vec<(&'static str, String)>,
+}
+
+impl UserError {
+    pub fn new(template: &'static str) -> Self {
+        Self {
+            template: Cow::Borrowed(template),
+            args: Vec::new(),
+        }
+    }
+
+    /// Fills the `{name}` placeholder with `value`
+    pub fn with(mut self, name: &'static str, value: impl fmt::Display) -> Self {
+        self.args.push((name, value.to_string()));
+        self
+    }
+
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    pub fn args(&self) -> &[(&'static str, String)] {
+        &self.args
+    }
+}
+
+impl fmt::Display for UserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&fill_placeholders(&self.template, &self.args))
+    }
+}
+
+/// `template` with each `{name}` replaced by its value in `args`. Done in one pass so a value
+/// holding a placeholder of its own is inserted as it is. Unknown placeholders are kept
+pub fn fill_placeholders<V: fmt::Display>(template: &str, args: &[(&str, V)]) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        text.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after.find('}').and_then(|close| {
+            let name = &after[..close];
+            let (_, value) = args.iter().find(|(arg, _)| *arg == name)?;
+            Some((close, value))
+        });
+        match value {
+            Some((close, value)) => {
+                text.push_str(&value.to_string());
+                rest = &after[close + 1..];
+            }
+            None => {
+                text.push('{');
+                rest = after;
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+impl std::error::Error for UserError {}
+
+/// A fixed text, translated when the catalogue has it
+impl From<&'static str> for UserError {
+    fn from(template: &'static str) -> Self {
+        Self::new(template)
+    }
+}
+
+/// Text built elsewhere (storage and other internal errors), shown as it is
+impl From<String> for UserError {
+    fn from(text: String) -> Self {
+        Self {
+            template: Cow::Owned(text),
+            args: Vec::new(),
+        }
+    }
+}
+
+impl From<UserError> for String {
+    fn from(error: UserError) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_the_filled_template() {
+        let error = UserError::new("You already have {count} tasks, the limit is {limit}")
+            .with("count", 3)
+            .with("limit", 3);
+
+        assert_eq!(error.template(), "You already have {count} tasks, the limit is {limit}");
+        assert_eq!(error.to_string(), "You already have 3 tasks, the limit is 3");
+        assert_eq!(String::from(UserError::from("Invalid hour".to_string())), "Invalid hour");
+    }
+
+    #[test]
+    fn inserted_values_are_not_filled_again() {
+        let error = UserError::new("Task {title} is due {when}")
+            .with("title", "{when}")
+            .with("when", "{x} today");
+
+        assert_eq!(error.to_string(), "Task {when} is due {x} today");
+        assert_eq!(fill_placeholders("{a} {b}", &[("b", "{a}")]), "{a} {a}");
+    }
+}
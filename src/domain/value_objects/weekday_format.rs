@@ -19,16 +19,16 @@ impl WeekdayFormat for Weekday {
         }
     }
 
-    /// Parses string representation into Weekday enum
+    /// Parses string representation into Weekday enum, English or Spanish
     fn from_str(s: &str) -> Option<Weekday> {
         match s.to_lowercase().as_str() {
-            "monday" | "mon" => Some(Weekday::Mon),
-            "tuesday" | "tue" => Some(Weekday::Tue),
-            "wednesday" | "wed" => Some(Weekday::Wed),
-            "thursday" | "thu" => Some(Weekday::Thu),
-            "friday" | "fri" => Some(Weekday::Fri),
-            "saturday" | "sat" => Some(Weekday::Sat),
-            "sunday" | "sun" => Some(Weekday::Sun),
+            "monday" | "mon" | "lunes" | "lun" => Some(Weekday::Mon),
+            "tuesday" | "tue" | "martes" | "mar" => Some(Weekday::Tue),
+            "wednesday" | "wed" | "miércoles" | "miercoles" | "mié" | "mie" => Some(Weekday::Wed),
+            "thursday" | "thu" | "jueves" | "jue" => Some(Weekday::Thu),
+            "friday" | "fri" | "viernes" | "vie" => Some(Weekday::Fri),
+            "saturday" | "sat" | "sábado" | "sabado" | "sáb" | "sab" => Some(Weekday::Sat),
+            "sunday" | "sun" | "domingo" | "dom" => Some(Weekday::Sun),
             _ => None,
         }
     }
@@ -0,0 +1,203 @@
+use crate::domain::value_objects::UserError;
+use chrono::{DateTime, Datelike, Duration, DurationRound, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Part of the day someone is available for meetings, local to their timezone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkingHours {
+    start_minute: u16, // minutes since midnight
+    end_minute: u16,   // exclusive, up to 24:00
+}
+
+impl WorkingHours {
+    /// Used for members who never set their working hours
+    pub const DEFAULT: Self = Self {
+        start_minute: 9 * 60,
+        end_minute: 17 * 60,
+    };
+
+    /// Whether a meeting from `start` lasting `duration` fits on one of this person's workdays
+    /// (Monday to Friday) in `zone`
+    pub fn covers(&self, zone: Tz, start: DateTime<Utc>, duration: Duration) -> bool {
+        let local_start = start.with_timezone(&zone);
+        let local_end = (start + duration).with_timezone(&zone);
+        if matches!(local_start.weekday(), Weekday::Sat | Weekday::Sun) {
+            return false;
+        }
+
+        let start_minute = (local_start.hour() * 60 + local_start.minute()) as i64;
+        let end_minute = start_minute + (local_end - local_start).num_minutes();
+        local_start.date_naive() == (local_end - Duration::minutes(1)).date_naive()
+            && start_minute >= self.start_minute as i64
+            && end_minute <= self.end_minute as i64
+    }
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Parses `09:00-17:30`, hours alone (`9-17`) are accepted too
+impl std::str::FromStr for WorkingHours {
+    type Err = UserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            UserError::new("'{range}' is not a time range, use HH:MM-HH:MM (e.g. 09:00-17:00)")
+                .with("range", s)
+        };
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let start_minute = parse_minute_of_day(start).ok_or_else(invalid)?;
+        let end_minute = parse_minute_of_day(end).ok_or_else(invalid)?;
+
+        if start_minute >= end_minute {
+            return Err("Working hours must start before they end".into());
+        }
+        Ok(Self {
+            start_minute,
+            end_minute,
+        })
+    }
+}
+
+impl fmt::Display for WorkingHours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02}",
+            self.start_minute / 60,
+            self.start_minute % 60,
+            self.end_minute / 60,
+            self.end_minute % 60
+        )
+    }
+}
+
+/// `HH:MM` or `HH`, 24:00 included so a range can end at midnight
+fn parse_minute_of_day(input: &str) -> Option<u16> {
+    let input = input.trim();
+    let (hour, minute) = input.split_once(':').unwrap_or((input, "0"));
+    let hour: u16 = hour.parse().ok()?;
+    let minute: u16 = minute.parse().ok()?;
+    // Checked before multiplying, large hours would overflow the u16
+    if hour > 24 || minute >= 60 {
+        return None;
+    }
+    let total = hour * 60 + minute;
+    (total <= 24 * 60).then_some(total)
+}
+
+/// A stretch of time in which a meeting can start anywhere and still fit everyone's hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeetingWindow {
+    pub start: DateTime<Utc>,
+    /// When the last meeting that fits would end
+    pub end: DateTime<Utc>,
+}
+
+/// Windows in the `days` after `from` where a `duration` meeting fits the working hours of every
+/// participant. Start times are checked every 30 minutes
+pub fn find_meeting_windows(
+    participants: &[(Tz, WorkingHours)],
+    from: DateTime<Utc>,
+    duration: Duration,
+    days: i64,
+) -> Vec<MeetingWindow> {
+    let step = Duration::minutes(30);
+    let first = from.duration_trunc(step).map_or(from, |start| start + step);
+    let until = from + Duration::days(days);
+
+    let mut windows: Vec<MeetingWindow> = Vec::new();
+    let mut candidate = first;
+    while candidate < until {
+        if participants
+            .iter()
+            .all(|(zone, hours)| hours.covers(*zone, candidate, duration))
+        {
+            match windows.last_mut() {
+                // Still the same window when the previous start fitted too
+                Some(window) if window.end == candidate - step + duration => {
+                    window.end = candidate + duration
+                }
+                _ => windows.push(MeetingWindow {
+                    start: candidate,
+                    end: candidate + duration,
+                }),
+            }
+        }
+        candidate += step;
+    }
+
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn parses_and_displays_ranges() {
+        let hours: WorkingHours = "9-17:30".parse().unwrap();
+        assert_eq!(hours.to_string(), "09:00-17:30");
+        assert_eq!(
+            "08:00-24:00".parse::<WorkingHours>().unwrap().to_string(),
+            "08:00-24:00"
+        );
+        assert!("17:00-09:00".parse::<WorkingHours>().is_err());
+        assert!("9am".parse::<WorkingHours>().is_err());
+        assert!("09:75-10:00".parse::<WorkingHours>().is_err());
+        assert!("2000-3000".parse::<WorkingHours>().is_err());
+        assert!("24:30-25:00".parse::<WorkingHours>().is_err());
+    }
+
+    #[test]
+    fn finds_the_overlap_of_working_days() {
+        let madrid: Tz = "Europe/Madrid".parse().unwrap();
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        // Monday 2025-01-06 12:00 UTC
+        let now = Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap();
+
+        let windows = find_meeting_windows(
+            &[
+                (madrid, WorkingHours::DEFAULT),
+                (new_york, WorkingHours::DEFAULT),
+            ],
+            now,
+            Duration::hours(1),
+            7,
+        );
+
+        // 09:00-17:00 in Madrid and New York overlap 14:00-16:00 UTC, Monday to Friday
+        assert_eq!(windows.len(), 5);
+        assert_eq!(
+            windows[0],
+            MeetingWindow {
+                start: Utc.with_ymd_and_hms(2025, 1, 6, 14, 0, 0).unwrap(),
+                end: Utc.with_ymd_and_hms(2025, 1, 6, 16, 0, 0).unwrap(),
+            }
+        );
+        assert_eq!(
+            windows[4].start,
+            Utc.with_ymd_and_hms(2025, 1, 10, 14, 0, 0).unwrap()
+        );
+
+        // Nothing fits a meeting longer than the overlap
+        assert!(
+            find_meeting_windows(
+                &[
+                    (madrid, WorkingHours::DEFAULT),
+                    (new_york, WorkingHours::DEFAULT)
+                ],
+                now,
+                Duration::hours(3),
+                7,
+            )
+            .is_empty()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::Path;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KickRandomConfig {
@@ -35,15 +36,15 @@ pub struct KickConfig {
 }
 
 impl KickConfig {
-    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
-        let targets_path = "./data/server_specific/kick_targets.json";
+    pub fn load(data_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let targets_path = data_dir.join("kick_targets.json");
         let content = fs::read_to_string(targets_path)?;
         let config: KickConfig = serde_json::from_str(&content)?;
         Ok(config)
     }
 
-    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let targets_path = "./data/server_specific/kick_targets.json";
+    pub fn save(&self, data_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let targets_path = data_dir.join("kick_targets.json");
         let content = serde_json::to_string_pretty(self)?;
         fs::write(targets_path, content)?;
         Ok(())
@@ -65,17 +66,16 @@ impl KickConfig {
 }
 
 impl KickTargetUser {
-    pub fn can_be_kicked(&self, config: &KickRandomConfig) -> bool {
+    pub fn can_be_kicked(&self, config: &KickRandomConfig, now: DateTime<Utc>) -> bool {
         if let Some(last_kick) = self.last_kick_time {
             let min_interval = Duration::minutes(config.min_minutes_between_kicks as i64);
-            let now = Utc::now();
             return now - last_kick >= min_interval;
         }
         true
     }
 
-    pub fn should_kick(&self, config: &KickRandomConfig) -> bool {
-        if !self.can_be_kicked(config) {
+    pub fn should_kick(&self, config: &KickRandomConfig, now: DateTime<Utc>) -> bool {
+        if !self.can_be_kicked(config, now) {
             return false;
         }
 
@@ -86,7 +86,7 @@ impl KickTargetUser {
         rng.gen_bool(probability as f64)
     }
 
-    pub fn update_kick_time(&mut self) {
-        self.last_kick_time = Some(Utc::now());
+    pub fn update_kick_time(&mut self, now: DateTime<Utc>) {
+        self.last_kick_time = Some(now);
     }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Duration, Utc};
 use std::fs;
+use std::path::Path;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RandomConfig {
@@ -35,22 +36,22 @@ pub struct NicknameConfig {
 }
 
 impl NicknameConfig {
-    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
-        let targets_path = "./data/server_specific/nickname_targets.json";
+    pub fn load(data_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let targets_path = data_dir.join("nickname_targets.json");
         let content = fs::read_to_string(targets_path)?;
         let config: NicknameConfig = serde_json::from_str(&content)?;
         Ok(config)
     }
 
-    pub fn load_nicknames() -> Result<Vec<String>, Box<dyn std::error::Error>> {
-        let nicknames_path = "./data/server_specific/nicknames.json";
+    pub fn load_nicknames(data_dir: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let nicknames_path = data_dir.join("nicknames.json");
         let content = fs::read_to_string(nicknames_path)?;
         let nicknames: Vec<String> = serde_json::from_str(&content)?;
         Ok(nicknames)
     }
 
-    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let targets_path = "./data/server_specific/nickname_targets.json";
+    pub fn save(&self, data_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let targets_path = data_dir.join("nickname_targets.json");
         let content = serde_json::to_string_pretty(self)?;
         fs::write(targets_path, content)?;
         Ok(())
@@ -70,17 +71,16 @@ impl NicknameConfig {
 }
 
 impl TargetUser {
-    pub fn can_change_nickname(&self, config: &RandomConfig) -> bool {
+    pub fn can_change_nickname(&self, config: &RandomConfig, now: DateTime<Utc>) -> bool {
         if let Some(last_change) = self.last_change_time {
             let min_interval = Duration::minutes(config.min_minutes_between_changes as i64);
-            let now = Utc::now();
             return now - last_change >= min_interval;
         }
         true
     }
 
-    pub fn should_change_nickname(&self, config: &RandomConfig) -> bool {
-        if !self.can_change_nickname(config) {
+    pub fn should_change_nickname(&self, config: &RandomConfig, now: DateTime<Utc>) -> bool {
+        if !self.can_change_nickname(config, now) {
             return false;
         }
 
@@ -91,7 +91,7 @@ impl TargetUser {
         rng.gen_bool(probability as f64)
     }
 
-    pub fn update_change_time(&mut self) {
-        self.last_change_time = Some(Utc::now());
+    pub fn update_change_time(&mut self, now: DateTime<Utc>) {
+        self.last_change_time = Some(now);
     }
 }
\ No newline at end of file
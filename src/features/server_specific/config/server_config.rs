@@ -27,17 +27,3 @@ impl Default for ServerConfig {
         }
     }
 }
-
-impl ServerConfig {
-    pub fn my_server() -> Self {
-        Self {
-            server_id: 479788664876957737,
-            general_channel_id: 491109094237929472,
-            enabled_features: vec![
-                Feature::NicknameChanger,
-                Feature::MentionResponse,
-                Feature::Kick,
-            ],
-        }
-    }
-}
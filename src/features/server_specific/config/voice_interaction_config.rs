@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::Path;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PermissionTarget {
@@ -20,13 +21,20 @@ pub struct VoiceInteractionConfig {
 }
 
 impl VoiceInteractionConfig {
-    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
-        let config_path = "./data/server_specific/interaction_permission.json";
+    pub fn load(data_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let config_path = data_dir.join("interaction_permission.json");
         let config_content = fs::read_to_string(config_path)?;
         let config: VoiceInteractionConfig = serde_json::from_str(&config_content)?;
         Ok(config)
     }
 
+    pub fn save(&self, data_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let config_path = data_dir.join("interaction_permission.json");
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(config_path, content)?;
+        Ok(())
+    }
+
     pub fn is_user_allowed(&self, user_id: u64) -> bool {
         self.targets.iter().any(|target| target.user_id == user_id)
     }
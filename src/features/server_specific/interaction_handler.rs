@@ -1,3 +1,6 @@
+use crate::application::i18n::{t, tf};
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::value_objects::Language;
 use crate::features::server_specific::config::kick_config::KickTargetUser;
 use crate::features::server_specific::services::{
     alias_service::AliasService,
@@ -5,6 +8,7 @@ use crate::features::server_specific::services::{
     voice_interaction_service::VoiceInteractionService,
 };
 use crate::features::server_specific::utils::extract_username_from_kick_message;
+use crate::infrastructure::observability::metrics;
 
 use serenity::all::{
     ChannelId, ComponentInteraction, CreateInteractionResponse, CreateInteractionResponseMessage,
@@ -18,21 +22,37 @@ pub struct ServerInteractionHandler {
     pub kick_service: Option<Arc<KickService>>,
     pub voice_interaction_service: Option<Arc<VoiceInteractionService>>,
     pub alias_service: Option<Arc<AliasService>>,
+    /// User that cannot be muted, kicked, disconnected, etc. (`server_specific.protected_user_id`)
+    pub protected_user_id: Option<u64>,
+    languages: Option<Arc<TimezoneService>>, // None answers in English
 }
 
 impl ServerInteractionHandler {
-    // Protected user ID (ponyrosa) - cannot be muted, kicked, disconnected, etc.
-    const PROTECTED_USER_ID: u64 = 300869447475003393;
-
     pub fn new(
         kick_service: Option<Arc<KickService>>,
         voice_interaction_service: Option<Arc<VoiceInteractionService>>,
         alias_service: Option<Arc<AliasService>>,
+        protected_user_id: Option<u64>,
     ) -> Self {
         Self {
             kick_service,
             voice_interaction_service,
             alias_service,
+            protected_user_id,
+            languages: None,
+        }
+    }
+
+    /// Answer in the server's language, as set with /server_settings
+    pub fn with_languages(mut self, timezone_service: Arc<TimezoneService>) -> Self {
+        self.languages = Some(timezone_service);
+        self
+    }
+
+    async fn language(&self, guild_id: Option<GuildId>) -> Language {
+        match (&self.languages, guild_id) {
+            (Some(languages), Some(guild_id)) => languages.guild_language(guild_id.get()).await,
+            _ => Language::default(),
         }
     }
 
@@ -63,6 +83,7 @@ impl ServerInteractionHandler {
         let author_id = message.author.id.get();
         let bot_id = ctx.cache.current_user().id.get();
         let guild_id = message.guild_id.unwrap();
+        let language = self.language(Some(guild_id)).await;
 
         // Check permission for voice interaction commands
         if let Some(voice_service) = &self.voice_interaction_service {
@@ -70,7 +91,7 @@ impl ServerInteractionHandler {
             let is_desmuteame_self = self.is_desmuteame_self_command(&content);
 
             if !is_desmuteame_self && !voice_service.has_permission(author_id) {
-                let _ = message.channel_id.say(&ctx.http, "Callate puta").await;
+                let _ = message.channel_id.say(&ctx.http, t(language, "Shut up")).await;
                 return;
             }
 
@@ -81,14 +102,14 @@ impl ServerInteractionHandler {
             {
                 if let Some(target_id) = target_user {
                     // Check if target is protected user
-                    if target_id == Self::PROTECTED_USER_ID
+                    if self.protected_user_id == Some(target_id)
                         && !(target_id == author_id
                             && matches!(
                                 action,
                                 crate::features::server_specific::services::voice_interaction_service::VoiceAction::Unmute
                             ))
                     {
-                        let _ = message.channel_id.say(&ctx.http, "Callate puta").await;
+                        let _ = message.channel_id.say(&ctx.http, t(language, "Shut up")).await;
                         return;
                     }
 
@@ -98,7 +119,7 @@ impl ServerInteractionHandler {
                         .await
                     {
                         Some(voice_channel_id) => {
-                            let _ = message.channel_id.say(&ctx.http, "Bueno").await;
+                            let _ = message.channel_id.say(&ctx.http, t(language, "Okay")).await;
 
                             let voice_service_clone = voice_service.clone();
                             let ctx_http = ctx.http.clone();
@@ -116,18 +137,26 @@ impl ServerInteractionHandler {
                                 {
                                     Ok(_) => {
                                         debug!("Voice action completed successfully");
+                                        metrics().record_feature_event(
+                                            "voice",
+                                            &format!("{:?}", action).to_lowercase(),
+                                        );
 
                                         if let crate::features::server_specific::services::voice_interaction_service::VoiceAction::Unmute = action {
                                             tokio::time::sleep(std::time::Duration::from_secs(3)).await;
                                         }
                                     }
                                     Err(e) => {
+                                        metrics().record_feature_event("voice", "failed");
                                         error!(
                                             "Failed to execute voice action in background: {}",
                                             e
                                         );
                                         let _ = message_channel_id
-                                            .say(&ctx_http, format!("Error en la acción: {}", e))
+                                            .say(
+                                                &ctx_http,
+                                                tf(language, "Action failed: {error}", &[("error", &e)]),
+                                            )
                                             .await;
                                     }
                                 }
@@ -136,14 +165,14 @@ impl ServerInteractionHandler {
                         None => {
                             let _ = message
                                 .channel_id
-                                .say(&ctx.http, "Pero no está en ningún canal puta")
+                                .say(&ctx.http, t(language, "They aren't in any voice channel"))
                                 .await;
                         }
                     }
                 } else {
                     let _ = message
                         .channel_id
-                        .say(&ctx.http, "Callate puta")
+                        .say(&ctx.http, t(language, "Shut up"))
                         .await;
                 }
                 return;
@@ -163,20 +192,20 @@ impl ServerInteractionHandler {
 
                 if let Some(target_id) = target_user {
                     // Check if target is protected user
-                    if target_id == Self::PROTECTED_USER_ID {
-                        let _ = message.channel_id.say(&ctx.http, "Callate puta").await;
+                    if self.protected_user_id == Some(target_id) {
+                        let _ = message.channel_id.say(&ctx.http, t(language, "Shut up")).await;
                         return;
                     }
 
                     // Verificar permiso de kick
                     if let Some(voice_service) = &self.voice_interaction_service {
                         if !voice_service.can_kick(author_id) {
-                            let _ = message.channel_id.say(&ctx.http, "Callate puta").await;
+                            let _ = message.channel_id.say(&ctx.http, t(language, "Shut up")).await;
                             return;
                         }
                     }
 
-                    let _ = message.channel_id.say(&ctx.http, "bueno").await;
+                    let _ = message.channel_id.say(&ctx.http, t(language, "Okay")).await;
 
                     let kick_service_clone = kick_service.clone();
                     let ctx_http = ctx.http.clone();
@@ -190,7 +219,10 @@ impl ServerInteractionHandler {
                             Err(e) => {
                                 error!("Failed to kick user in background: {}", e);
                                 let _ = message_channel_id
-                                    .say(&ctx_http, format!("Error al kickear: {}", e))
+                                    .say(
+                                        &ctx_http,
+                                        tf(language, "Couldn't kick them: {error}", &[("error", &e)]),
+                                    )
                                     .await;
                             }
                         }
@@ -198,7 +230,7 @@ impl ServerInteractionHandler {
                 } else {
                     let _ = message
                         .channel_id
-                        .say(&ctx.http, "Menciona al usuario o usa un alias conocido")
+                        .say(&ctx.http, t(language, "Mention the user or use a known alias"))
                         .await;
                 }
                 return;
@@ -206,7 +238,10 @@ impl ServerInteractionHandler {
         }
 
         // If no command matched, send default response
-        let _ = message.channel_id.say(&ctx.http, "Que homosexual").await;
+        let _ = message
+            .channel_id
+            .say(&ctx.http, t(language, "Not sure what you mean"))
+            .await;
     }
 
     async fn get_user_voice_channel_from_ctx(
@@ -405,8 +440,12 @@ impl ServerInteractionHandler {
 
         // Extract username from message
         let server_name = extract_username_from_kick_message(&original_message);
+        let language = self.language(component.guild_id).await;
 
         if let (Some(kick_service), Some(server_name)) = (&self.kick_service, server_name) {
+            let vote = if approved { "vote_approved" } else { "vote_rejected" };
+            metrics().record_feature_event("kick", vote);
+
             if approved {
                 // Find user ID by server name
                 if let Some(target) = self
@@ -414,8 +453,8 @@ impl ServerInteractionHandler {
                     .await
                 {
                     // Check if target is protected user
-                    if target.user_id == Self::PROTECTED_USER_ID {
-                        let response = "Callate puta".to_string();
+                    if self.protected_user_id == Some(target.user_id) {
+                        let response = t(language, "Shut up");
                         let _ = component
                             .create_response(
                                 &ctx.http,
@@ -431,7 +470,8 @@ impl ServerInteractionHandler {
 
                     match kick_service.execute_kick(target.user_id).await {
                         Ok(_) => {
-                            let response = format!("{} kickeado.", server_name);
+                            let response =
+                                tf(language, "{name} kicked.", &[("name", &server_name)]);
                             let _ = component
                                 .create_response(
                                     &ctx.http,
@@ -444,7 +484,11 @@ impl ServerInteractionHandler {
                                 .await;
                         }
                         Err(e) => {
-                            let response = format!("Error al kickear a {}: {}", server_name, e);
+                            let response = tf(
+                                language,
+                                "Couldn't kick {name}: {error}",
+                                &[("name", &server_name), ("error", &e)],
+                            );
                             let _ = component
                                 .create_response(
                                     &ctx.http,
@@ -459,7 +503,11 @@ impl ServerInteractionHandler {
                         }
                     }
                 } else {
-                    let response = format!("No encontré al usuario: {}", server_name);
+                    let response = tf(
+                        language,
+                        "I couldn't find the user: {name}",
+                        &[("name", &server_name)],
+                    );
                     let _ = component
                         .create_response(
                             &ctx.http,
@@ -472,7 +520,7 @@ impl ServerInteractionHandler {
                         .await;
                 }
             } else {
-                let response = format!("bueno...");
+                let response = t(language, "Okay...");
                 let _ = component
                     .create_response(
                         &ctx.http,
@@ -498,7 +546,7 @@ impl ServerInteractionHandler {
     ) -> Option<KickTargetUser> {
         let guild_id = GuildId::new(kick_service.server_config.server_id);
 
-        for target in &kick_service.kick_config.targets {
+        for target in kick_service.targets() {
             let user_id = UserId::new(target.user_id);
 
             match guild_id.member(http, user_id).await {
@@ -508,12 +556,12 @@ impl ServerInteractionHandler {
                         .clone()
                         .unwrap_or_else(|| member.user.name.clone());
                     if target_server_name == server_name {
-                        return Some(target.clone());
+                        return Some(target);
                     }
                 }
                 Err(_) => {
                     if target.display_name == server_name {
-                        return Some(target.clone());
+                        return Some(target);
                     }
                 }
             }
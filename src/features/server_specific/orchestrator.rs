@@ -12,24 +12,26 @@ use tracing::info;
 pub struct ServerFeaturesOrchestrator {
     pub nickname_changer_service: Option<Arc<NicknameChangerService>>,
     pub kick_service: Option<Arc<KickService>>,
+    /// Guild the features run in (`server_specific.guild_id`), None disables them
+    pub server_id: Option<u64>,
 }
 
 impl ServerFeaturesOrchestrator {
     pub fn new(
         nickname_changer_service: Option<Arc<NicknameChangerService>>,
         kick_service: Option<Arc<KickService>>,
+        server_id: Option<u64>,
     ) -> Self {
         Self {
             nickname_changer_service,
             kick_service,
+            server_id,
         }
     }
 
     /// Initialize specific feature for specific server
     pub async fn initialize_server_features(&self, guild_id: GuildId) {
-        let my_server_id = 479788664876957737; // My server (Kutums)
-
-        if guild_id.get() != my_server_id {
+        if self.server_id != Some(guild_id.get()) {
             return;
         }
 
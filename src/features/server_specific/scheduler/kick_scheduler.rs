@@ -29,6 +29,8 @@ impl KickScheduler {
         Duration::from_secs(
             self.service
                 .kick_config
+                .read()
+                .unwrap()
                 .random_config
                 .check_interval_minutes as u64
                 * 60,
@@ -1,4 +1,5 @@
 use crate::features::server_specific::services::nickname_changer::NicknameChangerService;
+use crate::infrastructure::observability::metrics;
 use std::sync::Arc;
 use tokio::time::{Duration, sleep};
 use tracing::{debug, warn};
@@ -29,6 +30,8 @@ impl NicknameScheduler {
         Duration::from_secs(
             self.service
                 .nickname_config
+                .read()
+                .unwrap()
                 .random_config
                 .check_interval_minutes as u64
                 * 60,
@@ -49,11 +52,15 @@ impl NicknameScheduler {
         if let Some(target) = targets_to_change.first() {
             debug!("Found target for nickname change: {}", target.display_name);
 
-            if let Err(e) = self.service.change_nickname_for_user(target.user_id).await {
-                warn!(
-                    "Failed to change nickname for {}: {}",
-                    target.display_name, e
-                );
+            match self.service.change_nickname_for_user(target.user_id).await {
+                Ok(_) => metrics().record_feature_event("nickname", "changed"),
+                Err(e) => {
+                    metrics().record_feature_event("nickname", "failed");
+                    warn!(
+                        "Failed to change nickname for {}: {}",
+                        target.display_name, e
+                    );
+                }
             }
         }
     }
@@ -97,6 +97,23 @@ impl AliasService {
         false
     }
 
+    /// Drop a user and all their aliases, returns whether they had an entry
+    pub async fn forget_user(&self, user_id: u64) -> bool {
+        let mut config = self.config.write().await;
+        let original_len = config.users.len();
+        config.users.retain(|user| user.user_id != user_id);
+
+        if config.users.len() == original_len {
+            return false;
+        }
+
+        debug!("Removed alias entry of user {}", user_id);
+        if let Err(e) = config.save(&self.config_path) {
+            error!("Failed to save alias config: {}", e);
+        }
+        true
+    }
+
     /// Get all users with their aliases
     pub async fn get_all_users(&self) -> Vec<UserAlias> {
         let config = self.config.read().await;
@@ -1,5 +1,10 @@
+use crate::application::i18n::{t, tf};
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::Clock;
+use crate::domain::value_objects::Language;
 use crate::features::server_specific::config::ServerConfig;
 use crate::features::server_specific::config::kick_config::{KickConfig, KickTargetUser};
+use crate::infrastructure::observability::metrics;
 
 use serenity::all::{
     ButtonStyle, ChannelId, CreateActionRow, CreateButton, CreateMessage, GuildId, UserId,
@@ -8,37 +13,58 @@ use serenity::http::Http;
 
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tracing::error;
 
+/// Content of the kick poll, the vote buttons read the name back from it
+pub const KICK_POLL: &str = "Can I kick {name}?";
+
 pub struct KickService {
     pub server_config: ServerConfig,
-    pub kick_config: KickConfig,
+    /// Behind a lock so erased users can be dropped from the targets while the scheduler runs
+    pub kick_config: RwLock<KickConfig>,
     http: Arc<Http>,
+    clock: Arc<dyn Clock>,
+    languages: Option<Arc<TimezoneService>>, // None posts polls in English
 }
 
 impl KickService {
-    pub fn new(server_config: ServerConfig, kick_config: KickConfig, http: Arc<Http>) -> Self {
+    pub fn new(
+        server_config: ServerConfig,
+        kick_config: KickConfig,
+        http: Arc<Http>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         Self {
             server_config,
-            kick_config,
+            kick_config: RwLock::new(kick_config),
             http,
+            clock,
+            languages: None,
         }
     }
 
+    /// Post polls in the server's language, as set with /server_settings
+    pub fn with_languages(mut self, timezone_service: Arc<TimezoneService>) -> Self {
+        self.languages = Some(timezone_service);
+        self
+    }
+
     /// Gets targets that should be considered for kicking based on random probability
     /// Only one user will be returned at most per cycle, even if multiple users pass the probability check
-    pub fn get_targets_for_random_kick(&self) -> Vec<&KickTargetUser> {
-        if !self.kick_config.is_enabled() {
+    pub fn get_targets_for_random_kick(&self) -> Vec<KickTargetUser> {
+        let kick_config = self.kick_config.read().unwrap();
+        if !kick_config.is_enabled() {
             return Vec::new();
         }
 
+        let now = self.clock.now();
+
         // Filter users who can be kicked (not in cooldown)
-        let mut eligible_targets: Vec<&KickTargetUser> = self
-            .kick_config
+        let mut eligible_targets: Vec<&KickTargetUser> = kick_config
             .targets
             .iter()
-            .filter(|target| target.can_be_kicked(&self.kick_config.random_config))
+            .filter(|target| target.can_be_kicked(&kick_config.random_config, now))
             .collect();
 
         if eligible_targets.is_empty() {
@@ -51,9 +77,9 @@ impl KickService {
 
         // Evaluate each user in random order until one meets the probability
         for target in eligible_targets {
-            if target.should_kick(&self.kick_config.random_config) {
+            if target.should_kick(&kick_config.random_config, now) {
                 // Only return ONE user at most
-                return vec![target];
+                return vec![target.clone()];
             }
         }
 
@@ -62,15 +88,23 @@ impl KickService {
     }
 
     pub async fn send_kick_poll_for_user(&self, user_id: u64) -> Result<String, String> {
-        if !self.kick_config.is_enabled() {
-            return Err("Kick feature is disabled".to_string());
-        }
+        // Copy the target out so the lock isn't held across the Discord calls
+        let target = {
+            let kick_config = self.kick_config.read().unwrap();
+            if !kick_config.is_enabled() {
+                return Err("Kick feature is disabled".to_string());
+            }
 
-        let target = self.get_target(user_id)?;
+            let target = kick_config
+                .find_target(user_id)
+                .cloned()
+                .ok_or_else(|| format!("User {} not found in kick targets", user_id))?;
 
-        if !target.can_be_kicked(&self.kick_config.random_config) {
-            return Err(format!("User {} is in cooldown", target.display_name));
-        }
+            if !target.can_be_kicked(&kick_config.random_config, self.clock.now()) {
+                return Err(format!("User {} is in cooldown", target.display_name));
+            }
+            target
+        };
 
         // Get server name instead of global name
         let server_name = self
@@ -79,14 +113,22 @@ impl KickService {
             .unwrap_or_else(|| target.display_name.clone());
 
         self.send_kick_poll_message(&server_name).await?;
+        metrics().record_feature_event("kick", "poll_sent");
 
         Ok(format!("Sent kick poll for user {}", server_name))
     }
 
-    fn get_target(&self, user_id: u64) -> Result<&KickTargetUser, String> {
-        self.kick_config
-            .find_target(user_id)
-            .ok_or_else(|| format!("User {} not found in kick targets", user_id))
+    /// Current kick targets
+    pub fn targets(&self) -> Vec<KickTargetUser> {
+        self.kick_config.read().unwrap().targets.clone()
+    }
+
+    /// Stop targeting a user, returns whether they were a target
+    pub fn forget_target(&self, user_id: u64) -> bool {
+        let mut kick_config = self.kick_config.write().unwrap();
+        let original_len = kick_config.targets.len();
+        kick_config.targets.retain(|target| target.user_id != user_id);
+        kick_config.targets.len() < original_len
     }
 
     /// Gets the name of the user on the server (nickname or display name)
@@ -112,11 +154,19 @@ impl KickService {
 
     async fn send_kick_poll_message(&self, display_name: &str) -> Result<(), String> {
         let channel_id = ChannelId::new(self.server_config.general_channel_id);
+        let language = match &self.languages {
+            Some(languages) => {
+                languages
+                    .guild_language(self.server_config.server_id)
+                    .await
+            }
+            None => Language::default(),
+        };
 
-        let message_content = format!("Puedo kickear a {}?", display_name);
+        let message_content = tf(language, KICK_POLL, &[("name", &display_name)]);
 
         let yes_button = CreateButton::new("kick_yes")
-            .label("Sí")
+            .label(t(language, "Yes"))
             .style(ButtonStyle::Success);
 
         let no_button = CreateButton::new("kick_no")
@@ -145,8 +195,12 @@ impl KickService {
         let user_id = UserId::new(user_id);
 
         match self.http.kick_member(guild_id, user_id, None).await {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                metrics().record_feature_event("kick", "executed");
+                Ok(())
+            }
             Err(why) => {
+                metrics().record_feature_event("kick", "failed");
                 let msg = format!("Failed to kick user {}: {}", user_id, why);
                 error!("{}", msg);
                 Err(msg)
@@ -3,8 +3,10 @@ pub mod nickname_changer;
 pub mod service_initializer;
 pub mod voice_interaction_service;
 pub mod alias_service;
+pub mod user_data;
 
 pub use kick_service::KickService;
 pub use nickname_changer::NicknameChangerService;
 pub use service_initializer::*;
+pub use user_data::ServerUserDataStore;
 pub use voice_interaction_service::*;
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::domain::Clock;
 use crate::features::server_specific::config::ServerConfig;
 use crate::features::server_specific::config::nickname_config::{NicknameConfig, TargetUser};
 use rand::seq::SliceRandom;
@@ -6,14 +7,16 @@ use serde_json::json;
 use serenity::builder::{CreateAllowedMentions, CreateMessage};
 use serenity::http::Http;
 use serenity::model::prelude::*;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tracing::{error, info, warn};
 
 pub struct NicknameChangerService {
     pub server_config: ServerConfig,
-    pub nickname_config: NicknameConfig,
+    /// Behind a lock so erased users can be dropped from the targets while the scheduler runs
+    pub nickname_config: RwLock<NicknameConfig>,
     pub nicknames_pool: Vec<String>,
     http: Arc<Http>,
+    clock: Arc<dyn Clock>,
 }
 
 impl NicknameChangerService {
@@ -22,28 +25,32 @@ impl NicknameChangerService {
         nickname_config: NicknameConfig,
         nicknames_pool: Vec<String>,
         http: Arc<Http>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             server_config,
-            nickname_config,
+            nickname_config: RwLock::new(nickname_config),
             nicknames_pool,
             http,
+            clock,
         }
     }
 
     /// Gets targets that should change nickname based on random probability
     /// Only one user will be returned at most per cycle, even if multiple users pass the probability check
-    pub fn get_targets_for_random_change(&self) -> Vec<&TargetUser> {
-        if !self.nickname_config.is_enabled() {
+    pub fn get_targets_for_random_change(&self) -> Vec<TargetUser> {
+        let nickname_config = self.nickname_config.read().unwrap();
+        if !nickname_config.is_enabled() {
             return Vec::new();
         }
 
+        let now = self.clock.now();
+
         // Filter users who can have their nickname changed (not in cooldown)
-        let mut eligible_targets: Vec<&TargetUser> = self
-            .nickname_config
+        let mut eligible_targets: Vec<&TargetUser> = nickname_config
             .targets
             .iter()
-            .filter(|target| target.can_change_nickname(&self.nickname_config.random_config))
+            .filter(|target| target.can_change_nickname(&nickname_config.random_config, now))
             .collect();
 
         if eligible_targets.is_empty() {
@@ -56,9 +63,9 @@ impl NicknameChangerService {
 
         // Evaluate each user in random order until one meets the probability
         for target in eligible_targets {
-            if target.should_change_nickname(&self.nickname_config.random_config) {
+            if target.should_change_nickname(&nickname_config.random_config, now) {
                 // Only return ONE user at most
-                return vec![target];
+                return vec![target.clone()];
             }
         }
 
@@ -67,17 +74,24 @@ impl NicknameChangerService {
     }
 
     pub async fn change_nickname_for_user(&self, user_id: u64) -> Result<String, String> {
-        if !self.nickname_config.is_enabled() {
-            return Err("Nickname changer feature is disabled".to_string());
-        }
+        // Copy the target out so the lock isn't held across the Discord calls
+        let target = {
+            let nickname_config = self.nickname_config.read().unwrap();
+            if !nickname_config.is_enabled() {
+                return Err("Nickname changer feature is disabled".to_string());
+            }
 
-        // Get immutable target for validation
-        let target = self.get_target(user_id)?;
+            let target = nickname_config
+                .find_target(user_id)
+                .cloned()
+                .ok_or_else(|| format!("User {} not found in nickname targets", user_id))?;
 
-        // Check if target can be changed
-        if !target.can_change_nickname(&self.nickname_config.random_config) {
-            return Err(format!("User {} is in cooldown", target.display_name));
-        }
+            // Check if target can be changed
+            if !target.can_change_nickname(&nickname_config.random_config, self.clock.now()) {
+                return Err(format!("User {} is in cooldown", target.display_name));
+            }
+            target
+        };
 
         let old_nickname = self
             .get_current_nickname_from_discord(user_id)
@@ -114,10 +128,12 @@ impl NicknameChangerService {
         self.nicknames_pool.choose(&mut rand::thread_rng()).cloned()
     }
 
-    fn get_target(&self, user_id: u64) -> Result<&TargetUser, String> {
-        self.nickname_config
-            .find_target(user_id)
-            .ok_or_else(|| format!("User {} not found in nickname targets", user_id))
+    /// Stop targeting a user, returns whether they were a target
+    pub fn forget_target(&self, user_id: u64) -> bool {
+        let mut nickname_config = self.nickname_config.write().unwrap();
+        let original_len = nickname_config.targets.len();
+        nickname_config.targets.retain(|target| target.user_id != user_id);
+        nickname_config.targets.len() < original_len
     }
 
     async fn get_current_nickname_from_discord(&self, user_id: u64) -> Option<String> {
@@ -1,33 +1,41 @@
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::Clock;
 use crate::features::server_specific::config::kick_config::KickConfig;
 use crate::features::server_specific::config::nickname_config::NicknameConfig;
 use crate::features::server_specific::config::voice_interaction_config::VoiceInteractionConfig;
 use crate::features::server_specific::config::{Feature, server_config::ServerConfig};
+use crate::infrastructure::config::ServerSpecificConfig;
 use crate::features::server_specific::services::{
     kick_service::KickService, nickname_changer::NicknameChangerService,
     voice_interaction_service::VoiceInteractionService,
 };
 use serenity::http::Http;
 use songbird::Songbird;
+use std::path::Path;
 use std::sync::Arc;
 use tracing::error;
 
-/// Initializes server-specific services
+/// Initializes server-specific services for the guild in the `[server_specific]` config section
 pub async fn initialize_specific_services(
     token: &str,
+    settings: &ServerSpecificConfig,
     songbird: Arc<Songbird>,
+    clock: Arc<dyn Clock>,
+    timezone_service: Arc<TimezoneService>,
 ) -> (
     Option<Arc<NicknameChangerService>>,
     Option<Arc<KickService>>,
     Option<Arc<VoiceInteractionService>>,
 ) {
-    let server_config = ServerConfig::my_server();
+    let server_config = settings.server_config();
+    let data_dir = settings.data_dir.as_path();
 
     // Initialize nickname changer service
     let nickname_service = if server_config
         .enabled_features
         .contains(&Feature::NicknameChanger)
     {
-        match initialize_nickname_service(&server_config, token).await {
+        match initialize_nickname_service(&server_config, data_dir, token, clock.clone()).await {
             Ok(service) => Some(service),
             Err(e) => {
                 error!("Failed to initialize nickname service: {}", e);
@@ -40,7 +48,9 @@ pub async fn initialize_specific_services(
 
     // Initialize kick service
     let kick_service = if server_config.enabled_features.contains(&Feature::Kick) {
-        match initialize_kick_service(&server_config, token).await {
+        match initialize_kick_service(&server_config, data_dir, token, clock, timezone_service)
+            .await
+        {
             Ok(service) => Some(service),
             Err(e) => {
                 error!("Failed to initialize kick service: {}", e);
@@ -56,7 +66,7 @@ pub async fn initialize_specific_services(
         .enabled_features
         .contains(&Feature::MentionResponse)
     {
-        match initialize_voice_interaction_service(token, songbird).await {
+        match initialize_voice_interaction_service(settings, token, songbird).await {
             Ok(service) => Some(service),
             Err(e) => {
                 error!("Failed to initialize voice interaction service: {}", e);
@@ -73,10 +83,12 @@ pub async fn initialize_specific_services(
 /// Initializes nickname changer service with configuration
 async fn initialize_nickname_service(
     server_config: &ServerConfig,
+    data_dir: &Path,
     token: &str,
+    clock: Arc<dyn Clock>,
 ) -> Result<Arc<NicknameChangerService>, Box<dyn std::error::Error>> {
-    let nickname_config = NicknameConfig::load()?;
-    let nicknames_pool = NicknameConfig::load_nicknames()?;
+    let nickname_config = NicknameConfig::load(data_dir)?;
+    let nicknames_pool = NicknameConfig::load_nicknames(data_dir)?;
 
     if nicknames_pool.is_empty() {
         return Err("No nicknames available in the pool".into());
@@ -87,32 +99,42 @@ async fn initialize_nickname_service(
         nickname_config,
         nicknames_pool,
         Arc::new(Http::new(token)),
+        clock,
     )))
 }
 
 /// Initializes kick service with configuration
 async fn initialize_kick_service(
     server_config: &ServerConfig,
+    data_dir: &Path,
     token: &str,
+    clock: Arc<dyn Clock>,
+    timezone_service: Arc<TimezoneService>,
 ) -> Result<Arc<KickService>, Box<dyn std::error::Error>> {
-    let kick_config = KickConfig::load()?;
+    let kick_config = KickConfig::load(data_dir)?;
 
-    Ok(Arc::new(KickService::new(
-        server_config.clone(),
-        kick_config,
-        Arc::new(Http::new(token)),
-    )))
+    Ok(Arc::new(
+        KickService::new(
+            server_config.clone(),
+            kick_config,
+            Arc::new(Http::new(token)),
+            clock,
+        )
+        .with_languages(timezone_service),
+    ))
 }
 
 /// Initializes voice interaction service with configuration
 async fn initialize_voice_interaction_service(
+    settings: &ServerSpecificConfig,
     token: &str,
     songbird: Arc<Songbird>,
 ) -> Result<Arc<VoiceInteractionService>, Box<dyn std::error::Error>> {
-    let voice_config = VoiceInteractionConfig::load()?;
+    let voice_config = VoiceInteractionConfig::load(&settings.data_dir)?;
 
     Ok(Arc::new(VoiceInteractionService::new(
         voice_config,
+        settings.sounds_dir.clone(),
         Arc::new(Http::new(token)),
         songbird,
     )))
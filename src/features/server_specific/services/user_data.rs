@@ -0,0 +1,198 @@
+use crate::domain::PersonalDataStore;
+use crate::features::server_specific::config::alias_config::AliasConfig;
+use crate::features::server_specific::config::{
+    KickConfig, NicknameConfig, VoiceInteractionConfig,
+};
+use crate::features::server_specific::services::{
+    alias_service::AliasService, kick_service::KickService,
+    nickname_changer::NicknameChangerService, voice_interaction_service::VoiceInteractionService,
+};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const ALIAS_FILE: &str = "targets_alias.json";
+
+/// The user entries in the JSON files of `server_specific.data_dir`
+/// Erasing also drops the user from the running services, so they stop being targeted right away
+pub struct ServerUserDataStore {
+    data_dir: PathBuf,
+    nickname_changer_service: Option<Arc<NicknameChangerService>>,
+    kick_service: Option<Arc<KickService>>,
+    voice_interaction_service: Option<Arc<VoiceInteractionService>>,
+    alias_service: Option<Arc<AliasService>>,
+}
+
+impl ServerUserDataStore {
+    pub fn new(
+        data_dir: PathBuf,
+        nickname_changer_service: Option<Arc<NicknameChangerService>>,
+        kick_service: Option<Arc<KickService>>,
+        voice_interaction_service: Option<Arc<VoiceInteractionService>>,
+        alias_service: Option<Arc<AliasService>>,
+    ) -> Self {
+        Self {
+            data_dir,
+            nickname_changer_service,
+            kick_service,
+            voice_interaction_service,
+            alias_service,
+        }
+    }
+
+    /// Entries of `user_id` in every file that exists, keyed by what they are
+    fn read_entries(&self, user_id: u64) -> Result<Map<String, Value>, Box<dyn std::error::Error>> {
+        let data_dir = self.data_dir.as_path();
+        let mut entries = Map::new();
+
+        if data_dir.join("nickname_targets.json").exists()
+            && let Some(target) = NicknameConfig::load(data_dir)?.find_target(user_id)
+        {
+            entries.insert("nickname_target".into(), serde_json::to_value(target)?);
+        }
+        if data_dir.join("kick_targets.json").exists()
+            && let Some(target) = KickConfig::load(data_dir)?.find_target(user_id)
+        {
+            entries.insert("kick_target".into(), serde_json::to_value(target)?);
+        }
+        if data_dir.join("interaction_permission.json").exists()
+            && let Some(target) = VoiceInteractionConfig::load(data_dir)?.find_target(user_id)
+        {
+            entries.insert("voice_permission".into(), serde_json::to_value(target)?);
+        }
+        if data_dir.join(ALIAS_FILE).exists()
+            && let Some(user) =
+                AliasConfig::load(data_dir.join(ALIAS_FILE))?.find_user_by_id(user_id)
+        {
+            entries.insert("aliases".into(), serde_json::to_value(user)?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Rewrite every file that mentions `user_id` without them, returns how many files changed
+    fn erase_entries(&self, user_id: u64) -> Result<usize, Box<dyn std::error::Error>> {
+        let data_dir = self.data_dir.as_path();
+        let mut removed = 0;
+
+        if data_dir.join("nickname_targets.json").exists() {
+            let mut config = NicknameConfig::load(data_dir)?;
+            if retain_others(&mut config.targets, |target| target.user_id, user_id) {
+                config.save(data_dir)?;
+                removed += 1;
+            }
+        }
+        if data_dir.join("kick_targets.json").exists() {
+            let mut config = KickConfig::load(data_dir)?;
+            if retain_others(&mut config.targets, |target| target.user_id, user_id) {
+                config.save(data_dir)?;
+                removed += 1;
+            }
+        }
+        if data_dir.join("interaction_permission.json").exists() {
+            let mut config = VoiceInteractionConfig::load(data_dir)?;
+            if retain_others(&mut config.targets, |target| target.user_id, user_id) {
+                config.save(data_dir)?;
+                removed += 1;
+            }
+        }
+        // AliasService rewrites its own file, this covers the case where it isn't running
+        let alias_path = data_dir.join(ALIAS_FILE);
+        if alias_path.exists() {
+            let mut config = AliasConfig::load(&alias_path)?;
+            if retain_others(&mut config.users, |user| user.user_id, user_id) {
+                config.save(&alias_path)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+/// Drop the entries of `user_id`, returns whether there were any
+fn retain_others<T>(entries: &mut Vec<T>, id: impl Fn(&T) -> u64, user_id: u64) -> bool {
+    let original_len = entries.len();
+    entries.retain(|entry| id(entry) != user_id);
+    entries.len() < original_len
+}
+
+#[async_trait]
+impl PersonalDataStore for ServerUserDataStore {
+    fn name(&self) -> &'static str {
+        "server_specific"
+    }
+
+    async fn export_user(&self, user_id: u64) -> Result<Option<Value>, String> {
+        let entries = self
+            .read_entries(user_id)
+            .map_err(|e| format!("Failed to read server-specific data: {}", e))?;
+
+        Ok((!entries.is_empty()).then_some(Value::Object(entries)))
+    }
+
+    async fn erase_user(&self, user_id: u64) -> Result<usize, String> {
+        if let Some(service) = &self.nickname_changer_service {
+            service.forget_target(user_id);
+        }
+        if let Some(service) = &self.kick_service {
+            service.forget_target(user_id);
+        }
+        if let Some(service) = &self.voice_interaction_service {
+            service.forget_target(user_id);
+        }
+        let alias_removed = match &self.alias_service {
+            Some(service) => service.forget_user(user_id).await,
+            None => false,
+        };
+
+        let files_removed = self
+            .erase_entries(user_id)
+            .map_err(|e| format!("Failed to erase server-specific data: {}", e))?;
+
+        // The alias file was already rewritten by the service when it had the user
+        Ok(files_removed + usize::from(alias_removed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn exports_and_erases_only_that_user() {
+        let dir = std::env::temp_dir().join(format!("daily-user-data-{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let target = |user_id: u64| json!({ "user_id": user_id, "display_name": "x", "kick_probability": null, "last_kick_time": null });
+        std::fs::write(
+            dir.join("kick_targets.json"),
+            json!({
+                "enabled": true,
+                "random_config": { "check_interval_minutes": 1, "kick_probability": 0.0, "min_minutes_between_kicks": 1 },
+                "targets": [target(1), target(2)],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join(ALIAS_FILE),
+            json!({ "users": [{ "user_id": 1, "discord_username": "one", "alias": ["uno"] }] })
+                .to_string(),
+        )
+        .unwrap();
+
+        let store = ServerUserDataStore::new(dir.clone(), None, None, None, None);
+
+        let exported = store.export_user(1).await.unwrap().unwrap();
+        assert_eq!(exported["kick_target"]["user_id"], 1);
+        assert_eq!(exported["aliases"]["alias"], json!(["uno"]));
+
+        assert_eq!(store.erase_user(1).await.unwrap(), 2);
+        assert!(store.export_user(1).await.unwrap().is_none());
+        assert!(store.export_user(2).await.unwrap().is_some());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -3,34 +3,51 @@ use rand::Rng;
 use serenity::all::{ChannelId, GuildId, UserId};
 use serenity::http::Http;
 use songbird::{Songbird, input::File};
-use std::sync::Arc;
-use std::{fs, path::Path};
+use std::sync::{Arc, RwLock};
+use std::fs;
+use std::path::PathBuf;
 use tokio::time::{Duration, sleep};
 use tracing::{info, warn};
 
 pub struct VoiceInteractionService {
-    config: VoiceInteractionConfig,
+    config: RwLock<VoiceInteractionConfig>,
     http: Arc<Http>,
     songbird: Arc<Songbird>,
+    /// Directory the random sounds are picked from
+    sounds_dir: PathBuf,
 }
 
 impl VoiceInteractionService {
-    pub fn new(config: VoiceInteractionConfig, http: Arc<Http>, songbird: Arc<Songbird>) -> Self {
+    pub fn new(
+        config: VoiceInteractionConfig,
+        sounds_dir: PathBuf,
+        http: Arc<Http>,
+        songbird: Arc<Songbird>,
+    ) -> Self {
         Self {
-            config,
+            config: RwLock::new(config),
             http,
             songbird,
+            sounds_dir,
         }
     }
 
     /// Check if user has permission to use voice interaction commands
     pub fn has_permission(&self, user_id: u64) -> bool {
-        self.config.is_user_allowed(user_id)
+        self.config.read().unwrap().is_user_allowed(user_id)
     }
 
     /// Check if user has permission to request kicks
     pub fn can_kick(&self, user_id: u64) -> bool {
-        self.config.can_user_kick(user_id)
+        self.config.read().unwrap().can_user_kick(user_id)
+    }
+
+    /// Revoke a user's permissions, returns whether they had any
+    pub fn forget_target(&self, user_id: u64) -> bool {
+        let mut config = self.config.write().unwrap();
+        let original_len = config.targets.len();
+        config.targets.retain(|target| target.user_id != user_id);
+        config.targets.len() < original_len
     }
 
     /// Execute voice action (mute/disconnect) on target user
@@ -105,24 +122,10 @@ impl VoiceInteractionService {
 
     /// Play a random sound from the sounds directory
     async fn play_random_sound(&self, guild_id: GuildId) -> Result<(), String> {
-        // Try multiple locations
-        let possible_paths = [
-            Path::new("data/sounds"),                              // Producción
-            Path::new("src/features/server_specific/data/sounds"), // Desarrollo
-            Path::new("./sounds"),                                 // Raíz alternativa
-        ];
-
-        let sounds_dir = possible_paths
-            .iter()
-            .find(|path| path.exists())
-            .ok_or_else(|| {
-                let paths_str = possible_paths
-                    .iter()
-                    .map(|p| format!("{:?}", p))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("No sounds directory found. Tried: {}", paths_str)
-            })?;
+        let sounds_dir = self.sounds_dir.as_path();
+        if !sounds_dir.exists() {
+            return Err(format!("Sounds directory {:?} not found", sounds_dir));
+        }
 
         info!("Using sounds directory: {:?}", sounds_dir);
 
@@ -1,14 +1,20 @@
+use crate::application::i18n::t;
+use crate::domain::value_objects::Language;
+use crate::features::server_specific::services::kick_service::KICK_POLL;
 use tracing::error;
 
-/// Extracts username from kick poll message
+/// Extracts username from kick poll message, in any of the languages polls are posted in
 pub fn extract_username_from_kick_message(message: &str) -> Option<String> {
-    let prefix = "Puedo kickear a ";
-    let suffix = "?";
+    for language in Language::ALL {
+        let Some((prefix, suffix)) = t(language, KICK_POLL).split_once("{name}") else {
+            continue;
+        };
 
-    if let Some(start) = message.find(prefix) {
-        let start_idx = start + prefix.len();
-        if let Some(end) = message.find(suffix) {
-            if end > start_idx {
+        if let Some(start) = message.find(prefix) {
+            let start_idx = start + prefix.len();
+            if let Some(end) = message.rfind(suffix)
+                && end > start_idx
+            {
                 return Some(message[start_idx..end].to_string());
             }
         }
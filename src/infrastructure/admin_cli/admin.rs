@@ -0,0 +1,550 @@
+use super::commands::{AdminCli, AdminCommand};
+use super::data_export::DataExport;
+use crate::application::services::notification_service::NotificationService;
+use crate::application::services::task_orchestrator::{ScheduleReport, TaskOrchestrator};
+use crate::application::services::task_service::TaskService;
+use crate::application::services::timezone_service::TimezoneService;
+use crate::domain::entities::scheduled_task::ScheduledTask;
+use crate::domain::repositories::{
+    TaskRepository, TaskSchedulerRepository, UserPreferencesRepository,
+};
+use crate::domain::{Clock, Recurrence, SystemClock, Task};
+use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::database::{BackupManager, DatabaseManager};
+use crate::infrastructure::notifications::RecordingNotificationSink;
+use crate::infrastructure::repositories::{
+    StorageBackend, sqlite_scheduler_repository::SqliteSchedulerRepository,
+    sqlite_task_repository::SqliteTaskRepository,
+    sqlite_user_preferences_repository::SqliteUserPreferencesRepository,
+};
+use crate::infrastructure::scheduler::priority_queue_scheduler::SHARED_POLL_SECONDS;
+use crate::infrastructure::timezone::timezone_manager::TimezoneManager;
+use chrono::{DateTime, Utc};
+use std::error::Error;
+use std::sync::Arc;
+
+/// Result of `daily-admin check`
+#[derive(Debug, Clone, Default)]
+pub struct CheckReport {
+    /// Problems reported by `PRAGMA integrity_check`
+    pub integrity: Vec<String>,
+    pub schedule: ScheduleReport,
+}
+
+/// What `daily-admin import` added
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub tasks: usize,
+    pub preferences: usize,
+    pub queued: usize,
+}
+
+/// The services daily-admin works through, wired the same way run_bot wires them for the bot
+pub struct AdminContext {
+    task_service: Arc<TaskService>,
+    task_orchestrator: Arc<TaskOrchestrator>,
+    user_prefs_repo: Arc<dyn UserPreferencesRepository>,
+    task_scheduler: Arc<dyn TaskSchedulerRepository>,
+    db_manager: Option<Arc<DatabaseManager>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl AdminContext {
+    pub fn new(
+        task_repo: Arc<dyn TaskRepository>,
+        user_prefs_repo: Arc<dyn UserPreferencesRepository>,
+        task_scheduler: Arc<dyn TaskSchedulerRepository>,
+        db_manager: Option<Arc<DatabaseManager>>,
+        timezone_manager: Arc<TimezoneManager>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        // Nothing is delivered from the CLI, due tasks are left to the bot's scheduler
+        let notification_service = Arc::new(NotificationService::new(Arc::new(
+            RecordingNotificationSink::new(),
+        )));
+        let timezone_service = Arc::new(TimezoneService::new(
+            user_prefs_repo.clone(),
+            timezone_manager,
+            clock.clone(),
+        ));
+        let task_service = Arc::new(TaskService::new(
+            task_repo,
+            notification_service,
+            timezone_service.clone(),
+            clock.clone(),
+        ));
+        let task_orchestrator = Arc::new(TaskOrchestrator::new(
+            task_service.clone(),
+            task_scheduler.clone(),
+            timezone_service,
+        ));
+
+        Self {
+            task_service,
+            task_orchestrator,
+            user_prefs_repo,
+            task_scheduler,
+            db_manager,
+            clock,
+        }
+    }
+
+    /// Open the configured database; like the bot at startup, this brings the schema up to date
+    pub async fn open(config: &AppConfig) -> Result<Self, Box<dyn Error>> {
+        if config.storage.backend != StorageBackend::Sqlite {
+            return Err(
+                "daily-admin needs storage.backend = \"sqlite\", the memory backend only lives inside the bot".into(),
+            );
+        }
+
+        let db_path = &config.storage.db_path;
+        if !db_path.exists() {
+            return Err(format!("Database {:?} does not exist", db_path).into());
+        }
+
+        let db_manager = Arc::new(DatabaseManager::new(db_path)?);
+        db_manager.initialize_database().await?;
+
+        let timezone_manager = Arc::new(TimezoneManager::new());
+
+        Ok(Self::new(
+            Arc::new(SqliteTaskRepository::new(db_path)?),
+            Arc::new(SqliteUserPreferencesRepository::new(db_path)?),
+            Arc::new(SqliteSchedulerRepository::new(db_path)?),
+            Some(db_manager),
+            timezone_manager,
+            Arc::new(SystemClock),
+        ))
+    }
+
+    /// Tasks ordered by ID, filtered by owner, guild and a case-insensitive text match
+    pub async fn find_tasks(
+        &self,
+        user_id: Option<u64>,
+        guild_id: Option<u64>,
+        search: Option<&str>,
+    ) -> Vec<Task> {
+        let search = search.map(str::to_lowercase);
+        let mut tasks: Vec<Task> = self
+            .task_service
+            .get_all_tasks_for_scheduling()
+            .await
+            .into_iter()
+            .filter(|task| user_id.is_none_or(|id| task.user_id == id))
+            .filter(|task| guild_id.is_none_or(|id| task.guild_id == id))
+            .filter(|task| {
+                search.as_deref().is_none_or(|needle| {
+                    task.title.to_lowercase().contains(needle)
+                        || task
+                            .description
+                            .as_deref()
+                            .is_some_and(|d| d.to_lowercase().contains(needle))
+                })
+            })
+            .collect();
+        tasks.sort_by_key(|task| task.id);
+        tasks
+    }
+
+    pub async fn queue(&self) -> Result<Vec<ScheduledTask>, String> {
+        self.task_scheduler
+            .list_pending()
+            .await
+            .map_err(|e| format!("Failed to read scheduler queue: {}", e))
+    }
+
+    pub async fn fire(&self, task_id: u64) -> Result<Task, String> {
+        self.task_orchestrator.fire_task_now(task_id).await
+    }
+
+    pub async fn reschedule(&self, task_id: u64, at: DateTime<Utc>) -> Result<Task, String> {
+        self.task_orchestrator.reschedule_task(task_id, at).await
+    }
+
+    pub async fn check(&self, repair: bool) -> Result<CheckReport, String> {
+        let integrity = match &self.db_manager {
+            Some(db_manager) => db_manager
+                .integrity_check()
+                .await
+                .map_err(|e| format!("Integrity check failed to run: {}", e))?,
+            None => Vec::new(),
+        };
+
+        let schedule = if repair {
+            self.task_orchestrator.repair_schedule().await?
+        } else {
+            self.task_orchestrator.check_schedule().await?
+        };
+
+        Ok(CheckReport {
+            integrity,
+            schedule,
+        })
+    }
+
+    pub async fn export(&self) -> Result<DataExport, String> {
+        let tasks = self.find_tasks(None, None, None).await;
+        let preferences = self
+            .user_prefs_repo
+            .list_all()
+            .await
+            .map_err(|e| format!("Failed to read preferences: {}", e))?;
+
+        let mut export = DataExport::new(tasks, preferences, self.clock.now());
+        export.archived_tasks = self.task_orchestrator.get_archived_tasks().await;
+        Ok(export)
+    }
+
+    /// Add every task (under new IDs) and upsert every preference, then queue what was added
+    pub async fn import(&self, data: DataExport) -> Result<ImportSummary, String> {
+        let mut summary = ImportSummary::default();
+
+        for task in data.tasks {
+            let original_id = task.id;
+            self.task_service
+                .task_repo
+                .add_task(task)
+                .await
+                .map_err(|e| format!("Failed to import task #{}: {}", original_id, e))?;
+            summary.tasks += 1;
+        }
+
+        for task in data.archived_tasks {
+            let original_id = task.id;
+            let task_repo = &self.task_service.task_repo;
+            let imported = match task_repo.add_task(task).await {
+                Ok(task_id) => task_repo.set_archived(task_id, true).await,
+                Err(e) => Err(e),
+            };
+            imported
+                .map_err(|e| format!("Failed to import archived task #{}: {}", original_id, e))?;
+            summary.tasks += 1;
+        }
+
+        for preferences in &data.preferences {
+            self.user_prefs_repo.save(preferences).await.map_err(|e| {
+                format!(
+                    "Failed to import preferences for user {}: {}",
+                    preferences.user_id, e
+                )
+            })?;
+            summary.preferences += 1;
+        }
+
+        summary.queued = self
+            .task_orchestrator
+            .repair_schedule()
+            .await?
+            .missing
+            .len();
+        Ok(summary)
+    }
+}
+
+/// Entry point of the daily-admin binary
+pub async fn run_admin(config: AppConfig, cli: AdminCli) -> Result<(), Box<dyn Error>> {
+    let backups = BackupManager::new(
+        &config.storage.db_path,
+        &config.backup.directory,
+        config.backup.retention(),
+    );
+
+    // Restoring replaces the database wholesale, so it runs before anything opens or migrates it
+    if let AdminCommand::Restore { snapshot } = &cli.command {
+        let snapshot = if snapshot.exists() {
+            snapshot.clone()
+        } else {
+            backups.directory().join(snapshot)
+        };
+        let safety_copy = backups.restore(&snapshot, Utc::now()).await?;
+
+        println!("Restored {:?} from {:?}", config.storage.db_path, snapshot);
+        if let Some(safety_copy) = safety_copy {
+            println!("The previous database was saved as {:?}", safety_copy.path);
+        }
+        return Ok(());
+    }
+
+    let admin = AdminContext::open(&config).await?;
+
+    match cli.command {
+        AdminCommand::Tasks {
+            user,
+            guild,
+            search,
+        } => {
+            let tasks = admin.find_tasks(user, guild, search.as_deref()).await;
+            for task in &tasks {
+                println!("{}", format_task(task));
+            }
+            println!("{} task(s)", tasks.len());
+        }
+        AdminCommand::Queue { limit } => {
+            let queue = admin.queue().await?;
+            let now = admin.clock.now();
+            for entry in queue.iter().take(limit) {
+                println!("{}", format_queue_entry(entry, now));
+            }
+            println!("{} pending entr(ies)", queue.len());
+        }
+        AdminCommand::Fire { task_id } => {
+            let task = admin.fire(task_id).await?;
+            println!(
+                "Task #{} is due now, a running bot delivers it within {} seconds",
+                task.id, SHARED_POLL_SECONDS
+            );
+        }
+        AdminCommand::Reschedule { task_id, at } => {
+            let task = admin.reschedule(task_id, at).await?;
+            println!(
+                "Task #{} rescheduled for {}, a running bot picks it up within {} seconds",
+                task.id,
+                at.to_rfc3339(),
+                SHARED_POLL_SECONDS
+            );
+        }
+        AdminCommand::Migrate => {
+            // Opening the context already ran every migration
+            println!("Schema of {:?} is up to date", config.storage.db_path);
+        }
+        AdminCommand::Check { repair } => {
+            let report = admin.check(repair).await?;
+            print_check_report(&report, repair);
+            if !repair && (!report.integrity.is_empty() || !report.schedule.is_consistent()) {
+                return Err("Check found problems".into());
+            }
+        }
+        AdminCommand::Export { output } => {
+            let json = admin.export().await?.to_json()?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, json)
+                        .map_err(|e| format!("Failed to write {:?}: {}", path, e))?;
+                    eprintln!("Exported to {:?}", path);
+                }
+                None => println!("{}", json),
+            }
+        }
+        AdminCommand::Import { file } => {
+            let content = std::fs::read_to_string(&file)
+                .map_err(|e| format!("Failed to read {:?}: {}", file, e))?;
+            let summary = admin.import(DataExport::from_json(&content)?).await?;
+            println!(
+                "Imported {} task(s) and {} preference(s), {} task(s) queued",
+                summary.tasks, summary.preferences, summary.queued
+            );
+        }
+        AdminCommand::Backup => {
+            let snapshot = backups.create_snapshot(admin.clock.now()).await?;
+            println!("Snapshot written to {:?}", snapshot.path);
+            for removed in backups.prune()? {
+                println!("Pruned {:?}", removed.path);
+            }
+        }
+        AdminCommand::Backups => {
+            let snapshots = backups.list_snapshots()?;
+            for snapshot in &snapshots {
+                let size = std::fs::metadata(&snapshot.path)
+                    .map(|metadata| metadata.len())
+                    .unwrap_or(0);
+                println!(
+                    "{}  {:>10} bytes  {:?}",
+                    snapshot.taken_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                    size,
+                    snapshot.path
+                );
+            }
+            println!(
+                "{} snapshot(s) in {:?}",
+                snapshots.len(),
+                backups.directory()
+            );
+        }
+        AdminCommand::Restore { .. } => unreachable!("handled before opening the database"),
+    }
+
+    Ok(())
+}
+
+fn format_task(task: &Task) -> String {
+    let when = task
+        .scheduled_time
+        .map(|time| time.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| "unscheduled".to_string());
+    let recurrence = match &task.recurrence {
+        Some(Recurrence::Weekly { .. }) => "weekly",
+        Some(Recurrence::EveryXDays { .. }) => "every x days",
+        None => "once",
+    };
+
+    format!(
+        "#{:<5} user {:<20} guild {:<20} {:<20} {:<12} {:?} \"{}\"",
+        task.id,
+        task.user_id,
+        task.guild_id,
+        when,
+        recurrence,
+        task.notification_method,
+        task.title
+    )
+}
+
+fn format_queue_entry(entry: &ScheduledTask, now: DateTime<Utc>) -> String {
+    let status = if entry.scheduled_time <= now {
+        "due".to_string()
+    } else {
+        format!("in {}m", (entry.scheduled_time - now).num_minutes())
+    };
+
+    format!(
+        "#{:<5} {} ({:<8}) user {:<20} guild {:<20} {:?}{} \"{}\"",
+        entry.task_id,
+        entry.scheduled_time.format("%Y-%m-%d %H:%M:%S UTC"),
+        status,
+        entry.user_id,
+        entry.guild_id,
+        entry.notification_method,
+        if entry.is_recurring { " recurring" } else { "" },
+        entry.title
+    )
+}
+
+fn print_check_report(report: &CheckReport, repaired: bool) {
+    if report.integrity.is_empty() {
+        println!("Database integrity: ok");
+    } else {
+        println!("Database integrity problems:");
+        for problem in &report.integrity {
+            println!("  {}", problem);
+        }
+    }
+
+    let schedule = &report.schedule;
+    if schedule.is_consistent() {
+        println!("Scheduler queue matches the tasks");
+        return;
+    }
+
+    let action =
+        |checked: &'static str, fixed: &'static str| if repaired { fixed } else { checked };
+    println!(
+        "Tasks {} the queue: {:?}",
+        action("missing from", "re-added to"),
+        schedule.missing
+    );
+    println!(
+        "Tasks queued at the wrong time{}: {:?}",
+        action("", " (re-queued)"),
+        schedule.mismatched
+    );
+    println!(
+        "Queue entries without a task{}: {:?}",
+        action("", " (dropped)"),
+        schedule.orphaned
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::ManualClock;
+    use crate::domain::entities::user_preferences::UserPreferences;
+    use crate::infrastructure::repositories::{
+        MemorySchedulerRepository, MemoryTaskRepository, MemoryUserPreferencesRepository,
+    };
+    use chrono::{Duration, TimeZone};
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap()
+    }
+
+    fn admin(clock: Arc<ManualClock>) -> (AdminContext, Arc<MemorySchedulerRepository>) {
+        let scheduler = Arc::new(MemorySchedulerRepository::new());
+        let admin = AdminContext::new(
+            Arc::new(MemoryTaskRepository::new()),
+            Arc::new(MemoryUserPreferencesRepository::new()),
+            scheduler.clone(),
+            None,
+            Arc::new(TimezoneManager::new()),
+            clock,
+        );
+        (admin, scheduler)
+    }
+
+    #[tokio::test]
+    async fn check_repairs_the_queue_and_fire_makes_a_task_due() {
+        let clock = Arc::new(ManualClock::new(start()));
+        let (admin, scheduler) = admin(clock.clone());
+
+        // Added behind the orchestrator's back, so the queue doesn't know about it
+        let later = start() + Duration::days(2);
+        let task_id = admin
+            .task_service
+            .task_repo
+            .add_task(Task::for_test(1, "Standup").scheduled_at(later))
+            .await
+            .unwrap();
+
+        let report = admin.check(false).await.unwrap();
+        assert_eq!(report.schedule.missing, vec![task_id]);
+        assert!(scheduler.list_pending().await.unwrap().is_empty());
+
+        admin.check(true).await.unwrap();
+        assert!(admin.check(false).await.unwrap().schedule.is_consistent());
+
+        clock.advance(Duration::minutes(5));
+        admin.fire(task_id).await.unwrap();
+        let queue = admin.queue().await.unwrap();
+        assert_eq!(queue[0].scheduled_time, clock.now());
+        assert!(admin.check(false).await.unwrap().schedule.is_consistent());
+
+        assert!(admin.fire(99).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn export_then_import_copies_tasks_and_preferences() {
+        let clock = Arc::new(ManualClock::new(start()));
+        let (source, _) = admin(clock.clone());
+        for (user_id, title) in [(1, "Gym"), (2, "Read"), (1, "Groceries")] {
+            source
+                .task_service
+                .task_repo
+                .add_task(Task::for_test(user_id, title).scheduled_at(start() + Duration::hours(1)))
+                .await
+                .unwrap();
+        }
+        source
+            .user_prefs_repo
+            .save(&UserPreferences::new_with_format(
+                1,
+                "Europe/Madrid".to_string(),
+                Some("DMY".to_string()),
+                start(),
+            ))
+            .await
+            .unwrap();
+
+        let found = source.find_tasks(Some(1), None, Some("GYM")).await;
+        assert_eq!(found.len(), 1);
+
+        let json = source.export().await.unwrap().to_json().unwrap();
+
+        let (target, scheduler) = admin(clock);
+        let summary = target
+            .import(DataExport::from_json(&json).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                tasks: 3,
+                preferences: 1,
+                queued: 3,
+            }
+        );
+        assert_eq!(scheduler.pending_count().await.unwrap(), 3);
+        assert_eq!(target.find_tasks(Some(1), None, None).await.len(), 2);
+        let prefs = target.user_prefs_repo.get(1).await.unwrap().unwrap();
+        assert_eq!(prefs.timezone, "Europe/Madrid");
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// Offline maintenance for the bot database, reads the same config.toml and overrides as the bot
+#[derive(Debug, Parser)]
+#[command(name = "daily-admin", version, about)]
+pub struct AdminCli {
+    #[command(subcommand)]
+    pub command: AdminCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AdminCommand {
+    /// List tasks, optionally filtered by owner, guild or text
+    Tasks {
+        #[arg(long)]
+        user: Option<u64>,
+        #[arg(long)]
+        guild: Option<u64>,
+        /// Case-insensitive match on title and description
+        #[arg(long)]
+        search: Option<String>,
+    },
+    /// Show the scheduler queue in delivery order
+    Queue {
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Make a task due now, a running bot delivers it within 30 seconds
+    Fire { task_id: u64 },
+    /// Move a task to a new time, e.g. 2025-01-06T09:00:00Z
+    Reschedule { task_id: u64, at: DateTime<Utc> },
+    /// Bring the database schema up to date
+    Migrate,
+    /// Run SQLite's integrity check and compare the scheduler queue with the tasks
+    Check {
+        /// Re-queue missing tasks and drop orphaned queue entries
+        #[arg(long)]
+        repair: bool,
+    },
+    /// Write every task and preference as JSON (stdout when no file is given)
+    Export {
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Add the tasks and preferences from an export file, tasks get new IDs
+    Import { file: PathBuf },
+    /// Snapshot the database into the backup directory now, then prune old snapshots
+    Backup,
+    /// List the snapshots in the backup directory, newest first
+    Backups,
+    /// Replace the database with a snapshot (stop the bot first), the current one is snapshotted
+    Restore {
+        /// A snapshot path, or a file name inside the backup directory
+        snapshot: PathBuf,
+    },
+}
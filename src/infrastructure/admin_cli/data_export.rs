@@ -0,0 +1,52 @@
+use crate::domain::Task;
+use crate::domain::entities::user_preferences::UserPreferences;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Bumped when the layout changes so old files can be told apart
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Everything daily-admin exports and imports: tasks plus the preferences needed to read them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataExport {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub tasks: Vec<Task>,
+    // Tasks paused while the bot is out of their guild, imported archived again
+    #[serde(default)]
+    pub archived_tasks: Vec<Task>,
+    pub preferences: Vec<UserPreferences>,
+}
+
+impl DataExport {
+    pub fn new(
+        tasks: Vec<Task>,
+        preferences: Vec<UserPreferences>,
+        exported_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            format_version: EXPORT_FORMAT_VERSION,
+            exported_at,
+            tasks,
+            archived_tasks: Vec::new(),
+            preferences,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize export: {}", e))
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        let export: Self =
+            serde_json::from_str(content).map_err(|e| format!("Invalid export file: {}", e))?;
+
+        if export.format_version != EXPORT_FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported export format version {} (expected {})",
+                export.format_version, EXPORT_FORMAT_VERSION
+            ));
+        }
+        Ok(export)
+    }
+}
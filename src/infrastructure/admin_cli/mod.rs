@@ -0,0 +1,7 @@
+pub mod admin;
+pub mod commands;
+pub mod data_export;
+
+pub use admin::{AdminContext, run_admin};
+pub use commands::{AdminCli, AdminCommand};
+pub use data_export::DataExport;
@@ -0,0 +1,637 @@
+use crate::features::server_specific::config::{Feature, ServerConfig};
+use crate::domain::value_objects::{OrphanedTaskPolicies, OrphanedTaskPolicy, TaskLimits};
+use crate::infrastructure::database::BackupRetention;
+use crate::infrastructure::repositories::StorageBackend;
+use serde::Deserialize;
+use std::fmt::Display;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use tracing::info;
+
+/// Config file used when DAILY_CONFIG is not set, running without it means running on defaults
+const DEFAULT_CONFIG_PATH: &str = "./config.toml";
+
+/// Runtime configuration: a TOML file, then environment overrides, validated before the bot starts
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    pub bot: BotConfig,
+    pub storage: StorageConfig,
+    pub observability: ObservabilityConfig,
+    pub backup: BackupConfig,
+    pub limits: LimitsConfig,
+    pub lifecycle: LifecycleConfig,
+    /// Features for a single personal server, disabled when the section is missing
+    pub server_specific: Option<ServerSpecificConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BotConfig {
+    /// Guild where /stats is registered (nowhere when unset)
+    pub stats_guild_id: Option<u64>,
+    /// Only user allowed to run /stats
+    pub creator_id: Option<u64>,
+    /// How long /add_task keeps its options while the user fills the modal
+    pub modal_ttl_secs: u64,
+    /// Scheduler lease owner, generated per process when unset
+    pub instance_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    pub db_path: PathBuf,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ObservabilityConfig {
+    /// Serve /healthz and /metrics here, e.g. "127.0.0.1:9100" (disabled when unset)
+    pub metrics_addr: Option<SocketAddr>,
+}
+
+/// Scheduled snapshots of the SQLite database, also used by `daily-admin backup` and `restore`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BackupConfig {
+    /// Run the backup job inside the bot
+    pub enabled: bool,
+    pub directory: PathBuf,
+    pub interval_hours: u64,
+    /// Newest snapshot of each of the last N days
+    pub keep_daily: usize,
+    /// Newest snapshot of each of the last N ISO weeks
+    pub keep_weekly: usize,
+}
+
+/// Task quotas and the interaction rate limit, to keep a single user or guild from flooding the bot
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    pub max_tasks_per_user: usize,
+    pub max_tasks_per_guild: usize,
+    pub max_recurring_tasks_per_user: usize,
+    /// Shortest allowed gap between two occurrences of a recurring task
+    pub min_recurrence_interval_hours: u32,
+    /// Commands and button clicks a user can make per minute on average
+    pub interactions_per_minute: u32,
+    /// Interactions allowed in a quick burst before the per-minute rate applies
+    pub interaction_burst: u32,
+}
+
+/// What happens to tasks whose guild, channel or owner membership goes away
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LifecycleConfig {
+    /// The bot was kicked from the guild or the guild was deleted
+    pub on_guild_removed: OrphanedTaskPolicy,
+    /// The task owner left the guild
+    pub on_member_left: OrphanedTaskPolicy,
+    /// The task's notification channel was deleted
+    pub on_channel_deleted: OrphanedTaskPolicy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerSpecificConfig {
+    pub guild_id: u64,
+    /// Channel for kick polls and nickname announcements
+    pub general_channel_id: u64,
+    pub enabled_features: Vec<Feature>,
+    /// Member that mentions can't mute, disconnect or kick
+    pub protected_user_id: Option<u64>,
+    /// Directory holding the targets, nicknames, permissions and alias JSON files
+    pub data_dir: PathBuf,
+    pub sounds_dir: PathBuf,
+}
+
+impl Default for BotConfig {
+    fn default() -> Self {
+        Self {
+            stats_guild_id: None,
+            creator_id: None,
+            modal_ttl_secs: 300,
+            instance_id: None,
+        }
+    }
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: StorageBackend::default(),
+            db_path: PathBuf::from("./data/bot.db"),
+        }
+    }
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: PathBuf::from("./data/backups"),
+            interval_hours: 24,
+            keep_daily: 7,
+            keep_weekly: 4,
+        }
+    }
+}
+
+impl BackupConfig {
+    pub fn retention(&self) -> BackupRetention {
+        BackupRetention {
+            keep_daily: self.keep_daily,
+            keep_weekly: self.keep_weekly,
+        }
+    }
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        let tasks = TaskLimits::default();
+        Self {
+            max_tasks_per_user: tasks.max_tasks_per_user,
+            max_tasks_per_guild: tasks.max_tasks_per_guild,
+            max_recurring_tasks_per_user: tasks.max_recurring_tasks_per_user,
+            min_recurrence_interval_hours: tasks.min_recurrence_interval.num_hours() as u32,
+            interactions_per_minute: 20,
+            interaction_burst: 5,
+        }
+    }
+}
+
+impl LimitsConfig {
+    pub fn task_limits(&self) -> TaskLimits {
+        TaskLimits {
+            max_tasks_per_user: self.max_tasks_per_user,
+            max_tasks_per_guild: self.max_tasks_per_guild,
+            max_recurring_tasks_per_user: self.max_recurring_tasks_per_user,
+            min_recurrence_interval: chrono::Duration::hours(i64::from(
+                self.min_recurrence_interval_hours,
+            )),
+        }
+    }
+}
+
+impl Default for LifecycleConfig {
+    fn default() -> Self {
+        let policies = OrphanedTaskPolicies::default();
+        Self {
+            on_guild_removed: policies.guild_removed,
+            on_member_left: policies.member_left,
+            on_channel_deleted: policies.channel_deleted,
+        }
+    }
+}
+
+impl LifecycleConfig {
+    pub fn policies(&self) -> OrphanedTaskPolicies {
+        OrphanedTaskPolicies {
+            guild_removed: self.on_guild_removed,
+            member_left: self.on_member_left,
+            channel_deleted: self.on_channel_deleted,
+        }
+    }
+}
+
+impl Default for ServerSpecificConfig {
+    fn default() -> Self {
+        Self {
+            guild_id: 0,
+            general_channel_id: 0,
+            enabled_features: ServerConfig::default().enabled_features,
+            protected_user_id: None,
+            data_dir: PathBuf::from("./data/server_specific"),
+            sounds_dir: PathBuf::from("./data/sounds"),
+        }
+    }
+}
+
+impl ServerSpecificConfig {
+    pub fn server_config(&self) -> ServerConfig {
+        ServerConfig {
+            server_id: self.guild_id,
+            general_channel_id: self.general_channel_id,
+            enabled_features: self.enabled_features.clone(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Read DAILY_CONFIG (or ./config.toml when it exists), apply environment overrides and validate
+    pub fn load() -> Result<Self, String> {
+        let explicit_path = std::env::var("DAILY_CONFIG").ok();
+        let path = explicit_path
+            .clone()
+            .unwrap_or_else(|| DEFAULT_CONFIG_PATH.to_string());
+
+        let mut config = match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                info!("Loading configuration from {}", path);
+                Self::from_toml(&content)
+                    .map_err(|e| format!("Invalid config file {}: {}", path, e))?
+            }
+            Err(e) if explicit_path.is_none() && e.kind() == std::io::ErrorKind::NotFound => {
+                info!("No {} found, using default configuration", path);
+                Self::default()
+            }
+            Err(e) => return Err(format!("Failed to read config file {}: {}", path, e)),
+        };
+
+        config.apply_overrides(|key| std::env::var(key).ok())?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    /// Environment variables win over the file, `lookup` is `std::env::var` outside tests
+    pub fn apply_overrides(
+        &mut self,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<(), String> {
+        let lookup = |key: &str| lookup(key).filter(|value| !value.trim().is_empty());
+
+        if let Some(value) = lookup("STORAGE_BACKEND") {
+            self.storage.backend = value.parse()?;
+        }
+        if let Some(value) = lookup("DATABASE_PATH") {
+            self.storage.db_path = PathBuf::from(value);
+        }
+        if let Some(value) = lookup("METRICS_ADDR") {
+            self.observability.metrics_addr = Some(parse("METRICS_ADDR", &value)?);
+        }
+        if let Some(value) = lookup("STATS_GUILD_ID") {
+            self.bot.stats_guild_id = Some(parse("STATS_GUILD_ID", &value)?);
+        }
+        if let Some(value) = lookup("CREATOR_ID") {
+            self.bot.creator_id = Some(parse("CREATOR_ID", &value)?);
+        }
+        if let Some(value) = lookup("MODAL_TTL_SECS") {
+            self.bot.modal_ttl_secs = parse("MODAL_TTL_SECS", &value)?;
+        }
+        if let Some(value) = lookup("INSTANCE_ID") {
+            self.bot.instance_id = Some(value);
+        }
+        if let Some(value) = lookup("BACKUP_ENABLED") {
+            self.backup.enabled = parse("BACKUP_ENABLED", &value)?;
+        }
+        if let Some(value) = lookup("BACKUP_DIR") {
+            self.backup.directory = PathBuf::from(value);
+        }
+        if let Some(value) = lookup("BACKUP_INTERVAL_HOURS") {
+            self.backup.interval_hours = parse("BACKUP_INTERVAL_HOURS", &value)?;
+        }
+        if let Some(value) = lookup("BACKUP_KEEP_DAILY") {
+            self.backup.keep_daily = parse("BACKUP_KEEP_DAILY", &value)?;
+        }
+        if let Some(value) = lookup("BACKUP_KEEP_WEEKLY") {
+            self.backup.keep_weekly = parse("BACKUP_KEEP_WEEKLY", &value)?;
+        }
+        if let Some(value) = lookup("MAX_TASKS_PER_USER") {
+            self.limits.max_tasks_per_user = parse("MAX_TASKS_PER_USER", &value)?;
+        }
+        if let Some(value) = lookup("MAX_TASKS_PER_GUILD") {
+            self.limits.max_tasks_per_guild = parse("MAX_TASKS_PER_GUILD", &value)?;
+        }
+        if let Some(value) = lookup("MAX_RECURRING_TASKS_PER_USER") {
+            self.limits.max_recurring_tasks_per_user =
+                parse("MAX_RECURRING_TASKS_PER_USER", &value)?;
+        }
+        if let Some(value) = lookup("MIN_RECURRENCE_INTERVAL_HOURS") {
+            self.limits.min_recurrence_interval_hours =
+                parse("MIN_RECURRENCE_INTERVAL_HOURS", &value)?;
+        }
+        if let Some(value) = lookup("RATE_LIMIT_PER_MINUTE") {
+            self.limits.interactions_per_minute = parse("RATE_LIMIT_PER_MINUTE", &value)?;
+        }
+        if let Some(value) = lookup("RATE_LIMIT_BURST") {
+            self.limits.interaction_burst = parse("RATE_LIMIT_BURST", &value)?;
+        }
+        if let Some(value) = lookup("TASKS_ON_GUILD_REMOVED") {
+            self.lifecycle.on_guild_removed = parse("TASKS_ON_GUILD_REMOVED", &value)?;
+        }
+        if let Some(value) = lookup("TASKS_ON_MEMBER_LEFT") {
+            self.lifecycle.on_member_left = parse("TASKS_ON_MEMBER_LEFT", &value)?;
+        }
+        if let Some(value) = lookup("TASKS_ON_CHANNEL_DELETED") {
+            self.lifecycle.on_channel_deleted = parse("TASKS_ON_CHANNEL_DELETED", &value)?;
+        }
+
+        // Setting any of these enables the server-specific section if the file didn't
+        let server_overrides = [
+            "SERVER_GUILD_ID",
+            "SERVER_GENERAL_CHANNEL_ID",
+            "PROTECTED_USER_ID",
+            "SERVER_DATA_DIR",
+            "SOUNDS_DIR",
+        ];
+        if server_overrides.iter().any(|key| lookup(key).is_some()) {
+            let server = self.server_specific.get_or_insert_with(Default::default);
+
+            if let Some(value) = lookup("SERVER_GUILD_ID") {
+                server.guild_id = parse("SERVER_GUILD_ID", &value)?;
+            }
+            if let Some(value) = lookup("SERVER_GENERAL_CHANNEL_ID") {
+                server.general_channel_id = parse("SERVER_GENERAL_CHANNEL_ID", &value)?;
+            }
+            if let Some(value) = lookup("PROTECTED_USER_ID") {
+                server.protected_user_id = Some(parse("PROTECTED_USER_ID", &value)?);
+            }
+            if let Some(value) = lookup("SERVER_DATA_DIR") {
+                server.data_dir = PathBuf::from(value);
+            }
+            if let Some(value) = lookup("SOUNDS_DIR") {
+                server.sounds_dir = PathBuf::from(value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check everything that would otherwise fail later (or panic, for zero Discord IDs), all problems at once
+    pub fn validate(&self) -> Result<(), String> {
+        let mut problems = Vec::new();
+
+        if self.bot.modal_ttl_secs == 0 {
+            problems.push("bot.modal_ttl_secs must be greater than 0".to_string());
+        }
+        check_id(&mut problems, "bot.stats_guild_id", self.bot.stats_guild_id);
+        check_id(&mut problems, "bot.creator_id", self.bot.creator_id);
+        if self.bot.stats_guild_id.is_some() && self.bot.creator_id.is_none() {
+            problems.push(
+                "bot.stats_guild_id is set but bot.creator_id is not, nobody could run /stats"
+                    .to_string(),
+            );
+        }
+        if self
+            .bot
+            .instance_id
+            .as_ref()
+            .is_some_and(|id| id.trim().is_empty())
+        {
+            problems.push("bot.instance_id must not be empty".to_string());
+        }
+
+        if self.storage.backend == StorageBackend::Sqlite
+            && self.storage.db_path.as_os_str().is_empty()
+        {
+            problems.push("storage.db_path must not be empty".to_string());
+        }
+
+        if self.backup.enabled {
+            if self.storage.backend != StorageBackend::Sqlite {
+                problems.push("backup.enabled requires storage.backend = \"sqlite\"".to_string());
+            }
+            if self.backup.interval_hours == 0 {
+                problems.push("backup.interval_hours must be greater than 0".to_string());
+            }
+            if self.backup.keep_daily == 0 && self.backup.keep_weekly == 0 {
+                problems.push(
+                    "backup.keep_daily and backup.keep_weekly are both 0, every backup would be deleted"
+                        .to_string(),
+                );
+            }
+        }
+
+        // Archived tasks come back when the bot rejoins, nothing brings back a member or a channel
+        for (name, policy) in [
+            ("lifecycle.on_member_left", self.lifecycle.on_member_left),
+            ("lifecycle.on_channel_deleted", self.lifecycle.on_channel_deleted),
+        ] {
+            if policy == OrphanedTaskPolicy::Archive {
+                problems.push(format!(
+                    "{} can't be \"archive\", only on_guild_removed can",
+                    name
+                ));
+            }
+        }
+
+        if self.limits.interactions_per_minute == 0 {
+            problems.push("limits.interactions_per_minute must be greater than 0".to_string());
+        }
+        if self.limits.interaction_burst == 0 {
+            problems.push("limits.interaction_burst must be greater than 0".to_string());
+        }
+
+        if let Some(server) = &self.server_specific {
+            check_id(
+                &mut problems,
+                "server_specific.guild_id",
+                Some(server.guild_id),
+            );
+            check_id(
+                &mut problems,
+                "server_specific.general_channel_id",
+                Some(server.general_channel_id),
+            );
+            check_id(
+                &mut problems,
+                "server_specific.protected_user_id",
+                server.protected_user_id,
+            );
+            if !server.data_dir.is_dir() {
+                problems.push(format!(
+                    "server_specific.data_dir {:?} is not a directory",
+                    server.data_dir
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Invalid configuration: {}", problems.join("; ")))
+        }
+    }
+}
+
+fn parse<T>(key: &str, value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|e| format!("Invalid {} '{}': {}", key, value, e))
+}
+
+/// Discord snowflakes are never 0 (serenity panics on it)
+fn check_id(problems: &mut Vec<String>, name: &str, id: Option<u64>) {
+    if id == Some(0) {
+        problems.push(format!("{} must be a Discord ID, got 0", name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |key| map.get(key).cloned()
+    }
+
+    #[test]
+    fn empty_file_uses_defaults() {
+        let config = AppConfig::from_toml("").unwrap();
+        assert_eq!(config.storage.backend, StorageBackend::Sqlite);
+        assert_eq!(config.storage.db_path, PathBuf::from("./data/bot.db"));
+        assert_eq!(config.bot.modal_ttl_secs, 300);
+        assert!(config.server_specific.is_none());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn file_values_are_read_and_unknown_keys_rejected() {
+        let config = AppConfig::from_toml(
+            r#"
+            [bot]
+            stats_guild_id = 10
+            creator_id = 42
+            modal_ttl_secs = 60
+
+            [storage]
+            backend = "memory"
+
+            [observability]
+            metrics_addr = "127.0.0.1:9100"
+
+            [server_specific]
+            guild_id = 10
+            general_channel_id = 777
+            enabled_features = ["Kick"]
+            data_dir = "./data/server_specific"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.bot.stats_guild_id, Some(10));
+        assert_eq!(config.bot.modal_ttl_secs, 60);
+        assert_eq!(config.storage.backend, StorageBackend::Memory);
+        assert_eq!(
+            config.observability.metrics_addr,
+            Some("127.0.0.1:9100".parse().unwrap())
+        );
+        let server = config.server_specific.as_ref().unwrap();
+        assert_eq!(server.server_config().enabled_features, vec![Feature::Kick]);
+        assert_eq!(server.sounds_dir, PathBuf::from("./data/sounds"));
+        assert!(config.validate().is_ok());
+
+        assert!(AppConfig::from_toml("[bot]\nstats_guild = 10").is_err());
+    }
+
+    #[test]
+    fn environment_overrides_the_file() {
+        let mut config =
+            AppConfig::from_toml("[bot]\ncreator_id = 1\nmodal_ttl_secs = 60").unwrap();
+        config
+            .apply_overrides(env(&[
+                ("CREATOR_ID", "42"),
+                ("STORAGE_BACKEND", "memory"),
+                ("DATABASE_PATH", "/tmp/other.db"),
+                ("MODAL_TTL_SECS", ""),
+                ("TASKS_ON_CHANNEL_DELETED", "delete"),
+            ]))
+            .unwrap();
+
+        assert_eq!(config.bot.creator_id, Some(42));
+        assert_eq!(config.bot.modal_ttl_secs, 60, "empty values are ignored");
+        assert_eq!(config.storage.backend, StorageBackend::Memory);
+        assert_eq!(config.storage.db_path, PathBuf::from("/tmp/other.db"));
+        assert_eq!(
+            config.lifecycle.on_channel_deleted,
+            OrphanedTaskPolicy::Delete
+        );
+
+        let error = config
+            .apply_overrides(env(&[("STATS_GUILD_ID", "not-a-number")]))
+            .unwrap_err();
+        assert!(error.contains("STATS_GUILD_ID"), "{}", error);
+    }
+
+    #[test]
+    fn server_overrides_enable_the_section() {
+        let mut config = AppConfig::default();
+        config
+            .apply_overrides(env(&[
+                ("SERVER_GUILD_ID", "10"),
+                ("SERVER_GENERAL_CHANNEL_ID", "777"),
+            ]))
+            .unwrap();
+
+        let server = config.server_specific.as_ref().unwrap();
+        assert_eq!((server.guild_id, server.general_channel_id), (10, 777));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validation_reports_every_problem() {
+        let config = AppConfig::from_toml(
+            r#"
+            [bot]
+            stats_guild_id = 0
+            modal_ttl_secs = 0
+
+            [storage]
+            db_path = ""
+
+            [backup]
+            enabled = true
+            interval_hours = 0
+            keep_daily = 0
+            keep_weekly = 0
+
+            [limits]
+            interaction_burst = 0
+
+            [lifecycle]
+            on_channel_deleted = "archive"
+
+            [server_specific]
+            general_channel_id = 777
+            data_dir = "./missing"
+            "#,
+        )
+        .unwrap();
+
+        let error = config.validate().unwrap_err();
+        for expected in [
+            "bot.modal_ttl_secs",
+            "bot.stats_guild_id must be a Discord ID",
+            "bot.creator_id is not",
+            "storage.db_path",
+            "backup.interval_hours",
+            "backup.keep_daily and backup.keep_weekly",
+            "limits.interaction_burst",
+            "lifecycle.on_channel_deleted can't be \"archive\"",
+            "server_specific.guild_id",
+            "server_specific.data_dir",
+        ] {
+            assert!(
+                error.contains(expected),
+                "missing '{}' in: {}",
+                expected,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn shipped_config_file_is_valid() {
+        let config = AppConfig::from_toml(include_str!("../../../config.toml")).unwrap();
+        let server = config.server_specific.as_ref().unwrap();
+        assert_eq!(server.enabled_features.len(), 3);
+        config.validate().unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod app_config;
+
+pub use app_config::{AppConfig, BotConfig, ServerSpecificConfig};
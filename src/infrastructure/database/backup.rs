@@ -0,0 +1,408 @@
+use crate::domain::Clock;
+use crate::utils::Shutdown;
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
+use rusqlite::backup::Backup;
+use rusqlite::{Connection, OpenFlags};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Snapshot files are named `bot-20250106-120000.db` after the UTC time they were taken
+const SNAPSHOT_PREFIX: &str = "bot-";
+const SNAPSHOT_EXTENSION: &str = "db";
+const SNAPSHOT_TIME_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// Copy this many pages per backup step, pausing in between so the bot's writers aren't starved
+const PAGES_PER_STEP: i32 = 256;
+const STEP_PAUSE: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Wait before trying again after a snapshot failed
+const RETRY_AFTER_MINUTES: i64 = 15;
+
+/// How many snapshots survive pruning: the newest of each of the last `keep_daily` days
+/// plus the newest of each of the last `keep_weekly` ISO weeks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackupRetention {
+    pub keep_daily: usize,
+    pub keep_weekly: usize,
+}
+
+/// A verified copy of the database in the backup directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub path: PathBuf,
+    pub taken_at: DateTime<Utc>,
+}
+
+/// Takes, lists, prunes and restores snapshots of `bot.db` through SQLite's online backup API,
+/// which copies a consistent image while other connections keep reading and writing
+pub struct BackupManager {
+    db_path: PathBuf,
+    directory: PathBuf,
+    retention: BackupRetention,
+}
+
+impl BackupManager {
+    pub fn new(
+        db_path: impl Into<PathBuf>,
+        directory: impl Into<PathBuf>,
+        retention: BackupRetention,
+    ) -> Self {
+        Self {
+            db_path: db_path.into(),
+            directory: directory.into(),
+            retention,
+        }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Copy the live database into the backup directory and verify the copy before keeping it
+    pub async fn create_snapshot(&self, now: DateTime<Utc>) -> Result<Snapshot> {
+        let db_path = self.db_path.clone();
+        let path = self.directory.join(snapshot_file_name(now));
+        let partial = path.with_extension("partial");
+
+        std::fs::create_dir_all(&self.directory)
+            .with_context(|| format!("Failed to create backup directory {:?}", self.directory))?;
+
+        let result = tokio::task::spawn_blocking({
+            let partial = partial.clone();
+            move || -> Result<()> {
+                let source =
+                    Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+                        .with_context(|| format!("Failed to open {:?}", db_path))?;
+                copy_database(&source, &partial)?;
+                verify_snapshot(&partial)
+            }
+        })
+        .await
+        .context("Backup task join error")?;
+
+        // Only verified copies get a snapshot name, a failed one never shows up in list_snapshots
+        if let Err(e) = result {
+            let _ = std::fs::remove_file(&partial);
+            return Err(e);
+        }
+        std::fs::rename(&partial, &path)
+            .with_context(|| format!("Failed to move snapshot into place at {:?}", path))?;
+
+        Ok(Snapshot {
+            path,
+            taken_at: parse_snapshot_time(&snapshot_file_name(now)).unwrap_or(now),
+        })
+    }
+
+    /// Snapshots in the backup directory, newest first
+    pub fn list_snapshots(&self) -> Result<Vec<Snapshot>> {
+        let entries = match std::fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to read {:?}", self.directory));
+            }
+        };
+
+        let mut snapshots: Vec<Snapshot> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let taken_at = parse_snapshot_time(entry.file_name().to_str()?)?;
+                Some(Snapshot {
+                    path: entry.path(),
+                    taken_at,
+                })
+            })
+            .collect();
+        snapshots.sort_by_key(|snapshot| std::cmp::Reverse(snapshot.taken_at));
+        Ok(snapshots)
+    }
+
+    /// Delete the snapshots the retention policy doesn't keep, returns what was removed
+    pub fn prune(&self) -> Result<Vec<Snapshot>> {
+        let snapshots = self.list_snapshots()?;
+        let keep = snapshots_to_keep(&snapshots, self.retention);
+
+        let mut removed = Vec::new();
+        for snapshot in snapshots {
+            if !keep.contains(&snapshot.path) {
+                std::fs::remove_file(&snapshot.path)
+                    .with_context(|| format!("Failed to delete {:?}", snapshot.path))?;
+                removed.push(snapshot);
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Replace the database contents with `snapshot`. The current database is snapshotted first
+    /// (returned, None when there was no database yet) so a wrong restore can be undone
+    pub async fn restore(&self, snapshot: &Path, now: DateTime<Utc>) -> Result<Option<Snapshot>> {
+        let snapshot = snapshot.to_path_buf();
+        let check = snapshot.clone();
+        tokio::task::spawn_blocking(move || verify_snapshot(&check))
+            .await
+            .context("Backup task join error")?
+            .with_context(|| format!("Refusing to restore {:?}", snapshot))?;
+
+        // The safety copy is named after `now`, it must not overwrite the snapshot being restored
+        if self.directory.join(snapshot_file_name(now)) == snapshot {
+            bail!("{:?} was taken this second, retry in a moment", snapshot);
+        }
+
+        let safety_copy = if self.db_path.exists() {
+            Some(self.create_snapshot(now).await.context(
+                "Failed to snapshot the current database before restoring, nothing was changed",
+            )?)
+        } else {
+            None
+        };
+
+        let db_path = self.db_path.clone();
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            let source = Connection::open_with_flags(&snapshot, OpenFlags::SQLITE_OPEN_READ_ONLY)
+                .with_context(|| format!("Failed to open {:?}", snapshot))?;
+            copy_database(&source, &db_path)
+        })
+        .await
+        .context("Backup task join error")??;
+
+        Ok(safety_copy)
+    }
+}
+
+/// Snapshot every `interval`, counted from the newest snapshot on disk so restarts don't reset
+/// the schedule, and prune after each one. Runs until shutdown starts
+pub async fn run_backup_job(
+    manager: Arc<BackupManager>,
+    interval: chrono::Duration,
+    clock: Arc<dyn Clock>,
+    shutdown: Shutdown,
+) {
+    info!(
+        "Backups enabled: every {}h into {:?}",
+        interval.num_hours(),
+        manager.directory()
+    );
+
+    loop {
+        let newest = match manager.list_snapshots() {
+            Ok(snapshots) => snapshots.first().map(|snapshot| snapshot.taken_at),
+            Err(e) => {
+                error!("Failed to list backups: {:#}", e);
+                None
+            }
+        };
+        let due = newest.map_or_else(|| clock.now(), |taken_at| taken_at + interval);
+
+        tokio::select! {
+            _ = clock.sleep_until(due) => {}
+            _ = shutdown.draining() => break,
+        }
+
+        match manager.create_snapshot(clock.now()).await {
+            Ok(snapshot) => {
+                info!("Backup written to {:?}", snapshot.path);
+                match manager.prune() {
+                    Ok(removed) if !removed.is_empty() => {
+                        info!("Pruned {} old backup(s)", removed.len())
+                    }
+                    Ok(_) => {}
+                    Err(e) => error!("Failed to prune backups: {:#}", e),
+                }
+            }
+            Err(e) => {
+                error!(
+                    "Backup failed: {:#}, retrying in {} minutes",
+                    e, RETRY_AFTER_MINUTES
+                );
+                let retry_at = clock.now() + chrono::Duration::minutes(RETRY_AFTER_MINUTES);
+                tokio::select! {
+                    _ = clock.sleep_until(retry_at) => {}
+                    _ = shutdown.draining() => break,
+                }
+            }
+        }
+    }
+
+    info!("Backup job stopped");
+}
+
+/// Snapshots kept by `retention`, given newest first
+pub fn snapshots_to_keep(snapshots: &[Snapshot], retention: BackupRetention) -> HashSet<PathBuf> {
+    let mut keep = HashSet::new();
+    let mut days = HashSet::new();
+    let mut weeks = HashSet::new();
+
+    for snapshot in snapshots {
+        let day = snapshot.taken_at.date_naive();
+        if days.len() < retention.keep_daily && days.insert(day) {
+            keep.insert(snapshot.path.clone());
+        }
+
+        let week = snapshot.taken_at.iso_week();
+        if weeks.len() < retention.keep_weekly && weeks.insert((week.year(), week.week())) {
+            keep.insert(snapshot.path.clone());
+        }
+    }
+    keep
+}
+
+fn snapshot_file_name(taken_at: DateTime<Utc>) -> String {
+    format!(
+        "{}{}.{}",
+        SNAPSHOT_PREFIX,
+        taken_at.format(SNAPSHOT_TIME_FORMAT),
+        SNAPSHOT_EXTENSION
+    )
+}
+
+fn parse_snapshot_time(file_name: &str) -> Option<DateTime<Utc>> {
+    let stamp = file_name
+        .strip_prefix(SNAPSHOT_PREFIX)?
+        .strip_suffix(SNAPSHOT_EXTENSION)?
+        .strip_suffix('.')?;
+    NaiveDateTime::parse_from_str(stamp, SNAPSHOT_TIME_FORMAT)
+        .ok()
+        .map(|time| time.and_utc())
+}
+
+/// Online backup of `source` into the file at `destination`, replacing its contents
+fn copy_database(source: &Connection, destination: &Path) -> Result<()> {
+    let mut target = Connection::open(destination)
+        .with_context(|| format!("Failed to open {:?}", destination))?;
+    let backup = Backup::new(source, &mut target).context("Failed to start backup")?;
+    backup
+        .run_to_completion(PAGES_PER_STEP, STEP_PAUSE, None)
+        .with_context(|| format!("Backup into {:?} failed", destination))
+}
+
+/// A snapshot is usable when SQLite opens it and its integrity check passes
+fn verify_snapshot(path: &Path) -> Result<()> {
+    let connection = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("Failed to open {:?}", path))?;
+    let result: String = connection
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+        .with_context(|| format!("{:?} is not a readable SQLite database", path))?;
+
+    if result != "ok" {
+        bail!("integrity check of {:?} failed: {}", path, result);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, day, hour, 0, 0).unwrap()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("daily-{}-{}", name, rand::random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn titles(db_path: &Path) -> Vec<String> {
+        let connection = Connection::open(db_path).unwrap();
+        let mut statement = connection
+            .prepare("SELECT title FROM tasks ORDER BY id")
+            .unwrap();
+        statement
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn snapshots_are_verified_and_restore_the_database() {
+        let dir = temp_dir("backup");
+        let db_path = dir.join("bot.db");
+        let live = Connection::open(&db_path).unwrap();
+        live.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             CREATE TABLE tasks (id INTEGER PRIMARY KEY, title TEXT NOT NULL);
+             INSERT INTO tasks (title) VALUES ('gym');",
+        )
+        .unwrap();
+
+        let manager = BackupManager::new(
+            &db_path,
+            dir.join("backups"),
+            BackupRetention {
+                keep_daily: 7,
+                keep_weekly: 4,
+            },
+        );
+
+        // Taken while the connection above is still open
+        let snapshot = manager.create_snapshot(at(6, 12)).await.unwrap();
+        assert_eq!(snapshot.taken_at, at(6, 12));
+        assert_eq!(manager.list_snapshots().unwrap(), vec![snapshot.clone()]);
+
+        live.execute("INSERT INTO tasks (title) VALUES ('read')", [])
+            .unwrap();
+        drop(live);
+
+        let safety = manager
+            .restore(&snapshot.path, at(6, 13))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(titles(&db_path), vec!["gym"]);
+        assert_eq!(titles(&safety.path), vec!["gym", "read"]);
+
+        // Garbage is refused before anything is touched
+        let garbage = dir.join("backups").join("bot-20250107-000000.db");
+        std::fs::write(&garbage, b"not a database").unwrap();
+        assert!(manager.restore(&garbage, at(7, 1)).await.is_err());
+        assert_eq!(titles(&db_path), vec!["gym"]);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn retention_keeps_newest_per_day_and_week() {
+        // Four snapshots a day from Wed 1 Jan to Sun 19 Jan 2025, newest first
+        let mut snapshots: Vec<Snapshot> = (1..=19)
+            .flat_map(|day| [0, 6, 12, 18].map(|hour| at(day, hour)))
+            .map(|taken_at| Snapshot {
+                path: PathBuf::from(snapshot_file_name(taken_at)),
+                taken_at,
+            })
+            .collect();
+        snapshots.reverse();
+
+        let keep = snapshots_to_keep(
+            &snapshots,
+            BackupRetention {
+                keep_daily: 3,
+                keep_weekly: 3,
+            },
+        );
+
+        let mut kept: Vec<DateTime<Utc>> = snapshots
+            .iter()
+            .filter(|snapshot| keep.contains(&snapshot.path))
+            .map(|snapshot| snapshot.taken_at)
+            .collect();
+        kept.sort();
+        // Last 3 days, plus the newest of the two ISO weeks before the current one
+        assert_eq!(
+            kept,
+            vec![at(5, 18), at(12, 18), at(17, 18), at(18, 18), at(19, 18)]
+        );
+
+        assert_eq!(
+            parse_snapshot_time(&snapshot_file_name(at(6, 12) + Duration::seconds(7))),
+            Some(at(6, 12) + Duration::seconds(7))
+        );
+        assert_eq!(parse_snapshot_time("bot.db"), None);
+    }
+}
@@ -20,7 +20,8 @@ impl DatabaseManager {
         connection.execute_batch(
             "PRAGMA foreign_keys = ON; 
              PRAGMA journal_mode = WAL; 
-             PRAGMA synchronous = NORMAL;",
+             PRAGMA synchronous = NORMAL;
+             PRAGMA busy_timeout = 5000;",
         )?;
 
         Ok(Self {
@@ -82,6 +83,46 @@ impl DatabaseManager {
     }
 }
 
+impl DatabaseManager {
+    /// Write a timestamp to a small bookkeeping table, fails if the file is read-only or locked for too long
+    pub async fn check_writable(&self) -> Result<()> {
+        self.execute_blocking(|connection| {
+            connection.execute_batch(
+                "CREATE TABLE IF NOT EXISTS health_check (id INTEGER PRIMARY KEY, checked_at INTEGER NOT NULL);
+                 INSERT OR REPLACE INTO health_check (id, checked_at) VALUES (1, strftime('%s', 'now'));",
+            )
+        })
+        .await
+    }
+
+    /// Run SQLite's own consistency check, returns the problems it found (empty when the file is sound)
+    pub async fn integrity_check(&self) -> Result<Vec<String>> {
+        let messages = self
+            .execute_blocking(|connection| {
+                let mut statement = connection.prepare("PRAGMA integrity_check")?;
+                let rows = statement.query_map([], |row| row.get::<_, String>(0))?;
+                rows.collect::<rusqlite::Result<Vec<String>>>()
+            })
+            .await?;
+
+        Ok(messages.into_iter().filter(|message| message != "ok").collect())
+    }
+
+    /// Fold the WAL back into the main database file, called on shutdown so nothing is left pending in bot.db-wal
+    pub async fn checkpoint(&self) -> Result<()> {
+        let busy: i64 = self
+            .execute_blocking(|connection| {
+                connection.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get(0))
+            })
+            .await?;
+
+        if busy != 0 {
+            anyhow::bail!("checkpoint blocked by another connection");
+        }
+        Ok(())
+    }
+}
+
 /// Custom result type for database operations using anyhow for error handling.
 #[allow(dead_code)]
 pub type DatabaseResult<T> = Result<T>;
@@ -1,3 +1,5 @@
+pub mod backup;
 pub mod connection;
 
+pub use backup::{BackupManager, BackupRetention, Snapshot, run_backup_job};
 pub use connection::DatabaseManager;
\ No newline at end of file
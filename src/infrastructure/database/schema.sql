@@ -10,7 +10,9 @@ CREATE TABLE IF NOT EXISTS tasks (
     recurrence_data TEXT,
     notification_method TEXT NOT NULL,
     channel_id INTEGER,
-    mention TEXT,
+    mentions TEXT, -- JSON list of mention targets
+    timezone TEXT, -- IANA zone the schedule was given in, NULL for the owner's
+    archived INTEGER NOT NULL DEFAULT 0, -- 1 while the bot is out of the task's guild
     created_at INTEGER NOT NULL DEFAULT (unixepoch()),
     updated_at INTEGER NOT NULL DEFAULT (unixepoch())
 );
@@ -19,7 +21,22 @@ CREATE TABLE IF NOT EXISTS tasks (
 CREATE TABLE IF NOT EXISTS user_preferences (
     user_id INTEGER PRIMARY KEY,
     timezone TEXT NOT NULL,
+    date_format TEXT,
     created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    working_hours TEXT, -- HH:MM-HH:MM local to the timezone, NULL for 09:00-17:00
+    date_format_chosen INTEGER NOT NULL DEFAULT 0, -- 1 when set in /preferences instead of inferred
+    clock_format TEXT, -- '12h' or '24h', NULL for 24h
+    first_weekday TEXT, -- 'Sun', 'Sat'..., NULL for Monday
+    language TEXT -- 'en' or 'es', NULL for the Discord client's language
+);
+
+-- Table for per-guild defaults managed with /server_settings
+CREATE TABLE IF NOT EXISTS guild_settings (
+    guild_id INTEGER PRIMARY KEY,
+    timezone TEXT, -- used for server tasks and members without a timezone
+    date_format TEXT,
+    language TEXT, -- language code, e.g. 'es'
     updated_at INTEGER NOT NULL
 );
 
@@ -41,8 +58,18 @@ CREATE TABLE IF NOT EXISTS scheduled_tasks (
     notification_method TEXT NOT NULL,     -- "dm" | "channel" | "both"
     is_recurring    INTEGER NOT NULL DEFAULT 0,  -- 0 = false, 1 = true
     is_deleted      INTEGER NOT NULL DEFAULT 0,  -- soft-delete flag
-    mention         TEXT
+    mention         TEXT,
+    lease_owner     TEXT,                  -- instance currently delivering this entry
+    lease_expires_at INTEGER               -- unix timestamp, another instance may reclaim after it
 );
 
 -- Index to quickly fetch the next pending (non-deleted) task
 CREATE INDEX IF NOT EXISTS idx_scheduled_tasks_time ON scheduled_tasks (is_deleted, scheduled_time);
+
+-- Occurrences already delivered (delivery_key = "task_id:scheduled_time"), so no instance sends one twice
+CREATE TABLE IF NOT EXISTS scheduled_deliveries (
+    delivery_key    TEXT PRIMARY KEY,
+    task_id         INTEGER NOT NULL,
+    delivered_by    TEXT NOT NULL,
+    delivered_at    INTEGER NOT NULL
+);
@@ -1,45 +1,67 @@
+use crate::application::i18n::tf;
 use crate::application::services::notification_service::NotificationService;
+use crate::application::services::task_lifecycle_service::TaskLifecycleService;
 use crate::application::services::task_orchestrator::TaskOrchestrator;
 use crate::application::services::task_service::TaskService;
 use crate::application::services::timezone_service::TimezoneService;
+use crate::application::services::user_data_service::UserDataService;
 use crate::domain::repositories::{
-    TaskRepository, TaskSchedulerRepository, UserPreferencesRepository,
+    GuildSettingsRepository, TaskRepository, TaskSchedulerRepository, UserPreferencesRepository,
 };
+use crate::domain::value_objects::Language;
+use crate::domain::{Clock, NotificationSink, PersonalDataStore, SystemClock};
+use crate::features::server_specific::services::ServerUserDataStore;
 use crate::features::server_specific::{
     ServerFeaturesOrchestrator, ServerInteractionHandler, initialize_specific_services,
 };
-use crate::infrastructure::database::DatabaseManager;
+use crate::infrastructure::config::{AppConfig, BotConfig};
+use crate::infrastructure::database::{BackupManager, DatabaseManager, run_backup_job};
+use crate::infrastructure::notifications::SerenityNotificationSink;
+use crate::infrastructure::observability::{ObservabilityState, health, metrics};
 use crate::infrastructure::repositories::{
+    MemoryGuildSettingsRepository, MemorySchedulerRepository, MemoryTaskRepository,
+    MemoryUserPreferencesRepository, StorageBackend,
+    sqlite_guild_settings_repository::SqliteGuildSettingsRepository,
     sqlite_scheduler_repository::SqliteSchedulerRepository,
     sqlite_task_repository::SqliteTaskRepository,
     sqlite_user_preferences_repository::SqliteUserPreferencesRepository,
 };
 use crate::infrastructure::scheduler::priority_queue_scheduler::PriorityQueueScheduler;
 use crate::infrastructure::timezone::timezone_manager::TimezoneManager;
-use crate::utils::ModalStorage;
-use serenity::all::{GuildId, Interaction, Message, Ready, ResumedEvent};
+use crate::utils::{MeetingProposal, ModalStorage, RateLimiter, Shutdown, wait_for_signal};
+use serenity::all::{
+    Cache, ConnectionStage, CreateInteractionResponse, CreateInteractionResponseMessage,
+    GuildChannel, GuildId, Interaction, Member, Message, Ready, ResumedEvent,
+    ShardStageUpdateEvent, UnavailableGuild, User,
+};
+use serenity::http::Http;
 use serenity::prelude::*;
 use songbird::SerenityInit;
 use std::sync::Arc;
-use tracing::{debug, error, info};
+use tracing::{Instrument, Span, debug, error, field, info, info_span, warn};
 
 pub struct CommandHandler {
     pub task_service: Arc<TaskService>,
     pub task_orchestrator: Arc<TaskOrchestrator>,
     pub notification_service: Arc<NotificationService>,
     pub timezone_service: Arc<TimezoneService>,
-    pub sqlite_scheduler_repo: Arc<SqliteSchedulerRepository>,
+    pub user_data_service: Arc<UserDataService>,
+    pub task_lifecycle_service: Arc<TaskLifecycleService>,
+    pub task_scheduler: Arc<dyn TaskSchedulerRepository>,
+    pub clock: Arc<dyn Clock>,
+    pub instance_id: String,
+    pub bot_config: BotConfig,
     pub server_features_orchestrator: Arc<ServerFeaturesOrchestrator>,
     pub server_interaction_handler: Arc<ServerInteractionHandler>,
     pub modal_storage: Arc<ModalStorage>,
+    pub meeting_storage: Arc<ModalStorage<MeetingProposal>>,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub shutdown: Shutdown,
 }
 
 impl CommandHandler {
     /// Register slash commands for a specific servers
-    async fn register_commands_for_guild(&self, ctx: &Context, guild_id: GuildId) {
-        // Test server ID
-        const SERVER_FOR_STATS_COMMAND: u64 = 479788664876957737;
-
+    pub(crate) async fn register_commands_for_guild(&self, http: &Http, guild_id: GuildId) {
         // Commands available for ALL servers
         let mut commands = vec![
             crate::application::commands::register_add_task_command(),
@@ -48,106 +70,193 @@ impl CommandHandler {
             crate::application::commands::register_help_command(),
             crate::application::commands::edit_task::register_edit_task_command(),
             crate::application::commands::timezone::register_timezone_command(),
+            crate::application::commands::preferences::register_preferences_command(),
+            crate::application::commands::register_my_data_command(),
+            crate::application::commands::world_clock::register_time_command(),
+            crate::application::commands::meeting_finder::register_working_hours_command(),
+            crate::application::commands::meeting_finder::register_meeting_finder_command(),
+            crate::application::commands::server_settings::register_server_settings_command(),
         ];
 
-        // Only add stats command if it's the allowed server
-        if guild_id.get() == SERVER_FOR_STATS_COMMAND {
+        // Only add stats command if it's the configured server
+        if self.bot_config.stats_guild_id == Some(guild_id.get()) {
             commands.push(crate::application::commands::register_stats_command());
         }
 
-        if let Err(e) = guild_id.set_commands(&ctx.http, commands).await {
+        if let Err(e) = guild_id.set_commands(http, commands).await {
             error!("Failed to register commands for guild {}: {}", guild_id, e);
         }
     }
-}
 
-#[serenity::async_trait]
-impl EventHandler for CommandHandler {
-    async fn ready(&self, ctx: Context, ready: Ready) {
-        info!("Bot ready as {}", ready.user.name);
-
-        for g in ready.guilds {
-            self.register_commands_for_guild(&ctx, g.id).await;
-            self.server_features_orchestrator
-                .initialize_server_features(g.id)
-                .await;
+    /// Route a task-related interaction to its command handler
+    /// Only needs the REST client and cache, so it can be driven without a gateway connection
+    pub async fn handle_interaction(&self, http: &Http, cache: &Cache, interaction: &Interaction) {
+        if let Interaction::Command(command) = interaction {
+            metrics().record_command(&command.data.name);
         }
 
-        // Load scheduled tasks on startup
-        if let Err(e) = self
-            .task_orchestrator
-            .initialize_scheduler_with_existing_tasks()
-            .await
-        {
-            error!("Failed to initialize scheduler: {}", e);
-        }
+        self.dispatch_interaction(http, cache, interaction)
+            .instrument(interaction_span(interaction))
+            .await;
+    }
 
-        // Start priority queue worker loop
-        PriorityQueueScheduler::start_scheduler(
-            Arc::new(ctx),
-            self.task_orchestrator.clone(),
-            self.notification_service.clone(),
-            self.sqlite_scheduler_repo.clone(),
-        );
+    /// Spend one of the user's rate limit tokens on a command or button click, telling them to
+    /// slow down when they have none left. Modal submits are free, the command that opened them paid
+    pub(crate) async fn admit_interaction(&self, http: &Http, interaction: &Interaction) -> bool {
+        let user_id = match interaction {
+            Interaction::Command(command) => command.user.id,
+            Interaction::Component(component) => component.user.id,
+            _ => return true,
+        };
 
-        info!("Scheduler started successfully");
-    }
+        let Err(retry_after) = self
+            .rate_limiter
+            .try_acquire(user_id.get(), std::time::Instant::now())
+        else {
+            return true;
+        };
 
-    async fn resume(&self, _ctx: Context, _resume: ResumedEvent) {
-        info!("Bot reconnected to Discord gateway");
+        debug!("Rate limited user {} for {:?}", user_id, retry_after);
+        let language = self.interaction_language(interaction).await;
+        let response = CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .content(tf(
+                    language,
+                    "⏳ You're going too fast, try again in {seconds} seconds",
+                    &[("seconds", &retry_after.as_secs().max(1))],
+                ))
+                .ephemeral(true),
+        );
+        let result = match interaction {
+            Interaction::Command(command) => command.create_response(http, response).await,
+            Interaction::Component(component) => component.create_response(http, response).await,
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            warn!("Failed to send rate limit reply: {}", e);
+        }
+        false
     }
 
-    async fn guild_create(
-        &self,
-        ctx: Context,
-        guild: serenity::model::guild::Guild,
-        is_new: Option<bool>,
-    ) {
-        if is_new.unwrap_or(false) {
-            self.register_commands_for_guild(&ctx, guild.id).await;
-            self.server_features_orchestrator
-                .initialize_server_features(guild.id)
-                .await;
-        }
+    /// Language of the replies to an interaction: the user's preference, then their Discord
+    /// client's language, then the server default
+    async fn interaction_language(&self, interaction: &Interaction) -> Language {
+        let (user_id, guild_id, locale) = match interaction {
+            Interaction::Command(command) => (command.user.id, command.guild_id, &command.locale),
+            Interaction::Component(component) => {
+                (component.user.id, component.guild_id, &component.locale)
+            }
+            Interaction::Modal(modal) => (modal.user.id, modal.guild_id, &modal.locale),
+            _ => return Language::default(),
+        };
+        self.timezone_service
+            .language_for(
+                user_id.get(),
+                guild_id.map(|id| id.get()),
+                Some(locale.as_str()),
+            )
+            .await
     }
 
-    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
-        match &interaction {
+    async fn dispatch_interaction(&self, http: &Http, cache: &Cache, interaction: &Interaction) {
+        let language = self.interaction_language(interaction).await;
+        match interaction {
             Interaction::Command(command) => match command.data.name.as_str() {
                 "add_task" => {
                     crate::application::commands::add_task::run_add_task(
-                        &ctx,
+                        http,
                         command,
                         &self.task_orchestrator,
                         &self.timezone_service,
                         &self.modal_storage,
+                        language,
                     )
                     .await;
                 }
                 "timezone" => {
                     crate::application::commands::timezone::run_timezone_command(
-                        &ctx,
+                        http,
+                        command,
+                        &self.timezone_service,
+                        language,
+                    )
+                    .await;
+                }
+                "time" => {
+                    crate::application::commands::world_clock::run_time_command(
+                        http,
+                        command,
+                        &self.timezone_service,
+                        language,
+                    )
+                    .await;
+                }
+                "preferences" => {
+                    crate::application::commands::preferences::run_preferences(
+                        http,
+                        command,
+                        &self.timezone_service,
+                        language,
+                    )
+                    .await;
+                }
+                "working_hours" => {
+                    crate::application::commands::meeting_finder::run_working_hours(
+                        http,
                         command,
                         &self.timezone_service,
+                        language,
+                    )
+                    .await;
+                }
+                "meeting_finder" => {
+                    crate::application::commands::meeting_finder::run_meeting_finder(
+                        http,
+                        command,
+                        &self.timezone_service,
+                        &self.meeting_storage,
+                        language,
+                    )
+                    .await;
+                }
+                "server_settings" => {
+                    crate::application::commands::server_settings::run_server_settings(
+                        http,
+                        command,
+                        &self.timezone_service,
+                        language,
+                    )
+                    .await;
+                }
+                "my_data" => {
+                    crate::application::commands::my_data::run_my_data(
+                        http,
+                        command,
+                        &self.user_data_service,
+                        language,
                     )
                     .await;
                 }
                 "stats" => {
                     crate::application::commands::stats::run_stats(
-                        &ctx,
+                        http,
+                        cache,
                         command,
                         &self.task_service,
+                        self.bot_config.creator_id,
+                        language,
                     )
                     .await;
                 }
                 _ => {
                     crate::application::commands::interaction_handlers::handle_command(
-                        &ctx,
-                        &interaction,
+                        http,
+                        interaction,
                         &self.task_service,
                         &self.task_orchestrator,
                         &self.notification_service,
                         &self.timezone_service,
+                        language,
                     )
                     .await;
                 }
@@ -158,29 +267,53 @@ impl EventHandler for CommandHandler {
                     component.data.custom_id
                 );
 
-                if component.data.custom_id.starts_with("kick_") {
-                    self.server_interaction_handler
-                        .handle_button_interaction(&ctx, component)
-                        .await;
-                } else {
-                    crate::application::commands::interaction_handlers::handle_component(
-                        &ctx,
-                        &interaction,
-                        &self.task_service,
+                if component
+                    .data
+                    .custom_id
+                    .starts_with(crate::application::commands::meeting_finder::MEETING_SLOT_PREFIX)
+                {
+                    crate::application::commands::meeting_finder::handle_meeting_slot(
+                        http,
+                        component,
                         &self.task_orchestrator,
                         &self.timezone_service,
+                        &self.meeting_storage,
+                        language,
                     )
                     .await;
+                    return;
                 }
+
+                if component.data.custom_id.starts_with("my_data_") {
+                    crate::application::commands::my_data::handle_my_data_component(
+                        http,
+                        component,
+                        &self.user_data_service,
+                        language,
+                    )
+                    .await;
+                    return;
+                }
+
+                crate::application::commands::interaction_handlers::handle_component(
+                    http,
+                    interaction,
+                    &self.task_service,
+                    &self.task_orchestrator,
+                    &self.timezone_service,
+                    language,
+                )
+                .await;
             }
             Interaction::Modal(_) => {
                 debug!("Received modal interaction");
                 crate::application::commands::interaction_handlers::handle_modal(
-                    &ctx,
-                    &interaction,
+                    http,
+                    interaction,
                     &self.task_orchestrator,
                     &self.timezone_service,
                     &self.modal_storage,
+                    language,
                 )
                 .await;
             }
@@ -189,11 +322,197 @@ impl EventHandler for CommandHandler {
             }
         }
     }
+}
+
+/// Span for one interaction, `task_id` is recorded by the orchestrator once a task is involved
+fn interaction_span(interaction: &Interaction) -> Span {
+    let (kind, name, user_id, guild_id) = match interaction {
+        Interaction::Command(command) => (
+            "command",
+            command.data.name.as_str(),
+            command.user.id,
+            command.guild_id,
+        ),
+        Interaction::Component(component) => (
+            "component",
+            component.data.custom_id.as_str(),
+            component.user.id,
+            component.guild_id,
+        ),
+        Interaction::Modal(modal) => (
+            "modal",
+            modal.data.custom_id.as_str(),
+            modal.user.id,
+            modal.guild_id,
+        ),
+        _ => return info_span!("interaction", interaction_id = %interaction.id()),
+    };
+
+    info_span!(
+        "interaction",
+        interaction_id = %interaction.id(),
+        kind,
+        name,
+        user_id = user_id.get(),
+        guild_id = guild_id.map(|id| id.get()),
+        task_id = field::Empty,
+    )
+}
+
+#[serenity::async_trait]
+impl EventHandler for CommandHandler {
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        info!("Bot ready as {}", ready.user.name);
+        health().set_gateway_connected(true);
+
+        for g in ready.guilds {
+            self.register_commands_for_guild(&ctx.http, g.id).await;
+            self.server_features_orchestrator
+                .initialize_server_features(g.id)
+                .await;
+        }
+
+        // Load scheduled tasks on startup
+        if let Err(e) = self
+            .task_orchestrator
+            .initialize_scheduler_with_existing_tasks()
+            .await
+        {
+            error!("Failed to initialize scheduler: {}", e);
+        }
+
+        // Start priority queue worker loop
+        PriorityQueueScheduler::start_scheduler(
+            self.task_orchestrator.clone(),
+            self.notification_service.clone(),
+            self.task_scheduler.clone(),
+            self.clock.clone(),
+            self.instance_id.clone(),
+            self.shutdown.clone(),
+        );
+
+        info!("Scheduler started successfully");
+    }
+
+    async fn resume(&self, _ctx: Context, _resume: ResumedEvent) {
+        info!("Bot reconnected to Discord gateway");
+        health().set_gateway_connected(true);
+    }
+
+    async fn shard_stage_update(&self, _ctx: Context, event: ShardStageUpdateEvent) {
+        debug!("Shard {} moved from {} to {}", event.shard_id, event.old, event.new);
+        health().set_gateway_connected(event.new == ConnectionStage::Connected);
+    }
+
+    async fn guild_create(
+        &self,
+        ctx: Context,
+        guild: serenity::model::guild::Guild,
+        is_new: Option<bool>,
+    ) {
+        if is_new.unwrap_or(false) {
+            self.register_commands_for_guild(&ctx.http, guild.id).await;
+            self.server_features_orchestrator
+                .initialize_server_features(guild.id)
+                .await;
+        }
+
+        // Tasks archived when the bot was removed from this guild resume
+        let Some(_in_flight) = self.shutdown.try_track() else {
+            return;
+        };
+        self.task_lifecycle_service
+            .on_guild_available(guild.id.get())
+            .await;
+    }
+
+    /// The bot left a guild (kicked, or the guild was deleted). Outages arrive as `unavailable`
+    /// and are ignored, the guild comes back with a `guild_create`
+    async fn guild_delete(
+        &self,
+        _ctx: Context,
+        incomplete: UnavailableGuild,
+        _full: Option<serenity::model::guild::Guild>,
+    ) {
+        if incomplete.unavailable {
+            return;
+        }
+        let Some(_in_flight) = self.shutdown.try_track() else {
+            return;
+        };
+
+        info!("Removed from guild {}", incomplete.id);
+        self.task_lifecycle_service
+            .on_guild_removed(incomplete.id.get())
+            .await;
+    }
+
+    async fn guild_member_removal(
+        &self,
+        _ctx: Context,
+        guild_id: GuildId,
+        user: User,
+        _member: Option<Member>,
+    ) {
+        let Some(_in_flight) = self.shutdown.try_track() else {
+            return;
+        };
+
+        self.task_lifecycle_service
+            .on_member_left(guild_id.get(), user.id.get())
+            .await;
+    }
+
+    async fn channel_delete(
+        &self,
+        _ctx: Context,
+        channel: GuildChannel,
+        _messages: Option<Vec<Message>>,
+    ) {
+        let Some(_in_flight) = self.shutdown.try_track() else {
+            return;
+        };
+
+        self.task_lifecycle_service
+            .on_channel_deleted(channel.id.get())
+            .await;
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let Some(_in_flight) = self.shutdown.try_track() else {
+            debug!("Ignoring interaction received during shutdown");
+            return;
+        };
+
+        let dispatch = async {
+            if !self.admit_interaction(&ctx.http, &interaction).await {
+                return;
+            }
+
+            // Kick votes belong to the server-specific features, which still need the gateway context
+            if let Interaction::Component(component) = &interaction
+                && component.data.custom_id.starts_with("kick_")
+            {
+                self.server_interaction_handler
+                    .handle_button_interaction(&ctx, component)
+                    .await;
+                return;
+            }
+
+            self.handle_interaction(&ctx.http, &ctx.cache, &interaction)
+                .await;
+        };
+
+        tokio::select! {
+            _ = dispatch => {}
+            _ = self.shutdown.aborting() => warn!("Interaction dispatch cut short by shutdown"),
+        }
+    }
 
     /// Handle message events for server-specific features
     async fn message(&self, ctx: Context, message: Message) {
-        // Ignore messages from bots
-        if message.author.bot {
+        // Ignore messages from bots, and everything once shutdown started
+        if message.author.bot || self.shutdown.is_draining() {
             return;
         }
 
@@ -204,8 +523,17 @@ impl EventHandler for CommandHandler {
     }
 }
 
+/// Repositories picked by STORAGE_BACKEND, plus the database handle when they are SQLite-backed
+type Storage = (
+    Arc<dyn TaskRepository>,
+    Arc<dyn UserPreferencesRepository>,
+    Arc<dyn GuildSettingsRepository>,
+    Arc<dyn TaskSchedulerRepository>,
+    Option<Arc<DatabaseManager>>,
+);
+
 /// Composition root: builds all repos, services, and bot handler
-pub async fn run_bot() -> Result<(), Box<dyn std::error::Error>> {
+pub async fn run_bot(config: AppConfig) -> Result<(), Box<dyn std::error::Error>> {
     let token = std::env::var("DISCORD_TOKEN").expect("Expected token in environment");
 
     let intents = GatewayIntents::GUILDS
@@ -214,38 +542,73 @@ pub async fn run_bot() -> Result<(), Box<dyn std::error::Error>> {
         | GatewayIntents::GUILD_MESSAGE_REACTIONS
         | GatewayIntents::GUILD_VOICE_STATES;
 
-    let db_path = "./data/bot.db";
+    let db_path = &config.storage.db_path;
 
-    let db_manager = Arc::new(DatabaseManager::new(db_path)?);
-    db_manager.initialize_database().await?;
-    info!("Database initialized successfully");
+    // Single time source for every service (replaced by a manual clock in tests)
+    let clock: Arc<dyn Clock> = Arc::new(SystemClock);
 
-    // SQLite repositories (all sync)
-    let task_repo: Arc<dyn TaskRepository> = Arc::new(SqliteTaskRepository::new(db_path)?);
-    let user_prefs_repo: Arc<dyn UserPreferencesRepository> =
-        Arc::new(SqliteUserPreferencesRepository::new(db_path)?);
+    // Shared by the gateway handler and the scheduler so a signal can drain both
+    let shutdown = Shutdown::new();
 
-    // Persistent task scheduler repository
-    let sqlite_scheduler_repo = Arc::new(SqliteSchedulerRepository::new(db_path)?);
-    let task_scheduler: Arc<dyn TaskSchedulerRepository> = sqlite_scheduler_repo.clone();
+    // Identifies this process when leasing scheduled tasks (several instances may share bot.db)
+    let instance_id = config.bot.instance_id.clone().unwrap_or_else(|| {
+        format!("{}-{:08x}", std::process::id(), rand::random::<u32>())
+    });
+    info!("Scheduler instance id: {}", instance_id);
+
+    let (task_repo, user_prefs_repo, guild_settings_repo, task_scheduler, db_manager): Storage =
+        match config.storage.backend {
+            StorageBackend::Sqlite => {
+                let db_manager = Arc::new(DatabaseManager::new(db_path)?);
+                db_manager.initialize_database().await?;
+                info!("Database initialized successfully");
+
+                // SQLite repositories (all sync) and persistent task scheduler repository
+                (
+                    Arc::new(SqliteTaskRepository::new(db_path)?),
+                    Arc::new(SqliteUserPreferencesRepository::new(db_path)?),
+                    Arc::new(SqliteGuildSettingsRepository::new(db_path)?),
+                    Arc::new(SqliteSchedulerRepository::new(db_path)?),
+                    Some(db_manager),
+                )
+            }
+            StorageBackend::Memory => {
+                warn!("Using in-memory storage, tasks and preferences will be lost on restart");
+                let task_repo = Arc::new(MemoryTaskRepository::new());
+                (
+                    task_repo.clone(),
+                    Arc::new(MemoryUserPreferencesRepository::new().with_tasks(task_repo)),
+                    Arc::new(MemoryGuildSettingsRepository::new()),
+                    Arc::new(MemorySchedulerRepository::new()),
+                    None,
+                )
+            }
+        };
 
-    let timezone_manager = Arc::new(
-        TimezoneManager::new()
-            .map_err(|e| format!("Failed to initialize timezone manager: {}", e))?,
-    );
+    let timezone_manager = Arc::new(TimezoneManager::new());
 
-    let notification_service = Arc::new(NotificationService::new());
+    // Task notifications go through the REST API, independent of the gateway context
+    let notification_sink: Arc<dyn NotificationSink> =
+        Arc::new(SerenityNotificationSink::new(Arc::new(Http::new(&token))));
 
-    let timezone_service = Arc::new(TimezoneService::new(
-        user_prefs_repo.clone(),
-        timezone_manager,
-    ));
+    let timezone_service = Arc::new(
+        TimezoneService::new(user_prefs_repo.clone(), timezone_manager, clock.clone())
+            .with_guild_settings(guild_settings_repo),
+    );
 
-    let task_service = Arc::new(TaskService::new(
-        task_repo.clone(),
-        notification_service.clone(),
-        timezone_service.clone(),
-    ));
+    let notification_service = Arc::new(
+        NotificationService::new(notification_sink).with_languages(timezone_service.clone()),
+    );
+
+    let task_service = Arc::new(
+        TaskService::new(
+            task_repo.clone(),
+            notification_service.clone(),
+            timezone_service.clone(),
+            clock.clone(),
+        )
+        .with_limits(config.limits.task_limits()),
+    );
 
     let task_orchestrator = Arc::new(TaskOrchestrator::new(
         task_service.clone(),
@@ -255,14 +618,28 @@ pub async fn run_bot() -> Result<(), Box<dyn std::error::Error>> {
 
     let songbird = songbird::Songbird::serenity();
 
-    // Initialize server-specific features
+    // Initialize server-specific features (only when the config has a [server_specific] section)
+    let server_specific = config.server_specific.as_ref();
     let (nickname_changer_service, kick_service, voice_interaction_service) =
-        initialize_specific_services(&token, songbird.clone()).await;
+        match server_specific {
+            Some(server_specific) => {
+                initialize_specific_services(
+                    &token,
+                    server_specific,
+                    songbird.clone(),
+                    clock.clone(),
+                    timezone_service.clone(),
+                )
+                .await
+            }
+            None => (None, None, None),
+        };
 
     // Initialize alias service
-    let alias_service =
+    let alias_service = server_specific.and_then(|server_specific| {
+        let alias_path = server_specific.data_dir.join("targets_alias.json");
         match crate::features::server_specific::services::alias_service::AliasService::new(
-            "./data/server_specific/targets_alias.json",
+            &alias_path.to_string_lossy(),
         ) {
             Ok(service) => {
                 info!("Alias service initialized successfully");
@@ -275,41 +652,155 @@ pub async fn run_bot() -> Result<(), Box<dyn std::error::Error>> {
                 );
                 None
             }
-        };
+        }
+    });
+
+    // /my_data export and delete also cover the server-specific files
+    let mut personal_data_stores: Vec<Arc<dyn PersonalDataStore>> = Vec::new();
+    if let Some(server_specific) = server_specific {
+        personal_data_stores.push(Arc::new(ServerUserDataStore::new(
+            server_specific.data_dir.clone(),
+            nickname_changer_service.clone(),
+            kick_service.clone(),
+            voice_interaction_service.clone(),
+            alias_service.clone(),
+        )));
+    }
+    let user_data_service = Arc::new(UserDataService::new(
+        task_orchestrator.clone(),
+        user_prefs_repo.clone(),
+        task_scheduler.clone(),
+        personal_data_stores,
+        clock.clone(),
+    ));
+
+    // Archives, deletes or moves to DM the tasks whose guild, channel or owner went away
+    let task_lifecycle_service = Arc::new(TaskLifecycleService::new(
+        task_orchestrator.clone(),
+        notification_service.clone(),
+        config.lifecycle.policies(),
+    ));
 
     let server_features_orchestrator = Arc::new(ServerFeaturesOrchestrator::new(
         nickname_changer_service.clone(),
         kick_service.clone(),
+        server_specific.map(|server_specific| server_specific.guild_id),
     ));
 
-    let server_interaction_handler = Arc::new(ServerInteractionHandler::new(
-        kick_service,
-        voice_interaction_service,
-        alias_service,
-    ));
+    let server_interaction_handler = Arc::new(
+        ServerInteractionHandler::new(
+            kick_service,
+            voice_interaction_service,
+            alias_service,
+            server_specific.and_then(|server_specific| server_specific.protected_user_id),
+        )
+        .with_languages(timezone_service.clone()),
+    );
 
-    // Create modal storage with 5-minute TTL (plenty of time for users to fill modals)
-    let modal_storage = Arc::new(ModalStorage::new(std::time::Duration::from_secs(300)));
+    // Modal storage keeps /add_task options until the modal is submitted (bot.modal_ttl_secs)
+    let modal_storage = Arc::new(ModalStorage::new(std::time::Duration::from_secs(
+        config.bot.modal_ttl_secs,
+    )));
+    // Slots offered by /meeting_finder wait for a pick just as long
+    let meeting_storage = Arc::new(ModalStorage::new(std::time::Duration::from_secs(
+        config.bot.modal_ttl_secs,
+    )));
+
+    // Per-user token buckets for commands and button clicks (limits.interactions_per_minute)
+    let rate_limiter = Arc::new(RateLimiter::new(
+        config.limits.interactions_per_minute,
+        config.limits.interaction_burst,
+    ));
 
-    // Spawn background task to clean up expired modal storage entries every minute
+    // Spawn background task to clean up expired modal storage entries and idle rate limit buckets every minute
     let storage_for_cleanup = modal_storage.clone();
+    let meetings_for_cleanup = meeting_storage.clone();
+    let limiter_for_cleanup = rate_limiter.clone();
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
         loop {
             interval.tick().await;
             storage_for_cleanup.cleanup_expired().await;
+            meetings_for_cleanup.cleanup_expired().await;
+            limiter_for_cleanup.cleanup(std::time::Instant::now());
+        }
+    });
+
+    // Spawn background task to purge soft-deleted scheduler entries and old delivery keys every hour
+    let scheduler_for_compaction = task_scheduler.clone();
+    let clock_for_compaction = clock.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            match scheduler_for_compaction.compact().await {
+                Ok(0) => {}
+                Ok(removed) => info!(
+                    "Scheduler compaction removed {} tombstones ({} since startup)",
+                    removed,
+                    scheduler_for_compaction.tombstones_removed()
+                ),
+                Err(e) => error!("Scheduler compaction failed: {}", e),
+            }
+
+            // Delivery keys only need to outlive leases and restarts, a week is plenty
+            let cutoff = clock_for_compaction.now() - chrono::Duration::days(7);
+            match scheduler_for_compaction.prune_deliveries(cutoff).await {
+                Ok(0) => {}
+                Ok(removed) => info!("Pruned {} old delivery keys", removed),
+                Err(e) => error!("Failed to prune delivery keys: {}", e),
+            }
         }
     });
 
+    // Optional scheduled snapshots of bot.db (backup.enabled, validated to need the SQLite backend)
+    if config.backup.enabled {
+        let backups = Arc::new(BackupManager::new(
+            db_path,
+            &config.backup.directory,
+            config.backup.retention(),
+        ));
+        let interval = chrono::Duration::hours(config.backup.interval_hours as i64);
+        tokio::spawn(run_backup_job(
+            backups,
+            interval,
+            clock.clone(),
+            shutdown.clone(),
+        ));
+    }
+
+    // Optional /healthz and /metrics endpoint (observability.metrics_addr)
+    if let Some(addr) = config.observability.metrics_addr {
+        let state = Arc::new(ObservabilityState {
+            scheduler_repo: task_scheduler.clone(),
+            db_manager: db_manager.clone(),
+            health: health(),
+            metrics: metrics(),
+        });
+        tokio::spawn(async move {
+            if let Err(e) = crate::infrastructure::observability::server::serve(addr, state).await {
+                error!("Health and metrics endpoint stopped: {}", e);
+            }
+        });
+    }
+
     let handler = CommandHandler {
         task_service,
         task_orchestrator,
         notification_service,
         timezone_service,
-        sqlite_scheduler_repo,
+        user_data_service,
+        task_lifecycle_service,
+        task_scheduler,
+        clock,
+        instance_id,
+        bot_config: config.bot.clone(),
         server_features_orchestrator,
         server_interaction_handler,
         modal_storage,
+        meeting_storage,
+        rate_limiter,
+        shutdown: shutdown.clone(),
     };
 
     let mut client = Client::builder(&token, intents)
@@ -317,6 +808,67 @@ pub async fn run_bot() -> Result<(), Box<dyn std::error::Error>> {
         .register_songbird_with(songbird.clone()) // ← Usar register_songbird_with
         .await?;
 
+    // On SIGINT/SIGTERM: drain in-flight work, leave voice channels, then close the gateway
+    let shard_manager = client.shard_manager.clone();
+    tokio::spawn(async move {
+        let signal = wait_for_signal().await;
+        info!("Received {}, shutting down", signal);
+
+        drain_in_flight(&shutdown).await;
+
+        let guilds: Vec<_> = songbird.iter().map(|(guild_id, _)| guild_id).collect();
+        for guild_id in guilds {
+            if let Err(e) = songbird.remove(guild_id).await {
+                warn!("Failed to leave voice channel in guild {}: {}", guild_id, e);
+            }
+        }
+
+        shard_manager.shutdown_all().await;
+    });
+
+    // Returns once every shard has been shut down
     client.start().await?;
+
+    if let Some(db_manager) = db_manager {
+        match db_manager.checkpoint().await {
+            Ok(()) => info!("Database flushed"),
+            Err(e) => error!("Failed to flush database: {}", e),
+        }
+    }
+
+    info!("Shutdown complete");
     Ok(())
 }
+
+/// How long interactions and deliveries already running get to finish after a shutdown signal
+const DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How long aborted work gets to hand back what it holds (e.g. claimed scheduler entries)
+const ABORT_GRACE: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Stop accepting work and wait for in-flight dispatches, aborting them once `DRAIN_TIMEOUT` passes
+async fn drain_in_flight(shutdown: &Shutdown) {
+    shutdown.begin_draining();
+    info!("Waiting for {} in-flight dispatches", shutdown.in_flight());
+
+    if tokio::time::timeout(DRAIN_TIMEOUT, shutdown.wait_idle())
+        .await
+        .is_ok()
+    {
+        return;
+    }
+
+    warn!(
+        "{} dispatches still running after {:?}, aborting them",
+        shutdown.in_flight(),
+        DRAIN_TIMEOUT
+    );
+    shutdown.abort();
+
+    if tokio::time::timeout(ABORT_GRACE, shutdown.wait_idle())
+        .await
+        .is_err()
+    {
+        warn!("{} dispatches did not stop, exiting anyway", shutdown.in_flight());
+    }
+}
@@ -0,0 +1,659 @@
+//! Local stand-in for the subset of the Discord REST API the bot talks to
+//! A serenity `Http` built with `FakeDiscord::http()` sends every request here instead of discord.com,
+//! so interaction handlers, the scheduler and server features can be exercised with no network
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde_json::{Value, json};
+use serenity::all::{Interaction, Permissions};
+use serenity::http::{Http, HttpBuilder};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+pub const APPLICATION_ID: u64 = 900_000_000_000_000_001;
+pub const BOT_USER_ID: u64 = 900_000_000_000_000_002;
+
+const TIMESTAMP: &str = "2025-01-06T12:00:00.000000+00:00";
+
+/// One request received by the fake API, path is relative to /api/v10
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub path: String,
+    pub body: Value,
+}
+
+#[derive(Default)]
+struct FakeDiscordState {
+    requests: Mutex<Vec<RecordedRequest>>,
+    // (guild_id, user_id) -> member object
+    members: Mutex<HashMap<(u64, u64), Value>>,
+    // guild_id -> guild object
+    guilds: Mutex<HashMap<u64, Value>>,
+    // channel_id -> guild channel object
+    channels: Mutex<HashMap<u64, Value>>,
+    // user_id -> DM channel id
+    dm_channels: Mutex<HashMap<u64, u64>>,
+    next_id: AtomicU64,
+    failing: AtomicBool,
+}
+
+/// Running fake Discord API, shut down when dropped
+pub struct FakeDiscord {
+    address: SocketAddr,
+    state: Arc<FakeDiscordState>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl FakeDiscord {
+    /// Bind to a random local port and start serving
+    pub async fn start() -> Self {
+        let state = Arc::new(FakeDiscordState {
+            next_id: AtomicU64::new(1_000),
+            ..Default::default()
+        });
+
+        let service_state = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle_request(state.clone(), request)
+                }))
+            }
+        });
+
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let address = server.local_addr();
+        let (shutdown, shutdown_signal) = oneshot::channel::<()>();
+
+        tokio::spawn(server.with_graceful_shutdown(async {
+            let _ = shutdown_signal.await;
+        }));
+
+        Self {
+            address,
+            state,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// REST client pointed at this server instead of discord.com
+    pub fn http(&self) -> Arc<Http> {
+        Arc::new(
+            HttpBuilder::new("fake-token")
+                .proxy(format!("http://{}", self.address))
+                .ratelimiter_disabled(true)
+                .application_id(APPLICATION_ID.into())
+                .build(),
+        )
+    }
+
+    /// Make a guild member known to the fake API
+    pub fn add_member(&self, guild_id: u64, user_id: u64, username: &str, nick: Option<&str>) {
+        let member = json!({
+            "user": user_json(user_id, username),
+            "nick": nick,
+            "roles": [],
+            "joined_at": TIMESTAMP,
+            "deaf": false,
+            "mute": false,
+            "flags": 0,
+        });
+        self.state
+            .members
+            .lock()
+            .unwrap()
+            .insert((guild_id, user_id), member);
+    }
+
+    /// Make a guild known to the fake API, `everyone_permissions` are the @everyone role's bits
+    pub fn add_guild(&self, guild_id: u64, owner_id: u64, everyone_permissions: u64) {
+        let guild = json!({
+            "id": guild_id.to_string(),
+            "name": "guild",
+            "owner_id": owner_id.to_string(),
+            "verification_level": 0,
+            "default_message_notifications": 0,
+            "explicit_content_filter": 0,
+            "roles": [{
+                "id": guild_id.to_string(),
+                "name": "@everyone",
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "permissions": everyone_permissions.to_string(),
+                "position": 0,
+            }],
+            "emojis": [],
+            "features": [],
+            "mfa_level": 0,
+            "system_channel_flags": 0,
+            "premium_tier": 0,
+            "preferred_locale": "en-US",
+            "nsfw_level": 0,
+            "stickers": [],
+            "premium_progress_bar_enabled": false,
+        });
+        self.state.guilds.lock().unwrap().insert(guild_id, guild);
+    }
+
+    /// Add a role without permissions to a known guild
+    pub fn add_role(&self, guild_id: u64, role_id: u64, mentionable: bool) {
+        if let Some(guild) = self.state.guilds.lock().unwrap().get_mut(&guild_id) {
+            guild["roles"].as_array_mut().unwrap().push(json!({
+                "id": role_id.to_string(),
+                "name": format!("role-{}", role_id),
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "mentionable": mentionable,
+                "permissions": "0",
+                "position": 1,
+            }));
+        }
+    }
+
+    /// Make a guild channel known to the fake API, `kind` is Discord's channel type (0 = text)
+    pub fn add_channel(&self, guild_id: u64, channel_id: u64, kind: u8) {
+        let channel = json!({
+            "id": channel_id.to_string(),
+            "guild_id": guild_id.to_string(),
+            "type": kind,
+            "name": format!("channel-{}", channel_id),
+            "position": 0,
+            "permission_overwrites": [],
+        });
+        self.state
+            .channels
+            .lock()
+            .unwrap()
+            .insert(channel_id, channel);
+    }
+
+    /// Deny `permissions` to a member in a channel through a permission overwrite
+    pub fn deny_in_channel(&self, channel_id: u64, user_id: u64, permissions: u64) {
+        if let Some(channel) = self.state.channels.lock().unwrap().get_mut(&channel_id) {
+            channel["permission_overwrites"]
+                .as_array_mut()
+                .unwrap()
+                .push(json!({
+                    "id": user_id.to_string(),
+                    "type": 1,
+                    "allow": "0",
+                    "deny": permissions.to_string(),
+                }));
+        }
+    }
+
+    /// Current member object, None once kicked
+    pub fn member(&self, guild_id: u64, user_id: u64) -> Option<Value> {
+        self.state
+            .members
+            .lock()
+            .unwrap()
+            .get(&(guild_id, user_id))
+            .cloned()
+    }
+
+    /// DM channel opened for a user, if any
+    pub fn dm_channel(&self, user_id: u64) -> Option<u64> {
+        self.state
+            .dm_channels
+            .lock()
+            .unwrap()
+            .get(&user_id)
+            .copied()
+    }
+
+    /// Answer every following request with a 500 (simulates a Discord outage) until reset
+    pub fn set_failing(&self, failing: bool) {
+        self.state.failing.store(failing, Ordering::SeqCst);
+    }
+
+    /// Every request received so far, in order
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// Requests matching a method and an exact path
+    pub fn requests_to(&self, method: Method, path: &str) -> Vec<RecordedRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.method == method && request.path == path)
+            .collect()
+    }
+
+    /// Bodies of every interaction callback (replies, modals...), in order
+    pub fn interaction_responses(&self) -> Vec<Value> {
+        self.requests()
+            .into_iter()
+            .filter(|request| {
+                request.method == Method::POST
+                    && request.path.starts_with("/interactions/")
+                    && request.path.ends_with("/callback")
+            })
+            .map(|request| request.body)
+            .collect()
+    }
+
+    /// Poll until a matching request shows up, panics after a few seconds
+    /// Needed for flows driven by background tasks such as the scheduler
+    pub async fn wait_for_request(&self, method: Method, path: &str) -> RecordedRequest {
+        for _ in 0..500 {
+            if let Some(request) = self.requests_to(method.clone(), path).pop() {
+                return request;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!(
+            "No {} {} request received, got: {:?}",
+            method,
+            path,
+            self.requests()
+        );
+    }
+}
+
+impl Drop for FakeDiscord {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+async fn handle_request(
+    state: Arc<FakeDiscordState>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let method = request.method().clone();
+    let path = request
+        .uri()
+        .path()
+        .trim_start_matches("/api/v10")
+        .to_string();
+    let bytes = hyper::body::to_bytes(request.into_body())
+        .await
+        .unwrap_or_default();
+    // Multipart bodies (file uploads) are kept as text so tests can look into the attachment
+    let body = serde_json::from_slice(&bytes).unwrap_or_else(|_| {
+        if bytes.is_empty() {
+            Value::Null
+        } else {
+            Value::String(String::from_utf8_lossy(&bytes).into_owned())
+        }
+    });
+
+    state.requests.lock().unwrap().push(RecordedRequest {
+        method: method.clone(),
+        path: path.clone(),
+        body: body.clone(),
+    });
+
+    if state.failing.load(Ordering::SeqCst) {
+        return Ok(error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            0,
+            "Fake Discord is set to fail",
+        ));
+    }
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    Ok(route(&state, &method, &segments, &body))
+}
+
+fn route(
+    state: &FakeDiscordState,
+    method: &Method,
+    segments: &[&str],
+    body: &Value,
+) -> Response<Body> {
+    match (method.as_str(), segments) {
+        // Create DM
+        ("POST", ["users", "@me", "channels"]) => {
+            let Some(user_id) = id_field(body, "recipient_id") else {
+                return error_response(StatusCode::BAD_REQUEST, 50035, "Invalid Form Body");
+            };
+            let channel_id = *state
+                .dm_channels
+                .lock()
+                .unwrap()
+                .entry(user_id)
+                .or_insert_with(|| state.next_id.fetch_add(1, Ordering::SeqCst));
+
+            json_response(
+                StatusCode::OK,
+                json!({
+                    "id": channel_id.to_string(),
+                    "type": 1,
+                    "last_message_id": null,
+                    "recipients": [user_json(user_id, "user")],
+                }),
+            )
+        }
+
+        ("GET", ["users", "@me"]) => {
+            json_response(StatusCode::OK, user_json(BOT_USER_ID, "Daily"))
+        }
+
+        ("GET", ["guilds", guild_id]) => {
+            let guild_id = guild_id.parse().unwrap_or_default();
+            match state.guilds.lock().unwrap().get(&guild_id) {
+                Some(guild) => json_response(StatusCode::OK, guild.clone()),
+                None => error_response(StatusCode::NOT_FOUND, 10004, "Unknown Guild"),
+            }
+        }
+
+        // The real API answers 403 for channels the bot can't see, unknown ones are close enough
+        ("GET", ["channels", channel_id]) => {
+            let channel_id = channel_id.parse().unwrap_or_default();
+            match state.channels.lock().unwrap().get(&channel_id) {
+                Some(channel) => json_response(StatusCode::OK, channel.clone()),
+                None => error_response(StatusCode::FORBIDDEN, 50001, "Missing Access"),
+            }
+        }
+
+        // Send message
+        ("POST", ["channels", channel_id, "messages"]) => {
+            let message_id = state.next_id.fetch_add(1, Ordering::SeqCst);
+            json_response(StatusCode::OK, message_json(channel_id, message_id, body))
+        }
+
+        // Edit message
+        ("PATCH", ["channels", channel_id, "messages", message_id]) => json_response(
+            StatusCode::OK,
+            message_json(channel_id, message_id.parse().unwrap_or_default(), body),
+        ),
+
+        // Get, edit and kick member
+        (_, ["guilds", guild_id, "members", user_id]) => {
+            let key = (
+                guild_id.parse().unwrap_or_default(),
+                user_id.parse().unwrap_or_default(),
+            );
+            let mut members = state.members.lock().unwrap();
+
+            match method.as_str() {
+                "GET" => match members.get(&key) {
+                    Some(member) => json_response(StatusCode::OK, member.clone()),
+                    None => unknown_member(),
+                },
+                "PATCH" => match members.get_mut(&key) {
+                    Some(member) => {
+                        if let Some(nick) = body.get("nick") {
+                            member["nick"] = nick.clone();
+                        }
+                        json_response(StatusCode::OK, member.clone())
+                    }
+                    None => unknown_member(),
+                },
+                "DELETE" => match members.remove(&key) {
+                    Some(_) => empty_response(),
+                    None => unknown_member(),
+                },
+                _ => not_found(),
+            }
+        }
+
+        // Overwrite guild commands, echoed back with the fields Discord fills in
+        ("PUT", ["applications", _, "guilds", guild_id, "commands"]) => {
+            let commands: Vec<Value> = body
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|mut command| {
+                    command["id"] = json!(state.next_id.fetch_add(1, Ordering::SeqCst).to_string());
+                    command["application_id"] = json!(APPLICATION_ID.to_string());
+                    command["guild_id"] = json!(guild_id);
+                    command["version"] = json!("1");
+                    if command.get("type").is_none() {
+                        command["type"] = json!(1);
+                    }
+                    command
+                })
+                .collect();
+            json_response(StatusCode::OK, Value::Array(commands))
+        }
+
+        // Interaction callback (reply, modal, update message...)
+        ("POST", ["interactions", _, _, "callback"]) => empty_response(),
+
+        // Edit original interaction response and followups
+        ("PATCH", ["webhooks", _, _, "messages", _]) | ("POST", ["webhooks", _, _]) => {
+            let message_id = state.next_id.fetch_add(1, Ordering::SeqCst);
+            json_response(StatusCode::OK, message_json("0", message_id, body))
+        }
+
+        _ => not_found(),
+    }
+}
+
+/// Discord serializes snowflakes as strings but serenity sends some of them as numbers
+fn id_field(body: &Value, field: &str) -> Option<u64> {
+    match body.get(field)? {
+        Value::String(id) => id.parse().ok(),
+        Value::Number(id) => id.as_u64(),
+        _ => None,
+    }
+}
+
+fn user_json(user_id: u64, username: &str) -> Value {
+    json!({
+        "id": user_id.to_string(),
+        "username": username,
+        "discriminator": "0",
+        "global_name": null,
+        "avatar": null,
+    })
+}
+
+fn message_json(channel_id: &str, message_id: u64, body: &Value) -> Value {
+    json!({
+        "id": message_id.to_string(),
+        "channel_id": channel_id,
+        "author": user_json(BOT_USER_ID, "Daily"),
+        "content": body.get("content").cloned().unwrap_or(json!("")),
+        "timestamp": TIMESTAMP,
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": body.get("embeds").cloned().unwrap_or(json!([])),
+        "components": body.get("components").cloned().unwrap_or(json!([])),
+        "pinned": false,
+        "type": 0,
+    })
+}
+
+fn json_response(status: StatusCode, body: Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn empty_response() -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::NO_CONTENT)
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn error_response(status: StatusCode, code: u64, message: &str) -> Response<Body> {
+    json_response(status, json!({ "code": code, "message": message }))
+}
+
+fn unknown_member() -> Response<Body> {
+    error_response(StatusCode::NOT_FOUND, 10007, "Unknown Member")
+}
+
+fn not_found() -> Response<Body> {
+    error_response(StatusCode::NOT_FOUND, 0, "404: Not Found")
+}
+
+/// Synthetic slash command as Discord would deliver it over the gateway
+/// `options` are (name, value) pairs, strings are sent as string options and numbers as channel ids
+pub fn slash_command(
+    guild_id: u64,
+    user_id: u64,
+    name: &str,
+    options: &[(&str, Value)],
+) -> Interaction {
+    let options: Vec<Value> = options
+        .iter()
+        .map(|(name, value)| {
+            // 3 = string, 7 = channel
+            let kind = if value.is_string() { 3 } else { 7 };
+            let value = if value.is_string() {
+                value.clone()
+            } else {
+                json!(value.to_string())
+            };
+            json!({ "name": name, "type": kind, "value": value })
+        })
+        .collect();
+
+    interaction_payload(
+        2,
+        guild_id,
+        user_id,
+        json!({
+            "id": "1",
+            "name": name,
+            "type": 1,
+            "options": options,
+        }),
+    )
+}
+
+/// The same command sent by a member with `permissions`, resolved as Discord does for interactions
+pub fn with_member_permissions(
+    mut interaction: Interaction,
+    permissions: Permissions,
+) -> Interaction {
+    if let Interaction::Command(command) = &mut interaction
+        && let Some(member) = &mut command.member
+    {
+        member.permissions = Some(permissions);
+    }
+    interaction
+}
+
+/// The same interaction sent from a Discord client set to `locale`
+pub fn with_locale(mut interaction: Interaction, locale: &str) -> Interaction {
+    match &mut interaction {
+        Interaction::Command(command) => command.locale = locale.to_string(),
+        Interaction::Component(component) => component.locale = locale.to_string(),
+        Interaction::Modal(modal) => modal.locale = locale.to_string(),
+        _ => {}
+    }
+    interaction
+}
+
+/// Synthetic modal submission, one text input per action row in the given order
+pub fn modal_submit(
+    guild_id: u64,
+    user_id: u64,
+    custom_id: &str,
+    inputs: &[(&str, &str)],
+) -> Interaction {
+    let rows: Vec<Value> = inputs
+        .iter()
+        .map(|(input_id, value)| {
+            json!({
+                "type": 1,
+                "components": [{ "type": 4, "custom_id": input_id, "value": value }],
+            })
+        })
+        .collect();
+
+    interaction_payload(
+        5,
+        guild_id,
+        user_id,
+        json!({ "custom_id": custom_id, "components": rows }),
+    )
+}
+
+/// Synthetic slash command with a single subcommand and no further options
+pub fn slash_subcommand(guild_id: u64, user_id: u64, name: &str, subcommand: &str) -> Interaction {
+    interaction_payload(
+        2,
+        guild_id,
+        user_id,
+        json!({
+            "id": "1",
+            "name": name,
+            "type": 1,
+            "options": [{ "name": subcommand, "type": 1, "options": [] }],
+        }),
+    )
+}
+
+/// Synthetic click on a button of an (ephemeral) bot message
+pub fn button_click(guild_id: u64, user_id: u64, custom_id: &str) -> Interaction {
+    let mut payload = interaction_json(
+        3,
+        guild_id,
+        user_id,
+        json!({ "custom_id": custom_id, "component_type": 2 }),
+    );
+    payload["message"] = message_json("1", 1, &Value::Null);
+    serde_json::from_value(payload).expect("synthetic interaction payload should deserialize")
+}
+
+/// Synthetic pick in a string select menu of an (ephemeral) bot message
+pub fn select_menu(guild_id: u64, user_id: u64, custom_id: &str, values: &[&str]) -> Interaction {
+    let mut payload = interaction_json(
+        3,
+        guild_id,
+        user_id,
+        json!({ "custom_id": custom_id, "component_type": 3, "values": values }),
+    );
+    payload["message"] = message_json("1", 1, &Value::Null);
+    serde_json::from_value(payload).expect("synthetic interaction payload should deserialize")
+}
+
+fn interaction_payload(kind: u8, guild_id: u64, user_id: u64, data: Value) -> Interaction {
+    serde_json::from_value(interaction_json(kind, guild_id, user_id, data))
+        .expect("synthetic interaction payload should deserialize")
+}
+
+fn interaction_json(kind: u8, guild_id: u64, user_id: u64, data: Value) -> Value {
+    static NEXT_INTERACTION_ID: AtomicU64 = AtomicU64::new(1);
+    let interaction_id = NEXT_INTERACTION_ID.fetch_add(1, Ordering::SeqCst);
+
+    json!({
+        "id": interaction_id.to_string(),
+        "application_id": APPLICATION_ID.to_string(),
+        "type": kind,
+        "data": data,
+        "guild_id": guild_id.to_string(),
+        "channel_id": "1",
+        "member": {
+            "user": user_json(user_id, "user"),
+            "nick": null,
+            "roles": [],
+            "joined_at": TIMESTAMP,
+            "deaf": false,
+            "mute": false,
+            "flags": 0,
+        },
+        "token": format!("interaction-token-{}", interaction_id),
+        "version": 1,
+        "app_permissions": "0",
+        "locale": "en-US",
+        "guild_locale": "en-US",
+        "entitlements": [],
+    })
+}
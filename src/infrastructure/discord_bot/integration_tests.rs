@@ -0,0 +1,1402 @@
+//! End-to-end flows against the fake Discord API: synthetic interactions go through the real
+//! command handlers, the scheduler runs on simulated time and notifications leave through serenity
+
+use super::bot::CommandHandler;
+use super::fake_discord::{
+    BOT_USER_ID, FakeDiscord, button_click, modal_submit, select_menu, slash_command,
+    slash_subcommand, with_locale, with_member_permissions,
+};
+use crate::application::services::notification_service::NotificationService;
+use crate::application::services::task_lifecycle_service::{
+    OrphanedTasksOutcome, TaskLifecycleService,
+};
+use crate::application::services::task_orchestrator::TaskOrchestrator;
+use crate::application::services::task_service::TaskService;
+use crate::application::services::timezone_service::TimezoneService;
+use crate::application::services::user_data_service::UserDataService;
+use crate::domain::clock::ManualClock;
+use crate::domain::entities::guild_settings::GuildSettings;
+use crate::domain::entities::task::NotificationMethod;
+use crate::domain::value_objects::{Language, MentionTarget, OrphanedTaskPolicies};
+use crate::features::server_specific::config::ServerConfig;
+use crate::features::server_specific::config::kick_config::{
+    KickConfig, KickRandomConfig, KickTargetUser,
+};
+use crate::features::server_specific::config::nickname_config::{
+    NicknameConfig, RandomConfig, TargetUser,
+};
+use crate::features::server_specific::services::kick_service::KickService;
+use crate::features::server_specific::services::nickname_changer::NicknameChangerService;
+use crate::features::server_specific::{ServerFeaturesOrchestrator, ServerInteractionHandler};
+use crate::infrastructure::config::BotConfig;
+use crate::infrastructure::notifications::SerenityNotificationSink;
+use crate::infrastructure::repositories::{
+    MemoryGuildSettingsRepository, MemorySchedulerRepository, MemoryTaskRepository,
+    MemoryUserPreferencesRepository,
+};
+use crate::infrastructure::scheduler::priority_queue_scheduler::PriorityQueueScheduler;
+use crate::infrastructure::timezone::timezone_manager::TimezoneManager;
+use crate::utils::{ModalStorage, RateLimiter, Shutdown};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use hyper::Method;
+use serde_json::{Value, json};
+use serenity::all::{Cache, GuildId, Interaction, Permissions};
+use serenity::http::Http;
+use std::sync::Arc;
+
+const GUILD_ID: u64 = 10;
+const USER_ID: u64 = 42;
+const CHANNEL_ID: u64 = 555;
+
+// Monday 2025-01-06 12:00 UTC
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap()
+}
+
+/// The bot's composition root on in-memory storage, simulated time and the fake API
+struct TestBot {
+    discord: FakeDiscord,
+    http: Arc<Http>,
+    cache: Cache,
+    clock: Arc<ManualClock>,
+    handler: CommandHandler,
+}
+
+impl TestBot {
+    async fn start() -> Self {
+        let discord = FakeDiscord::start().await;
+        let http = discord.http();
+        let clock = Arc::new(ManualClock::new(start()));
+
+        // A guild where everyone can post embeds in CHANNEL_ID, for the channel permission checks
+        let everyone =
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS;
+        discord.add_guild(GUILD_ID, 1, everyone.bits());
+        discord.add_channel(GUILD_ID, CHANNEL_ID, 0);
+        discord.add_role(GUILD_ID, 77, true);
+        discord.add_member(GUILD_ID, USER_ID, "user", None);
+        discord.add_member(GUILD_ID, BOT_USER_ID, "Daily", None);
+
+        let task_repo = Arc::new(MemoryTaskRepository::new());
+        let user_prefs_repo =
+            Arc::new(MemoryUserPreferencesRepository::new().with_tasks(task_repo.clone()));
+        let timezone_service = Arc::new(
+            TimezoneService::new(
+                user_prefs_repo.clone(),
+                Arc::new(TimezoneManager::new()),
+                clock.clone(),
+            )
+            .with_guild_settings(Arc::new(MemoryGuildSettingsRepository::new())),
+        );
+        let notification_service = Arc::new(
+            NotificationService::new(Arc::new(SerenityNotificationSink::new(http.clone())))
+                .with_languages(timezone_service.clone()),
+        );
+        let task_service = Arc::new(TaskService::new(
+            task_repo,
+            notification_service.clone(),
+            timezone_service.clone(),
+            clock.clone(),
+        ));
+        let task_scheduler = Arc::new(MemorySchedulerRepository::new());
+        let task_orchestrator = Arc::new(TaskOrchestrator::new(
+            task_service.clone(),
+            task_scheduler.clone(),
+            timezone_service.clone(),
+        ));
+        let user_data_service = Arc::new(UserDataService::new(
+            task_orchestrator.clone(),
+            user_prefs_repo,
+            task_scheduler.clone(),
+            Vec::new(),
+            clock.clone(),
+        ));
+        let task_lifecycle_service = Arc::new(TaskLifecycleService::new(
+            task_orchestrator.clone(),
+            notification_service.clone(),
+            OrphanedTaskPolicies::default(),
+        ));
+
+        let handler = CommandHandler {
+            task_service,
+            task_orchestrator,
+            notification_service,
+            timezone_service,
+            user_data_service,
+            task_lifecycle_service,
+            task_scheduler,
+            clock: clock.clone(),
+            instance_id: "test-instance".to_string(),
+            bot_config: BotConfig::default(),
+            server_features_orchestrator: Arc::new(ServerFeaturesOrchestrator::new(None, None, None)),
+            server_interaction_handler: Arc::new(ServerInteractionHandler::new(None, None, None, None)),
+            modal_storage: Arc::new(ModalStorage::new(std::time::Duration::from_secs(300))),
+            meeting_storage: Arc::new(ModalStorage::new(std::time::Duration::from_secs(300))),
+            rate_limiter: Arc::new(RateLimiter::new(20, 5)),
+            shutdown: Shutdown::new(),
+        };
+
+        Self {
+            discord,
+            http,
+            cache: Cache::new(),
+            clock,
+            handler,
+        }
+    }
+
+    async fn dispatch(&self, interaction: Interaction) {
+        self.handler
+            .handle_interaction(&self.http, &self.cache, &interaction)
+            .await;
+    }
+
+    fn start_scheduler(&self) {
+        PriorityQueueScheduler::start_scheduler(
+            self.handler.task_orchestrator.clone(),
+            self.handler.notification_service.clone(),
+            self.handler.task_scheduler.clone(),
+            self.handler.clock.clone(),
+            self.handler.instance_id.clone(),
+            self.handler.shutdown.clone(),
+        );
+    }
+
+    /// Run /add_task and submit the modal it opens, returns the modal's reply
+    async fn add_task(&self, options: &[(&str, Value)], inputs: &[(&str, &str)]) -> Value {
+        self.dispatch(slash_command(GUILD_ID, USER_ID, "add_task", options))
+            .await;
+
+        let modal = self.discord.interaction_responses().pop().unwrap();
+        assert_eq!(modal["type"], 9, "expected a modal, got {}", modal);
+        let custom_id = modal["data"]["custom_id"].as_str().unwrap().to_string();
+
+        self.dispatch(modal_submit(GUILD_ID, USER_ID, &custom_id, inputs))
+            .await;
+        self.discord.interaction_responses().pop().unwrap()
+    }
+
+    /// Advance simulated time in small steps until `path` is hit again, for retries scheduled off `now()`
+    async fn advance_until_next_request(&self, method: Method, path: &str, step: Duration) {
+        let seen = self.discord.requests_to(method.clone(), path).len();
+        for _ in 0..20 {
+            if self.discord.requests_to(method.clone(), path).len() > seen {
+                return;
+            }
+            self.clock.advance(step);
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+        panic!("No {} {} request after advancing the clock", method, path);
+    }
+}
+
+fn task_inputs<'a>(title: &'a str, date: &'a str, time: &'a str) -> [(&'a str, &'a str); 4] {
+    [
+        ("task_title", title),
+        ("date", date),
+        ("time", time),
+        ("task_description", ""),
+    ]
+}
+
+#[tokio::test]
+async fn add_task_opens_a_modal_and_the_scheduler_delivers_a_dm() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+
+    let reply = bot
+        .add_task(
+            &[
+                ("notification_method", json!("DM")),
+                ("task_type", json!("single")),
+            ],
+            &task_inputs("Standup", "2025-01-06", "13:00"),
+        )
+        .await;
+    assert_eq!(reply["type"], 4);
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(
+        content.contains("Standup") && content.contains("created successfully"),
+        "{}",
+        content
+    );
+
+    bot.start_scheduler();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert!(
+        bot.discord
+            .requests_to(Method::POST, "/users/@me/channels")
+            .is_empty()
+    );
+
+    bot.clock.advance(Duration::hours(1));
+
+    let dm = bot
+        .discord
+        .wait_for_request(Method::POST, "/users/@me/channels")
+        .await;
+    assert_eq!(dm.body["recipient_id"], json!(USER_ID.to_string()));
+
+    let dm_channel = bot.discord.dm_channel(USER_ID).unwrap();
+    let message = bot
+        .discord
+        .wait_for_request(Method::POST, &format!("/channels/{}/messages", dm_channel))
+        .await;
+    let title = message.body["embeds"][0]["title"].as_str().unwrap();
+    assert!(title.contains("Standup"), "{}", title);
+    // The due time is Discord markup, so every reader sees it in their own timezone
+    let fields = message.body["embeds"][0]["fields"].to_string();
+    assert!(fields.contains("<t:1736168400:F>"), "{}", fields);
+}
+
+#[tokio::test]
+async fn channel_tasks_are_posted_to_the_selected_channel() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+
+    let reply = bot
+        .add_task(
+            &[
+                ("notification_method", json!("Channel")),
+                ("task_type", json!("single")),
+                ("channel", json!(CHANNEL_ID)),
+                ("mention", json!("<@&77> <@42>")),
+            ],
+            &task_inputs("Deploy", "2025-01-06", "12:30"),
+        )
+        .await;
+    assert!(
+        reply["data"]["content"]
+            .as_str()
+            .unwrap()
+            .contains("specified channel")
+    );
+
+    bot.start_scheduler();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    bot.clock.advance(Duration::minutes(30));
+
+    let message = bot
+        .discord
+        .wait_for_request(Method::POST, "/channels/555/messages")
+        .await;
+    assert!(
+        message.body["embeds"][0]["title"]
+            .as_str()
+            .unwrap()
+            .contains("Deploy")
+    );
+    // Only the chosen targets may be pinged
+    assert_eq!(message.body["content"], "Your task is ready! <@&77> <@42>");
+    assert_eq!(message.body["allowed_mentions"]["parse"], json!([]));
+    assert_eq!(message.body["allowed_mentions"]["users"], json!(["42"]));
+    assert_eq!(message.body["allowed_mentions"]["roles"], json!(["77"]));
+    assert!(
+        bot.discord
+            .requests_to(Method::POST, "/users/@me/channels")
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn add_task_requires_a_timezone_first() {
+    let bot = TestBot::start().await;
+
+    let reply = bot
+        .add_task(
+            &[
+                ("notification_method", json!("DM")),
+                ("task_type", json!("single")),
+            ],
+            &task_inputs("Standup", "2025-01-06", "13:00"),
+        )
+        .await;
+
+    assert!(
+        reply["data"]["content"]
+            .as_str()
+            .unwrap()
+            .contains("setup your timezone")
+    );
+    assert_eq!(
+        bot.handler
+            .task_service
+            .get_total_task_count()
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn add_task_reads_times_in_the_given_timezone() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+
+    let reply = bot
+        .add_task(
+            &[
+                ("notification_method", json!("DM")),
+                ("task_type", json!("weekly")),
+                ("timezone", json!("Nueva York")),
+            ],
+            &[
+                ("task_title", "Call"),
+                ("days", "Mon"),
+                ("time", "10:00"),
+                ("task_description", ""),
+            ],
+        )
+        .await;
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.contains("America/New_York"), "{}", content);
+
+    // 10:00 in New York is 15:00 UTC in January
+    let task = bot.handler.task_orchestrator.get_task_by_id(1).await.unwrap();
+    assert_eq!(task.timezone.as_deref(), Some("America/New_York"));
+    assert_eq!(
+        task.scheduled_time,
+        Some(Utc.with_ymd_and_hms(2025, 1, 6, 15, 0, 0).unwrap())
+    );
+
+    // Searches matching several zones are refused before the modal opens
+    bot.dispatch(slash_command(
+        GUILD_ID,
+        USER_ID,
+        "add_task",
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("single")),
+            ("timezone", json!("Estados Unidos")),
+        ],
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.contains("Invalid timezone"), "{}", content);
+}
+
+#[tokio::test]
+async fn timezone_change_can_keep_tasks_at_the_same_local_time() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "America/Argentina/Buenos_Aires")
+        .await
+        .unwrap();
+
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("weekly")),
+        ],
+        &[
+            ("task_title", "Standup"),
+            ("days", "Tue"),
+            ("time", "09:00"),
+            ("task_description", ""),
+        ],
+    )
+    .await;
+    let task = bot.handler.task_orchestrator.get_task_by_id(1).await.unwrap();
+    assert_eq!(
+        task.scheduled_time,
+        Some(Utc.with_ymd_and_hms(2025, 1, 7, 12, 0, 0).unwrap())
+    );
+
+    // Confirming a new zone first previews the affected tasks
+    bot.dispatch(button_click(
+        GUILD_ID,
+        USER_ID,
+        "timezone_confirm:Europe/Madrid",
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let preview = reply["data"]["embeds"][0]["description"].as_str().unwrap();
+    assert!(preview.contains("`Every Tue at 09:00` → `Every Tue at 13:00`"), "{}", preview);
+
+    bot.dispatch(button_click(
+        GUILD_ID,
+        USER_ID,
+        "timezone_keep_local:Europe/Madrid",
+    ))
+    .await;
+    let timezone = bot
+        .handler
+        .timezone_service
+        .get_user_timezone(USER_ID)
+        .await
+        .unwrap();
+    assert_eq!(timezone.as_deref(), Some("Europe/Madrid"));
+
+    // 09:00 in Madrid is 08:00 UTC, and the queue follows the task
+    let task = bot.handler.task_orchestrator.get_task_by_id(1).await.unwrap();
+    let moved = Some(Utc.with_ymd_and_hms(2025, 1, 7, 8, 0, 0).unwrap());
+    assert_eq!(task.scheduled_time, moved);
+    let queued = bot.handler.task_scheduler.list_pending().await.unwrap();
+    assert_eq!(queued.len(), 1);
+    assert_eq!(Some(queued[0].scheduled_time), moved);
+
+    // Keeping absolute times leaves the task where it is
+    bot.dispatch(button_click(
+        GUILD_ID,
+        USER_ID,
+        "timezone_keep_absolute:Asia/Tokyo",
+    ))
+    .await;
+    let task = bot.handler.task_orchestrator.get_task_by_id(1).await.unwrap();
+    assert_eq!(task.scheduled_time, moved);
+}
+
+#[tokio::test]
+async fn time_and_meeting_finder_use_each_members_timezone() {
+    const TEAMMATE_ID: u64 = 43;
+    let bot = TestBot::start().await;
+    // Interactions come from channel 1
+    bot.discord.add_channel(GUILD_ID, 1, 0);
+    let timezones = &bot.handler.timezone_service;
+    timezones.set_user_timezone(USER_ID, "Europe/Madrid").await.unwrap();
+    timezones
+        .set_user_timezone(TEAMMATE_ID, "America/New_York")
+        .await
+        .unwrap();
+
+    // Monday 12:00 UTC, sorted west to east, without pinging anyone
+    bot.dispatch(slash_command(
+        GUILD_ID,
+        USER_ID,
+        "time",
+        &[("members", json!("<@42> <@43> <@44>"))],
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let clock = reply["data"]["embeds"][0]["description"].as_str().unwrap();
+    assert_eq!(
+        clock,
+        "<@43> · **07:00** Mon · `America/New_York`\n\
+         <@42> · **13:00** Mon · `Europe/Madrid`\n\
+         <@44> · no timezone set"
+    );
+    assert_eq!(reply["data"]["allowed_mentions"]["parse"], json!([]));
+
+    // The teammate starts at 10:00 New York time, so the overlap is 15:00-16:00 UTC
+    bot.dispatch(slash_command(
+        GUILD_ID,
+        TEAMMATE_ID,
+        "working_hours",
+        &[("hours", json!("10:00-18:00"))],
+    ))
+    .await;
+    bot.dispatch(slash_command(
+        GUILD_ID,
+        USER_ID,
+        "meeting_finder",
+        &[("members", json!("<@43>")), ("title", json!("Sync"))],
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let menu = &reply["data"]["components"][0]["components"][0];
+    let options = menu["options"].as_array().unwrap();
+    assert_eq!(options.len(), 5, "{}", reply);
+    // Shown in the caller's Madrid time
+    assert_eq!(options[0]["label"], "Mon 06 Jan 16:00-17:00");
+    let start = Utc.with_ymd_and_hms(2025, 1, 6, 15, 0, 0).unwrap();
+    assert_eq!(options[0]["value"], start.timestamp().to_string());
+
+    let custom_id = menu["custom_id"].as_str().unwrap().to_string();
+    let value = start.timestamp().to_string();
+    // A pick that fails leaves the proposal for the next one
+    bot.discord
+        .deny_in_channel(1, BOT_USER_ID, Permissions::SEND_MESSAGES.bits());
+    bot.dispatch(select_menu(GUILD_ID, USER_ID, &custom_id, &[&value]))
+        .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.contains("Send Messages"), "{}", content);
+    bot.discord.add_channel(GUILD_ID, 1, 0);
+
+    bot.dispatch(select_menu(GUILD_ID, USER_ID, &custom_id, &[&value]))
+        .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.contains("**Sync**"), "{}", content);
+
+    // One channel task owned by the caller that pings the teammate
+    let task = bot.handler.task_orchestrator.get_task_by_id(1).await.unwrap();
+    assert_eq!(task.user_id, USER_ID);
+    assert_eq!(task.scheduled_time, Some(start));
+    assert_eq!(task.channel_id, Some(1));
+    assert!(matches!(task.notification_method, NotificationMethod::Channel));
+    assert_eq!(task.mentions, vec![MentionTarget::User(TEAMMATE_ID)]);
+
+    // The proposal can't be used twice
+    bot.dispatch(select_menu(GUILD_ID, USER_ID, &custom_id, &[&value]))
+        .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    assert!(reply["data"]["content"].as_str().unwrap().contains("expired"));
+}
+
+#[tokio::test]
+async fn server_settings_give_members_without_a_timezone_the_server_default() {
+    let bot = TestBot::start().await;
+    let settings = [
+        ("timezone", json!("America/Bogota")),
+        ("date_format", json!("DMY")),
+    ];
+
+    // Only members who can manage the server change its settings
+    bot.dispatch(slash_command(GUILD_ID, USER_ID, "server_settings", &settings))
+        .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    assert!(
+        reply["data"]["content"]
+            .as_str()
+            .unwrap()
+            .contains("Manage Server")
+    );
+
+    bot.dispatch(with_member_permissions(
+        slash_command(GUILD_ID, USER_ID, "server_settings", &settings),
+        Permissions::MANAGE_GUILD,
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    assert_eq!(
+        reply["data"]["embeds"][0]["fields"][0]["value"],
+        "America/Bogota"
+    );
+
+    // USER_ID never ran /timezone: the task is read in Bogota (UTC-5) with the server's DMY
+    let reply = bot
+        .add_task(
+            &[
+                ("notification_method", json!("DM")),
+                ("task_type", json!("single")),
+            ],
+            &task_inputs("Standup", "06-01-2025", "09:00"),
+        )
+        .await;
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.contains("created successfully"), "{}", content);
+    assert!(content.contains("America/Bogota"), "{}", content);
+
+    let task = bot
+        .handler
+        .task_service
+        .get_user_tasks(USER_ID)
+        .await
+        .pop()
+        .unwrap();
+    assert_eq!(task.timezone.as_deref(), Some("America/Bogota"));
+    assert_eq!(
+        task.scheduled_time,
+        Some(Utc.with_ymd_and_hms(2025, 1, 6, 14, 0, 0).unwrap())
+    );
+
+    // /list_tasks shows the tasks instead of asking for a timezone
+    bot.dispatch(slash_command(GUILD_ID, USER_ID, "list_tasks", &[]))
+        .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let embed = &reply["data"]["embeds"][0];
+    assert_eq!(embed["title"], "📝 Your Tasks");
+    assert!(
+        embed["description"]
+            .as_str()
+            .unwrap()
+            .contains("server's default timezone")
+    );
+    let fields = embed["fields"].to_string();
+    assert!(fields.contains("06-01-2025 09:00"), "{}", fields);
+    assert!(fields.contains("<t:1736172000:R>"), "{}", fields);
+}
+
+#[tokio::test]
+async fn preferences_change_how_times_are_read_and_written() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "America/New_York")
+        .await
+        .unwrap();
+
+    bot.dispatch(slash_command(
+        GUILD_ID,
+        USER_ID,
+        "preferences",
+        &[
+            ("date_format", json!("DMY")),
+            ("clock", json!("12h")),
+            ("first_day", json!("Sun")),
+        ],
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let fields = reply["data"]["embeds"][0]["fields"].to_string();
+    assert!(fields.contains("DD-MM-YYYY") && fields.contains("12-hour"), "{}", fields);
+
+    // 3:30 PM in New York is 20:30 UTC
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("weekly")),
+        ],
+        &[
+            ("task_title", "Standup"),
+            ("days", "Mon,Sun"),
+            ("time", "3:30 PM"),
+            ("task_description", ""),
+        ],
+    )
+    .await;
+    let task = bot.handler.task_orchestrator.get_task_by_id(1).await.unwrap();
+    assert_eq!(
+        task.scheduled_time,
+        Some(Utc.with_ymd_and_hms(2025, 1, 6, 20, 30, 0).unwrap())
+    );
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("single")),
+        ],
+        &task_inputs("Review", "07-01-2025", "3pm"),
+    )
+    .await;
+    let task = bot.handler.task_orchestrator.get_task_by_id(2).await.unwrap();
+    assert_eq!(
+        task.scheduled_time,
+        Some(Utc.with_ymd_and_hms(2025, 1, 7, 20, 0, 0).unwrap())
+    );
+
+    bot.dispatch(slash_command(GUILD_ID, USER_ID, "list_tasks", &[]))
+        .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let fields = reply["data"]["embeds"][0]["fields"].to_string();
+    assert!(fields.contains("Every Sun, Mon at 3:30 PM"), "{}", fields);
+
+    // A date format chosen by hand survives a timezone change
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "Asia/Tokyo")
+        .await
+        .unwrap();
+    let date_format = bot
+        .handler
+        .timezone_service
+        .get_user_date_format(USER_ID)
+        .await
+        .unwrap();
+    assert_eq!(date_format.as_deref(), Some("DMY"));
+}
+
+#[tokio::test]
+async fn replies_follow_the_discord_locale_until_a_language_is_chosen() {
+    let bot = TestBot::start().await;
+
+    bot.dispatch(with_locale(
+        slash_command(GUILD_ID, USER_ID, "help", &[]),
+        "es-ES",
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.starts_with("**Comandos disponibles:**"), "{}", content);
+
+    let preferences = || {
+        with_locale(
+            slash_command(GUILD_ID, USER_ID, "preferences", &[("language", json!("en"))]),
+            "es-ES",
+        )
+    };
+    bot.dispatch(preferences()).await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.contains("zona horaria"), "{}", content);
+
+    // A language picked in /preferences wins over the client's locale
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "Europe/Madrid")
+        .await
+        .unwrap();
+    bot.dispatch(preferences()).await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let fields = reply["data"]["embeds"][0]["fields"].to_string();
+    assert!(fields.contains("English"), "{}", fields);
+
+    bot.dispatch(with_locale(
+        slash_command(GUILD_ID, USER_ID, "help", &[]),
+        "es-ES",
+    ))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(content.starts_with("**Available Commands:**"), "{}", content);
+}
+
+#[tokio::test]
+async fn add_task_rejects_channel_methods_without_a_channel() {
+    let bot = TestBot::start().await;
+
+    bot.dispatch(slash_command(
+        GUILD_ID,
+        USER_ID,
+        "add_task",
+        &[
+            ("notification_method", json!("Both")),
+            ("task_type", json!("single")),
+        ],
+    ))
+    .await;
+
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    assert_eq!(reply["type"], 4);
+    assert!(
+        reply["data"]["content"]
+            .as_str()
+            .unwrap()
+            .contains("Channel required")
+    );
+}
+
+#[tokio::test]
+async fn failed_deliveries_are_retried_once_discord_recovers() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("single")),
+        ],
+        &task_inputs("Standup", "2025-01-06", "13:00"),
+    )
+    .await;
+
+    bot.discord.set_failing(true);
+    bot.start_scheduler();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    bot.clock.advance(Duration::hours(1));
+    bot.discord
+        .wait_for_request(Method::POST, "/users/@me/channels")
+        .await;
+    assert!(bot.discord.dm_channel(USER_ID).is_none());
+
+    bot.discord.set_failing(false);
+    bot.advance_until_next_request(Method::POST, "/users/@me/channels", Duration::minutes(1))
+        .await;
+
+    let dm_channel = bot.discord.dm_channel(USER_ID).unwrap();
+    bot.discord
+        .wait_for_request(Method::POST, &format!("/channels/{}/messages", dm_channel))
+        .await;
+}
+
+#[tokio::test]
+async fn guild_commands_are_registered_in_one_overwrite() {
+    let bot = TestBot::start().await;
+
+    bot.handler
+        .register_commands_for_guild(&bot.http, GuildId::new(GUILD_ID))
+        .await;
+
+    let request = bot
+        .discord
+        .wait_for_request(
+            Method::PUT,
+            &format!(
+                "/applications/{}/guilds/{}/commands",
+                super::fake_discord::APPLICATION_ID,
+                GUILD_ID
+            ),
+        )
+        .await;
+    let names: Vec<&str> = request
+        .body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|command| command["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"add_task") && names.contains(&"timezone"));
+    assert!(
+        !names.contains(&"stats"),
+        "stats is restricted to the test server"
+    );
+}
+
+#[tokio::test]
+async fn my_data_export_dms_a_json_file() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("single")),
+        ],
+        &task_inputs("Standup", "2025-01-06", "13:00"),
+    )
+    .await;
+
+    bot.dispatch(slash_subcommand(GUILD_ID, USER_ID, "my_data", "export"))
+        .await;
+
+    // Deferred, then the DM carries the file and the original reply is edited
+    assert_eq!(bot.discord.interaction_responses().pop().unwrap()["type"], 5);
+    let dm_channel = bot.discord.dm_channel(USER_ID).unwrap();
+    let upload = bot
+        .discord
+        .wait_for_request(Method::POST, &format!("/channels/{}/messages", dm_channel))
+        .await;
+    let body = upload.body.as_str().unwrap();
+    assert!(body.contains(&format!("my_data_{}.json", USER_ID)));
+    assert!(body.contains("\"title\": \"Standup\"") && body.contains("\"timezone\": \"UTC\""));
+
+    let edit = bot
+        .discord
+        .requests()
+        .into_iter()
+        .rfind(|request| request.method == Method::PATCH && request.path.starts_with("/webhooks/"))
+        .unwrap();
+    assert!(edit.body["content"].as_str().unwrap().contains("DM"));
+}
+
+#[tokio::test]
+async fn my_data_delete_asks_first_then_erases_everything() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("single")),
+        ],
+        &task_inputs("Standup", "2025-01-06", "13:00"),
+    )
+    .await;
+
+    bot.dispatch(slash_subcommand(GUILD_ID, USER_ID, "my_data", "delete"))
+        .await;
+    let prompt = bot.discord.interaction_responses().pop().unwrap();
+    assert_eq!(
+        prompt["data"]["components"][0]["components"][0]["custom_id"],
+        "my_data_delete_confirm"
+    );
+    assert_eq!(bot.handler.task_orchestrator.get_user_tasks(USER_ID).await.len(), 1);
+
+    bot.dispatch(button_click(GUILD_ID, USER_ID, "my_data_delete_confirm"))
+        .await;
+
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    assert_eq!(reply["type"], 7);
+    assert!(
+        reply["data"]["content"]
+            .as_str()
+            .unwrap()
+            .contains("1 tasks")
+    );
+    assert!(bot.handler.task_orchestrator.get_user_tasks(USER_ID).await.is_empty());
+    assert!(bot.handler.task_scheduler.list_pending().await.unwrap().is_empty());
+    assert!(
+        bot.handler
+            .timezone_service
+            .get_user_timezone(USER_ID)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+fn server_config() -> ServerConfig {
+    ServerConfig {
+        server_id: GUILD_ID,
+        general_channel_id: 777,
+        ..ServerConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn deleted_channel_moves_its_tasks_to_dm_and_removed_guild_archives_them_until_it_returns() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+    bot.add_task(
+        &[
+            ("notification_method", json!("Channel")),
+            ("task_type", json!("single")),
+            ("channel", json!(CHANNEL_ID)),
+        ],
+        &task_inputs("Deploy", "2025-01-06", "13:00"),
+    )
+    .await;
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("single")),
+        ],
+        &task_inputs("Standup", "2025-01-06", "14:00"),
+    )
+    .await;
+
+    let lifecycle = &bot.handler.task_lifecycle_service;
+    assert_eq!(
+        lifecycle.on_channel_deleted(555).await,
+        OrphanedTasksOutcome {
+            moved_to_dm: 1,
+            ..OrphanedTasksOutcome::default()
+        }
+    );
+    let moved = bot.handler.task_orchestrator.get_task_by_id(1).await.unwrap();
+    assert!(matches!(moved.notification_method, NotificationMethod::DM));
+    assert_eq!(moved.channel_id, None);
+
+    let dm_channel = bot.discord.dm_channel(USER_ID).unwrap();
+    let dm_path = format!("/channels/{}/messages", dm_channel);
+    let notice = bot.discord.wait_for_request(Method::POST, &dm_path).await;
+    let content = notice.body["content"].as_str().unwrap();
+    assert!(content.contains("by DM") && content.contains("Deploy"), "{}", content);
+
+    // Leaving another guild touches nothing
+    assert_eq!(
+        lifecycle.on_guild_removed(GUILD_ID + 1).await,
+        OrphanedTasksOutcome::default()
+    );
+
+    assert_eq!(lifecycle.on_guild_removed(GUILD_ID).await.archived, 2);
+    assert!(bot.handler.task_orchestrator.get_user_tasks(USER_ID).await.is_empty());
+    assert!(bot.handler.task_scheduler.list_pending().await.unwrap().is_empty());
+    assert_eq!(bot.handler.task_orchestrator.get_archived_tasks().await.len(), 2);
+
+    let copies: Vec<_> = bot
+        .discord
+        .requests_to(Method::POST, &dm_path)
+        .into_iter()
+        .skip(1)
+        .collect();
+    assert_eq!(copies.len(), 2);
+    assert_eq!(copies[1].body["embeds"][0]["title"], "Standup");
+
+    // Re-inviting the bot brings them back on the schedule
+    assert_eq!(lifecycle.on_guild_available(GUILD_ID + 1).await, 0);
+    assert_eq!(lifecycle.on_guild_available(GUILD_ID).await, 2);
+    assert_eq!(bot.handler.task_orchestrator.get_user_tasks(USER_ID).await.len(), 2);
+    assert!(bot.handler.task_orchestrator.get_archived_tasks().await.is_empty());
+    let queued: Vec<u64> = bot
+        .handler
+        .task_scheduler
+        .list_pending()
+        .await
+        .unwrap()
+        .iter()
+        .map(|entry| entry.task_id)
+        .collect();
+    assert_eq!(queued, vec![1, 2]);
+
+    let notice = bot.discord.requests_to(Method::POST, &dm_path).pop().unwrap();
+    let content = notice.body["content"].as_str().unwrap();
+    assert!(content.contains("active again") && content.contains("Standup"), "{}", content);
+    assert_eq!(lifecycle.on_guild_available(GUILD_ID).await, 0);
+}
+
+#[tokio::test]
+async fn add_task_refuses_channels_without_the_needed_permissions() {
+    let bot = TestBot::start().await;
+    let add_channel_task = |channel_id: u64| {
+        slash_command(
+            GUILD_ID,
+            USER_ID,
+            "add_task",
+            &[
+                ("notification_method", json!("Channel")),
+                ("task_type", json!("single")),
+                ("channel", json!(channel_id)),
+            ],
+        )
+    };
+    let error = |reply: Value| {
+        assert_eq!(reply["type"], 4, "expected an error, got {}", reply);
+        assert_eq!(reply["data"]["flags"], 64);
+        reply["data"]["content"].as_str().unwrap().to_string()
+    };
+
+    bot.discord.add_channel(GUILD_ID, 600, 0);
+    bot.discord
+        .deny_in_channel(600, BOT_USER_ID, Permissions::EMBED_LINKS.bits());
+    bot.dispatch(add_channel_task(600)).await;
+    let content = error(bot.discord.interaction_responses().pop().unwrap());
+    assert!(
+        content.contains("I'm missing the **Embed Links** permission"),
+        "{}",
+        content
+    );
+
+    bot.discord.add_channel(GUILD_ID, 601, 0);
+    bot.discord
+        .deny_in_channel(601, USER_ID, Permissions::SEND_MESSAGES.bits());
+    bot.dispatch(add_channel_task(601)).await;
+    let content = error(bot.discord.interaction_responses().pop().unwrap());
+    assert!(
+        content.contains("You need the **Send Messages** permission"),
+        "{}",
+        content
+    );
+
+    bot.discord.add_channel(GUILD_ID + 1, 602, 0);
+    bot.dispatch(add_channel_task(602)).await;
+    let content = error(bot.discord.interaction_responses().pop().unwrap());
+    assert!(content.contains("another server"), "{}", content);
+
+    // Voice channel
+    bot.discord.add_channel(GUILD_ID, 603, 2);
+    bot.dispatch(add_channel_task(603)).await;
+    let content = error(bot.discord.interaction_responses().pop().unwrap());
+    assert!(content.contains("not a text channel"), "{}", content);
+
+    bot.dispatch(add_channel_task(CHANNEL_ID)).await;
+    assert_eq!(bot.discord.interaction_responses().pop().unwrap()["type"], 9);
+}
+
+#[tokio::test]
+async fn quota_and_permission_errors_follow_the_locale() {
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+    let spanish = |interaction| with_locale(interaction, "es-ES");
+
+    // The default quota allows 25 recurring tasks per user
+    for n in 0..25 {
+        bot.handler
+            .task_orchestrator
+            .create_and_schedule_weekly_task(
+                USER_ID,
+                GUILD_ID,
+                format!("Task {}", n),
+                String::new(),
+                vec![chrono::Weekday::Mon],
+                9,
+                0,
+                NotificationMethod::DM,
+                None,
+                Vec::new(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    bot.dispatch(spanish(slash_command(
+        GUILD_ID,
+        USER_ID,
+        "add_task",
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("weekly")),
+        ],
+    )))
+    .await;
+    let modal = bot.discord.interaction_responses().pop().unwrap();
+    let custom_id = modal["data"]["custom_id"].as_str().unwrap().to_string();
+    bot.dispatch(spanish(modal_submit(
+        GUILD_ID,
+        USER_ID,
+        &custom_id,
+        &[
+            ("task_title", "Standup"),
+            ("days", "Tue"),
+            ("time", "09:00"),
+            ("task_description", ""),
+        ],
+    )))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert_eq!(
+        content,
+        "❌ Ya tienes 25 tareas recurrentes, el límite es 25"
+    );
+
+    bot.discord.add_channel(GUILD_ID, 600, 0);
+    bot.discord
+        .deny_in_channel(600, BOT_USER_ID, Permissions::EMBED_LINKS.bits());
+    bot.dispatch(spanish(slash_command(
+        GUILD_ID,
+        USER_ID,
+        "add_task",
+        &[
+            ("notification_method", json!("Channel")),
+            ("task_type", json!("single")),
+            ("channel", json!(600)),
+        ],
+    )))
+    .await;
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    let content = reply["data"]["content"].as_str().unwrap();
+    assert!(
+        content.ends_with("Me falta el permiso **Embed Links** en <#600>"),
+        "{}",
+        content
+    );
+}
+
+#[tokio::test]
+async fn add_task_refuses_mentions_that_would_not_ping() {
+    let bot = TestBot::start().await;
+    bot.discord.add_role(GUILD_ID, 78, false);
+    let add_task_mentioning = |method: &str, mention: &str| {
+        let mut options = vec![
+            ("notification_method", json!(method)),
+            ("task_type", json!("single")),
+        ];
+        if method != "DM" {
+            options.push(("channel", json!(CHANNEL_ID)));
+        }
+        options.push(("mention", json!(mention)));
+        slash_command(GUILD_ID, USER_ID, "add_task", &options)
+    };
+    let error = |reply: Value| {
+        assert_eq!(reply["type"], 4, "expected an error, got {}", reply);
+        assert_eq!(reply["data"]["flags"], 64);
+        reply["data"]["content"].as_str().unwrap().to_string()
+    };
+
+    bot.dispatch(add_task_mentioning("Channel", "@john")).await;
+    let content = error(bot.discord.interaction_responses().pop().unwrap());
+    assert!(content.contains("`@john` is not a mention"), "{}", content);
+
+    bot.dispatch(add_task_mentioning("DM", "<@42>")).await;
+    let content = error(bot.discord.interaction_responses().pop().unwrap());
+    assert!(content.contains("Direct messages can't ping"), "{}", content);
+
+    bot.dispatch(add_task_mentioning("Channel", "<@&79>")).await;
+    let content = error(bot.discord.interaction_responses().pop().unwrap());
+    assert!(content.contains("not a role of this server"), "{}", content);
+
+    for mention in ["@everyone", "<@&78>"] {
+        bot.dispatch(add_task_mentioning("Both", mention)).await;
+        let content = error(bot.discord.interaction_responses().pop().unwrap());
+        assert!(
+            content.contains("I'm missing the **Mention Everyone** permission"),
+            "{}",
+            content
+        );
+    }
+
+    bot.dispatch(add_task_mentioning("Channel", "<@&77> <@42>"))
+        .await;
+    assert_eq!(bot.discord.interaction_responses().pop().unwrap()["type"], 9);
+}
+
+#[tokio::test]
+async fn commands_past_the_burst_get_a_slow_down_reply() {
+    let bot = TestBot::start().await;
+
+    for _ in 0..5 {
+        assert!(
+            bot.handler
+                .admit_interaction(&bot.http, &slash_command(GUILD_ID, USER_ID, "help", &[]))
+                .await
+        );
+    }
+    assert!(
+        !bot.handler
+            .admit_interaction(&bot.http, &button_click(GUILD_ID, USER_ID, "my_data_delete_cancel"))
+            .await
+    );
+
+    let reply = bot.discord.interaction_responses().pop().unwrap();
+    assert_eq!(reply["data"]["flags"], 64);
+    assert!(
+        reply["data"]["content"]
+            .as_str()
+            .unwrap()
+            .contains("going too fast")
+    );
+
+    // Someone else is unaffected
+    assert!(
+        bot.handler
+            .admit_interaction(&bot.http, &slash_command(GUILD_ID, USER_ID + 1, "help", &[]))
+            .await
+    );
+}
+
+#[tokio::test]
+async fn kick_poll_and_kick_go_through_member_endpoints() {
+    let discord = FakeDiscord::start().await;
+    discord.add_member(GUILD_ID, USER_ID, "target", Some("Nick"));
+
+    // The poll is written in the guild's language
+    let clock = Arc::new(ManualClock::new(start()));
+    let timezone_service = Arc::new(
+        TimezoneService::new(
+            Arc::new(MemoryUserPreferencesRepository::new()),
+            Arc::new(TimezoneManager::new()),
+            clock.clone(),
+        )
+        .with_guild_settings(Arc::new(MemoryGuildSettingsRepository::new())),
+    );
+    let mut settings = GuildSettings::new(GUILD_ID, start());
+    settings.language = Some(Language::Es);
+    timezone_service.save_guild_settings(settings).await.unwrap();
+
+    let kick_service = KickService::new(
+        server_config(),
+        KickConfig {
+            enabled: true,
+            random_config: KickRandomConfig::default(),
+            targets: vec![KickTargetUser {
+                user_id: USER_ID,
+                display_name: "Target".to_string(),
+                kick_probability: None,
+                last_kick_time: None,
+            }],
+        },
+        discord.http(),
+        clock,
+    )
+    .with_languages(timezone_service);
+
+    kick_service.send_kick_poll_for_user(USER_ID).await.unwrap();
+    let poll = discord
+        .wait_for_request(Method::POST, "/channels/777/messages")
+        .await;
+    assert_eq!(poll.body["content"], "Puedo kickear a Nick?");
+    assert_eq!(
+        poll.body["components"][0]["components"][0]["custom_id"],
+        "kick_yes"
+    );
+
+    kick_service.execute_kick(USER_ID).await.unwrap();
+    assert!(discord.member(GUILD_ID, USER_ID).is_none());
+
+    // The member is gone, a second kick surfaces Discord's error
+    assert!(kick_service.execute_kick(USER_ID).await.is_err());
+}
+
+#[tokio::test]
+async fn nickname_change_reads_and_edits_the_member() {
+    let discord = FakeDiscord::start().await;
+    discord.add_member(GUILD_ID, USER_ID, "target", None);
+
+    let nickname_service = NicknameChangerService::new(
+        server_config(),
+        NicknameConfig {
+            enabled: true,
+            random_config: RandomConfig::default(),
+            targets: vec![TargetUser {
+                user_id: USER_ID,
+                display_name: "Target".to_string(),
+                change_probability: None,
+                last_change_time: None,
+            }],
+        },
+        vec!["Pancho".to_string()],
+        discord.http(),
+        Arc::new(ManualClock::new(start())),
+    );
+
+    nickname_service
+        .change_nickname_for_user(USER_ID)
+        .await
+        .unwrap();
+
+    assert_eq!(discord.member(GUILD_ID, USER_ID).unwrap()["nick"], "Pancho");
+    let announcement = discord
+        .wait_for_request(Method::POST, "/channels/777/messages")
+        .await;
+    assert_eq!(
+        announcement.body["content"],
+        "target, no me gusta tu nombre. Te lo puedo cambiar?\n*target → Pancho*"
+    );
+}
+
+/// Log sink for one test, collects the formatted output
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn interaction_logs_carry_the_user_guild_and_created_task() {
+    use tracing::instrument::WithSubscriber;
+
+    let bot = TestBot::start().await;
+    bot.handler
+        .timezone_service
+        .set_user_timezone(USER_ID, "UTC")
+        .await
+        .unwrap();
+
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true)
+        .with_span_list(true)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    bot.add_task(
+        &[
+            ("notification_method", json!("DM")),
+            ("task_type", json!("single")),
+        ],
+        &task_inputs("Standup", "2025-01-06", "13:00"),
+    )
+    .with_subscriber(subscriber)
+    .await;
+
+    let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    let scheduled: Value = output
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .find(|line| {
+            line["fields"]["message"]
+                .as_str()
+                .is_some_and(|message| message.starts_with("Task #1 scheduled"))
+        })
+        .unwrap_or_else(|| panic!("no scheduling log in:\n{}", output));
+
+    let span = &scheduled["span"];
+    assert_eq!(span["name"], "interaction");
+    assert_eq!(span["kind"], "modal");
+    assert_eq!(span["user_id"], USER_ID);
+    assert_eq!(span["guild_id"], GUILD_ID);
+    assert_eq!(span["task_id"], 1);
+    assert!(span["interaction_id"].is_string());
+}
@@ -1 +1,6 @@
 pub mod bot;
+
+#[cfg(test)]
+mod fake_discord;
+#[cfg(test)]
+mod integration_tests;
@@ -1,5 +1,9 @@
+pub mod admin_cli;
+pub mod config;
 pub mod discord_bot;
 pub mod database;
+pub mod notifications;
+pub mod observability;
 pub mod repositories;
 pub mod scheduler;
 pub mod timezone;
\ No newline at end of file
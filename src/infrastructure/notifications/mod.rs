@@ -0,0 +1,6 @@
+pub mod recording_notification_sink;
+pub mod serenity_notification_sink;
+
+#[allow(unused_imports)]
+pub use recording_notification_sink::{RecordedNotification, RecordingNotificationSink};
+pub use serenity_notification_sink::SerenityNotificationSink;
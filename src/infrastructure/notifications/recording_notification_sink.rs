@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use crate::domain::notification_sink::{NotificationSink, OutgoingMessage, SentMessage};
+
+/// One call made against the sink
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordedNotification {
+    Dm {
+        user_id: u64,
+        message: OutgoingMessage,
+    },
+    Channel {
+        channel_id: u64,
+        message: OutgoingMessage,
+    },
+    Edit {
+        sent: SentMessage,
+        message: OutgoingMessage,
+    },
+}
+
+/// In-memory NotificationSink that records every delivery instead of talking to Discord
+/// Used to exercise the scheduler and notification flow offline
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct RecordingNotificationSink {
+    records: Mutex<Vec<RecordedNotification>>,
+    next_message_id: AtomicU64,
+    failing: AtomicBool,
+}
+
+#[allow(dead_code)]
+impl RecordingNotificationSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of everything delivered so far, in order
+    pub fn records(&self) -> Vec<RecordedNotification> {
+        self.records.lock().unwrap().clone()
+    }
+
+    /// Make every following call fail (simulates Discord being unreachable) until reset
+    pub fn set_failing(&self, failing: bool) {
+        self.failing.store(failing, Ordering::SeqCst);
+    }
+
+    fn record(&self, channel_id: u64, record: RecordedNotification) -> Result<SentMessage, String> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err("Recording sink is set to fail".to_string());
+        }
+
+        self.records.lock().unwrap().push(record);
+        Ok(SentMessage {
+            channel_id,
+            message_id: self.next_message_id.fetch_add(1, Ordering::SeqCst) + 1,
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationSink for RecordingNotificationSink {
+    async fn send_dm(&self, user_id: u64, message: OutgoingMessage) -> Result<SentMessage, String> {
+        // DM channels are keyed by user in the recording
+        self.record(user_id, RecordedNotification::Dm { user_id, message })
+    }
+
+    async fn send_to_channel(
+        &self,
+        channel_id: u64,
+        message: OutgoingMessage,
+    ) -> Result<SentMessage, String> {
+        self.record(
+            channel_id,
+            RecordedNotification::Channel {
+                channel_id,
+                message,
+            },
+        )
+    }
+
+    async fn edit_message(
+        &self,
+        sent: SentMessage,
+        message: OutgoingMessage,
+    ) -> Result<(), String> {
+        self.record(
+            sent.channel_id,
+            RecordedNotification::Edit { sent, message },
+        )
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use serenity::builder::{CreateAllowedMentions, CreateEmbed, CreateMessage, EditMessage};
+use serenity::http::Http;
+use serenity::model::colour::Color;
+use serenity::model::id::{ChannelId, MessageId, RoleId, UserId};
+use std::sync::Arc;
+
+use crate::domain::notification_sink::{
+    MessageEmbed, NotificationSink, OutgoingMessage, SentMessage,
+};
+use crate::domain::value_objects::MentionTarget;
+
+/// NotificationSink backed by the Discord REST API
+pub struct SerenityNotificationSink {
+    http: Arc<Http>,
+}
+
+impl SerenityNotificationSink {
+    pub fn new(http: Arc<Http>) -> Self {
+        Self { http }
+    }
+
+    fn to_create_embed(embed: &MessageEmbed) -> CreateEmbed {
+        let mut builder = CreateEmbed::new();
+        if let Some(title) = &embed.title {
+            builder = builder.title(title);
+        }
+        if let Some(description) = &embed.description {
+            builder = builder.description(description);
+        }
+        if let Some(color) = embed.color {
+            builder = builder.color(Color::new(color));
+        }
+        for field in &embed.fields {
+            builder = builder.field(&field.name, &field.value, field.inline);
+        }
+        builder
+    }
+
+    /// Only the listed targets are pinged, whatever else the content contains
+    fn to_allowed_mentions(targets: &[MentionTarget]) -> CreateAllowedMentions {
+        let users = targets.iter().filter_map(|target| match target {
+            MentionTarget::User(id) => Some(UserId::new(*id)),
+            _ => None,
+        });
+        let roles = targets.iter().filter_map(|target| match target {
+            MentionTarget::Role(id) => Some(RoleId::new(*id)),
+            _ => None,
+        });
+
+        CreateAllowedMentions::new()
+            .users(users)
+            .roles(roles)
+            .everyone(targets.iter().any(MentionTarget::is_broadcast))
+    }
+
+    fn to_create_message(message: &OutgoingMessage) -> CreateMessage {
+        let mut builder = CreateMessage::new()
+            .allowed_mentions(Self::to_allowed_mentions(&message.allowed_mentions));
+        if let Some(content) = &message.content {
+            builder = builder.content(content);
+        }
+        if let Some(embed) = &message.embed {
+            builder = builder.embed(Self::to_create_embed(embed));
+        }
+        builder
+    }
+}
+
+#[async_trait]
+impl NotificationSink for SerenityNotificationSink {
+    async fn send_dm(&self, user_id: u64, message: OutgoingMessage) -> Result<SentMessage, String> {
+        let user_id = UserId::new(user_id);
+
+        let dm_channel = user_id
+            .create_dm_channel(&self.http)
+            .await
+            .map_err(|e| format!("Failed to create DM channel for user {}: {}", user_id, e))?;
+
+        let sent = dm_channel
+            .send_message(&self.http, Self::to_create_message(&message))
+            .await
+            .map_err(|e| format!("Failed to send DM to user {}: {}", user_id, e))?;
+
+        Ok(SentMessage {
+            channel_id: sent.channel_id.get(),
+            message_id: sent.id.get(),
+        })
+    }
+
+    async fn send_to_channel(
+        &self,
+        channel_id: u64,
+        message: OutgoingMessage,
+    ) -> Result<SentMessage, String> {
+        let sent = ChannelId::new(channel_id)
+            .send_message(&self.http, Self::to_create_message(&message))
+            .await
+            .map_err(|e| format!("Failed to send message to channel {}: {}", channel_id, e))?;
+
+        Ok(SentMessage {
+            channel_id: sent.channel_id.get(),
+            message_id: sent.id.get(),
+        })
+    }
+
+    async fn edit_message(
+        &self,
+        sent: SentMessage,
+        message: OutgoingMessage,
+    ) -> Result<(), String> {
+        let mut builder = EditMessage::new()
+            .allowed_mentions(Self::to_allowed_mentions(&message.allowed_mentions));
+        if let Some(content) = &message.content {
+            builder = builder.content(content);
+        }
+        if let Some(embed) = &message.embed {
+            builder = builder.embed(Self::to_create_embed(embed));
+        }
+
+        ChannelId::new(sent.channel_id)
+            .edit_message(&self.http, MessageId::new(sent.message_id), builder)
+            .await
+            .map_err(|e| format!("Failed to edit message {}: {}", sent.message_id, e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use lazy_static::lazy_static;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+lazy_static! {
+    static ref HEALTH: Health = Health::new();
+}
+
+/// Process-wide liveness flags reported on `/healthz`
+pub fn health() -> &'static Health {
+    &HEALTH
+}
+
+/// Liveness of the long-running parts of the bot
+pub struct Health {
+    gateway_connected: AtomicBool,
+    schedulers_running: AtomicUsize,
+}
+
+/// Keeps the scheduler counted as running until dropped (loop exit or panic)
+pub struct SchedulerRunning {
+    health: &'static Health,
+}
+
+impl Health {
+    pub fn new() -> Self {
+        Self {
+            gateway_connected: AtomicBool::new(false),
+            schedulers_running: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn set_gateway_connected(&self, connected: bool) {
+        self.gateway_connected.store(connected, Ordering::Relaxed);
+    }
+
+    pub fn gateway_connected(&self) -> bool {
+        self.gateway_connected.load(Ordering::Relaxed)
+    }
+
+    /// Called by a scheduler loop when it starts, hold the guard for as long as it runs
+    pub fn scheduler_started(&'static self) -> SchedulerRunning {
+        self.schedulers_running.fetch_add(1, Ordering::Relaxed);
+        SchedulerRunning { health: self }
+    }
+
+    pub fn scheduler_alive(&self) -> bool {
+        self.schedulers_running.load(Ordering::Relaxed) > 0
+    }
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SchedulerRunning {
+    fn drop(&mut self) {
+        self.health.schedulers_running.fetch_sub(1, Ordering::Relaxed);
+    }
+}
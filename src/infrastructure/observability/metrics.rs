@@ -0,0 +1,178 @@
+use crate::domain::NotificationMethod;
+use lazy_static::lazy_static;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+
+lazy_static! {
+    static ref METRICS: Metrics = Metrics::new();
+}
+
+/// Process-wide metrics, recorded wherever the event happens and served on `/metrics`
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+/// Seconds between a task's scheduled time and its notification going out
+const DELIVERY_LATENCY_BUCKETS: &[f64] = &[
+    0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0,
+];
+
+/// Prometheus collectors for the bot, all registered in their own registry
+pub struct Metrics {
+    registry: Registry,
+    scheduler_queue_depth: IntGauge,
+    delivery_latency_seconds: HistogramVec,
+    notifications_total: IntCounterVec,
+    commands_total: IntCounterVec,
+    feature_events_total: IntCounterVec,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let scheduler_queue_depth = IntGauge::new(
+            "daily_scheduler_queue_depth",
+            "Live entries waiting in the scheduler queue",
+        )
+        .unwrap();
+        let delivery_latency_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "daily_notification_delivery_latency_seconds",
+                "Delay between a task's scheduled time and its notification being sent",
+            )
+            .buckets(DELIVERY_LATENCY_BUCKETS.to_vec()),
+            &["method"],
+        )
+        .unwrap();
+        let notifications_total = IntCounterVec::new(
+            Opts::new(
+                "daily_notifications_total",
+                "Task notifications by delivery method and outcome",
+            ),
+            &["method", "outcome"],
+        )
+        .unwrap();
+        let commands_total = IntCounterVec::new(
+            Opts::new("daily_commands_total", "Slash command invocations"),
+            &["command"],
+        )
+        .unwrap();
+        let feature_events_total = IntCounterVec::new(
+            Opts::new(
+                "daily_server_feature_events_total",
+                "Events from the server-specific features (kicks, nicknames, voice actions)",
+            ),
+            &["feature", "event"],
+        )
+        .unwrap();
+
+        // Names are unique and static, registration can only fail on a programming error
+        registry
+            .register(Box::new(scheduler_queue_depth.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(delivery_latency_seconds.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(notifications_total.clone()))
+            .unwrap();
+        registry.register(Box::new(commands_total.clone())).unwrap();
+        registry
+            .register(Box::new(feature_events_total.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            scheduler_queue_depth,
+            delivery_latency_seconds,
+            notifications_total,
+            commands_total,
+            feature_events_total,
+        }
+    }
+
+    pub fn set_scheduler_queue_depth(&self, depth: usize) {
+        self.scheduler_queue_depth.set(depth as i64);
+    }
+
+    /// A notification went out `latency` after its scheduled time
+    pub fn record_delivery(&self, method: &NotificationMethod, latency: chrono::Duration) {
+        let method = method_label(method);
+        let seconds = latency.num_milliseconds().max(0) as f64 / 1000.0;
+        self.delivery_latency_seconds
+            .with_label_values(&[method])
+            .observe(seconds);
+        self.notifications_total
+            .with_label_values(&[method, "delivered"])
+            .inc();
+    }
+
+    pub fn record_delivery_failure(&self, method: &NotificationMethod) {
+        self.notifications_total
+            .with_label_values(&[method_label(method), "failed"])
+            .inc();
+    }
+
+    pub fn record_command(&self, command: &str) {
+        self.commands_total.with_label_values(&[command]).inc();
+    }
+
+    /// e.g. `("kick", "executed")`, `("nickname", "failed")`
+    pub fn record_feature_event(&self, feature: &str, event: &str) {
+        self.feature_events_total
+            .with_label_values(&[feature, event])
+            .inc();
+    }
+
+    /// Everything in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        // Encoding into a Vec can't fail for metrics we built ourselves
+        let _ = TextEncoder::new().encode(&self.registry.gather(), &mut buffer);
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn method_label(method: &NotificationMethod) -> &'static str {
+    match method {
+        NotificationMethod::DM => "dm",
+        NotificationMethod::Channel => "channel",
+        NotificationMethod::Both => "both",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_exposes_recorded_values() {
+        let metrics = Metrics::new();
+        metrics.set_scheduler_queue_depth(3);
+        metrics.record_delivery(&NotificationMethod::DM, chrono::Duration::milliseconds(400));
+        metrics.record_delivery_failure(&NotificationMethod::Channel);
+        metrics.record_command("add_task");
+        metrics.record_command("add_task");
+        metrics.record_feature_event("kick", "executed");
+
+        let text = metrics.render();
+        assert!(text.contains("daily_scheduler_queue_depth 3"));
+        assert!(text.contains(
+            "daily_notification_delivery_latency_seconds_bucket{method=\"dm\",le=\"0.5\"} 1"
+        ));
+        assert!(text.contains("daily_notifications_total{method=\"dm\",outcome=\"delivered\"} 1"));
+        assert!(text.contains("daily_notifications_total{method=\"channel\",outcome=\"failed\"} 1"));
+        assert!(text.contains("daily_commands_total{command=\"add_task\"} 2"));
+        assert!(text.contains(
+            "daily_server_feature_events_total{event=\"executed\",feature=\"kick\"} 1"
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod health;
+pub mod metrics;
+pub mod server;
+
+pub use health::{Health, health};
+pub use metrics::{Metrics, metrics};
+pub use server::ObservabilityState;
@@ -0,0 +1,180 @@
+use super::{Health, Metrics};
+use crate::domain::repositories::TaskSchedulerRepository;
+use crate::infrastructure::database::DatabaseManager;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde_json::json;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Everything the endpoint reads to answer `/healthz` and `/metrics`
+pub struct ObservabilityState {
+    pub scheduler_repo: Arc<dyn TaskSchedulerRepository>,
+    /// None for the in-memory backend, which has nothing to check
+    pub db_manager: Option<Arc<DatabaseManager>>,
+    pub health: &'static Health,
+    pub metrics: &'static Metrics,
+}
+
+/// Serve `/healthz` and `/metrics` on `addr` until the process exits
+pub async fn serve(addr: SocketAddr, state: Arc<ObservabilityState>) -> Result<(), hyper::Error> {
+    let make_service = make_service_fn(move |_| {
+        let state = state.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let state = state.clone();
+                async move { Ok::<_, Infallible>(handle(&request, &state).await) }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)?.serve(make_service);
+    info!("Health and metrics endpoint listening on http://{}", server.local_addr());
+    server.await
+}
+
+pub(crate) async fn handle(request: &Request<Body>, state: &ObservabilityState) -> Response<Body> {
+    match (request.method(), request.uri().path()) {
+        (&Method::GET, "/healthz") => healthz(state).await,
+        (&Method::GET, "/metrics") => metrics(state).await,
+        _ => response(StatusCode::NOT_FOUND, "text/plain", "Not found".to_string()),
+    }
+}
+
+/// 200 when the gateway is connected, a scheduler loop is running and the database accepts writes, 503 otherwise
+async fn healthz(state: &ObservabilityState) -> Response<Body> {
+    let gateway = state.health.gateway_connected();
+    let scheduler = state.health.scheduler_alive();
+    let database = match &state.db_manager {
+        Some(db_manager) => match db_manager.check_writable().await {
+            Ok(()) => true,
+            Err(e) => {
+                error!("Health check could not write to the database: {}", e);
+                false
+            }
+        },
+        None => true,
+    };
+
+    let healthy = gateway && scheduler && database;
+    let body = json!({
+        "status": if healthy { "ok" } else { "unhealthy" },
+        "gateway_connected": gateway,
+        "scheduler_alive": scheduler,
+        "database_writable": database,
+    });
+
+    let status = if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    response(status, "application/json", body.to_string())
+}
+
+async fn metrics(state: &ObservabilityState) -> Response<Body> {
+    // Queue depth is sampled on scrape rather than tracked on every queue change
+    match state.scheduler_repo.pending_count().await {
+        Ok(depth) => state.metrics.set_scheduler_queue_depth(depth),
+        Err(e) => error!("Failed to read scheduler queue depth: {}", e),
+    }
+
+    response(
+        StatusCode::OK,
+        "text/plain; version=0.0.4",
+        state.metrics.render(),
+    )
+}
+
+fn response(status: StatusCode, content_type: &str, body: String) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", content_type)
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::scheduled_task::ScheduledTask;
+    use crate::domain::entities::task::Task;
+    use crate::infrastructure::repositories::MemorySchedulerRepository;
+    use chrono::Utc;
+
+    fn state(scheduler_repo: Arc<MemorySchedulerRepository>) -> ObservabilityState {
+        ObservabilityState {
+            scheduler_repo,
+            db_manager: None,
+            health: Box::leak(Box::new(Health::new())),
+            metrics: Box::leak(Box::new(Metrics::new())),
+        }
+    }
+
+    async fn get(state: &ObservabilityState, path: &str) -> (StatusCode, String) {
+        let request = Request::get(path).body(Body::empty()).unwrap();
+        let response = handle(&request, state).await;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn healthz_reports_each_component() {
+        let state = state(Arc::new(MemorySchedulerRepository::new()));
+
+        let (status, body) = get(&state, "/healthz").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(body.contains("\"gateway_connected\":false"));
+        assert!(body.contains("\"scheduler_alive\":false"));
+
+        state.health.set_gateway_connected(true);
+        let running = state.health.scheduler_started();
+        let (status, body) = get(&state, "/healthz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("\"status\":\"ok\""));
+
+        drop(running);
+        let (status, _) = get(&state, "/healthz").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn healthz_checks_the_database_is_writable() {
+        let dir = std::env::temp_dir().join(format!("daily-healthz-{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_manager = Arc::new(DatabaseManager::new(dir.join("bot.db")).unwrap());
+
+        let mut state = state(Arc::new(MemorySchedulerRepository::new()));
+        state.db_manager = Some(db_manager);
+        state.health.set_gateway_connected(true);
+        let _running = state.health.scheduler_started();
+
+        let (status, body) = get(&state, "/healthz").await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        assert!(body.contains("\"database_writable\":true"));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn metrics_samples_queue_depth_on_scrape() {
+        let scheduler_repo = Arc::new(MemorySchedulerRepository::new());
+        let state = state(scheduler_repo.clone());
+
+        let task = Task::for_test(1, "t");
+        scheduler_repo
+            .add_scheduled_task(ScheduledTask::new(1, Utc::now(), &task))
+            .await
+            .unwrap();
+
+        let (status, body) = get(&state, "/metrics").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("daily_scheduler_queue_depth 1"));
+
+        let (status, _) = get(&state, "/nope").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
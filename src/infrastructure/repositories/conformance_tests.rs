@@ -0,0 +1,847 @@
+//! Shared conformance suite: every repository implementation must pass the same checks,
+//! so the in-memory backends can stand in for SQLite without behaving differently.
+
+use chrono::{DateTime, TimeZone, Utc, Weekday};
+
+use crate::domain::entities::guild_settings::GuildSettings;
+use crate::domain::entities::scheduled_task::ScheduledTask;
+use crate::domain::entities::user_preferences::UserPreferences;
+use crate::domain::repositories::user_preferences_repository::RepositoryError;
+use crate::domain::repositories::{
+    GuildSettingsRepository, SchedulerError, TaskRepository, TaskSchedulerRepository,
+    UserPreferencesRepository,
+};
+use crate::domain::value_objects::{ClockFormat, Language, MentionTarget};
+use crate::domain::{NotificationMethod, Recurrence, Task};
+
+use super::{
+    MemoryGuildSettingsRepository, MemorySchedulerRepository, MemoryTaskRepository,
+    MemoryUserPreferencesRepository, SqliteGuildSettingsRepository, SqliteSchedulerRepository,
+    SqliteTaskRepository, SqliteUserPreferencesRepository,
+};
+
+// Whole seconds only, SQLite stores timestamps with second precision
+fn at(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+}
+
+fn scheduled(task_id: u64, secs: i64) -> ScheduledTask {
+    ScheduledTask::new(task_id, at(secs), &Task::for_test(1, &format!("task {}", task_id)))
+}
+
+mod task_repository {
+    use super::*;
+
+    pub async fn add_assigns_sequential_ids(repo: &dyn TaskRepository) {
+        assert_eq!(repo.add_task(Task::for_test(1, "first")).await.unwrap(), 1);
+        assert_eq!(repo.add_task(Task::for_test(1, "second")).await.unwrap(), 2);
+
+        let ids: Vec<u64> = repo.list_tasks().await.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    pub async fn round_trips_all_fields(repo: &dyn TaskRepository) {
+        let mut task = Task::for_test(7, "weekly").scheduled_at(at(0));
+        task.description = Some("description".to_string());
+        task.recurrence = Some(Recurrence::Weekly {
+            days: vec![Weekday::Mon, Weekday::Fri],
+            hour: 9,
+            minute: 30,
+        });
+        task.notification_method = NotificationMethod::Channel;
+        task.channel_id = Some(1234);
+        task.mentions = vec![MentionTarget::Role(99), MentionTarget::Here];
+        task.timezone = Some("America/New_York".to_string());
+
+        let id = repo.add_task(task).await.unwrap();
+        let stored = repo.list_tasks().await.into_iter().find(|t| t.id == id).unwrap();
+
+        assert_eq!(stored.user_id, 7);
+        assert_eq!(stored.guild_id, 10);
+        assert_eq!(stored.title, "weekly");
+        assert_eq!(stored.description.as_deref(), Some("description"));
+        assert_eq!(stored.scheduled_time, Some(at(0)));
+        assert!(matches!(
+            stored.recurrence,
+            Some(Recurrence::Weekly { ref days, hour: 9, minute: 30 })
+                if days == &vec![Weekday::Mon, Weekday::Fri]
+        ));
+        assert!(matches!(stored.notification_method, NotificationMethod::Channel));
+        assert_eq!(stored.channel_id, Some(1234));
+        assert_eq!(
+            stored.mentions,
+            vec![MentionTarget::Role(99), MentionTarget::Here]
+        );
+        assert_eq!(stored.timezone.as_deref(), Some("America/New_York"));
+
+        // Some(None) goes back to the owner's timezone
+        let edited = repo
+            .edit_task(id, None, None, None, None, None, Some(None))
+            .await
+            .unwrap();
+        assert_eq!(edited.timezone, None);
+        let stored = repo.list_tasks().await.into_iter().find(|t| t.id == id).unwrap();
+        assert_eq!(stored.timezone, None);
+    }
+
+    pub async fn edit_updates_only_given_fields(repo: &dyn TaskRepository) {
+        let mut task = Task::for_test(1, "original");
+        task.description = Some("description".to_string());
+        let id = repo.add_task(task).await.unwrap();
+
+        let edited = repo
+            .edit_task(id, Some("renamed".to_string()), None, Some(at(60)), None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(edited.title, "renamed");
+        assert_eq!(edited.description.as_deref(), Some("description"));
+        assert_eq!(edited.scheduled_time, Some(at(60)));
+
+        let stored = repo.list_tasks().await.into_iter().find(|t| t.id == id).unwrap();
+        assert_eq!(stored.title, "renamed");
+        assert_eq!(stored.scheduled_time, Some(at(60)));
+    }
+
+    pub async fn edit_rejects_missing_task_and_empty_title(repo: &dyn TaskRepository) {
+        let missing = repo.edit_task(99, Some("x".to_string()), None, None, None, None, None).await;
+        assert_eq!(missing.unwrap_err(), "Couldn't find task with ID 99");
+
+        let id = repo.add_task(Task::for_test(1, "keep me")).await.unwrap();
+        assert!(repo.edit_task(id, Some("  ".to_string()), None, None, None, None, None).await.is_err());
+
+        let stored = repo.list_tasks().await.into_iter().find(|t| t.id == id).unwrap();
+        assert_eq!(stored.title, "keep me");
+    }
+
+    pub async fn remove_reports_whether_task_existed(repo: &dyn TaskRepository) {
+        let id = repo.add_task(Task::for_test(1, "gone")).await.unwrap();
+
+        assert!(repo.remove_task(id).await);
+        assert!(!repo.remove_task(id).await);
+        assert!(repo.list_tasks().await.is_empty());
+    }
+
+    pub async fn remove_all_by_user_only_touches_that_user(repo: &dyn TaskRepository) {
+        repo.add_task(Task::for_test(1, "a")).await.unwrap();
+        repo.add_task(Task::for_test(1, "b")).await.unwrap();
+        repo.add_task(Task::for_test(2, "c")).await.unwrap();
+
+        assert_eq!(repo.remove_all_by_user(1).await, 2);
+        assert_eq!(repo.remove_all_by_user(1).await, 0);
+
+        let remaining = repo.list_tasks().await;
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].user_id, 2);
+    }
+
+    pub async fn update_task_time_and_count(repo: &dyn TaskRepository) {
+        let id = repo.add_task(Task::for_test(1, "moving")).await.unwrap();
+        repo.add_task(Task::for_test(1, "other")).await.unwrap();
+
+        repo.update_task_time(id, at(3600)).await.unwrap();
+        // Updating an unknown task is a no-op, not an error
+        repo.update_task_time(99, at(3600)).await.unwrap();
+
+        let stored = repo.list_tasks().await.into_iter().find(|t| t.id == id).unwrap();
+        assert_eq!(stored.scheduled_time, Some(at(3600)));
+        assert_eq!(repo.get_total_task_count().await.unwrap(), 2);
+    }
+
+    pub async fn update_schedules_is_all_or_nothing(repo: &dyn TaskRepository) {
+        let single = repo.add_task(Task::for_test(1, "single").scheduled_at(at(0))).await.unwrap();
+        let weekly = repo.add_task(Task::for_test(1, "weekly").scheduled_at(at(0))).await.unwrap();
+
+        // One unknown task rolls back the whole batch
+        let failed = repo
+            .update_schedules(vec![(single, Some(at(60)), None), (99, Some(at(60)), None)])
+            .await;
+        assert!(failed.is_err());
+        let stored = repo.list_tasks().await;
+        assert_eq!(stored[0].scheduled_time, Some(at(0)));
+
+        repo.update_schedules(vec![
+            (single, Some(at(60)), None),
+            (
+                weekly,
+                Some(at(120)),
+                Some(Recurrence::Weekly {
+                    days: vec![Weekday::Tue],
+                    hour: 8,
+                    minute: 15,
+                }),
+            ),
+        ])
+        .await
+        .unwrap();
+        let stored = repo.list_tasks().await;
+        assert_eq!(stored[0].scheduled_time, Some(at(60)));
+        assert_eq!(stored[1].scheduled_time, Some(at(120)));
+        assert!(matches!(
+            &stored[1].recurrence,
+            Some(Recurrence::Weekly { days, hour: 8, minute: 15 }) if days == &[Weekday::Tue]
+        ));
+        assert_eq!(stored[1].title, "weekly");
+    }
+
+    pub async fn move_to_dm_drops_channel_and_mention(repo: &dyn TaskRepository) {
+        let mut task = Task::for_test(1, "standup");
+        task.notification_method = NotificationMethod::Both;
+        task.channel_id = Some(555);
+        task.mentions = vec![MentionTarget::Role(7)];
+        let id = repo.add_task(task).await.unwrap();
+
+        let moved = repo.move_to_dm(id).await.unwrap();
+        assert!(matches!(moved.notification_method, NotificationMethod::DM));
+        assert_eq!(moved.channel_id, None);
+        assert!(moved.mentions.is_empty());
+        assert_eq!(moved.title, "standup");
+
+        let stored = repo.list_tasks().await.into_iter().find(|t| t.id == id).unwrap();
+        assert!(matches!(stored.notification_method, NotificationMethod::DM));
+        assert_eq!(stored.channel_id, None);
+        assert!(repo.move_to_dm(99).await.is_err());
+    }
+
+    pub async fn archived_tasks_are_hidden_until_restored(repo: &dyn TaskRepository) {
+        let kept = repo.add_task(Task::for_test(1, "kept")).await.unwrap();
+        let paused = repo.add_task(Task::for_test(1, "paused")).await.unwrap();
+
+        let archived = repo.set_archived(paused, true).await.unwrap();
+        assert_eq!(archived.title, "paused");
+        let active: Vec<u64> = repo.list_tasks().await.iter().map(|t| t.id).collect();
+        assert_eq!(active, vec![kept]);
+        let hidden: Vec<u64> = repo.list_archived().await.iter().map(|t| t.id).collect();
+        assert_eq!(hidden, vec![paused]);
+
+        repo.set_archived(paused, false).await.unwrap();
+        assert_eq!(repo.list_tasks().await.len(), 2);
+        assert!(repo.list_archived().await.is_empty());
+
+        repo.set_archived(paused, true).await.unwrap();
+        assert!(repo.remove_task(paused).await);
+        assert!(repo.list_archived().await.is_empty());
+        assert!(repo.set_archived(99, true).await.is_err());
+    }
+}
+
+mod user_preferences_repository {
+    use super::*;
+
+    pub async fn get_missing_returns_none(repo: &dyn UserPreferencesRepository) {
+        assert!(repo.get(1).await.unwrap().is_none());
+    }
+
+    pub async fn save_and_get_round_trip(repo: &dyn UserPreferencesRepository) {
+        let mut prefs = UserPreferences::new_with_format(
+            1,
+            "Europe/Madrid".to_string(),
+            Some("DMY".to_string()),
+            at(0),
+        );
+        prefs.working_hours = Some("08:30-16:00".parse().unwrap());
+        prefs.date_format_chosen = true;
+        prefs.clock_format = ClockFormat::H12;
+        prefs.first_weekday = Some(Weekday::Sun);
+        prefs.language = Some(Language::Es);
+        repo.save(&prefs).await.unwrap();
+
+        let stored = repo.get(1).await.unwrap().unwrap();
+        assert_eq!(stored.user_id, 1);
+        assert_eq!(stored.timezone, "Europe/Madrid");
+        assert_eq!(stored.date_format.as_deref(), Some("DMY"));
+        assert_eq!(stored.working_hours, prefs.working_hours);
+        assert!(stored.date_format_chosen);
+        assert_eq!(stored.clock_format, ClockFormat::H12);
+        assert_eq!(stored.first_weekday, Some(Weekday::Sun));
+        assert_eq!(stored.language, Some(Language::Es));
+    }
+
+    pub async fn save_rejects_invalid_preferences(repo: &dyn UserPreferencesRepository) {
+        let prefs = UserPreferences::new_with_format(1, String::new(), None, at(0));
+
+        assert!(matches!(repo.save(&prefs).await, Err(RepositoryError::InvalidData(_))));
+        assert!(repo.get(1).await.unwrap().is_none());
+    }
+
+    pub async fn save_overwrites_but_keeps_created_at(repo: &dyn UserPreferencesRepository) {
+        let first = UserPreferences::new_with_format(1, "UTC".to_string(), None, at(0));
+        repo.save(&first).await.unwrap();
+
+        let second = UserPreferences::new_with_format(
+            1,
+            "America/Bogota".to_string(),
+            Some("MDY".to_string()),
+            at(100),
+        );
+        repo.save(&second).await.unwrap();
+
+        let stored = repo.get(1).await.unwrap().unwrap();
+        assert_eq!(stored.timezone, "America/Bogota");
+        assert_eq!(stored.date_format.as_deref(), Some("MDY"));
+        assert_eq!(stored.created_at.timestamp(), at(0).timestamp());
+        assert_eq!(stored.updated_at.timestamp(), at(100).timestamp());
+    }
+
+    pub async fn list_all_orders_by_user(repo: &dyn UserPreferencesRepository) {
+        assert!(repo.list_all().await.unwrap().is_empty());
+
+        for user_id in [3, 1, 2] {
+            let prefs = UserPreferences::new_with_format(user_id, "UTC".to_string(), None, at(0));
+            repo.save(&prefs).await.unwrap();
+        }
+
+        let users: Vec<u64> = repo.list_all().await.unwrap().iter().map(|p| p.user_id).collect();
+        assert_eq!(users, vec![1, 2, 3]);
+    }
+
+    pub async fn delete_existing_then_missing(repo: &dyn UserPreferencesRepository) {
+        let prefs = UserPreferences::new_with_format(1, "UTC".to_string(), None, at(0));
+        repo.save(&prefs).await.unwrap();
+
+        repo.delete(1).await.unwrap();
+        assert!(repo.get(1).await.unwrap().is_none());
+        assert!(matches!(repo.delete(1).await, Err(RepositoryError::NotFound)));
+    }
+}
+
+mod guild_settings_repository {
+    use super::*;
+
+    pub async fn save_and_get_round_trip(repo: &dyn GuildSettingsRepository) {
+        assert!(repo.get(7).await.unwrap().is_none());
+
+        let mut settings = GuildSettings::new(7, at(0));
+        settings.timezone = Some("America/Bogota".to_string());
+        settings.date_format = Some("DMY".to_string());
+        settings.language = Some(Language::Es);
+        repo.save(&settings).await.unwrap();
+
+        assert_eq!(repo.get(7).await.unwrap(), Some(settings));
+    }
+
+    pub async fn save_overwrites_and_clears_fields(repo: &dyn GuildSettingsRepository) {
+        let mut settings = GuildSettings::new(7, at(0));
+        settings.timezone = Some("Europe/Madrid".to_string());
+        settings.language = Some(Language::Es);
+        repo.save(&settings).await.unwrap();
+
+        settings.timezone = None;
+        settings.updated_at = at(100);
+        repo.save(&settings).await.unwrap();
+
+        let stored = repo.get(7).await.unwrap().unwrap();
+        assert_eq!(stored.timezone, None);
+        assert_eq!(stored.language, Some(Language::Es));
+        assert_eq!(stored.updated_at.timestamp(), at(100).timestamp());
+        assert!(matches!(
+            repo.save(&GuildSettings::new(0, at(0))).await,
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    pub async fn delete_existing_then_missing(repo: &dyn GuildSettingsRepository) {
+        repo.save(&GuildSettings::new(7, at(0))).await.unwrap();
+
+        repo.delete(7).await.unwrap();
+        assert!(repo.get(7).await.unwrap().is_none());
+        assert!(matches!(repo.delete(7).await, Err(RepositoryError::NotFound)));
+    }
+}
+
+mod task_scheduler_repository {
+    use super::*;
+
+    /// Earliest live entry, without claiming it
+    async fn next_pending(repo: &dyn TaskSchedulerRepository) -> Option<ScheduledTask> {
+        repo.list_pending().await.unwrap().into_iter().next()
+    }
+
+    /// Claims the earliest entry and completes it, as a delivery would
+    async fn deliver_next(repo: &dyn TaskSchedulerRepository) -> Option<ScheduledTask> {
+        let task = repo.claim_due_task("test", at(100_000), at(100_120)).await.unwrap()?;
+        assert!(repo.complete_claim(&task, "test", at(100_000)).await.unwrap());
+        Some(task)
+    }
+
+    pub async fn delivered_earliest_first(repo: &dyn TaskSchedulerRepository) {
+        assert!(next_pending(repo).await.is_none());
+
+        repo.add_scheduled_task(scheduled(1, 300)).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 100)).await.unwrap();
+        repo.add_scheduled_task(scheduled(3, 200)).await.unwrap();
+
+        assert_eq!(next_pending(repo).await.unwrap().task_id, 2);
+        // Looking does not consume
+        assert_eq!(next_pending(repo).await.unwrap().task_id, 2);
+
+        let order: Vec<u64> = [
+            deliver_next(repo).await,
+            deliver_next(repo).await,
+            deliver_next(repo).await,
+        ]
+        .into_iter()
+        .map(|t| t.unwrap().task_id)
+        .collect();
+        assert_eq!(order, vec![2, 3, 1]);
+        assert!(deliver_next(repo).await.is_none());
+    }
+
+    pub async fn claim_preserves_entry_fields(repo: &dyn TaskSchedulerRepository) {
+        let mut entry = scheduled(5, 10);
+        entry.notification_method = NotificationMethod::Both;
+        entry.is_recurring = true;
+        entry.mentions = vec![MentionTarget::User(123), MentionTarget::Everyone];
+        repo.add_scheduled_task(entry).await.unwrap();
+
+        let claimed = deliver_next(repo).await.unwrap();
+        assert_eq!(claimed.task_id, 5);
+        assert_eq!(claimed.scheduled_time, at(10));
+        assert_eq!(claimed.user_id, 1);
+        assert_eq!(claimed.guild_id, 10);
+        assert_eq!(claimed.title, "task 5");
+        assert!(matches!(claimed.notification_method, NotificationMethod::Both));
+        assert!(claimed.is_recurring);
+        assert!(!claimed.is_deleted);
+        assert_eq!(
+            claimed.mentions,
+            vec![MentionTarget::User(123), MentionTarget::Everyone]
+        );
+    }
+
+    pub async fn removed_tasks_are_hidden(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 200)).await.unwrap();
+
+        repo.remove_task(1).await.unwrap();
+        assert!(matches!(repo.remove_task(1).await, Err(SchedulerError::TaskNotFound)));
+        assert!(matches!(repo.remove_task(99).await, Err(SchedulerError::TaskNotFound)));
+
+        assert_eq!(next_pending(repo).await.unwrap().task_id, 2);
+        assert_eq!(deliver_next(repo).await.unwrap().task_id, 2);
+        assert!(deliver_next(repo).await.is_none());
+    }
+
+    pub async fn re_adding_replaces_existing_entry(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        repo.add_scheduled_task(scheduled(1, 500)).await.unwrap();
+
+        let claimed = deliver_next(repo).await.unwrap();
+        assert_eq!(claimed.scheduled_time, at(500));
+        assert!(deliver_next(repo).await.is_none());
+
+        // A removed task comes back when scheduled again
+        repo.add_scheduled_task(scheduled(2, 100)).await.unwrap();
+        repo.remove_task(2).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 200)).await.unwrap();
+        assert_eq!(next_pending(repo).await.unwrap().scheduled_time, at(200));
+    }
+
+    pub async fn has_pending_ignores_removed(repo: &dyn TaskSchedulerRepository) {
+        assert!(!repo.has_pending_tasks().await.unwrap());
+
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        assert!(repo.has_pending_tasks().await.unwrap());
+
+        repo.remove_task(1).await.unwrap();
+        assert!(!repo.has_pending_tasks().await.unwrap());
+    }
+
+    pub async fn pending_count_tracks_live_entries(repo: &dyn TaskSchedulerRepository) {
+        assert_eq!(repo.pending_count().await.unwrap(), 0);
+
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 200)).await.unwrap();
+        // Rescheduling replaces the entry instead of adding a second one
+        repo.add_scheduled_task(scheduled(2, 300)).await.unwrap();
+        assert_eq!(repo.pending_count().await.unwrap(), 2);
+
+        repo.remove_task(1).await.unwrap();
+        assert_eq!(repo.pending_count().await.unwrap(), 1);
+    }
+
+    pub async fn list_pending_is_in_delivery_order(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 300)).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 100)).await.unwrap();
+        repo.add_scheduled_task(scheduled(3, 200)).await.unwrap();
+        repo.remove_task(3).await.unwrap();
+
+        let ids: Vec<u64> = repo.list_pending().await.unwrap().iter().map(|t| t.task_id).collect();
+        assert_eq!(ids, vec![2, 1]);
+    }
+
+    pub async fn compact_keeps_live_entries(repo: &dyn TaskSchedulerRepository) {
+        for id in 1..=10 {
+            repo.add_scheduled_task(scheduled(id, id as i64)).await.unwrap();
+        }
+        for id in 1..=5 {
+            repo.remove_task(id).await.unwrap();
+        }
+
+        let removed = repo.compact().await.unwrap();
+        assert!(repo.tombstones_removed() >= removed as u64);
+
+        assert_eq!(next_pending(repo).await.unwrap().task_id, 6);
+        let mut remaining = 0;
+        while deliver_next(repo).await.is_some() {
+            remaining += 1;
+        }
+        assert_eq!(remaining, 5);
+    }
+
+    pub async fn add_signals_wakeup(repo: &dyn TaskSchedulerRepository) {
+        let mut wakeup = repo.subscribe_wakeup();
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        assert!(wakeup.try_recv().is_ok());
+    }
+
+    pub async fn claim_takes_earliest_due_task_once(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 200)).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 100)).await.unwrap();
+        repo.add_scheduled_task(scheduled(3, 900)).await.unwrap();
+
+        // Nothing due yet
+        assert!(repo.claim_due_task("a", at(50), at(170)).await.unwrap().is_none());
+
+        let first = repo.claim_due_task("a", at(250), at(370)).await.unwrap().unwrap();
+        assert_eq!((first.task_id, first.scheduled_time), (2, at(100)));
+        assert_eq!(repo.claim_due_task("b", at(250), at(370)).await.unwrap().unwrap().task_id, 1);
+
+        // Both due tasks are leased, the remaining one is not due
+        assert!(repo.claim_due_task("c", at(250), at(370)).await.unwrap().is_none());
+    }
+
+    pub async fn expired_lease_can_be_reclaimed(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        repo.claim_due_task("crashed", at(100), at(220)).await.unwrap().unwrap();
+
+        assert!(repo.claim_due_task("b", at(219), at(339)).await.unwrap().is_none());
+        let reclaimed = repo.claim_due_task("b", at(220), at(340)).await.unwrap().unwrap();
+        assert_eq!(reclaimed.task_id, 1);
+
+        // The new owner completes it, the crashed one no longer can
+        assert!(repo.complete_claim(&reclaimed, "b", at(230)).await.unwrap());
+        assert!(!repo.complete_claim(&reclaimed, "crashed", at(231)).await.unwrap());
+        assert!(next_pending(repo).await.is_none());
+    }
+
+    pub async fn next_claimable_time_waits_for_leases(repo: &dyn TaskSchedulerRepository) {
+        assert!(repo.next_claimable_time().await.unwrap().is_none());
+
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 500)).await.unwrap();
+        assert_eq!(repo.next_claimable_time().await.unwrap(), Some(at(100)));
+
+        repo.claim_due_task("a", at(100), at(220)).await.unwrap().unwrap();
+        assert_eq!(repo.next_claimable_time().await.unwrap(), Some(at(220)));
+
+        repo.remove_task(1).await.unwrap();
+        assert_eq!(repo.next_claimable_time().await.unwrap(), Some(at(500)));
+    }
+
+    pub async fn release_reschedules_and_frees_the_lease(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        repo.claim_due_task("a", at(100), at(220)).await.unwrap().unwrap();
+
+        // Only the lease holder can hand it back
+        repo.release_claim(1, "b", at(110)).await.unwrap();
+        assert_eq!(repo.next_claimable_time().await.unwrap(), Some(at(220)));
+
+        repo.release_claim(1, "a", at(160)).await.unwrap();
+        assert_eq!(repo.next_claimable_time().await.unwrap(), Some(at(160)));
+        assert!(repo.claim_due_task("b", at(159), at(279)).await.unwrap().is_none());
+        let retried = repo.claim_due_task("b", at(160), at(280)).await.unwrap().unwrap();
+        assert_eq!((retried.task_id, retried.scheduled_time), (1, at(160)));
+    }
+
+    pub async fn complete_records_delivery_key(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        let claimed = repo.claim_due_task("a", at(100), at(220)).await.unwrap().unwrap();
+        assert!(!repo.is_delivered(&claimed.delivery_key()).await.unwrap());
+
+        assert!(repo.complete_claim(&claimed, "a", at(105)).await.unwrap());
+        assert!(repo.is_delivered(&claimed.delivery_key()).await.unwrap());
+        assert!(!repo.is_delivered(&scheduled(1, 200).delivery_key()).await.unwrap());
+        assert!(!repo.has_pending_tasks().await.unwrap());
+
+        assert_eq!(repo.prune_deliveries(at(105)).await.unwrap(), 0);
+        assert_eq!(repo.prune_deliveries(at(106)).await.unwrap(), 1);
+        assert!(!repo.is_delivered(&claimed.delivery_key()).await.unwrap());
+    }
+
+    pub async fn purge_user_removes_entries_and_history(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        let claimed = repo.claim_due_task("a", at(100), at(220)).await.unwrap().unwrap();
+        repo.complete_claim(&claimed, "a", at(105)).await.unwrap();
+        repo.add_scheduled_task(scheduled(1, 200)).await.unwrap();
+        repo.add_scheduled_task(scheduled(2, 300)).await.unwrap();
+        repo.remove_task(2).await.unwrap();
+        repo.add_scheduled_task(ScheduledTask::new(3, at(150), &Task::for_test(2, "other")))
+            .await
+            .unwrap();
+
+        let history = repo.list_deliveries(&[1, 3]).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            (history[0].task_id, history[0].scheduled_time, history[0].delivered_at),
+            (1, at(100), at(105))
+        );
+
+        // Tombstones go too (the in-memory heap may still hold the delivered one), the other user's entry stays
+        assert!(repo.purge_user(1, &[1]).await.unwrap() >= 2);
+        assert!(repo.list_deliveries(&[1]).await.unwrap().is_empty());
+        assert!(!repo.is_delivered(&claimed.delivery_key()).await.unwrap());
+        let ids: Vec<u64> = repo.list_pending().await.unwrap().iter().map(|t| t.task_id).collect();
+        assert_eq!(ids, vec![3]);
+    }
+
+    pub async fn rescheduling_a_claimed_task_drops_the_lease(repo: &dyn TaskSchedulerRepository) {
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        let claimed = repo.claim_due_task("a", at(100), at(220)).await.unwrap().unwrap();
+
+        // Reloading the same occurrence keeps it leased
+        repo.add_scheduled_task(scheduled(1, 100)).await.unwrap();
+        assert!(repo.claim_due_task("b", at(150), at(270)).await.unwrap().is_none());
+
+        // Editing the task moves it to a new occurrence, which is free and must survive completion
+        repo.add_scheduled_task(scheduled(1, 130)).await.unwrap();
+        assert!(!repo.complete_claim(&claimed, "a", at(150)).await.unwrap());
+        let edited = repo.claim_due_task("b", at(150), at(270)).await.unwrap().unwrap();
+        assert_eq!(edited.scheduled_time, at(130));
+    }
+}
+
+/// Expands every check in `$suite` into a #[tokio::test] against a fresh repository from `$factory`
+macro_rules! conformance {
+    ($backend:ident, $suite:ident, $factory:expr, [$($check:ident),* $(,)?]) => {
+        mod $backend {
+            use super::*;
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    let repo = $factory;
+                    $suite::$check(&repo).await;
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! task_repository_conformance {
+    ($backend:ident, $factory:expr) => {
+        conformance!($backend, task_repository, $factory, [
+            add_assigns_sequential_ids,
+            round_trips_all_fields,
+            edit_updates_only_given_fields,
+            edit_rejects_missing_task_and_empty_title,
+            remove_reports_whether_task_existed,
+            remove_all_by_user_only_touches_that_user,
+            update_task_time_and_count,
+            update_schedules_is_all_or_nothing,
+            move_to_dm_drops_channel_and_mention,
+            archived_tasks_are_hidden_until_restored,
+        ]);
+    };
+}
+
+macro_rules! user_preferences_repository_conformance {
+    ($backend:ident, $factory:expr) => {
+        conformance!($backend, user_preferences_repository, $factory, [
+            get_missing_returns_none,
+            save_and_get_round_trip,
+            save_rejects_invalid_preferences,
+            save_overwrites_but_keeps_created_at,
+            list_all_orders_by_user,
+            delete_existing_then_missing,
+        ]);
+    };
+}
+
+macro_rules! guild_settings_repository_conformance {
+    ($backend:ident, $factory:expr) => {
+        conformance!($backend, guild_settings_repository, $factory, [
+            save_and_get_round_trip,
+            save_overwrites_and_clears_fields,
+            delete_existing_then_missing,
+        ]);
+    };
+}
+
+macro_rules! task_scheduler_repository_conformance {
+    ($backend:ident, $factory:expr) => {
+        conformance!($backend, task_scheduler_repository, $factory, [
+            delivered_earliest_first,
+            claim_preserves_entry_fields,
+            removed_tasks_are_hidden,
+            re_adding_replaces_existing_entry,
+            has_pending_ignores_removed,
+            pending_count_tracks_live_entries,
+            list_pending_is_in_delivery_order,
+            compact_keeps_live_entries,
+            add_signals_wakeup,
+            claim_takes_earliest_due_task_once,
+            expired_lease_can_be_reclaimed,
+            next_claimable_time_waits_for_leases,
+            release_reschedules_and_frees_the_lease,
+            complete_records_delivery_key,
+            purge_user_removes_entries_and_history,
+            rescheduling_a_claimed_task_drops_the_lease,
+        ]);
+    };
+}
+
+// SQLite implementations run against a private in-memory database per test
+task_repository_conformance!(
+    sqlite_task,
+    SqliteTaskRepository::new(":memory:").unwrap()
+);
+task_repository_conformance!(memory_task, MemoryTaskRepository::new());
+
+user_preferences_repository_conformance!(
+    sqlite_user_preferences,
+    SqliteUserPreferencesRepository::new(":memory:").unwrap()
+);
+user_preferences_repository_conformance!(
+    memory_user_preferences,
+    MemoryUserPreferencesRepository::new()
+);
+
+guild_settings_repository_conformance!(
+    sqlite_guild_settings,
+    SqliteGuildSettingsRepository::new(":memory:").unwrap()
+);
+guild_settings_repository_conformance!(
+    memory_guild_settings,
+    MemoryGuildSettingsRepository::new()
+);
+
+task_scheduler_repository_conformance!(
+    sqlite_scheduler,
+    SqliteSchedulerRepository::new(":memory:").unwrap()
+);
+task_scheduler_repository_conformance!(
+    memory_scheduler,
+    MemorySchedulerRepository::new()
+);
+
+// Tasks and preferences are separate repositories over one store, a timezone change writes both
+async fn timezone_change_is_all_or_nothing(
+    tasks: &dyn TaskRepository,
+    preferences: &dyn UserPreferencesRepository,
+) {
+    let task_id = tasks.add_task(Task::for_test(1, "standup").scheduled_at(at(0))).await.unwrap();
+    let madrid = UserPreferences::new_with_format(1, "Europe/Madrid".to_string(), None, at(0));
+    preferences.save(&madrid).await.unwrap();
+
+    // An unknown task leaves both the tasks and the preference as they were
+    let mut tokyo = madrid.clone();
+    tokyo.timezone = "Asia/Tokyo".to_string();
+    let failed = preferences
+        .save_with_task_schedules(
+            &tokyo,
+            vec![(task_id, Some(at(60)), None), (99, Some(at(60)), None)],
+        )
+        .await;
+    assert!(matches!(failed, Err(RepositoryError::InvalidData(_))));
+    assert_eq!(preferences.get(1).await.unwrap().unwrap().timezone, "Europe/Madrid");
+    assert_eq!(tasks.list_tasks().await[0].scheduled_time, Some(at(0)));
+
+    preferences
+        .save_with_task_schedules(&tokyo, vec![(task_id, Some(at(60)), None)])
+        .await
+        .unwrap();
+    assert_eq!(preferences.get(1).await.unwrap().unwrap().timezone, "Asia/Tokyo");
+    assert_eq!(tasks.list_tasks().await[0].scheduled_time, Some(at(60)));
+}
+
+#[tokio::test]
+async fn memory_timezone_change_is_all_or_nothing() {
+    let tasks = std::sync::Arc::new(MemoryTaskRepository::new());
+    let preferences = MemoryUserPreferencesRepository::new().with_tasks(tasks.clone());
+    timezone_change_is_all_or_nothing(tasks.as_ref(), &preferences).await;
+}
+
+#[tokio::test]
+async fn sqlite_timezone_change_is_all_or_nothing() {
+    // Both repositories open the same file, like the bot does
+    let path = std::env::temp_dir().join(format!(
+        "daily-timezone-change-{}-{}.db",
+        std::process::id(),
+        rand::random::<u32>()
+    ));
+    let tasks = SqliteTaskRepository::new(&path).unwrap();
+    let preferences = SqliteUserPreferencesRepository::new(&path).unwrap();
+    timezone_change_is_all_or_nothing(&tasks, &preferences).await;
+    let _ = std::fs::remove_file(&path);
+}
+
+// Two processes on one database file: claims must be exclusive across connections
+#[tokio::test]
+async fn sqlite_claims_are_exclusive_across_connections() {
+    let path = std::env::temp_dir().join(format!(
+        "daily-scheduler-lease-{}-{}.db",
+        std::process::id(),
+        rand::random::<u32>()
+    ));
+    let instance_a = SqliteSchedulerRepository::new(&path).unwrap();
+    let instance_b = SqliteSchedulerRepository::new(&path).unwrap();
+
+    for task_id in 1..=20 {
+        instance_a
+            .add_scheduled_task(scheduled(task_id, task_id as i64))
+            .await
+            .unwrap();
+    }
+
+    let claim_all = |repo: SqliteSchedulerRepository, owner: &'static str| {
+        tokio::spawn(async move {
+            let mut claimed = Vec::new();
+            while let Some(task) = repo.claim_due_task(owner, at(100), at(220)).await.unwrap() {
+                claimed.push(task.task_id);
+            }
+            claimed
+        })
+    };
+    let (a, b) = (claim_all(instance_a, "a"), claim_all(instance_b, "b"));
+    let mut all: Vec<u64> = a.await.unwrap().into_iter().chain(b.await.unwrap()).collect();
+    all.sort();
+
+    assert_eq!(all, (1..=20).collect::<Vec<u64>>());
+    let _ = std::fs::remove_file(&path);
+}
+
+// Databases written before mentions were typed keep free text in the `mention` column
+#[tokio::test]
+async fn sqlite_migrates_free_text_mentions() {
+    let path = std::env::temp_dir().join(format!(
+        "daily-legacy-mentions-{}-{}.db",
+        std::process::id(),
+        rand::random::<u32>()
+    ));
+    let legacy = rusqlite::Connection::open(&path).unwrap();
+    legacy
+        .execute_batch(
+            "CREATE TABLE tasks (
+                id INTEGER PRIMARY KEY, user_id INTEGER NOT NULL, guild_id INTEGER NOT NULL,
+                title TEXT NOT NULL, description TEXT, scheduled_time INTEGER,
+                recurrence_type TEXT, recurrence_data TEXT, notification_method TEXT NOT NULL,
+                channel_id INTEGER, mention TEXT
+            );
+            INSERT INTO tasks (id, user_id, guild_id, title, notification_method, channel_id, mention)
+            VALUES (1, 7, 42, 'standup', 'channel', 555, 'hey <@&99> and <@8>!');",
+        )
+        .unwrap();
+    drop(legacy);
+
+    let repo = SqliteTaskRepository::new(&path).unwrap();
+    let task = repo.list_tasks().await.pop().unwrap();
+    assert_eq!(
+        task.mentions,
+        vec![MentionTarget::Role(99), MentionTarget::User(8)]
+    );
+
+    // Already migrated, opening again changes nothing
+    drop(repo);
+    let repo = SqliteTaskRepository::new(&path).unwrap();
+    assert_eq!(repo.list_tasks().await.pop().unwrap().mentions, task.mentions);
+    let _ = std::fs::remove_file(&path);
+}
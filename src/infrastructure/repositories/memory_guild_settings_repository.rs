@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+
+use crate::domain::entities::guild_settings::GuildSettings;
+use crate::domain::repositories::guild_settings_repository::GuildSettingsRepository;
+use crate::domain::repositories::user_preferences_repository::RepositoryError;
+
+/// In-memory implementation of GuildSettingsRepository, mirrors SqliteGuildSettingsRepository semantics
+#[derive(Debug, Default)]
+pub struct MemoryGuildSettingsRepository {
+    settings: Mutex<HashMap<u64, GuildSettings>>,
+}
+
+impl MemoryGuildSettingsRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl GuildSettingsRepository for MemoryGuildSettingsRepository {
+    async fn get(&self, guild_id: u64) -> Result<Option<GuildSettings>, RepositoryError> {
+        Ok(self.settings.lock().await.get(&guild_id).cloned())
+    }
+
+    async fn save(&self, settings: &GuildSettings) -> Result<(), RepositoryError> {
+        if !settings.is_valid() {
+            return Err(RepositoryError::InvalidData(
+                "Invalid guild settings".into(),
+            ));
+        }
+
+        self.settings
+            .lock()
+            .await
+            .insert(settings.guild_id, settings.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, guild_id: u64) -> Result<(), RepositoryError> {
+        match self.settings.lock().await.remove(&guild_id) {
+            Some(_) => Ok(()),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+}
@@ -1,26 +1,40 @@
 use async_trait::async_trait;
-use std::collections::BinaryHeap;
+use chrono::{DateTime, Utc};
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::sync::{Mutex, broadcast};
+use tracing::debug;
 
-use crate::domain::entities::scheduled_task::ScheduledTask;
+use crate::domain::entities::scheduled_task::{DeliveryRecord, ScheduledTask};
 use crate::domain::repositories::task_scheduler_repository::{
     SchedulerError, TaskSchedulerRepository,
 };
 
 /// In-memory implementation of TaskSchedulerRepository using a priority queue with lazy deletion
-#[allow(dead_code)]
 #[derive(Debug)]
 pub struct MemorySchedulerRepository {
     // ScheduledTask already implements correct ordering (earliest times first, deleted tasks sink)
     tasks: Mutex<BinaryHeap<ScheduledTask>>,
     // Channel to notify scheduler when new tasks are added
     wakeup_sender: broadcast::Sender<()>,
+    // Running total of tombstones physically removed from the heap
+    tombstones_removed: AtomicU64,
+    // task_id -> entries being delivered, off the heap until completed or released
+    leases: Mutex<HashMap<u64, Lease>>,
+    // delivery_key -> delivered_at
+    deliveries: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+/// Entry taken off the heap while `owner` delivers it
+#[derive(Debug)]
+struct Lease {
+    owner: String,
+    expires_at: DateTime<Utc>,
+    task: ScheduledTask,
 }
 
 // Configuration for lazy deletion cleanup
-#[allow(dead_code)]
 const CLEANUP_THRESHOLD_RATIO: f64 = 0.25; // Cleanup when 25% of tasks are deleted
-#[allow(dead_code)]
 const MIN_TASKS_FOR_CLEANUP: usize = 100;  // Don't cleanup unless we have at least 100 tasks
 
 impl MemorySchedulerRepository {
@@ -29,39 +43,67 @@ impl MemorySchedulerRepository {
         Self {
             tasks: Mutex::new(BinaryHeap::new()),
             wakeup_sender,
+            tombstones_removed: AtomicU64::new(0),
+            leases: Mutex::new(HashMap::new()),
+            deliveries: Mutex::new(HashMap::new()),
         }
     }
 
-    /// Get a receiver for wake-up notifications
-    #[allow(dead_code)]
-    pub fn subscribe_wakeup(&self) -> broadcast::Receiver<()> {
-        self.wakeup_sender.subscribe()
-    }
-
-    /// Cleanup deleted tasks if threshold is exceeded (periodic maintenance)
-    #[allow(dead_code)]
-    async fn cleanup_if_needed(&self, tasks: &mut std::collections::BinaryHeap<ScheduledTask>) {
+    /// Cleanup deleted tasks if threshold is exceeded (periodic maintenance), returns removed count
+    fn cleanup_if_needed(&self, tasks: &mut BinaryHeap<ScheduledTask>) -> usize {
         let total_count = tasks.len();
         
         if total_count < MIN_TASKS_FOR_CLEANUP {
-            return; // Don't cleanup small queues
+            return 0; // Don't cleanup small queues
         }
 
         let deleted_count = tasks.iter().filter(|task| task.is_marked_for_deletion()).count();
         let deleted_ratio = deleted_count as f64 / total_count as f64;
 
-        if deleted_ratio >= CLEANUP_THRESHOLD_RATIO {
-            // Rebuild heap without deleted tasks
-            let original_heap = std::mem::replace(tasks, BinaryHeap::new());
-            let mut new_heap = BinaryHeap::new();
-            
-            for task in original_heap.into_vec() {
-                if !task.is_marked_for_deletion() {
-                    new_heap.push(task);
-                }
+        if deleted_ratio < CLEANUP_THRESHOLD_RATIO {
+            return 0;
+        }
+
+        // Rebuild heap without deleted tasks
+        let original_heap = std::mem::take(tasks);
+        *tasks = original_heap
+            .into_vec()
+            .into_iter()
+            .filter(|task| !task.is_marked_for_deletion())
+            .collect();
+
+        self.record_removed(deleted_count);
+        debug!(
+            "Compacted in-memory scheduler: removed {} tombstones, {} entries remaining",
+            deleted_count,
+            tasks.len()
+        );
+        deleted_count
+    }
+
+    /// Lazy deletion: mark live entries for `task_id` as deleted instead of removing them
+    /// This is O(n) search but O(1) deletion, much faster than heap rebuild
+    fn mark_deleted(tasks: &mut BinaryHeap<ScheduledTask>, task_id: u64) -> bool {
+        let original_heap = std::mem::take(tasks);
+        let mut found_and_marked = false;
+
+        for mut task in original_heap.into_vec() {
+            if task.task_id == task_id && !task.is_marked_for_deletion() {
+                // Mark as deleted instead of removing
+                task.mark_deleted();
+                found_and_marked = true;
             }
-            
-            *tasks = new_heap;
+            tasks.push(task);
+        }
+
+        found_and_marked
+    }
+
+    /// Add removed tombstones to the running total
+    fn record_removed(&self, count: usize) {
+        if count > 0 {
+            self.tombstones_removed
+                .fetch_add(count as u64, Ordering::Relaxed);
         }
     }
 }
@@ -76,6 +118,22 @@ impl Default for MemorySchedulerRepository {
 impl TaskSchedulerRepository for MemorySchedulerRepository {
     async fn add_scheduled_task(&self, task: ScheduledTask) -> Result<(), SchedulerError> {
         let mut tasks = self.tasks.lock().await;
+        let mut leases = self.leases.lock().await;
+
+        // An in-flight lease only survives if the same occurrence is re-added
+        if let Some(lease) = leases.get_mut(&task.task_id) {
+            if lease.task.scheduled_time == task.scheduled_time {
+                lease.task = task;
+                return Ok(());
+            }
+            leases.remove(&task.task_id);
+        }
+        drop(leases);
+
+        // Re-adding a task replaces its previous entry, like the SQLite upsert
+        if Self::mark_deleted(&mut tasks, task.task_id) {
+            self.cleanup_if_needed(&mut tasks);
+        }
         
         // Check if this task should interrupt current sleep (is earlier than current top)
         let should_interrupt = tasks.peek()
@@ -95,80 +153,197 @@ impl TaskSchedulerRepository for MemorySchedulerRepository {
         Ok(())
     }
 
-    async fn peek_next_task(&self) -> Result<Option<ScheduledTask>, SchedulerError> {
+    async fn claim_due_task(
+        &self,
+        owner: &str,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Option<ScheduledTask>, SchedulerError> {
         let mut tasks = self.tasks.lock().await;
-        
-        // Skip deleted tasks at the top of the queue
-        loop {
-            match tasks.peek() {
-                Some(next_task) if next_task.is_marked_for_deletion() => {
-                    // Remove deleted task from top of queue and continue
-                    tasks.pop().unwrap();
-                    continue;
-                }
-                Some(next_task) => {
-                    // Found a valid (non-deleted) task
-                    return Ok(Some(next_task.clone()));
-                }
-                None => {
-                    return Ok(None);
-                }
+        let mut leases = self.leases.lock().await;
+
+        // Leases whose owner crashed, only the few entries in flight are scanned
+        let expired = leases
+            .values()
+            .filter(|lease| lease.expires_at <= now && lease.task.scheduled_time <= now)
+            .min_by_key(|lease| lease.task.scheduled_time)
+            .map(|lease| (lease.task.task_id, lease.task.scheduled_time));
+        // Tombstones sink, so the top is the earliest live entry
+        let queued = tasks
+            .peek()
+            .filter(|task| !task.is_marked_for_deletion() && task.scheduled_time <= now)
+            .map(|task| task.scheduled_time);
+
+        match (expired, queued) {
+            (Some((task_id, expired_at)), queued) if queued.is_none_or(|due| expired_at <= due) => {
+                let lease = leases.get_mut(&task_id).expect("expired lease was just found");
+                lease.owner = owner.to_string();
+                lease.expires_at = lease_until;
+                Ok(Some(lease.task.clone()))
+            }
+            (_, Some(_)) => {
+                let task = tasks.pop().expect("due entry was just peeked");
+                leases.insert(
+                    task.task_id,
+                    Lease {
+                        owner: owner.to_string(),
+                        expires_at: lease_until,
+                        task: task.clone(),
+                    },
+                );
+                Ok(Some(task))
             }
+            _ => Ok(None),
         }
     }
 
-    async fn pop_next_task(&self) -> Result<Option<ScheduledTask>, SchedulerError> {
+    async fn next_claimable_time(&self) -> Result<Option<DateTime<Utc>>, SchedulerError> {
+        let tasks = self.tasks.lock().await;
+        let leases = self.leases.lock().await;
+
+        let queued = tasks
+            .peek()
+            .filter(|task| !task.is_marked_for_deletion())
+            .map(|task| task.scheduled_time);
+        let leased = leases
+            .values()
+            .map(|lease| lease.task.scheduled_time.max(lease.expires_at));
+        Ok(queued.into_iter().chain(leased).min())
+    }
+
+    async fn release_claim(
+        &self,
+        task_id: u64,
+        owner: &str,
+        retry_at: DateTime<Utc>,
+    ) -> Result<(), SchedulerError> {
         let mut tasks = self.tasks.lock().await;
-        
-        // Skip deleted tasks and return the first valid one
-        loop {
-            match tasks.pop() {
-                Some(popped_task) if popped_task.is_marked_for_deletion() => {
-                    // Skip deleted task and continue
-                    continue;
-                }
-                Some(popped_task) => {
-                    return Ok(Some(popped_task));
-                }
-                None => {
-                    return Ok(None);
-                }
-            }
+        let mut leases = self.leases.lock().await;
+
+        if leases.get(&task_id).is_some_and(|lease| lease.owner == owner)
+            && let Some(mut lease) = leases.remove(&task_id)
+        {
+            lease.task.scheduled_time = retry_at;
+            tasks.push(lease.task);
         }
+        Ok(())
     }
 
-    async fn remove_task(&self, task_id: u64) -> Result<(), SchedulerError> {
-        let mut tasks = self.tasks.lock().await;
+    async fn complete_claim(
+        &self,
+        task: &ScheduledTask,
+        owner: &str,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<bool, SchedulerError> {
+        let mut leases = self.leases.lock().await;
 
-        // Lazy deletion: Mark task as deleted instead of immediate removal
-        // This is O(n) search but O(1) deletion, much faster than heap rebuild
-        let original_heap = std::mem::replace(&mut *tasks, BinaryHeap::new());
-        let mut new_heap = BinaryHeap::new();
-        let mut found_and_marked = false;
+        self.deliveries
+            .lock()
+            .await
+            .entry(task.delivery_key())
+            .or_insert(delivered_at);
 
-        for mut task in original_heap.into_vec() {
-            if task.task_id == task_id && !task.is_marked_for_deletion() {
-                // Mark as deleted instead of removing
-                task.mark_deleted();
-                found_and_marked = true;
-            }
-            new_heap.push(task);
+        // The entry only goes away if it is still the occurrence we claimed
+        let still_ours = leases.get(&task.task_id).is_some_and(|lease| {
+            lease.owner == owner && lease.task.scheduled_time == task.scheduled_time
+        });
+
+        if still_ours {
+            leases.remove(&task.task_id);
         }
+        Ok(still_ours)
+    }
+
+    async fn is_delivered(&self, delivery_key: &str) -> Result<bool, SchedulerError> {
+        Ok(self.deliveries.lock().await.contains_key(delivery_key))
+    }
+
+    async fn prune_deliveries(&self, before: DateTime<Utc>) -> Result<usize, SchedulerError> {
+        let mut deliveries = self.deliveries.lock().await;
+        let original_len = deliveries.len();
+        deliveries.retain(|_, delivered_at| *delivered_at >= before);
+        Ok(original_len - deliveries.len())
+    }
 
-        *tasks = new_heap;
+    async fn list_deliveries(&self, task_ids: &[u64]) -> Result<Vec<DeliveryRecord>, SchedulerError> {
+        let deliveries = self.deliveries.lock().await;
+        let mut records: Vec<DeliveryRecord> = deliveries
+            .iter()
+            .filter_map(|(key, delivered_at)| DeliveryRecord::from_key(key, *delivered_at))
+            .filter(|record| task_ids.contains(&record.task_id))
+            .collect();
+        records.sort_by_key(|record| (record.delivered_at, record.task_id));
+        Ok(records)
+    }
+
+    async fn purge_user(&self, user_id: u64, task_ids: &[u64]) -> Result<usize, SchedulerError> {
+        let mut tasks = self.tasks.lock().await;
+        let mut leases = self.leases.lock().await;
 
-        if !found_and_marked {
+        let original_len = tasks.len() + leases.len();
+        let owned = |task: &ScheduledTask| task.user_id == user_id || task_ids.contains(&task.task_id);
+        leases.retain(|_, lease| !owned(&lease.task));
+        tasks.retain(|task| !owned(task));
+        let removed = original_len - tasks.len() - leases.len();
+
+        self.deliveries.lock().await.retain(|key, delivered_at| {
+            DeliveryRecord::from_key(key, *delivered_at)
+                .is_none_or(|record| !task_ids.contains(&record.task_id))
+        });
+        Ok(removed)
+    }
+
+    async fn remove_task(&self, task_id: u64) -> Result<(), SchedulerError> {
+        let mut tasks = self.tasks.lock().await;
+
+        let was_leased = self.leases.lock().await.remove(&task_id).is_some();
+        if !Self::mark_deleted(&mut tasks, task_id) && !was_leased {
             return Err(SchedulerError::TaskNotFound);
         }
 
         // Check if cleanup is needed
-        self.cleanup_if_needed(&mut tasks).await;
+        self.cleanup_if_needed(&mut tasks);
         
         Ok(())
     }
 
     async fn has_pending_tasks(&self) -> Result<bool, SchedulerError> {
         let tasks = self.tasks.lock().await;
-        Ok(!tasks.is_empty())
+        Ok(tasks.peek().is_some_and(|task| !task.is_marked_for_deletion())
+            || !self.leases.lock().await.is_empty())
+    }
+
+    async fn pending_count(&self) -> Result<usize, SchedulerError> {
+        let tasks = self.tasks.lock().await;
+        let queued = tasks.iter().filter(|task| !task.is_marked_for_deletion()).count();
+        Ok(queued + self.leases.lock().await.len())
+    }
+
+    async fn list_pending(&self) -> Result<Vec<ScheduledTask>, SchedulerError> {
+        let tasks = self.tasks.lock().await;
+        let leases = self.leases.lock().await;
+        let mut pending: Vec<ScheduledTask> = tasks
+            .iter()
+            .filter(|task| !task.is_marked_for_deletion())
+            .chain(leases.values().map(|lease| &lease.task))
+            .cloned()
+            .collect();
+        pending.sort_by_key(|task| (task.scheduled_time, task.task_id));
+        Ok(pending)
+    }
+
+    /// Rebuild the heap without tombstones once they exceed CLEANUP_THRESHOLD_RATIO
+    async fn compact(&self) -> Result<usize, SchedulerError> {
+        let mut tasks = self.tasks.lock().await;
+        Ok(self.cleanup_if_needed(&mut tasks))
+    }
+
+    fn tombstones_removed(&self) -> u64 {
+        self.tombstones_removed.load(Ordering::Relaxed)
+    }
+
+    /// Get a receiver for wake-up notifications
+    fn subscribe_wakeup(&self) -> broadcast::Receiver<()> {
+        self.wakeup_sender.subscribe()
     }
 }
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet};
+use tokio::sync::Mutex;
+
+use crate::domain::repositories::TaskRepository;
+use crate::domain::{NotificationMethod, Recurrence, Task};
+
+/// In-memory implementation of TaskRepository, mirrors SqliteTaskRepository semantics
+/// Intended for tests and ephemeral runs where nothing should touch the disk
+#[derive(Debug, Default)]
+pub struct MemoryTaskRepository {
+    // Ordered by id so list_tasks matches the SQLite rowid order
+    tasks: Mutex<BTreeMap<u64, Task>>,
+    // Ids of archived tasks, hidden from list_tasks
+    archived: Mutex<BTreeSet<u64>>,
+}
+
+impl MemoryTaskRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TaskRepository for MemoryTaskRepository {
+    async fn add_task(&self, mut task: Task) -> Result<u64, String> {
+        let mut tasks = self.tasks.lock().await;
+
+        // Same id policy as SQLite: MAX(id) + 1
+        let id = tasks.keys().next_back().copied().unwrap_or(0) + 1;
+        task.id = id;
+        tasks.insert(id, task);
+
+        Ok(id)
+    }
+
+    async fn edit_task(
+        &self,
+        task_id: u64,
+        new_title: Option<String>,
+        new_description: Option<String>,
+        new_scheduled_time: Option<DateTime<Utc>>,
+        new_recurrence: Option<Recurrence>,
+        new_notification_method: Option<NotificationMethod>,
+        new_timezone: Option<Option<String>>,
+    ) -> Result<Task, String> {
+        let mut tasks = self.tasks.lock().await;
+
+        let mut updated = tasks
+            .get(&task_id)
+            .cloned()
+            .ok_or_else(|| format!("Couldn't find task with ID {}", task_id))?;
+
+        // apply changes
+        if let Some(t) = new_title {
+            if t.trim().is_empty() {
+                return Err("Task title cannot be empty".to_string());
+            }
+            updated.title = t;
+        }
+        if let Some(d) = new_description {
+            updated.description = Some(d);
+        }
+        if let Some(t) = new_scheduled_time {
+            updated.scheduled_time = Some(t);
+        }
+        if let Some(r) = new_recurrence {
+            updated.recurrence = Some(r);
+        }
+        if let Some(n) = new_notification_method {
+            updated.notification_method = n;
+        }
+        if let Some(timezone) = new_timezone {
+            updated.timezone = timezone;
+        }
+
+        tasks.insert(task_id, updated.clone());
+        Ok(updated)
+    }
+
+    async fn move_to_dm(&self, task_id: u64) -> Result<Task, String> {
+        let mut tasks = self.tasks.lock().await;
+        let task = tasks
+            .get_mut(&task_id)
+            .ok_or_else(|| format!("Couldn't find task with ID {}", task_id))?;
+
+        task.notification_method = NotificationMethod::DM;
+        task.channel_id = None;
+        task.mentions.clear();
+        Ok(task.clone())
+    }
+
+    async fn remove_task(&self, task_id: u64) -> bool {
+        let mut tasks = self.tasks.lock().await;
+        self.archived.lock().await.remove(&task_id);
+        tasks.remove(&task_id).is_some()
+    }
+
+    async fn remove_all_by_user(&self, user_id: u64) -> usize {
+        let mut tasks = self.tasks.lock().await;
+        let mut archived = self.archived.lock().await;
+        let before = tasks.len();
+        tasks.retain(|_, task| task.user_id != user_id);
+        archived.retain(|task_id| tasks.contains_key(task_id));
+        before - tasks.len()
+    }
+
+    async fn list_tasks(&self) -> Vec<Task> {
+        let tasks = self.tasks.lock().await;
+        let archived = self.archived.lock().await;
+        tasks
+            .values()
+            .filter(|task| !archived.contains(&task.id))
+            .cloned()
+            .collect()
+    }
+
+    async fn list_archived(&self) -> Vec<Task> {
+        let tasks = self.tasks.lock().await;
+        let archived = self.archived.lock().await;
+        tasks
+            .values()
+            .filter(|task| archived.contains(&task.id))
+            .cloned()
+            .collect()
+    }
+
+    async fn set_archived(&self, task_id: u64, archived: bool) -> Result<Task, String> {
+        let tasks = self.tasks.lock().await;
+        let task = tasks
+            .get(&task_id)
+            .ok_or_else(|| format!("Couldn't find task with ID {}", task_id))?;
+
+        let mut archived_ids = self.archived.lock().await;
+        if archived {
+            archived_ids.insert(task_id);
+        } else {
+            archived_ids.remove(&task_id);
+        }
+        Ok(task.clone())
+    }
+
+    async fn update_schedules(
+        &self,
+        schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+    ) -> Result<(), String> {
+        let mut tasks = self.tasks.lock().await;
+
+        // Check every task first so a missing one changes nothing, like the SQLite transaction
+        if let Some((task_id, _, _)) = schedules.iter().find(|(id, _, _)| !tasks.contains_key(id)) {
+            return Err(format!("Couldn't find task with ID {}", task_id));
+        }
+        for (task_id, scheduled_time, recurrence) in schedules {
+            if let Some(task) = tasks.get_mut(&task_id) {
+                task.scheduled_time = scheduled_time;
+                task.recurrence = recurrence;
+            }
+        }
+        Ok(())
+    }
+
+    async fn update_task_time(&self, task_id: u64, new_time: DateTime<Utc>) -> Result<(), String> {
+        // Like the SQL UPDATE, a missing task is not an error
+        if let Some(task) = self.tasks.lock().await.get_mut(&task_id) {
+            task.scheduled_time = Some(new_time);
+        }
+        Ok(())
+    }
+
+    async fn get_total_task_count(&self) -> Result<u64, String> {
+        Ok(self.tasks.lock().await.len() as u64)
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::entities::task::Recurrence;
+use crate::domain::entities::user_preferences::UserPreferences;
+use crate::domain::repositories::TaskRepository;
+use crate::domain::repositories::user_preferences_repository::{
+    RepositoryError, UserPreferencesRepository,
+};
+use crate::infrastructure::repositories::MemoryTaskRepository;
+
+/// In-memory implementation of UserPreferencesRepository, mirrors SqliteUserPreferencesRepository semantics
+#[derive(Debug, Default)]
+pub struct MemoryUserPreferencesRepository {
+    preferences: Mutex<HashMap<u64, UserPreferences>>,
+    tasks: Option<Arc<MemoryTaskRepository>>, // What save_with_task_schedules updates, like the shared SQLite file
+}
+
+impl MemoryUserPreferencesRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tasks(mut self, tasks: Arc<MemoryTaskRepository>) -> Self {
+        self.tasks = Some(tasks);
+        self
+    }
+}
+
+#[async_trait]
+impl UserPreferencesRepository for MemoryUserPreferencesRepository {
+    async fn get(&self, user_id: u64) -> Result<Option<UserPreferences>, RepositoryError> {
+        Ok(self.preferences.lock().await.get(&user_id).cloned())
+    }
+
+    async fn save(&self, preferences: &UserPreferences) -> Result<(), RepositoryError> {
+        if !preferences.is_valid() {
+            return Err(RepositoryError::InvalidData(
+                "Invalid user preferences".into(),
+            ));
+        }
+
+        let mut stored = self.preferences.lock().await;
+
+        // Upsert keeps the original created_at, like ON CONFLICT DO UPDATE
+        let mut prefs = preferences.clone();
+        if let Some(existing) = stored.get(&prefs.user_id) {
+            prefs.created_at = existing.created_at;
+        }
+        stored.insert(prefs.user_id, prefs);
+
+        Ok(())
+    }
+
+    async fn save_with_task_schedules(
+        &self,
+        preferences: &UserPreferences,
+        schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+    ) -> Result<(), RepositoryError> {
+        if !preferences.is_valid() {
+            return Err(RepositoryError::InvalidData(
+                "Invalid user preferences".into(),
+            ));
+        }
+
+        if !schedules.is_empty() {
+            let tasks = self.tasks.as_ref().ok_or_else(|| {
+                RepositoryError::StorageError("No task repository to update".into())
+            })?;
+            // All or nothing on its own, and the save below can't fail anymore
+            tasks
+                .update_schedules(schedules)
+                .await
+                .map_err(RepositoryError::InvalidData)?;
+        }
+        self.save(preferences).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<UserPreferences>, RepositoryError> {
+        let mut all: Vec<UserPreferences> =
+            self.preferences.lock().await.values().cloned().collect();
+        all.sort_by_key(|prefs| prefs.user_id);
+        Ok(all)
+    }
+
+    async fn delete(&self, user_id: u64) -> Result<(), RepositoryError> {
+        match self.preferences.lock().await.remove(&user_id) {
+            Some(_) => Ok(()),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+}
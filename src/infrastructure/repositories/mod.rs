@@ -1,14 +1,32 @@
+pub mod memory_guild_settings_repository;
 pub mod memory_scheduler_repository;
+pub mod memory_task_repository;
+pub mod memory_user_preferences_repository;
+pub mod sqlite_guild_settings_repository;
 pub mod sqlite_task_repository;
 pub mod sqlite_user_preferences_repository;
 pub mod sqlite_scheduler_repository;
+pub mod storage_backend;
 
+#[cfg(test)]
+mod conformance_tests;
+
+#[allow(unused_imports)]
+pub use memory_guild_settings_repository::MemoryGuildSettingsRepository;
 #[allow(unused_imports)]
 pub use memory_scheduler_repository::MemorySchedulerRepository;
+#[allow(unused_imports)]
+pub use memory_task_repository::MemoryTaskRepository;
+#[allow(unused_imports)]
+pub use memory_user_preferences_repository::MemoryUserPreferencesRepository;
 
+#[allow(unused_imports)]
+pub use sqlite_guild_settings_repository::SqliteGuildSettingsRepository;
 #[allow(unused_imports)]
 pub use sqlite_task_repository::SqliteTaskRepository;
 #[allow(unused_imports)]
 pub use sqlite_user_preferences_repository::SqliteUserPreferencesRepository;
 #[allow(unused_imports)]
-pub use sqlite_scheduler_repository::SqliteSchedulerRepository;
\ No newline at end of file
+pub use sqlite_scheduler_repository::SqliteSchedulerRepository;
+
+pub use storage_backend::StorageBackend;
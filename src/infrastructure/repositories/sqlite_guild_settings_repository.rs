@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use chrono::TimeZone;
+use rusqlite::{Connection, params};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::domain::entities::guild_settings::GuildSettings;
+use crate::domain::repositories::guild_settings_repository::GuildSettingsRepository;
+use crate::domain::repositories::user_preferences_repository::RepositoryError;
+
+pub struct SqliteGuildSettingsRepository {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteGuildSettingsRepository {
+    pub fn new(db_path: impl Into<PathBuf>) -> Result<Self, RepositoryError> {
+        let conn = Connection::open(db_path.into()).map_err(|e| {
+            RepositoryError::StorageError(format!("Failed to open SQLite DB: {}", e))
+        })?;
+
+        let repo = Self {
+            connection: Arc::new(Mutex::new(conn)),
+        };
+
+        repo.initialize_schema()?;
+        Ok(repo)
+    }
+
+    fn initialize_schema(&self) -> Result<(), RepositoryError> {
+        let conn = self
+            .connection
+            .lock()
+            .map_err(|e| RepositoryError::StorageError(format!("Lock poisoned: {}", e)))?;
+
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS guild_settings (
+                guild_id     INTEGER PRIMARY KEY,
+                timezone     TEXT, -- NULL when unset
+                date_format  TEXT,
+                language     TEXT, -- language code, e.g. 'es'
+                updated_at   INTEGER NOT NULL
+            );
+            "#,
+            [],
+        )
+        .map_err(|e| RepositoryError::StorageError(format!("Failed to create table: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn row_to_settings(row: &rusqlite::Row) -> rusqlite::Result<GuildSettings> {
+        let updated_at = row.get::<_, i64>(4)?;
+
+        Ok(GuildSettings {
+            guild_id: row.get::<_, i64>(0)? as u64,
+            timezone: row.get(1)?,
+            date_format: row.get(2)?,
+            language: row
+                .get::<_, Option<String>>(3)?
+                .and_then(|code| code.parse().ok()),
+            updated_at: chrono::Utc
+                .timestamp_opt(updated_at, 0)
+                .single()
+                .ok_or(rusqlite::Error::InvalidQuery)?,
+        })
+    }
+}
+
+fn lock_error(e: impl std::fmt::Display) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::other(format!(
+        "Lock poisoned: {}",
+        e
+    ))))
+}
+
+#[async_trait]
+impl GuildSettingsRepository for SqliteGuildSettingsRepository {
+    async fn get(&self, guild_id: u64) -> Result<Option<GuildSettings>, RepositoryError> {
+        let conn = self.connection.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let conn = conn.lock().map_err(lock_error)?;
+
+            let mut stmt = conn.prepare(
+                "SELECT guild_id, timezone, date_format, language, updated_at
+                 FROM guild_settings WHERE guild_id = ?1",
+            )?;
+
+            match stmt.query_row(params![guild_id as i64], Self::row_to_settings) {
+                Ok(settings) => Ok(Some(settings)),
+                Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+        .await
+        .map_err(|_| RepositoryError::StorageError("Task join error".into()))?;
+
+        result.map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn save(&self, settings: &GuildSettings) -> Result<(), RepositoryError> {
+        if !settings.is_valid() {
+            return Err(RepositoryError::InvalidData(
+                "Invalid guild settings".into(),
+            ));
+        }
+
+        let conn = self.connection.clone();
+        let settings = settings.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let conn = conn.lock().map_err(lock_error)?;
+
+            conn.execute(
+                r#"
+                INSERT INTO guild_settings (guild_id, timezone, date_format, language, updated_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT(guild_id) DO UPDATE SET
+                    timezone = excluded.timezone,
+                    date_format = excluded.date_format,
+                    language = excluded.language,
+                    updated_at = excluded.updated_at;
+                "#,
+                params![
+                    settings.guild_id as i64,
+                    settings.timezone,
+                    settings.date_format,
+                    settings.language.map(|language| language.code()),
+                    settings.updated_at.timestamp()
+                ],
+            )?;
+
+            Ok::<_, rusqlite::Error>(())
+        })
+        .await
+        .map_err(|_| RepositoryError::StorageError("Task join error".into()))?;
+
+        result.map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
+    async fn delete(&self, guild_id: u64) -> Result<(), RepositoryError> {
+        let conn = self.connection.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let conn = conn.lock().map_err(lock_error)?;
+
+            let affected = conn.execute(
+                "DELETE FROM guild_settings WHERE guild_id = ?1",
+                params![guild_id as i64],
+            )?;
+
+            if affected == 0 {
+                return Err(rusqlite::Error::QueryReturnedNoRows);
+            }
+
+            Ok::<_, rusqlite::Error>(())
+        })
+        .await
+        .map_err(|_| RepositoryError::StorageError("Task join error".into()))?;
+
+        result.map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => RepositoryError::NotFound,
+            other => RepositoryError::StorageError(other.to_string()),
+        })
+    }
+}
+
+impl std::fmt::Debug for SqliteGuildSettingsRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteGuildSettingsRepository").finish()
+    }
+}
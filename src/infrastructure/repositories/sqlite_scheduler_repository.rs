@@ -1,17 +1,20 @@
 //! Uses spawn_blocking to avoid blocking the async runtime.
 //! Stores scheduled tasks in `scheduled_tasks` table (see schema.sql).
 
-use crate::domain::entities::scheduled_task::ScheduledTask;
+use crate::domain::entities::scheduled_task::{DeliveryRecord, ScheduledTask};
 use crate::domain::repositories::task_scheduler_repository::{
     SchedulerError, TaskSchedulerRepository,
 };
+use crate::domain::value_objects::mention::{format_mentions, parse_mentions_lenient};
 
 use async_trait::async_trait;
-use chrono::{TimeZone, Utc};
-use rusqlite::{Connection, Row, params};
+use chrono::{DateTime, TimeZone, Utc};
+use rusqlite::{Connection, OptionalExtension, Row, TransactionBehavior, params};
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::sync::broadcast;
+use tracing::debug;
 
 /// Simple helper to map NotificationMethod <-> string
 fn notification_method_to_str(
@@ -38,6 +41,8 @@ pub struct SqliteSchedulerRepository {
     conn: Arc<Mutex<Connection>>,
     // Channel to notify scheduler when new tasks are added
     wakeup_sender: broadcast::Sender<()>,
+    // Running total of soft-deleted rows purged by compact()
+    tombstones_removed: Arc<AtomicU64>,
 }
 
 impl SqliteSchedulerRepository {
@@ -58,27 +63,58 @@ impl SqliteSchedulerRepository {
                 notification_method TEXT NOT NULL,
                 is_recurring    INTEGER NOT NULL DEFAULT 0,
                 is_deleted      INTEGER NOT NULL DEFAULT 0,
-                mention         TEXT
+                mention         TEXT,
+                lease_owner     TEXT,
+                lease_expires_at INTEGER
             );
 
             CREATE INDEX IF NOT EXISTS idx_scheduled_tasks_time ON scheduled_tasks (is_deleted, scheduled_time);
+
+            CREATE TABLE IF NOT EXISTS scheduled_deliveries (
+                delivery_key    TEXT PRIMARY KEY,
+                task_id         INTEGER NOT NULL,
+                delivered_by    TEXT NOT NULL,
+                delivered_at    INTEGER NOT NULL
+            );
             "#,
         )
         .map_err(|e| SchedulerError::StorageError(format!("Failed to initialize scheduler table: {}", e)))?;
 
+        // Databases created before leases were introduced lack the lease columns
+        for (column, definition) in [("lease_owner", "TEXT"), ("lease_expires_at", "INTEGER")] {
+            let column_exists: bool = conn
+                .query_row(
+                    "SELECT COUNT(*) FROM pragma_table_info('scheduled_tasks') WHERE name = ?1",
+                    params![column],
+                    |row| row.get::<_, i64>(0),
+                )
+                .map(|count| count > 0)
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
+            if !column_exists {
+                conn.execute(
+                    &format!("ALTER TABLE scheduled_tasks ADD COLUMN {} {}", column, definition),
+                    [],
+                )
+                .map_err(|e| {
+                    SchedulerError::StorageError(format!("Failed to add {} column: {}", column, e))
+                })?;
+            }
+        }
+
+        // Other bot instances may hold the write lock while claiming, wait for it instead of failing
+        conn.busy_timeout(std::time::Duration::from_secs(5))
+            .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
         let (wakeup_sender, _) = broadcast::channel(1);
         
         Ok(Self {
             conn: Arc::new(Mutex::new(conn)),
             wakeup_sender,
+            tombstones_removed: Arc::new(AtomicU64::new(0)),
         })
     }
 
-    /// Get a receiver for wake-up notifications (for scheduler to react to new tasks)
-    pub fn subscribe_wakeup(&self) -> broadcast::Receiver<()> {
-        self.wakeup_sender.subscribe()
-    }
-
     /// Helper: build a ScheduledTask from a rusqlite::Row
     fn row_to_scheduled_task(row: &Row) -> Result<ScheduledTask, SchedulerError> {
         let task_id: i64 = row
@@ -123,7 +159,9 @@ impl SqliteSchedulerRepository {
             notification_method: notification_method_from_str(&notification_method_str),
             is_recurring: is_recurring_i != 0,
             is_deleted: is_deleted_i != 0,
-            mention,
+            mentions: mention
+                .map(|mention| parse_mentions_lenient(&mention))
+                .unwrap_or_default(),
         })
     }
 }
@@ -156,7 +194,16 @@ impl TaskSchedulerRepository for SqliteSchedulerRepository {
                     notification_method = excluded.notification_method,
                     is_recurring = excluded.is_recurring,
                     is_deleted = 0,
-                    mention = excluded.mention
+                    mention = excluded.mention,
+                    -- keep an in-flight lease only if the same occurrence is re-added (e.g. startup reload)
+                    lease_owner = CASE
+                        WHEN scheduled_tasks.is_deleted = 0
+                            AND scheduled_tasks.scheduled_time = excluded.scheduled_time
+                        THEN scheduled_tasks.lease_owner ELSE NULL END,
+                    lease_expires_at = CASE
+                        WHEN scheduled_tasks.is_deleted = 0
+                            AND scheduled_tasks.scheduled_time = excluded.scheduled_time
+                        THEN scheduled_tasks.lease_expires_at ELSE NULL END
                 "#,
                 params![
                     task_clone.task_id as i64,
@@ -167,7 +214,8 @@ impl TaskSchedulerRepository for SqliteSchedulerRepository {
                     notification_method_to_str(&task_clone.notification_method),
                     if task_clone.is_recurring { 1 } else { 0 },
                     0i64, // clear is_deleted on upsert
-                    task_clone.mention
+                    (!task_clone.mentions.is_empty())
+                        .then(|| format_mentions(&task_clone.mentions))
                 ],
             )
             .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
@@ -185,64 +233,237 @@ impl TaskSchedulerRepository for SqliteSchedulerRepository {
         Ok(())
     }
 
-    /// Return next (non-deleted) scheduled task without removing it, ordered by scheduled_time ASC.
-    async fn peek_next_task(&self) -> Result<Option<ScheduledTask>, SchedulerError> {
+    /// Lease the earliest due row inside an IMMEDIATE transaction, so two processes never claim the same row.
+    async fn claim_due_task(
+        &self,
+        owner: &str,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+    ) -> Result<Option<ScheduledTask>, SchedulerError> {
+        let conn = self.conn.clone();
+        let owner = owner.to_string();
+        tokio::task::spawn_blocking(move || {
+            let mut conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            let tx = conn_lock
+                .transaction_with_behavior(TransactionBehavior::Immediate)
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
+            let claimed = tx
+                .query_row(
+                    "UPDATE scheduled_tasks SET lease_owner = ?1, lease_expires_at = ?3
+                     WHERE task_id = (
+                         SELECT task_id FROM scheduled_tasks
+                         WHERE is_deleted = 0 AND scheduled_time <= ?2
+                           AND (lease_owner IS NULL OR lease_expires_at <= ?2)
+                         ORDER BY scheduled_time ASC LIMIT 1
+                     )
+                     RETURNING task_id, scheduled_time, user_id, guild_id, title, notification_method, is_recurring, is_deleted, mention",
+                    params![owner, now.timestamp(), lease_until.timestamp()],
+                    |row| Ok(SqliteSchedulerRepository::row_to_scheduled_task(row)),
+                )
+                .optional()
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?
+                .transpose()?;
+
+            tx.commit().map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+            Ok(claimed)
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    /// Earliest MAX(scheduled_time, lease_expires_at) among pending rows.
+    async fn next_claimable_time(&self) -> Result<Option<DateTime<Utc>>, SchedulerError> {
         let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || {
+            let conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            let next: Option<i64> = conn_lock
+                .query_row(
+                    "SELECT MIN(MAX(scheduled_time, COALESCE(lease_expires_at, scheduled_time)))
+                     FROM scheduled_tasks WHERE is_deleted = 0",
+                    [],
+                    |r| r.get(0),
+                )
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
+            next.map(|ts| {
+                Utc.timestamp_opt(ts, 0)
+                    .single()
+                    .ok_or_else(|| SchedulerError::StorageError("Invalid timestamp in DB".to_string()))
+            })
+            .transpose()
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    /// Move a claimed row to `retry_at` and clear its lease, only if `owner` still holds it.
+    async fn release_claim(
+        &self,
+        task_id: u64,
+        owner: &str,
+        retry_at: DateTime<Utc>,
+    ) -> Result<(), SchedulerError> {
+        let conn = self.conn.clone();
+        let owner = owner.to_string();
+        tokio::task::spawn_blocking(move || {
+            let conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            conn_lock
+                .execute(
+                    "UPDATE scheduled_tasks SET scheduled_time = ?3, lease_owner = NULL, lease_expires_at = NULL
+                     WHERE task_id = ?1 AND lease_owner = ?2",
+                    params![task_id as i64, owner, retry_at.timestamp()],
+                )
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+            Ok(())
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    /// Insert the delivery key and delete the claimed row in one transaction.
+    async fn complete_claim(
+        &self,
+        task: &ScheduledTask,
+        owner: &str,
+        delivered_at: DateTime<Utc>,
+    ) -> Result<bool, SchedulerError> {
+        let conn = self.conn.clone();
+        let owner = owner.to_string();
+        let task_id = task.task_id;
+        let scheduled_time = task.scheduled_time.timestamp();
+        let delivery_key = task.delivery_key();
+        tokio::task::spawn_blocking(move || {
+            let mut conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            let tx = conn_lock
+                .transaction_with_behavior(TransactionBehavior::Immediate)
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
+            tx.execute(
+                "INSERT OR IGNORE INTO scheduled_deliveries (delivery_key, task_id, delivered_by, delivered_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![delivery_key, task_id as i64, owner, delivered_at.timestamp()],
+            )
+            .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
+            // The row only goes away if it is still the occurrence we claimed
+            let removed = tx
+                .execute(
+                    "DELETE FROM scheduled_tasks WHERE task_id = ?1 AND lease_owner = ?2 AND scheduled_time = ?3",
+                    params![task_id as i64, owner, scheduled_time],
+                )
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
+            tx.commit().map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+            Ok(removed > 0)
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    async fn is_delivered(&self, delivery_key: &str) -> Result<bool, SchedulerError> {
+        let conn = self.conn.clone();
+        let delivery_key = delivery_key.to_string();
+        tokio::task::spawn_blocking(move || {
+            let conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            conn_lock
+                .query_row(
+                    "SELECT EXISTS(SELECT 1 FROM scheduled_deliveries WHERE delivery_key = ?1)",
+                    params![delivery_key],
+                    |r| r.get(0),
+                )
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    async fn prune_deliveries(&self, before: DateTime<Utc>) -> Result<usize, SchedulerError> {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || {
+            let conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            conn_lock
+                .execute(
+                    "DELETE FROM scheduled_deliveries WHERE delivered_at < ?1",
+                    params![before.timestamp()],
+                )
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    async fn list_deliveries(&self, task_ids: &[u64]) -> Result<Vec<DeliveryRecord>, SchedulerError> {
+        let conn = self.conn.clone();
+        let task_ids = task_ids.to_vec();
         tokio::task::spawn_blocking(move || {
             let conn_lock = conn.lock()
                 .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
             let mut stmt = conn_lock.prepare(
-                "SELECT task_id, scheduled_time, user_id, guild_id, title, notification_method, is_recurring, is_deleted, mention
-                 FROM scheduled_tasks WHERE is_deleted = 0 ORDER BY scheduled_time ASC LIMIT 1",
+                "SELECT delivery_key, delivered_at FROM scheduled_deliveries
+                 WHERE task_id = ?1 ORDER BY delivered_at ASC",
             ).map_err(|e| SchedulerError::StorageError(e.to_string()))?;
 
-            let mut rows = stmt.query([]).map_err(|e| SchedulerError::StorageError(e.to_string()))?;
-            match rows.next().map_err(|e| SchedulerError::StorageError(e.to_string()))? {
-                Some(row) => SqliteSchedulerRepository::row_to_scheduled_task(&row).map(Some),
-                None => Ok(None),
+            let mut records = Vec::new();
+            for task_id in task_ids {
+                let rows = stmt
+                    .query_map(params![task_id as i64], |row| {
+                        Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+                    })
+                    .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+                for row in rows {
+                    let (key, delivered_at) = row.map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+                    let delivered_at = DateTime::from_timestamp(delivered_at, 0)
+                        .ok_or_else(|| SchedulerError::StorageError("Invalid timestamp".into()))?;
+                    records.extend(DeliveryRecord::from_key(&key, delivered_at));
+                }
             }
+            records.sort_by_key(|record| (record.delivered_at, record.task_id));
+            Ok(records)
         })
         .await
         .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
     }
 
-    /// Remove and return the next pending task (non-deleted). This deletes the row from DB.
-    async fn pop_next_task(&self) -> Result<Option<ScheduledTask>, SchedulerError> {
+    /// Delete the user's rows and their delivery keys in one transaction.
+    async fn purge_user(&self, user_id: u64, task_ids: &[u64]) -> Result<usize, SchedulerError> {
         let conn = self.conn.clone();
+        let task_ids = task_ids.to_vec();
         tokio::task::spawn_blocking(move || {
             let mut conn_lock = conn.lock()
                 .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
-            let tx = conn_lock.transaction().map_err(|e| SchedulerError::StorageError(e.to_string()))?;
-
-            // Select next non-deleted
-            let task_opt = {
-                let mut stmt = tx.prepare(
-                    "SELECT task_id, scheduled_time, user_id, guild_id, title, notification_method, is_recurring, is_deleted, mention
-                     FROM scheduled_tasks WHERE is_deleted = 0 ORDER BY scheduled_time ASC LIMIT 1",
-                ).map_err(|e| SchedulerError::StorageError(e.to_string()))?;
-
-                let mut rows = stmt.query([]).map_err(|e| SchedulerError::StorageError(e.to_string()))?;
-                if let Some(row) = rows.next().map_err(|e| SchedulerError::StorageError(e.to_string()))? {
-                    Some(SqliteSchedulerRepository::row_to_scheduled_task(&row)?)
-                } else {
-                    None
-                }
-            };
+            let tx = conn_lock
+                .transaction_with_behavior(TransactionBehavior::Immediate)
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
 
-            if let Some(task) = task_opt {
-                // Delete selected task (pop)
+            let mut removed = tx
+                .execute(
+                    "DELETE FROM scheduled_tasks WHERE user_id = ?1",
+                    params![user_id as i64],
+                )
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+            for task_id in &task_ids {
+                removed += tx
+                    .execute(
+                        "DELETE FROM scheduled_tasks WHERE task_id = ?1",
+                        params![*task_id as i64],
+                    )
+                    .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
                 tx.execute(
-                    "DELETE FROM scheduled_tasks WHERE task_id = ?1",
-                    params![task.task_id as i64],
+                    "DELETE FROM scheduled_deliveries WHERE task_id = ?1",
+                    params![*task_id as i64],
                 )
                 .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
-
-                tx.commit().map_err(|e| SchedulerError::StorageError(e.to_string()))?;
-                Ok(Some(task))
-            } else {
-                // nothing to pop
-                Ok(None)
             }
+
+            tx.commit().map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+            Ok(removed)
         })
         .await
         .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
@@ -289,4 +510,78 @@ impl TaskSchedulerRepository for SqliteSchedulerRepository {
         .await
         .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
     }
+
+    async fn pending_count(&self) -> Result<usize, SchedulerError> {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || {
+            let conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            let count: i64 = conn_lock
+                .query_row(
+                    "SELECT COUNT(1) FROM scheduled_tasks WHERE is_deleted = 0",
+                    [],
+                    |r| r.get(0),
+                )
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+            Ok(count as usize)
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    async fn list_pending(&self) -> Result<Vec<ScheduledTask>, SchedulerError> {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || {
+            let conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            let mut stmt = conn_lock.prepare(
+                "SELECT task_id, scheduled_time, user_id, guild_id, title, notification_method, is_recurring, is_deleted, mention
+                 FROM scheduled_tasks WHERE is_deleted = 0 ORDER BY scheduled_time ASC, task_id ASC",
+            ).map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+
+            let mut rows = stmt.query([]).map_err(|e| SchedulerError::StorageError(e.to_string()))?;
+            let mut pending = Vec::new();
+            while let Some(row) = rows.next().map_err(|e| SchedulerError::StorageError(e.to_string()))? {
+                pending.push(SqliteSchedulerRepository::row_to_scheduled_task(row)?);
+            }
+            Ok(pending)
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))?
+    }
+
+    /// Purge soft-deleted rows. Upserts reuse tombstoned rows, so only abandoned ones are left to remove.
+    async fn compact(&self) -> Result<usize, SchedulerError> {
+        let conn = self.conn.clone();
+        let removed = tokio::task::spawn_blocking(move || {
+            let conn_lock = conn.lock()
+                .map_err(|e| SchedulerError::StorageError(format!("Lock poisoned: {}", e)))?;
+            conn_lock
+                .execute("DELETE FROM scheduled_tasks WHERE is_deleted = 1", [])
+                .map_err(|e| SchedulerError::StorageError(e.to_string()))
+        })
+        .await
+        .map_err(|_| SchedulerError::StorageError("Task join error".into()))??;
+
+        if removed > 0 {
+            self.tombstones_removed
+                .fetch_add(removed as u64, Ordering::Relaxed);
+            debug!("Purged {} soft-deleted scheduler rows", removed);
+        }
+        Ok(removed)
+    }
+
+    fn tombstones_removed(&self) -> u64 {
+        self.tombstones_removed.load(Ordering::Relaxed)
+    }
+
+    /// Get a receiver for wake-up notifications (for scheduler to react to new tasks)
+    fn subscribe_wakeup(&self) -> broadcast::Receiver<()> {
+        self.wakeup_sender.subscribe()
+    }
+
+    /// The database file can be opened by other bot instances and the admin CLI
+    fn is_shared(&self) -> bool {
+        true
+    }
 }
@@ -1,11 +1,14 @@
 // src/infrastructure/repositories/sqlite_task_repository.rs
 use crate::domain::repositories::TaskRepository;
+use crate::domain::value_objects::MentionTarget;
+use crate::domain::value_objects::mention::parse_mentions_lenient;
 use crate::domain::{NotificationMethod, Recurrence, Task};
 
 use async_trait::async_trait;
 use chrono::{DateTime, TimeZone, Utc};
 use rusqlite::{Connection, params};
 use serde_json;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 pub struct SqliteTaskRepository {
@@ -13,7 +16,7 @@ pub struct SqliteTaskRepository {
 }
 
 impl SqliteTaskRepository {
-    pub fn new(path: &str) -> Result<Self, String> {
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, String> {
         let conn = Connection::open(path).map_err(|e| e.to_string())?;
 
         // Create table if it doesn't exist (with all columns)
@@ -30,7 +33,10 @@ impl SqliteTaskRepository {
                 recurrence_data  TEXT,
                 notification_method TEXT NOT NULL,
                 channel_id       INTEGER,
-                mention          TEXT
+                mention          TEXT,
+                mentions         TEXT,
+                timezone         TEXT,
+                archived         INTEGER NOT NULL DEFAULT 0
             );
             ",
         )
@@ -48,11 +54,69 @@ impl SqliteTaskRepository {
                 .map_err(|e| format!("Failed to add channel_id column: {}", e))?;
         }
 
+        Self::migrate_mentions(&conn)?;
+
+        let timezone_exists: bool = conn
+            .prepare("SELECT COUNT(*) FROM pragma_table_info('tasks') WHERE name = 'timezone'")
+            .and_then(|mut stmt| stmt.query_row([], |row| row.get::<_, i32>(0)))
+            .map(|count| count > 0)
+            .unwrap_or(false);
+        if !timezone_exists {
+            conn.execute("ALTER TABLE tasks ADD COLUMN timezone TEXT", [])
+                .map_err(|e| format!("Failed to add timezone column: {}", e))?;
+        }
+
+        let archived_exists: bool = conn
+            .prepare("SELECT COUNT(*) FROM pragma_table_info('tasks') WHERE name = 'archived'")
+            .and_then(|mut stmt| stmt.query_row([], |row| row.get::<_, i32>(0)))
+            .map(|count| count > 0)
+            .unwrap_or(false);
+        if !archived_exists {
+            conn.execute(
+                "ALTER TABLE tasks ADD COLUMN archived INTEGER NOT NULL DEFAULT 0",
+                [],
+            )
+            .map_err(|e| format!("Failed to add archived column: {}", e))?;
+        }
+
         Ok(Self {
             conn: Arc::new(Mutex::new(conn)),
         })
     }
 
+    /// Older versions kept mentions as free text in `mention`, parse them into the JSON `mentions`
+    /// column once. Text that isn't a user or role mention is dropped
+    fn migrate_mentions(conn: &Connection) -> Result<(), String> {
+        let column_exists: bool = conn
+            .prepare("SELECT COUNT(*) FROM pragma_table_info('tasks') WHERE name = 'mentions'")
+            .and_then(|mut stmt| stmt.query_row([], |row| row.get::<_, i32>(0)))
+            .map(|count| count > 0)
+            .unwrap_or(false);
+        if !column_exists {
+            conn.execute("ALTER TABLE tasks ADD COLUMN mentions TEXT", [])
+                .map_err(|e| format!("Failed to add mentions column: {}", e))?;
+        }
+
+        let legacy: Vec<(i64, String)> = conn
+            .prepare("SELECT id, mention FROM tasks WHERE mention IS NOT NULL")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect()
+            })
+            .map_err(|e| e.to_string())?;
+
+        for (id, mention) in legacy {
+            let mentions = mentions_to_json(&parse_mentions_lenient(&mention))?;
+            conn.execute(
+                "UPDATE tasks SET mentions = ?2, mention = NULL WHERE id = ?1",
+                params![id, mentions],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
     // NOTE: helper to convert a rusqlite::Row -> Task; kept synchronous because it runs inside spawn_blocking
     fn row_to_task(row: &rusqlite::Row) -> Result<Task, String> {
         let id: i64 = row.get("id").map_err(|e| e.to_string())?;
@@ -105,7 +169,12 @@ impl SqliteTaskRepository {
         };
 
         let channel_id: Option<i64> = row.get("channel_id").map_err(|e| e.to_string())?;
-        let mention: Option<String> = row.get("mention").map_err(|e| e.to_string())?;
+        let mentions: Option<String> = row.get("mentions").map_err(|e| e.to_string())?;
+        let mentions: Vec<MentionTarget> = match mentions {
+            Some(json) => serde_json::from_str(&json).map_err(|e| e.to_string())?,
+            None => Vec::new(),
+        };
+        let timezone: Option<String> = row.get("timezone").map_err(|e| e.to_string())?;
 
         Ok(Task::new(
             id as u64,
@@ -117,8 +186,39 @@ impl SqliteTaskRepository {
             recurrence,
             notification_method,
             channel_id.map(|v| v as u64),
-            mention,
-        ))
+            mentions,
+        )
+        .with_timezone(timezone))
+    }
+
+    /// Tasks in rowid order, either the archived ones or the rest
+    async fn select_tasks(&self, archived: bool) -> Vec<Task> {
+        let conn = self.conn.clone();
+
+        tokio::task::spawn_blocking(move || -> Vec<Task> {
+            let conn_lock = match conn.lock() {
+                Ok(lock) => lock,
+                Err(_) => return Vec::new(),
+            };
+
+            let mut stmt = match conn_lock.prepare("SELECT * FROM tasks WHERE archived = ?1") {
+                Ok(s) => s,
+                Err(_) => return Vec::new(),
+            };
+
+            let iter = match stmt.query_map(params![archived], |row| {
+                SqliteTaskRepository::row_to_task(row).map_err(|e| {
+                    rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::other(e)))
+                })
+            }) {
+                Ok(it) => it,
+                Err(_) => return Vec::new(),
+            };
+
+            iter.filter_map(|r| r.ok()).collect()
+        })
+        .await
+        .unwrap_or_else(|_| Vec::new())
     }
 }
 
@@ -141,37 +241,14 @@ impl TaskRepository for SqliteTaskRepository {
 
             let scheduled_ts = task.scheduled_time.map(|dt| dt.timestamp());
 
-            let (rec_type, rec_data) = match &task.recurrence {
-                Some(Recurrence::Weekly { days, hour, minute }) => {
-                    let json = serde_json::to_string(&crate::domain::WeeklyRecurrenceData {
-                        days: days.clone(),
-                        hour: *hour,
-                        minute: *minute,
-                    })
-                    .map_err(|e| e.to_string())?;
-                    (Some("weekly".to_string()), Some(json))
-                }
-                Some(Recurrence::EveryXDays {
-                    interval,
-                    hour,
-                    minute,
-                }) => {
-                    let json = serde_json::to_string(&crate::domain::EveryXDaysRecurrenceData {
-                        interval: *interval,
-                        hour: *hour,
-                        minute: *minute,
-                    })
-                    .map_err(|e| e.to_string())?;
-                    (Some("every_x_days".to_string()), Some(json))
-                }
-                None => (None, None),
-            };
+            let (rec_type, rec_data) = recurrence_columns(&task.recurrence)?;
 
             let notif = match task.notification_method {
                 NotificationMethod::DM => "dm",
                 NotificationMethod::Channel => "channel",
                 NotificationMethod::Both => "both",
             };
+            let mentions = mentions_to_json(&task.mentions)?;
 
             let conn_lock = conn.lock()
                 .map_err(|e| format!("Lock poisoned: {}", e))?;
@@ -180,9 +257,9 @@ impl TaskRepository for SqliteTaskRepository {
                     "INSERT INTO tasks (
                             id, user_id, guild_id, title, description, scheduled_time,
                             recurrence_type, recurrence_data,
-                            notification_method, channel_id, mention
+                            notification_method, channel_id, mentions, timezone
                          )
-                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
                     params![
                         id as i64,
                         task.user_id as i64,
@@ -194,7 +271,8 @@ impl TaskRepository for SqliteTaskRepository {
                         rec_data,
                         notif,
                         task.channel_id.map(|v| v as i64),
-                        task.mention
+                        mentions,
+                        task.timezone
                     ],
                 )
                 .map_err(|e| e.to_string())?;
@@ -213,6 +291,7 @@ impl TaskRepository for SqliteTaskRepository {
         new_scheduled_time: Option<DateTime<Utc>>,
         new_recurrence: Option<Recurrence>,
         new_notification_method: Option<NotificationMethod>,
+        new_timezone: Option<Option<String>>,
     ) -> Result<Task, String> {
         let conn = self.conn.clone();
 
@@ -257,35 +336,14 @@ impl TaskRepository for SqliteTaskRepository {
             if let Some(n) = new_notification_method {
                 updated.notification_method = n;
             }
+            if let Some(timezone) = new_timezone {
+                updated.timezone = timezone;
+            }
 
             // prepare fields
             let scheduled_ts = updated.scheduled_time.map(|dt| dt.timestamp());
 
-            let (rec_type, rec_data) = match &updated.recurrence {
-                Some(Recurrence::Weekly { days, hour, minute }) => {
-                    let json = serde_json::to_string(&crate::domain::WeeklyRecurrenceData {
-                        days: days.clone(),
-                        hour: *hour,
-                        minute: *minute,
-                    })
-                    .map_err(|e| e.to_string())?;
-                    (Some("weekly".to_string()), Some(json))
-                }
-                Some(Recurrence::EveryXDays {
-                    interval,
-                    hour,
-                    minute,
-                }) => {
-                    let json = serde_json::to_string(&crate::domain::EveryXDaysRecurrenceData {
-                        interval: *interval,
-                        hour: *hour,
-                        minute: *minute,
-                    })
-                    .map_err(|e| e.to_string())?;
-                    (Some("every_x_days".to_string()), Some(json))
-                }
-                None => (None, None),
-            };
+            let (rec_type, rec_data) = recurrence_columns(&updated.recurrence)?;
 
             let notif = match updated.notification_method {
                 NotificationMethod::DM => "dm",
@@ -305,7 +363,8 @@ impl TaskRepository for SqliteTaskRepository {
                             recurrence_data = ?8,
                             notification_method = ?9,
                             channel_id = ?10,
-                            mention = ?11
+                            mentions = ?11,
+                            timezone = ?12
                          WHERE id = ?1",
                     params![
                         task_id as i64,
@@ -318,7 +377,8 @@ impl TaskRepository for SqliteTaskRepository {
                         rec_data,
                         notif,
                         updated.channel_id.map(|v| v as i64),
-                        updated.mention
+                        mentions_to_json(&updated.mentions)?,
+                        updated.timezone
                     ],
                 )
                 .map_err(|e| e.to_string())?;
@@ -329,6 +389,35 @@ impl TaskRepository for SqliteTaskRepository {
         .map_err(|e| e.to_string())?
     }
 
+    async fn move_to_dm(&self, task_id: u64) -> Result<Task, String> {
+        let conn = self.conn.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<Task, String> {
+            let conn_lock = conn.lock().map_err(|e| format!("Lock poisoned: {}", e))?;
+
+            let changed = conn_lock
+                .execute(
+                    "UPDATE tasks SET notification_method = 'dm', channel_id = NULL, mentions = NULL
+                     WHERE id = ?1",
+                    params![task_id as i64],
+                )
+                .map_err(|e| e.to_string())?;
+            if changed == 0 {
+                return Err(format!("Couldn't find task with ID {}", task_id));
+            }
+
+            let mut stmt = conn_lock
+                .prepare("SELECT * FROM tasks WHERE id = ?1")
+                .map_err(|e| e.to_string())?;
+            let row_result = stmt.query_row(params![task_id as i64], |row| {
+                Ok(SqliteTaskRepository::row_to_task(row))
+            });
+            row_result.map_err(|e| e.to_string())?
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
     async fn remove_task(&self, task_id: u64) -> bool {
         let conn = self.conn.clone();
 
@@ -366,35 +455,39 @@ impl TaskRepository for SqliteTaskRepository {
     }
 
     async fn list_tasks(&self) -> Vec<Task> {
-        let conn = self.conn.clone();
+        self.select_tasks(false).await
+    }
 
-        tokio::task::spawn_blocking(move || -> Vec<Task> {
-            let conn_lock = match conn.lock() {
-                Ok(lock) => lock,
-                Err(_) => return Vec::new(),
-            };
+    async fn list_archived(&self) -> Vec<Task> {
+        self.select_tasks(true).await
+    }
 
-            let mut stmt = match conn_lock.prepare("SELECT * FROM tasks") {
-                Ok(s) => s,
-                Err(_) => return Vec::new(),
-            };
+    async fn set_archived(&self, task_id: u64, archived: bool) -> Result<Task, String> {
+        let conn = self.conn.clone();
 
-            let iter = match stmt.query_map([], |row| {
-                SqliteTaskRepository::row_to_task(row).map_err(|e| {
-                    rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        e,
-                    )))
-                })
-            }) {
-                Ok(it) => it,
-                Err(_) => return Vec::new(),
-            };
+        tokio::task::spawn_blocking(move || -> Result<Task, String> {
+            let conn_lock = conn.lock().map_err(|e| format!("Lock poisoned: {}", e))?;
 
-            iter.filter_map(|r| r.ok()).collect()
+            let changed = conn_lock
+                .execute(
+                    "UPDATE tasks SET archived = ?2 WHERE id = ?1",
+                    params![task_id as i64, archived],
+                )
+                .map_err(|e| e.to_string())?;
+            if changed == 0 {
+                return Err(format!("Couldn't find task with ID {}", task_id));
+            }
+
+            let mut stmt = conn_lock
+                .prepare("SELECT * FROM tasks WHERE id = ?1")
+                .map_err(|e| e.to_string())?;
+            let row_result = stmt.query_row(params![task_id as i64], |row| {
+                Ok(SqliteTaskRepository::row_to_task(row))
+            });
+            row_result.map_err(|e| e.to_string())?
         })
         .await
-        .unwrap_or_else(|_| Vec::new())
+        .map_err(|e| e.to_string())?
     }
 
     /// Get total count of all tasks in the system (admin only)
@@ -420,6 +513,23 @@ impl TaskRepository for SqliteTaskRepository {
         count
     }
 
+    async fn update_schedules(
+        &self,
+        schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+    ) -> Result<(), String> {
+        let conn = self.conn.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<(), String> {
+            let mut conn_lock = conn.lock().map_err(|e| format!("Lock poisoned: {}", e))?;
+            // Dropping the transaction on an early return rolls every change back
+            let tx = conn_lock.transaction().map_err(|e| e.to_string())?;
+            write_schedules(&tx, schedules)?;
+            tx.commit().map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
     async fn update_task_time(&self, task_id: u64, new_time: DateTime<Utc>) -> Result<(), String> {
         let conn = self.conn.clone();
         let ts = new_time.timestamp();
@@ -438,3 +548,72 @@ impl TaskRepository for SqliteTaskRepository {
         .map_err(|e| e.to_string())?
     }
 }
+
+/// Replace the scheduled time and recurrence of each task, stops at the first missing one.
+/// Run it inside a transaction so a failure leaves nothing half written
+pub(crate) fn write_schedules(
+    conn: &Connection,
+    schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+) -> Result<(), String> {
+    for (task_id, scheduled_time, recurrence) in schedules {
+        let (rec_type, rec_data) = recurrence_columns(&recurrence)?;
+        let changed = conn
+            .execute(
+                "UPDATE tasks SET scheduled_time = ?2, recurrence_type = ?3,
+                    recurrence_data = ?4
+                 WHERE id = ?1",
+                params![
+                    task_id as i64,
+                    scheduled_time.map(|dt| dt.timestamp()),
+                    rec_type,
+                    rec_data
+                ],
+            )
+            .map_err(|e| e.to_string())?;
+        if changed == 0 {
+            return Err(format!("Couldn't find task with ID {}", task_id));
+        }
+    }
+    Ok(())
+}
+
+/// `recurrence_type` and `recurrence_data` column values, NULL for single tasks
+fn recurrence_columns(
+    recurrence: &Option<Recurrence>,
+) -> Result<(Option<String>, Option<String>), String> {
+    match recurrence {
+        Some(Recurrence::Weekly { days, hour, minute }) => {
+            let json = serde_json::to_string(&crate::domain::WeeklyRecurrenceData {
+                days: days.clone(),
+                hour: *hour,
+                minute: *minute,
+            })
+            .map_err(|e| e.to_string())?;
+            Ok((Some("weekly".to_string()), Some(json)))
+        }
+        Some(Recurrence::EveryXDays {
+            interval,
+            hour,
+            minute,
+        }) => {
+            let json = serde_json::to_string(&crate::domain::EveryXDaysRecurrenceData {
+                interval: *interval,
+                hour: *hour,
+                minute: *minute,
+            })
+            .map_err(|e| e.to_string())?;
+            Ok((Some("every_x_days".to_string()), Some(json)))
+        }
+        None => Ok((None, None)),
+    }
+}
+
+/// `mentions` column value, NULL when there are none
+fn mentions_to_json(mentions: &[MentionTarget]) -> Result<Option<String>, String> {
+    if mentions.is_empty() {
+        return Ok(None);
+    }
+    serde_json::to_string(mentions)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
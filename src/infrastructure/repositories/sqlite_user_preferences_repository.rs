@@ -1,13 +1,16 @@
 use async_trait::async_trait;
-use chrono::TimeZone;
+use chrono::{DateTime, TimeZone, Utc};
 use rusqlite::{Connection, params};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
+use crate::domain::entities::task::Recurrence;
 use crate::domain::entities::user_preferences::UserPreferences;
 use crate::domain::repositories::user_preferences_repository::{
     RepositoryError, UserPreferencesRepository,
 };
+use crate::domain::value_objects::weekday_format::WeekdayFormat;
+use crate::infrastructure::repositories::sqlite_task_repository::write_schedules;
 
 pub struct SqliteUserPreferencesRepository {
     connection: Arc<Mutex<Connection>>,
@@ -41,7 +44,12 @@ impl SqliteUserPreferencesRepository {
                 timezone       TEXT NOT NULL,
                 date_format    TEXT, -- NULL for backward compatibility
                 created_at     INTEGER NOT NULL,
-                updated_at     INTEGER NOT NULL
+                updated_at     INTEGER NOT NULL,
+                working_hours  TEXT, -- HH:MM-HH:MM, NULL for the default
+                date_format_chosen INTEGER NOT NULL DEFAULT 0,
+                clock_format   TEXT, -- '12h' or '24h', NULL for 24h
+                first_weekday  TEXT, -- 'Sun', 'Sat'..., NULL for Monday
+                language       TEXT -- 'en' or 'es', NULL for the Discord client's language
             );
             "#,
             [],
@@ -53,9 +61,92 @@ impl SqliteUserPreferencesRepository {
             "ALTER TABLE user_preferences ADD COLUMN date_format TEXT;",
             [],
         );
+        let _ = conn.execute(
+            "ALTER TABLE user_preferences ADD COLUMN working_hours TEXT;",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE user_preferences ADD COLUMN date_format_chosen INTEGER NOT NULL DEFAULT 0;",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE user_preferences ADD COLUMN clock_format TEXT;",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE user_preferences ADD COLUMN first_weekday TEXT;",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE user_preferences ADD COLUMN language TEXT;",
+            [],
+        );
 
         Ok(())
     }
+
+    /// Insert or update the row, created_at stays the one first stored
+    fn upsert(conn: &Connection, prefs: &UserPreferences) -> rusqlite::Result<usize> {
+        conn.execute(
+            r#"
+            INSERT INTO user_preferences (user_id, timezone, date_format, created_at, updated_at, working_hours,
+                                          date_format_chosen, clock_format, first_weekday, language)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            ON CONFLICT(user_id) DO UPDATE SET
+                timezone = excluded.timezone,
+                date_format = excluded.date_format,
+                updated_at = excluded.updated_at,
+                working_hours = excluded.working_hours,
+                date_format_chosen = excluded.date_format_chosen,
+                clock_format = excluded.clock_format,
+                first_weekday = excluded.first_weekday,
+                language = excluded.language;
+            "#,
+            params![
+                prefs.user_id as i64,
+                prefs.timezone,
+                prefs.date_format,
+                prefs.created_at.timestamp(),
+                prefs.updated_at.timestamp(),
+                prefs.working_hours.map(|hours| hours.to_string()),
+                prefs.date_format_chosen,
+                prefs.clock_format.code(),
+                prefs.first_weekday.map(|day| day.to_short_en()),
+                prefs.language.map(|language| language.code())
+            ],
+        )
+    }
+
+    fn row_to_preferences(row: &rusqlite::Row) -> rusqlite::Result<UserPreferences> {
+        let created_at = row.get::<_, i64>(3)?;
+        let updated_at = row.get::<_, i64>(4)?;
+
+        Ok(UserPreferences {
+            user_id: row.get::<_, i64>(0)? as u64,
+            timezone: row.get(1)?,
+            date_format: row.get(2)?,
+            working_hours: row
+                .get::<_, Option<String>>(5)?
+                .and_then(|hours| hours.parse().ok()),
+            date_format_chosen: row.get(6)?,
+            clock_format: row
+                .get::<_, Option<String>>(7)?
+                .and_then(|clock| clock.parse().ok())
+                .unwrap_or_default(),
+            first_weekday: row
+                .get::<_, Option<String>>(8)?
+                .and_then(|day| <chrono::Weekday as WeekdayFormat>::from_str(&day)),
+            language: row
+                .get::<_, Option<String>>(9)?
+                .and_then(|language| language.parse().ok()),
+            created_at: chrono::Utc.timestamp_opt(created_at, 0)
+                .single()
+                .ok_or(rusqlite::Error::InvalidQuery)?,
+            updated_at: chrono::Utc.timestamp_opt(updated_at, 0)
+                .single()
+                .ok_or(rusqlite::Error::InvalidQuery)?,
+        })
+    }
 }
 
 #[async_trait]
@@ -68,29 +159,12 @@ impl UserPreferencesRepository for SqliteUserPreferencesRepository {
                 .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Lock poisoned: {}", e)))))?;
 
             let mut stmt = conn.prepare(
-                "SELECT user_id, timezone, date_format, created_at, updated_at
+                "SELECT user_id, timezone, date_format, created_at, updated_at, working_hours,
+                        date_format_chosen, clock_format, first_weekday, language
                  FROM user_preferences WHERE user_id = ?1",
             )?;
 
-            let row = stmt.query_row(params![user_id as i64], |row| {
-                let user_id_val = row.get::<_, i64>(0)? as u64;
-                let timezone_val = row.get::<_, String>(1)?;
-                let date_format_val = row.get::<_, Option<String>>(2)?;
-                let created_at_val = row.get::<_, i64>(3)?;
-                let updated_at_val = row.get::<_, i64>(4)?;
-
-                Ok(UserPreferences {
-                    user_id: user_id_val,
-                    timezone: timezone_val,
-                    date_format: date_format_val,
-                    created_at: chrono::Utc.timestamp_opt(created_at_val, 0)
-                        .single()
-                        .ok_or_else(|| rusqlite::Error::InvalidQuery)?,
-                    updated_at: chrono::Utc.timestamp_opt(updated_at_val, 0)
-                        .single()
-                        .ok_or_else(|| rusqlite::Error::InvalidQuery)?,
-                })
-            });
+            let row = stmt.query_row(params![user_id as i64], Self::row_to_preferences);
 
             match row {
                 Ok(pref) => Ok(Some(pref)),
@@ -118,23 +192,7 @@ impl UserPreferencesRepository for SqliteUserPreferencesRepository {
             let conn = conn.lock()
                 .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Lock poisoned: {}", e)))))?;
 
-            conn.execute(
-                r#"
-                INSERT INTO user_preferences (user_id, timezone, date_format, created_at, updated_at)
-                VALUES (?1, ?2, ?3, ?4, ?5)
-                ON CONFLICT(user_id) DO UPDATE SET
-                    timezone = excluded.timezone,
-                    date_format = excluded.date_format,
-                    updated_at = excluded.updated_at;
-                "#,
-                params![
-                    prefs.user_id as i64,
-                    prefs.timezone,
-                    prefs.date_format,
-                    prefs.created_at.timestamp(),
-                    prefs.updated_at.timestamp()
-                ],
-            )?;
+            Self::upsert(&conn, &prefs)?;
 
             Ok::<_, rusqlite::Error>(())
         })
@@ -144,6 +202,58 @@ impl UserPreferencesRepository for SqliteUserPreferencesRepository {
         result.map_err(|e| RepositoryError::StorageError(e.to_string()))
     }
 
+    async fn save_with_task_schedules(
+        &self,
+        preferences: &UserPreferences,
+        schedules: Vec<(u64, Option<DateTime<Utc>>, Option<Recurrence>)>,
+    ) -> Result<(), RepositoryError> {
+        if !preferences.is_valid() {
+            return Err(RepositoryError::InvalidData(
+                "Invalid user preferences".into(),
+            ));
+        }
+
+        let conn = self.connection.clone();
+        let prefs = preferences.clone();
+
+        // The tasks table lives in the same database file, so one transaction covers both
+        tokio::task::spawn_blocking(move || {
+            let mut conn = conn
+                .lock()
+                .map_err(|e| RepositoryError::StorageError(format!("Lock poisoned: {}", e)))?;
+            let tx = conn
+                .transaction()
+                .map_err(|e| RepositoryError::StorageError(e.to_string()))?;
+            write_schedules(&tx, schedules).map_err(RepositoryError::InvalidData)?;
+            Self::upsert(&tx, &prefs).map_err(|e| RepositoryError::StorageError(e.to_string()))?;
+            tx.commit()
+                .map_err(|e| RepositoryError::StorageError(e.to_string()))
+        })
+        .await
+        .map_err(|_| RepositoryError::StorageError("Task join error".into()))?
+    }
+
+    async fn list_all(&self) -> Result<Vec<UserPreferences>, RepositoryError> {
+        let conn = self.connection.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let conn = conn.lock()
+                .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(std::io::Error::other(format!("Lock poisoned: {}", e)))))?;
+
+            let mut stmt = conn.prepare(
+                "SELECT user_id, timezone, date_format, created_at, updated_at, working_hours,
+                        date_format_chosen, clock_format, first_weekday, language
+                 FROM user_preferences ORDER BY user_id",
+            )?;
+            let rows = stmt.query_map([], Self::row_to_preferences)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()
+        })
+        .await
+        .map_err(|_| RepositoryError::StorageError("Task join error".into()))?;
+
+        result.map_err(|e| RepositoryError::StorageError(e.to_string()))
+    }
+
     async fn delete(&self, user_id: u64) -> Result<(), RepositoryError> {
         let conn = self.connection.clone();
 
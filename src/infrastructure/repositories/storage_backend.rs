@@ -0,0 +1,25 @@
+/// Which set of repositories the bot runs on, `storage.backend` in the config (or STORAGE_BACKEND)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// Persistent SQLite database (default)
+    #[default]
+    Sqlite,
+    /// Everything kept in memory and lost on restart (tests and ephemeral runs)
+    Memory,
+}
+
+impl std::str::FromStr for StorageBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "sqlite" => Ok(Self::Sqlite),
+            "memory" => Ok(Self::Memory),
+            other => Err(format!(
+                "Unknown STORAGE_BACKEND '{}', expected 'sqlite' or 'memory'",
+                other
+            )),
+        }
+    }
+}
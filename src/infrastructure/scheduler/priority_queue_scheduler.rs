@@ -1,111 +1,208 @@
 use crate::application::services::notification_service::NotificationService;
 use crate::application::services::task_orchestrator::TaskOrchestrator;
-use chrono::Utc;
-use serenity::prelude::Context;
+use crate::domain::Clock;
+use crate::domain::entities::scheduled_task::ScheduledTask;
+use crate::domain::repositories::{SchedulerError, TaskSchedulerRepository};
+use crate::infrastructure::observability::{health, metrics};
+use crate::utils::Shutdown;
+use chrono::Duration;
 use std::sync::Arc;
 use tokio::sync::broadcast;
-use tokio::time::{Duration, sleep};
-use tracing::error;
+use tracing::{Instrument, error, info, info_span, warn};
 
 /// Efficient scheduler using priority queue
 pub struct PriorityQueueScheduler;
 
+/// How long a claimed task stays reserved for this instance while it is being delivered
+const LEASE_DURATION_MINUTES: i64 = 2;
+
+/// Longest sleep on shared storage, where tasks added or leased by other processes don't wake us up
+pub(crate) const SHARED_POLL_SECONDS: i64 = 30;
+
 impl PriorityQueueScheduler {
+    /// `instance_id` identifies this process when leasing tasks, so several bot instances can
+    /// share one scheduler store without delivering the same occurrence twice.
+    /// The loop stops claiming once `shutdown` starts draining, a delivery still running when it
+    /// aborts is handed back to the store unchanged
     pub fn start_scheduler(
-        ctx: Arc<Context>,
         task_orchestrator: Arc<TaskOrchestrator>,
         notification_service: Arc<NotificationService>,
-        scheduler_repo: Arc<crate::infrastructure::repositories::sqlite_scheduler_repository::SqliteSchedulerRepository>,
+        scheduler_repo: Arc<dyn TaskSchedulerRepository>,
+        clock: Arc<dyn Clock>,
+        instance_id: String,
+        shutdown: Shutdown,
     ) {
         tokio::spawn(async move {
+            // Reported on /healthz until the loop exits
+            let _running = health().scheduler_started();
+
             // Subscribe to wake-up notifications
             let mut wakeup_receiver = scheduler_repo.subscribe_wakeup();
 
-            loop {
-                match Self::scheduler_iteration(
-                    &ctx,
-                    &task_orchestrator,
-                    &notification_service,
+            while !shutdown.is_draining() {
+                let result = match Self::poll_due_task(
+                    scheduler_repo.as_ref(),
+                    clock.as_ref(),
                     &mut wakeup_receiver,
+                    &instance_id,
+                    &shutdown,
                 )
                 .await
                 {
-                    Ok(should_continue) => {
-                        if !should_continue {
-                            // No pending tasks, sleep for a while
-                            tokio::select! {
-                                _ = sleep(Duration::from_secs(300)) => {},
-                                _ = wakeup_receiver.recv() => {}
+                    // task claimed, continue immediately afterwards (there might be more due tasks)
+                    Ok(Some(due_task)) => {
+                        let Some(_in_flight) = shutdown.try_track() else {
+                            // shutdown started right after the claim, leave it for the next instance
+                            Self::hand_back(scheduler_repo.as_ref(), &instance_id, &due_task).await;
+                            break;
+                        };
+
+                        let delivery_span = info_span!(
+                            "delivery",
+                            task_id = due_task.task_id,
+                            user_id = due_task.user_id,
+                            guild_id = due_task.guild_id,
+                            scheduled_for = %due_task.scheduled_time,
+                            instance_id = %instance_id,
+                        );
+
+                        tokio::select! {
+                            result = Self::process_due_task(
+                                &task_orchestrator,
+                                &notification_service,
+                                scheduler_repo.as_ref(),
+                                clock.as_ref(),
+                                &instance_id,
+                                due_task.clone(),
+                            ).instrument(delivery_span) => result,
+                            _ = shutdown.aborting() => {
+                                warn!(
+                                    "Shutdown deadline reached while delivering task #{}, handing it back",
+                                    due_task.task_id
+                                );
+                                Self::hand_back(scheduler_repo.as_ref(), &instance_id, &due_task).await;
+                                break;
                             }
-                            continue;
                         }
                     }
-                    Err(e) => {
-                        error!("Scheduler iteration error: {}", e);
-                        // wait 1m before retrying in case of error
-                        sleep(Duration::from_secs(60)).await;
+                    Ok(None) => Ok(()),
+                    Err(e) => Err(e.into()),
+                };
+
+                if let Err(e) = result {
+                    error!("Scheduler iteration error: {}", e);
+                    // wait 1m before retrying in case of error
+                    tokio::select! {
+                        _ = clock.sleep(Duration::minutes(1)) => {}
+                        _ = shutdown.draining() => {}
                     }
                 }
             }
+
+            info!("Scheduler stopped");
         });
     }
 
-    async fn scheduler_iteration(
-        ctx: &Context,
-        task_orchestrator: &TaskOrchestrator,
-        notification_service: &NotificationService,
+    /// Release a claimed task without touching its scheduled time, so its delivery key stays the
+    /// same and whoever claims it next can still tell whether it went out
+    async fn hand_back(
+        scheduler_repo: &dyn TaskSchedulerRepository,
+        instance_id: &str,
+        scheduled_task: &ScheduledTask,
+    ) {
+        if let Err(e) = scheduler_repo
+            .release_claim(scheduled_task.task_id, instance_id, scheduled_task.scheduled_time)
+            .await
+        {
+            error!(
+                "Failed to hand back task #{}, it will be retried once its lease expires: {}",
+                scheduled_task.task_id, e
+            );
+        }
+    }
+
+    /// Claims and returns the next task if it is already due. Otherwise sleeps on `clock` until
+    /// one becomes claimable (5 minutes when the queue is empty, at most SHARED_POLL_SECONDS on
+    /// shared storage), a new task wakes us up or shutdown starts, and returns None
+    pub(crate) async fn poll_due_task(
+        scheduler_repo: &dyn TaskSchedulerRepository,
+        clock: &dyn Clock,
         wakeup_receiver: &mut broadcast::Receiver<()>,
-    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
-        let now = Utc::now();
-
-        // verify next scheduled task (pending)
-        if let Some(next_task) = task_orchestrator.peek_next_scheduled_task().await? {
-            if next_task.scheduled_time <= now {
-                // task ready to notify
-
-                Self::process_due_task(ctx, task_orchestrator, notification_service, next_task)
-                    .await?;
-
-                return Ok(true); // continue immediatly (there might be more due tasks)
-            } else {
-                // Sleep until next task is due OR until interrupted by new task
-                let time_until_task = (next_task.scheduled_time - now)
-                    .to_std()
-                    .unwrap_or(Duration::from_secs(1));
-
-                // Use tokio::select! to sleep until task time OR wake-up signal
-                tokio::select! {
-                    _ = sleep(time_until_task) => {}
-                    _ = wakeup_receiver.recv() => {}
-                }
+        instance_id: &str,
+        shutdown: &Shutdown,
+    ) -> Result<Option<ScheduledTask>, SchedulerError> {
+        let now = clock.now();
 
-                return Ok(true);
-            }
-        } else {
-            // no pending tasks
-            return Ok(false);
+        let lease_until = now + Duration::minutes(LEASE_DURATION_MINUTES);
+        if let Some(due_task) = scheduler_repo
+            .claim_due_task(instance_id, now, lease_until)
+            .await?
+        {
+            return Ok(Some(due_task));
         }
+
+        // Not due yet, or leased by another instance until its lease expires
+        let mut wake_at = scheduler_repo
+            .next_claimable_time()
+            .await?
+            // no pending tasks, sleep for a while
+            .unwrap_or(now + Duration::minutes(5));
+        if scheduler_repo.is_shared() {
+            // another instance may have added an earlier task or left a lease behind meanwhile
+            wake_at = wake_at.min(now + Duration::seconds(SHARED_POLL_SECONDS));
+        }
+
+        // Sleep until next task is due OR until interrupted by new task
+        tokio::select! {
+            _ = clock.sleep_until(wake_at) => {}
+            _ = wakeup_receiver.recv() => {}
+            _ = shutdown.draining() => {}
+        }
+
+        Ok(None)
     }
 
     async fn process_due_task(
-        ctx: &Context,
         task_orchestrator: &TaskOrchestrator,
         notification_service: &NotificationService,
-        scheduled_task: crate::domain::entities::scheduled_task::ScheduledTask,
+        scheduler_repo: &dyn TaskSchedulerRepository,
+        clock: &dyn Clock,
+        instance_id: &str,
+        scheduled_task: ScheduledTask,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        // remove the task from the scheduler (it's already in scheduled_task)
-        task_orchestrator.pop_next_scheduled_task().await?;
+        let delivery_key = scheduled_task.delivery_key();
 
-        // send notification using task-specific channel
-        if let Err(_err) = notification_service
-            .send_task_notification_from_scheduled(&scheduled_task, ctx, task_orchestrator)
+        if scheduler_repo.is_delivered(&delivery_key).await? {
+            // a previous owner sent it but lost its lease before completing
+            info!("Occurrence {} was already delivered, not sending it again", delivery_key);
+        } else if let Err(_err) = notification_service
+            .send_task_notification_from_scheduled(&scheduled_task, task_orchestrator)
             .await
         {
-            // reinsert task if notification failed (retry in 1 minute)
-            let retry_time = Utc::now() + chrono::Duration::minutes(1);
-            let mut retry_task = scheduled_task.clone();
-            retry_task.scheduled_time = retry_time;
-            task_orchestrator.add_scheduled_task(retry_task).await?;
+            metrics().record_delivery_failure(&scheduled_task.notification_method);
+
+            // hand the task back if notification failed (retry in 1 minute)
+            let retry_time = clock.now() + Duration::minutes(1);
+            scheduler_repo
+                .release_claim(scheduled_task.task_id, instance_id, retry_time)
+                .await?;
+            return Ok(());
+        } else {
+            metrics().record_delivery(
+                &scheduled_task.notification_method,
+                clock.now() - scheduled_task.scheduled_time,
+            );
+        }
+
+        if !scheduler_repo
+            .complete_claim(&scheduled_task, instance_id, clock.now())
+            .await?
+        {
+            // task was edited or reclaimed meanwhile, whoever holds it now takes care of it
+            warn!(
+                "Lost the lease on task #{} during delivery, skipping post-notification",
+                scheduled_task.task_id
+            );
             return Ok(());
         }
 
@@ -122,3 +219,526 @@ impl PriorityQueueScheduler {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::task_service::TaskService;
+    use crate::application::services::timezone_service::TimezoneService;
+    use crate::domain::NotificationMethod;
+    use crate::domain::clock::ManualClock;
+    use crate::domain::notification_sink::{NotificationSink, OutgoingMessage, SentMessage};
+    use crate::infrastructure::notifications::{RecordedNotification, RecordingNotificationSink};
+    use crate::domain::entities::task::Task;
+    use crate::infrastructure::repositories::{
+        MemorySchedulerRepository, MemoryTaskRepository, MemoryUserPreferencesRepository,
+        SqliteSchedulerRepository,
+    };
+    use crate::infrastructure::timezone::timezone_manager::TimezoneManager;
+    use chrono::{DateTime, TimeZone, Utc, Weekday};
+
+    // Monday 2025-01-06 12:00 UTC
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap()
+    }
+
+    /// Full delivery stack on in-memory repositories, simulated time and a recording sink
+    struct Harness {
+        clock: Arc<ManualClock>,
+        orchestrator: Arc<TaskOrchestrator>,
+        notification_service: Arc<NotificationService>,
+        scheduler_repo: Arc<MemorySchedulerRepository>,
+        sink: Arc<RecordingNotificationSink>,
+        shutdown: Shutdown,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            let clock = Arc::new(ManualClock::new(start()));
+            let sink = Arc::new(RecordingNotificationSink::new());
+            let notification_service = Arc::new(NotificationService::new(sink.clone()));
+            let timezone_service = Arc::new(TimezoneService::new(
+                Arc::new(MemoryUserPreferencesRepository::new()),
+                Arc::new(TimezoneManager::new()),
+                clock.clone(),
+            ));
+            let task_service = Arc::new(TaskService::new(
+                Arc::new(MemoryTaskRepository::new()),
+                notification_service.clone(),
+                timezone_service.clone(),
+                clock.clone(),
+            ));
+            let scheduler_repo = Arc::new(MemorySchedulerRepository::new());
+            let orchestrator = Arc::new(TaskOrchestrator::new(
+                task_service,
+                scheduler_repo.clone(),
+                timezone_service,
+            ));
+
+            Self {
+                clock,
+                orchestrator,
+                notification_service,
+                scheduler_repo,
+                sink,
+                shutdown: Shutdown::new(),
+            }
+        }
+
+        async fn add_task_in(&self, minutes: i64, notification_method: NotificationMethod) -> u64 {
+            let channel_id = match notification_method {
+                NotificationMethod::DM => None,
+                _ => Some(555),
+            };
+
+            self.orchestrator
+                .create_and_schedule_single_task(
+                    1,
+                    1,
+                    format!("in {} minutes", minutes),
+                    String::new(),
+                    start() + Duration::minutes(minutes),
+                    notification_method,
+                    channel_id,
+                    Vec::new(),
+                    None,
+                )
+                .await
+                .unwrap()
+        }
+
+        fn start_scheduler(&self) {
+            self.start_instance("instance-a");
+        }
+
+        /// Start a scheduler loop as its own bot instance, all instances share the same store
+        fn start_instance(&self, instance_id: &str) {
+            PriorityQueueScheduler::start_scheduler(
+                self.orchestrator.clone(),
+                self.notification_service.clone(),
+                self.scheduler_repo.clone(),
+                self.clock.clone(),
+                instance_id.to_string(),
+                self.shutdown.clone(),
+            );
+        }
+
+        fn spawn_poll(&self) -> tokio::task::JoinHandle<Result<Option<ScheduledTask>, SchedulerError>> {
+            let (clock, scheduler_repo) = (self.clock.clone(), self.scheduler_repo.clone());
+            let shutdown = self.shutdown.clone();
+            let mut wakeup = self.scheduler_repo.subscribe_wakeup();
+            tokio::spawn(async move {
+                PriorityQueueScheduler::poll_due_task(
+                    scheduler_repo.as_ref(),
+                    clock.as_ref(),
+                    &mut wakeup,
+                    "instance-a",
+                    &shutdown,
+                )
+                .await
+            })
+        }
+
+        async fn next_scheduled(&self) -> Option<ScheduledTask> {
+            self.scheduler_repo.list_pending().await.unwrap().into_iter().next()
+        }
+
+        /// Move simulated time forward and let the scheduler react
+        async fn advance(&self, duration: Duration) {
+            self.clock.advance(duration);
+            settle().await;
+        }
+    }
+
+    /// Let spawned tasks run until they block, so they read the clock before we move it
+    async fn settle() {
+        for _ in 0..50 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn task_is_not_due_until_simulated_time_reaches_it() {
+        let harness = Harness::new();
+        let task_id = harness.add_task_in(10, NotificationMethod::DM).await;
+
+        let poll = harness.spawn_poll();
+        settle().await;
+
+        harness.advance(Duration::minutes(9)).await;
+        assert!(!poll.is_finished(), "woke up before the task was due");
+
+        harness.clock.advance(Duration::minutes(1));
+        assert!(poll.await.unwrap().unwrap().is_none());
+
+        let mut wakeup = harness.scheduler_repo.subscribe_wakeup();
+        let due = PriorityQueueScheduler::poll_due_task(
+            harness.scheduler_repo.as_ref(),
+            harness.clock.as_ref(),
+            &mut wakeup,
+            "instance-a",
+            &harness.shutdown,
+        )
+        .await
+        .unwrap();
+        assert_eq!(due.map(|t| t.task_id), Some(task_id));
+    }
+
+    #[tokio::test]
+    async fn new_task_interrupts_idle_sleep() {
+        let harness = Harness::new();
+
+        let poll = harness.spawn_poll();
+        settle().await;
+        assert!(!poll.is_finished(), "empty queue should idle");
+
+        // No simulated time passes, the wake-up signal alone ends the sleep
+        harness.add_task_in(30, NotificationMethod::DM).await;
+        assert!(poll.await.unwrap().unwrap().is_none());
+        assert_eq!(harness.clock.now(), start());
+    }
+
+    #[tokio::test]
+    async fn idle_sleep_ends_after_five_simulated_minutes() {
+        let harness = Harness::new();
+
+        let poll = harness.spawn_poll();
+        settle().await;
+
+        harness.advance(Duration::minutes(4)).await;
+        assert!(!poll.is_finished());
+
+        harness.clock.advance(Duration::minutes(1));
+        assert!(poll.await.unwrap().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn single_task_is_delivered_at_due_time_and_removed() {
+        let harness = Harness::new();
+        let task_id = harness.add_task_in(10, NotificationMethod::DM).await;
+        harness.start_scheduler();
+        settle().await;
+
+        harness.advance(Duration::minutes(9)).await;
+        assert!(harness.sink.records().is_empty());
+
+        harness.advance(Duration::minutes(1)).await;
+        let records = harness.sink.records();
+        assert_eq!(records.len(), 1);
+        match &records[0] {
+            RecordedNotification::Dm { user_id, message } => {
+                assert_eq!(*user_id, 1);
+                let embed = message.embed.as_ref().unwrap();
+                assert_eq!(embed.title.as_deref(), Some("in 10 minutes"));
+            }
+            other => panic!("expected a DM, got {:?}", other),
+        }
+
+        assert!(harness.orchestrator.get_task_by_id(task_id).await.is_none());
+        assert!(harness.next_scheduled().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn both_method_delivers_dm_and_channel_message() {
+        let harness = Harness::new();
+        harness.add_task_in(1, NotificationMethod::Both).await;
+        harness.start_scheduler();
+        settle().await;
+
+        harness.advance(Duration::minutes(1)).await;
+        let records = harness.sink.records();
+        assert_eq!(records.len(), 2);
+        assert!(matches!(records[0], RecordedNotification::Dm { user_id: 1, .. }));
+        match &records[1] {
+            RecordedNotification::Channel { channel_id, message } => {
+                assert_eq!(*channel_id, 555);
+                assert_eq!(message.content.as_deref(), Some("Your task is ready! <@1>"));
+            }
+            other => panic!("expected a channel message, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn weekly_task_is_rescheduled_after_delivery() {
+        let harness = Harness::new();
+        let task_id = harness
+            .orchestrator
+            .create_and_schedule_weekly_task(
+                1,
+                1,
+                "standup".to_string(),
+                String::new(),
+                vec![Weekday::Mon],
+                12,
+                30,
+                NotificationMethod::DM,
+                None,
+                Vec::new(),
+                None,
+            )
+            .await
+            .unwrap();
+        harness.start_scheduler();
+        settle().await;
+
+        harness.advance(Duration::minutes(30)).await;
+        assert_eq!(harness.sink.records().len(), 1);
+
+        let next_week = Utc.with_ymd_and_hms(2025, 1, 13, 12, 30, 0).unwrap();
+        let next = harness.next_scheduled().await.unwrap();
+        assert_eq!((next.task_id, next.scheduled_time), (task_id, next_week));
+        let task = harness.orchestrator.get_task_by_id(task_id).await.unwrap();
+        assert_eq!(task.scheduled_time, Some(next_week));
+    }
+
+    #[tokio::test]
+    async fn failed_delivery_is_retried_a_minute_later() {
+        let harness = Harness::new();
+        harness.add_task_in(5, NotificationMethod::DM).await;
+        harness.start_scheduler();
+        settle().await;
+
+        harness.sink.set_failing(true);
+        harness.advance(Duration::minutes(5)).await;
+        assert!(harness.sink.records().is_empty());
+
+        let retry = harness.next_scheduled().await.unwrap();
+        assert_eq!(retry.scheduled_time, start() + Duration::minutes(6));
+
+        harness.sink.set_failing(false);
+        harness.advance(Duration::minutes(1)).await;
+        assert_eq!(harness.sink.records().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn instances_sharing_a_store_deliver_each_occurrence_once() {
+        let harness = Harness::new();
+        harness.add_task_in(5, NotificationMethod::DM).await;
+        harness.add_task_in(5, NotificationMethod::Channel).await;
+        harness.start_instance("instance-a");
+        harness.start_instance("instance-b");
+        settle().await;
+
+        harness.advance(Duration::minutes(5)).await;
+        assert_eq!(harness.sink.records().len(), 2);
+        assert!(harness.next_scheduled().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn lease_of_a_crashed_instance_is_reclaimed_after_it_expires() {
+        let harness = Harness::new();
+        harness.add_task_in(5, NotificationMethod::DM).await;
+
+        // Another instance claimed the task and died before delivering it
+        let lease_until = start() + Duration::minutes(5 + LEASE_DURATION_MINUTES);
+        let claimed = harness
+            .scheduler_repo
+            .claim_due_task("crashed", start() + Duration::minutes(5), lease_until)
+            .await
+            .unwrap();
+        assert!(claimed.is_some());
+
+        harness.start_scheduler();
+        settle().await;
+
+        harness.advance(Duration::minutes(5)).await;
+        assert!(harness.sink.records().is_empty(), "lease is still held");
+
+        harness.advance(Duration::minutes(LEASE_DURATION_MINUTES)).await;
+        assert_eq!(harness.sink.records().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn already_delivered_occurrence_is_not_sent_again() {
+        let harness = Harness::new();
+        let task_id = harness.add_task_in(5, NotificationMethod::DM).await;
+
+        // Another instance sent it and recorded the key, but lost its lease before removing the entry
+        let scheduled = harness.next_scheduled().await.unwrap();
+        let due = start() + Duration::minutes(5);
+        harness
+            .scheduler_repo
+            .claim_due_task("slow", due, due)
+            .await
+            .unwrap();
+        harness
+            .scheduler_repo
+            .complete_claim(&scheduled, "other", due)
+            .await
+            .unwrap();
+
+        harness.start_scheduler();
+        settle().await;
+        harness.advance(Duration::minutes(5)).await;
+
+        assert!(harness.sink.records().is_empty());
+        assert!(harness.next_scheduled().await.is_none());
+        assert!(harness.orchestrator.get_task_by_id(task_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn draining_scheduler_leaves_due_tasks_for_the_next_instance() {
+        let harness = Harness::new();
+        harness.add_task_in(5, NotificationMethod::DM).await;
+        harness.start_instance("instance-a");
+        settle().await;
+
+        harness.shutdown.begin_draining();
+        settle().await;
+        harness.advance(Duration::minutes(5)).await;
+        assert!(harness.sink.records().is_empty());
+        assert!(harness.next_scheduled().await.is_some());
+
+        // A fresh process (with its own shutdown state) picks it up
+        PriorityQueueScheduler::start_scheduler(
+            harness.orchestrator.clone(),
+            harness.notification_service.clone(),
+            harness.scheduler_repo.clone(),
+            harness.clock.clone(),
+            "instance-b".to_string(),
+            Shutdown::new(),
+        );
+        settle().await;
+        assert_eq!(harness.sink.records().len(), 1);
+    }
+
+    /// Sink whose sends never complete, like a Discord API that stopped answering
+    struct StalledSink;
+
+    #[async_trait::async_trait]
+    impl NotificationSink for StalledSink {
+        async fn send_dm(
+            &self,
+            _user_id: u64,
+            _message: OutgoingMessage,
+        ) -> Result<SentMessage, String> {
+            std::future::pending().await
+        }
+
+        async fn send_to_channel(
+            &self,
+            _channel_id: u64,
+            _message: OutgoingMessage,
+        ) -> Result<SentMessage, String> {
+            std::future::pending().await
+        }
+
+        async fn edit_message(
+            &self,
+            _sent: SentMessage,
+            _message: OutgoingMessage,
+        ) -> Result<(), String> {
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn delivery_stuck_at_abort_is_handed_back_unchanged() {
+        let harness = Harness::new();
+        let task_id = harness.add_task_in(5, NotificationMethod::DM).await;
+        PriorityQueueScheduler::start_scheduler(
+            harness.orchestrator.clone(),
+            Arc::new(NotificationService::new(Arc::new(StalledSink))),
+            harness.scheduler_repo.clone(),
+            harness.clock.clone(),
+            "instance-a".to_string(),
+            harness.shutdown.clone(),
+        );
+        settle().await;
+
+        harness.advance(Duration::minutes(5)).await;
+        assert_eq!(harness.shutdown.in_flight(), 1, "delivery should be in flight");
+
+        harness.shutdown.begin_draining();
+        settle().await;
+        assert_eq!(harness.shutdown.in_flight(), 1, "draining lets the delivery continue");
+
+        harness.shutdown.abort();
+        tokio::time::timeout(std::time::Duration::from_secs(1), harness.shutdown.wait_idle())
+            .await
+            .unwrap();
+
+        // Same occurrence, immediately claimable by another instance
+        let due = start() + Duration::minutes(5);
+        let reclaimed = harness
+            .scheduler_repo
+            .claim_due_task("instance-b", due, due + Duration::minutes(LEASE_DURATION_MINUTES))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((reclaimed.task_id, reclaimed.scheduled_time), (task_id, due));
+    }
+
+    fn scheduled_in(task_id: u64, duration: Duration) -> ScheduledTask {
+        let due = start() + duration;
+        let task = Task::for_test(1, &format!("task {}", task_id)).scheduled_at(due);
+        ScheduledTask::new(task_id, due, &task)
+    }
+
+    #[tokio::test]
+    async fn shared_storage_is_re_polled_for_tasks_added_by_other_instances() {
+        let path = std::env::temp_dir().join(format!(
+            "daily-scheduler-poll-{}-{}.db",
+            std::process::id(),
+            rand::random::<u32>()
+        ));
+        let instance_a = SqliteSchedulerRepository::new(&path).unwrap();
+        let instance_b = Arc::new(SqliteSchedulerRepository::new(&path).unwrap());
+        let clock = Arc::new(ManualClock::new(start()));
+        let shutdown = Shutdown::new();
+
+        // B only knows about a task days away
+        instance_b
+            .add_scheduled_task(scheduled_in(1, Duration::days(3)))
+            .await
+            .unwrap();
+        let mut wakeup = instance_b.subscribe_wakeup();
+        let poll = {
+            let (instance_b, clock, shutdown) = (instance_b.clone(), clock.clone(), shutdown.clone());
+            tokio::spawn(async move {
+                PriorityQueueScheduler::poll_due_task(
+                    instance_b.as_ref(),
+                    clock.as_ref(),
+                    &mut wakeup,
+                    "instance-b",
+                    &shutdown,
+                )
+                .await
+            })
+        };
+        // SQLite calls run on blocking threads, give B real time to go to sleep
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // A adds a reminder due in an hour and goes away, B gets no wake-up signal
+        instance_a
+            .add_scheduled_task(scheduled_in(2, Duration::hours(1)))
+            .await
+            .unwrap();
+        drop(instance_a);
+
+        clock.advance(Duration::seconds(SHARED_POLL_SECONDS - 1));
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(!poll.is_finished(), "woke up before the poll interval");
+
+        clock.advance(Duration::seconds(1));
+        assert!(poll.await.unwrap().unwrap().is_none());
+        assert_eq!(
+            instance_b.next_claimable_time().await.unwrap(),
+            Some(start() + Duration::hours(1))
+        );
+
+        clock.set(start() + Duration::hours(1));
+        let mut wakeup = instance_b.subscribe_wakeup();
+        let due = PriorityQueueScheduler::poll_due_task(
+            instance_b.as_ref(),
+            clock.as_ref(),
+            &mut wakeup,
+            "instance-b",
+            &shutdown,
+        )
+        .await
+        .unwrap();
+        assert_eq!(due.map(|task| task.task_id), Some(2));
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -1,368 +1,383 @@
+use chrono_tz::Tz;
 use fuzzy_matcher::FuzzyMatcher;
-use serde::Deserialize;
+use fuzzy_matcher::skim::SkimMatcherV2;
 use std::collections::HashMap;
-use std::fs;
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
-pub struct TimezoneData {
-    pub timezones: Vec<TimezoneInfo>,
-}
+/// Every IANA zone listed in tzdata's zone.tab, plus UTC and the fixed Etc/GMT offsets
+const ZONES: &str = include_str!("data/zones.tsv");
+/// English and Spanish country names by ISO 3166 code
+const COUNTRIES: &str = include_str!("data/countries.tsv");
+/// Cities, states and provinces that don't appear in any zone name
+const PLACES: &str = include_str!("data/places.tsv");
+
+/// Results for a name that matches several zones, e.g. a country (Discord's select menu limit)
+const MAX_EXACT_RESULTS: usize = 25;
+/// Results for a fuzzy search
+const MAX_FUZZY_RESULTS: usize = 8;
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TimezoneInfo {
-    pub value: String,
-    pub abbr: String,
-    pub offset: f32, // hours from UTC
-    pub isdst: bool,
+    /// Canonical IANA name, e.g. "America/New_York"
+    pub id: String,
+    /// Name shown to users, e.g. "New York, United States (Eastern (most areas))"
     pub text: String,
-    pub utc: Vec<String>,
+    /// ISO 3166 codes of the countries using the zone
+    pub countries: Vec<String>,
+    /// Letter abbreviations used during the year, e.g. ["EST", "EDT"]
+    pub abbreviations: Vec<String>,
+    /// UTC offsets in minutes in January and July, equal for zones without DST
+    pub offsets: (i32, i32),
+    pub tz: Tz,
 }
 
+/// The IANA catalogue embedded in the binary, searchable by zone name, alias, city, country
+/// (English or Spanish), state and abbreviation
 pub struct TimezoneManager {
-    timezones: HashMap<String, TimezoneInfo>,
-    city_to_timezone: HashMap<String, Vec<String>>, // city ​​-> timezone list
-    fuzzy_matcher: fuzzy_matcher::skim::SkimMatcherV2,
+    zones: Vec<TimezoneInfo>,
+    /// Zone names and their aliases, e.g. "Asia/Calcutta" -> Asia/Kolkata
+    by_name: HashMap<String, usize>,
+    /// Normalized search names -> the zones they stand for, in catalogue order
+    search_names: HashMap<String, Vec<usize>>,
+    /// How many aliases and places point to each zone, the best known one stands for its offsets
+    weights: Vec<usize>,
+    fuzzy_matcher: SkimMatcherV2,
 }
 
 impl TimezoneManager {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let data_path = "./data/timezones.json";
-        let file_content = fs::read_to_string(data_path)?;
-
-        let timezones_list: Vec<TimezoneInfo> = serde_json::from_str(&file_content)?;
-
-        let mut timezones = HashMap::new();
-        let mut city_to_timezone = HashMap::new();
-
-        // index timezones and cities
-        for tz_info in timezones_list {
-            // index by each UTC timezone
-            for utc_tz in &tz_info.utc {
-                timezones.insert(utc_tz.clone(), tz_info.clone());
+    pub fn new() -> Self {
+        let countries: HashMap<&str, Vec<&str>> = data_lines(COUNTRIES)
+            .map(|fields| {
+                let mut names = vec![fields[1], fields[2]];
+                names.extend(list(fields.get(3).copied().unwrap_or_default()));
+                (fields[0], names)
+            })
+            .collect();
+
+        let mut manager = Self {
+            zones: Vec::new(),
+            by_name: HashMap::new(),
+            search_names: HashMap::new(),
+            weights: Vec::new(),
+            fuzzy_matcher: SkimMatcherV2::default(),
+        };
+
+        for fields in data_lines(ZONES) {
+            let id = fields[0];
+            let tz: Tz = id
+                .parse()
+                .unwrap_or_else(|_| panic!("Embedded zone {} is unknown to chrono-tz", id));
+            let country_codes: Vec<String> = list(fields[1]).map(str::to_string).collect();
+            let abbreviations: Vec<String> = list(fields[3]).map(str::to_string).collect();
+            let offsets = (parse_minutes(fields[4]), parse_minutes(fields[5]));
+            let aliases: Vec<&str> = list(fields[6]).collect();
+
+            let index = manager.zones.len();
+            let country_name = country_codes
+                .first()
+                .and_then(|code| countries.get(code.as_str()))
+                .map(|names| names[0]);
+            manager.zones.push(TimezoneInfo {
+                id: id.to_string(),
+                text: zone_text(id, country_name, fields[2], offsets.0),
+                countries: country_codes.clone(),
+                abbreviations: abbreviations.clone(),
+                offsets,
+                tz,
+            });
+            manager.weights.push(aliases.len());
+
+            manager.by_name.insert(id.to_string(), index);
+            manager.add_search_name(id, index);
+            // "America/Argentina/Buenos_Aires" is found by "Argentina" and "Buenos Aires". Etc
+            // zones are not: "Etc/GMT+2" is UTC-2, they are found by their real offset instead
+            let is_fixed = id.starts_with("Etc/");
+            for part in id.split('/').skip(1).filter(|_| !is_fixed) {
+                manager.add_search_name(part, index);
             }
-
-            // index cities from UTC names
-            for utc_tz in &tz_info.utc {
-                if let Some(city_name) = Self::extract_city_name(utc_tz) {
-                    city_to_timezone
-                        .entry(city_name.to_lowercase())
-                        .or_insert_with(Vec::new)
-                        .push(utc_tz.clone());
+            for alias in aliases {
+                manager.by_name.insert(alias.to_string(), index);
+                manager.add_search_name(alias, index);
+                if !is_fixed {
+                    manager.add_search_name(alias.rsplit('/').next().unwrap_or(alias), index);
+                }
+            }
+            for abbreviation in &abbreviations {
+                manager.add_search_name(abbreviation, index);
+            }
+            if id.starts_with("Etc/GMT") {
+                for name in fixed_offset_names(offsets.0) {
+                    manager.add_search_name(&name, index);
                 }
             }
+            for code in &country_codes {
+                for name in countries.get(code.as_str()).into_iter().flatten() {
+                    manager.add_search_name(name, index);
+                }
+            }
+        }
 
-            timezones.insert(tz_info.value.clone(), tz_info.clone());
+        for fields in data_lines(PLACES) {
+            let index = *manager
+                .by_name
+                .get(fields[1])
+                .unwrap_or_else(|| panic!("Embedded place {} has unknown zone", fields[0]));
+            manager.add_search_name(fields[0], index);
+            manager.weights[index] += 1;
         }
 
-        Ok(Self {
-            timezones,
-            city_to_timezone,
-            fuzzy_matcher: fuzzy_matcher::skim::SkimMatcherV2::default(),
-        })
+        manager
     }
 
-    /// Extracts the city name from a timezone string (ex: "America/New_York" => "New York")
-    fn extract_city_name(utc_tz: &str) -> Option<String> {
-        let parts: Vec<&str> = utc_tz.split('/').collect();
-        if parts.len() >= 2 {
-            let city_part = parts.last().unwrap();
-            Some(city_part.replace('_', " "))
-        } else {
-            None
+    fn add_search_name(&mut self, name: &str, index: usize) {
+        let name = normalize(name);
+        if name.is_empty() {
+            return;
+        }
+        let zones = self.search_names.entry(name).or_default();
+        if !zones.contains(&index) {
+            zones.push(index);
         }
     }
 
-    /// Search timezones by city, country, using fuzzy matching with prioritization
+    /// Search timezones by city, country, state, zone name or abbreviation. An exact name returns
+    /// all its zones, otherwise names are ranked with fuzzy matching, prefixes first
     pub fn search_timezones(&self, query: &str) -> Vec<&TimezoneInfo> {
-        let query_lower = query.to_lowercase().trim().to_string();
-        let mut results = Vec::new();
-
-        // SPECIAL CASE: US timezones
-        if query_lower == "america"
-            || query_lower == "usa"
-            || query_lower == "us"
-            || query_lower == "united states"
-        {
-            return self.search_us_timezones();
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
         }
 
-        // SPECIAL CASE: Canada timezones
-        if query_lower == "canada" || query_lower == "canadá" {
-            return self.search_canada_timezones();
-        }
-
-        // SPECIAL CASE: North America - both US and Canada
-        if query_lower == "north america" {
-            return self.search_north_america_timezones();
-        }
-
-        // exact search in cities first
-        if let Some(timezone_names) = self.city_to_timezone.get(&query_lower) {
-            for tz_name in timezone_names {
-                if let Some(tz_info) = self.timezones.get(tz_name) {
-                    if !results.contains(&tz_info) {
-                        results.push(tz_info);
-                    }
-                }
-            }
-        }
-
-        // if we already have exact results, return them
-        if !results.is_empty() {
+        if let Some(zones) = self.search_names.get(&query) {
+            let mut results = self.distinct_local_times(zones);
+            results.sort_by_key(|tz_info| std::cmp::Reverse(tz_info.offsets.0));
+            results.truncate(MAX_EXACT_RESULTS);
             return results;
         }
 
-        // fuzzy search with prioritization
-        let mut fuzzy_results: Vec<(i64, &TimezoneInfo, u8)> = Vec::new(); // (score, tz_info, priority)
-
-        for tz_info in self.timezones.values() {
-            let mut priority = 0;
-
-            // Priority 1: Exact match in value (timezone name)
-            let value_score = self
-                .fuzzy_matcher
-                .fuzzy_match(&tz_info.value.to_lowercase(), &query_lower);
-            if let Some(score) = value_score {
-                if score > 50 {
-                    // Good match threshold
-                    priority = 3;
-                }
-                fuzzy_results.push((score, tz_info, priority));
+        // Best (is prefix, score) per zone, a country name counts once per local time
+        let mut best: HashMap<&str, ((bool, i64), &TimezoneInfo)> = HashMap::new();
+        for (name, zones) in &self.search_names {
+            let Some(score) = self.fuzzy_matcher.fuzzy_match(name, &query) else {
+                continue;
+            };
+            let is_prefix = name.starts_with(&query) || name.contains(&format!(" {}", query));
+            for tz_info in self.distinct_local_times(zones) {
+                let rank = (is_prefix, score);
+                best.entry(tz_info.id.as_str())
+                    .and_modify(|entry| entry.0 = entry.0.max(rank))
+                    .or_insert((rank, tz_info));
             }
+        }
 
-            // Priority 2: Search in UTC cities (higher priority for direct matches)
-            for utc_tz in &tz_info.utc {
-                if let Some(city_name) = Self::extract_city_name(utc_tz) {
-                    let city_score = self
-                        .fuzzy_matcher
-                        .fuzzy_match(&city_name.to_lowercase(), &query_lower);
-                    if let Some(score) = city_score {
-                        let city_priority = if score > 70 { 4 } else { 2 };
-                        fuzzy_results.push((score, tz_info, city_priority));
-                    }
-                }
-            }
+        let mut ranked: Vec<((bool, i64), &TimezoneInfo)> = best.into_values().collect();
+        ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.id.cmp(&b.1.id)));
+        ranked
+            .into_iter()
+            .take(MAX_FUZZY_RESULTS)
+            .map(|(_, tz_info)| tz_info)
+            .collect()
+    }
 
-            // Priority 3: Search in descriptive text (lower priority)
-            let text_score = self
-                .fuzzy_matcher
-                .fuzzy_match(&tz_info.text.to_lowercase(), &query_lower);
-            if let Some(score) = text_score {
-                // Lower priority for text matches to avoid generic results like "Central America"
-                let text_priority = if score > 60 && !self.is_generic_timezone(tz_info) {
-                    1
-                } else {
-                    0
-                };
-                fuzzy_results.push((score, tz_info, text_priority));
+    /// One zone per distinct pair of offsets, e.g. a single US Eastern zone instead of eleven. The
+    /// best known zone of each group is kept, e.g. São Paulo rather than Belém
+    fn distinct_local_times(&self, zones: &[usize]) -> Vec<&TimezoneInfo> {
+        let mut picked: Vec<usize> = Vec::new();
+        for &index in zones {
+            let offsets = self.zones[index].offsets;
+            match picked
+                .iter_mut()
+                .find(|kept| self.zones[**kept].offsets == offsets)
+            {
+                Some(kept) if self.weights[index] > self.weights[*kept] => *kept = index,
+                Some(_) => {}
+                None => picked.push(index),
             }
         }
+        picked.into_iter().map(|index| &self.zones[index]).collect()
+    }
 
-        // Sort by priority first, then by score
-        fuzzy_results.sort_by(|a, b| {
-            b.2.cmp(&a.2) // Higher priority first
-                .then(b.0.cmp(&a.0)) // Then higher score
-        });
-
-        for (_, tz_info, _) in fuzzy_results {
-            if !results.contains(&tz_info) {
-                results.push(tz_info);
-                // Limit to 8 results for better UX
-                if results.len() >= 8 {
-                    break;
-                }
-            }
-        }
+    /// Get time zone information by IANA name or alias
+    pub fn get_timezone_info(&self, timezone: &str) -> Option<&TimezoneInfo> {
+        self.by_name.get(timezone).map(|index| &self.zones[*index])
+    }
 
-        results
+    /// Check if a timezone exists
+    pub fn validate_timezone(&self, timezone: &str) -> bool {
+        self.by_name.contains_key(timezone)
     }
+}
 
-    /// Special search for US timezones only
-    fn search_us_timezones(&self) -> Vec<&TimezoneInfo> {
-        let mut results = Vec::new();
-        let mut seen_values = std::collections::HashSet::new();
-
-        let us_utc_timezones = [
-            // Eastern Time Zone
-            "America/New_York",
-            "America/Detroit",
-            "America/Indiana/Indianapolis",
-            "America/Indiana/Marengo",
-            "America/Indiana/Vevay",
-            "America/Louisville",
-            
-            // Central Time Zone
-            "America/Chicago",
-            "America/Menominee",
-            "America/Indiana/Knox",
-            
-            // Mountain Time Zone
-            "America/Denver",
-            "America/Boise",
-            
-            // Mountain Standard (Arizona - no DST)
-            "America/Phoenix",
-            
-            // Pacific Time Zone
-            "America/Los_Angeles",
-            
-            // Alaska Time Zone
-            "America/Anchorage",
-            "America/Juneau",
-            
-            // Hawaii
-            "Pacific/Honolulu",
-        ];
-
-        for utc_tz in &us_utc_timezones {
-            if let Some(tz_info) = self.timezones.get(*utc_tz) {
-                if seen_values.insert(&tz_info.value) {
-                    results.push(tz_info);
-                }
-            }
-        }
+impl Default for TimezoneManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        results.sort_by(|a, b| {
-            b.offset
-                .partial_cmp(&a.offset)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+/// Tab separated fields of each non-comment line
+fn data_lines(data: &'static str) -> impl Iterator<Item = Vec<&'static str>> {
+    data.lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split('\t').collect())
+}
 
-        results
-    }
+fn list(field: &str) -> impl Iterator<Item = &str> {
+    field
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
 
-    /// Special search for Canada timezones only
-    fn search_canada_timezones(&self) -> Vec<&TimezoneInfo> {
-        let mut results = Vec::new();
-        let mut seen_values = std::collections::HashSet::new();
-
-        let canada_utc_timezones = [
-            // Newfoundland Time (UTC-3:30) - Unique Canadian timezone
-            "America/St_Johns",
-            
-            // Atlantic Time Zone (Canada)
-            "America/Halifax",
-            "America/Moncton",
-            "America/Glace_Bay",
-            
-            // Eastern Time Zone (shared with US)
-            "America/Toronto",
-            "America/Montreal",
-            "America/Iqaluit",
-            
-            // Central Time Zone (shared with US)
-            "America/Winnipeg",
-            
-            // Saskatchewan (Central Standard - no DST)
-            "America/Regina",
-            
-            // Mountain Time Zone (shared with US)
-            "America/Edmonton",
-            
-            // Pacific Time Zone (shared with US)
-            "America/Vancouver",
-        ];
-
-        for utc_tz in &canada_utc_timezones {
-            if let Some(tz_info) = self.timezones.get(*utc_tz) {
-                if seen_values.insert(&tz_info.value) {
-                    results.push(tz_info);
-                }
-            }
-        }
+fn parse_minutes(field: &str) -> i32 {
+    field
+        .parse()
+        .unwrap_or_else(|_| panic!("Invalid offset '{}' in embedded zones", field))
+}
 
-        results.sort_by(|a, b| {
-            b.offset
-                .partial_cmp(&a.offset)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+/// "New York, United States (Eastern (most areas))", "UTC-03:00" for the fixed offsets
+fn zone_text(id: &str, country: Option<&str>, comment: &str, offset: i32) -> String {
+    if id == "Etc/UTC" {
+        return "UTC".to_string();
+    }
+    if id.starts_with("Etc/") {
+        return format_offset(offset);
+    }
 
-        results
+    let city = id.rsplit('/').next().unwrap_or(id).replace('_', " ");
+    let mut text = match country {
+        Some(country) if country != city => format!("{}, {}", city, country),
+        _ => city,
+    };
+    if !comment.is_empty() {
+        text.push_str(&format!(" ({})", comment));
     }
+    text
+}
 
-    /// Special search for all North American timezones (US + Canada)
-    fn search_north_america_timezones(&self) -> Vec<&TimezoneInfo> {
-        let mut results = Vec::new();
-        let mut seen_values = std::collections::HashSet::new();
-
-        // Combine both US and Canada timezones
-        let north_america_utc_timezones = [
-            // Canada - Newfoundland
-            "America/St_Johns",
-            // Canada - Atlantic
-            "America/Halifax",
-            "America/Moncton",
-            // US/Canada - Eastern
-            "America/New_York",
-            "America/Toronto",
-            "America/Detroit",
-            "America/Indiana/Indianapolis",
-            // US/Canada - Central
-            "America/Chicago",
-            "America/Winnipeg",
-            // Canada - Saskatchewan
-            "America/Regina",
-            // US/Canada - Mountain
-            "America/Denver",
-            "America/Edmonton",
-            "America/Boise",
-            // US - Arizona
-            "America/Phoenix",
-            // US/Canada - Pacific
-            "America/Los_Angeles",
-            "America/Vancouver",
-            // US - Alaska
-            "America/Anchorage",
-            "America/Juneau",
-            // US - Hawaii
-            "Pacific/Honolulu",
-        ];
-
-        for utc_tz in &north_america_utc_timezones {
-            if let Some(tz_info) = self.timezones.get(*utc_tz) {
-                if seen_values.insert(&tz_info.value) {
-                    results.push(tz_info);
-                }
-            }
-        }
+fn format_offset(minutes: i32) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.abs();
+    format!("UTC{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+}
 
-        results.sort_by(|a, b| {
-            b.offset
-                .partial_cmp(&a.offset)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+/// "UTC-3", "UTC-03:00" and "GMT-3" for a whole-hour offset
+fn fixed_offset_names(minutes: i32) -> Vec<String> {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let hours = minutes.abs() / 60;
+    vec![
+        format!("utc{}{}", sign, hours),
+        format_offset(minutes),
+        format!("gmt{}{}", sign, hours),
+    ]
+}
 
-        results
+/// Lowercase without accents, underscores or parenthesized notes, e.g. "Georgia (US state)" and
+/// "GEORGIA" both give "georgia"
+fn normalize(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    let mut depth = 0;
+    for c in name.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = (depth - 1).max(0),
+            _ if depth > 0 => {}
+            '_' => normalized.push(' '),
+            _ => normalized.extend(fold_accent(c).to_lowercase()),
+        }
     }
+    normalized.split_whitespace().collect::<Vec<_>>().join(" ")
+}
 
-    /// Check if a timezone is too generic (like "Central America" when searching for US)
-    fn is_generic_timezone(&self, tz_info: &TimezoneInfo) -> bool {
-        let generic_terms = [
-            "central america",
-            "south america",
-            "latin america",
-            "caribbean",
-            "generic",
-            "etc/gmt",
-        ];
-
-        let text_lower = tz_info.text.to_lowercase();
-        generic_terms.iter().any(|term| text_lower.contains(term))
+fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'ä' | 'â' | 'ã' | 'å' | 'Á' | 'À' | 'Ä' | 'Â' | 'Ã' | 'Å' => 'a',
+        'é' | 'è' | 'ë' | 'ê' | 'É' | 'È' | 'Ë' | 'Ê' => 'e',
+        'í' | 'ì' | 'ï' | 'î' | 'Í' | 'Ì' | 'Ï' | 'Î' => 'i',
+        'ó' | 'ò' | 'ö' | 'ô' | 'õ' | 'Ó' | 'Ò' | 'Ö' | 'Ô' | 'Õ' => 'o',
+        'ú' | 'ù' | 'ü' | 'û' | 'Ú' | 'Ù' | 'Ü' | 'Û' => 'u',
+        'ñ' | 'Ñ' => 'n',
+        'ç' | 'Ç' => 'c',
+        _ => c,
     }
+}
 
-    /// Get time zone information by exact name
-    pub fn get_timezone_info(&self, timezone: &str) -> Option<&TimezoneInfo> {
-        self.timezones.get(timezone)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(results: Vec<&TimezoneInfo>) -> Vec<&str> {
+        results.iter().map(|tz_info| tz_info.id.as_str()).collect()
     }
 
-    /// Check if a timezone exists
-    pub fn validate_timezone(&self, timezone: &str) -> bool {
-        self.timezones.contains_key(timezone)
+    #[test]
+    fn finds_cities_countries_aliases_and_abbreviations() {
+        let manager = TimezoneManager::new();
+
+        assert_eq!(
+            ids(manager.search_timezones("Buenos Aires")),
+            ["America/Argentina/Buenos_Aires"]
+        );
+        assert_eq!(
+            ids(manager.search_timezones("Asia/Calcutta")),
+            ["Asia/Kolkata"]
+        );
+        assert_eq!(
+            ids(manager.search_timezones("barcelona")),
+            ["Europe/Madrid"]
+        );
+        assert_eq!(ids(manager.search_timezones("Alemania")), ["Europe/Berlin"]);
+        assert_eq!(
+            ids(manager.search_timezones("nueva york")),
+            ["America/New_York"]
+        );
+        assert_eq!(
+            ids(manager.search_timezones("méxico")),
+            ids(manager.search_timezones("mexico"))
+        );
+        assert_eq!(ids(manager.search_timezones("UTC-3")), ["Etc/GMT+3"]);
+        assert_eq!(ids(manager.search_timezones("GMT+2")), ["Etc/GMT-2"]);
+        assert!(
+            manager
+                .search_timezones("cet")
+                .iter()
+                .any(|tz_info| tz_info.abbreviations == ["CET", "CEST"])
+        );
+
+        // One zone per local time, east to west
+        let us = ids(manager.search_timezones("Estados Unidos"));
+        assert_eq!(
+            &us[..4],
+            [
+                "America/New_York",
+                "America/Chicago",
+                "America/Denver",
+                "America/Phoenix"
+            ]
+        );
+        assert!(us.len() < 12);
+        assert!(ids(manager.search_timezones("Brasil")).contains(&"America/Sao_Paulo"));
+
+        // Typos still find the city
+        assert_eq!(
+            manager.search_timezones("Montevido")[0].id,
+            "America/Montevideo"
+        );
+        assert!(manager.search_timezones("").is_empty());
     }
-}
 
-impl Default for TimezoneManager {
-    fn default() -> Self {
-        Self::new().expect("Failed to initialize TimezoneManager")
+    #[test]
+    fn resolves_aliases_and_describes_zones() {
+        let manager = TimezoneManager::new();
+
+        let kolkata = manager.get_timezone_info("Asia/Calcutta").unwrap();
+        assert_eq!(kolkata.id, "Asia/Kolkata");
+        assert_eq!(kolkata.text, "Kolkata, India");
+        assert_eq!(kolkata.tz, chrono_tz::Asia::Kolkata);
+        assert_eq!(
+            manager.get_timezone_info("Etc/GMT+3").unwrap().text,
+            "UTC-03:00"
+        );
+        assert_eq!(manager.get_timezone_info("UTC").unwrap().id, "Etc/UTC");
+        assert!(manager.validate_timezone("America/Montreal"));
+        assert!(!manager.validate_timezone("Mars/Olympus_Mons"));
     }
 }
@@ -0,0 +1,6 @@
+//! Daily bot library, shared by the `Daily` bot binary and the `daily-admin` CLI
+pub mod application;
+pub mod domain;
+pub mod features;
+pub mod infrastructure;
+pub mod utils;
@@ -1,18 +1,34 @@
+use daily::infrastructure::config::AppConfig;
+use daily::infrastructure::discord_bot::bot::run_bot;
+use daily::utils;
 use dotenvy::dotenv;
-mod application;
-mod domain;
-mod infrastructure;
-pub mod features;
-mod utils;
-use crate::infrastructure::discord_bot::bot::run_bot;
 use tracing::{error};
 
 #[tokio::main]
 async fn main() {
-    utils::setup_logging();
+    // Load .env first so RUST_LOG and the LOG_* settings can live there too
     dotenv().ok();
 
-    if let Err(e) = run_bot().await {
+    // Held until exit so buffered file logs are flushed
+    let _logging = match utils::LoggingConfig::from_env()
+        .and_then(|config| utils::setup_logging(&config))
+    {
+        Ok(guard) => guard,
+        Err(e) => {
+            eprintln!("Failed to set up logging: {}", e);
+            return;
+        }
+    };
+
+    let config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = run_bot(config).await {
         error!("Error running bot: {}", e);
     }
 }
@@ -1,15 +1,167 @@
-use tracing::Level;
-use tracing_subscriber::FmtSubscriber;
-
-pub fn setup_logging() {
-    // Create a subscriber that logs to stdout
-    let subscriber = FmtSubscriber::builder()
-        // Set the maximum log level (adjust for production)
-        .with_max_level(Level::INFO)
-        // Complete the builder
-        .finish();
-
-    // Set the global default subscriber
-    tracing::subscriber::set_global_default(subscriber)
-        .expect("Failed to set default tracing subscriber");
-}
\ No newline at end of file
+use std::path::PathBuf;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry, fmt};
+
+/// Filter used when RUST_LOG is not set
+const DEFAULT_FILTER: &str = "info";
+
+/// File name prefix for rotated log files, e.g. `daily-bot.log.2025-01-06`
+const LOG_FILE_PREFIX: &str = "daily-bot.log";
+
+/// Shape of each log line, selected with the LOG_FORMAT env var
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human readable lines (default)
+    #[default]
+    Text,
+    /// One JSON object per line, including the active spans, for log shipping
+    Json,
+}
+
+/// How often the log file is rotated, selected with the LOG_ROTATION env var
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogRotation {
+    Hourly,
+    #[default]
+    Daily,
+    Never,
+}
+
+/// Logging settings read from the environment
+#[derive(Debug, Clone, Default)]
+pub struct LoggingConfig {
+    /// RUST_LOG-style directives, e.g. `info,serenity=warn,daily=debug`
+    pub filter: Option<String>,
+    pub format: LogFormat,
+    /// LOG_DIR: also write rotated log files here (stdout only when unset)
+    pub directory: Option<PathBuf>,
+    pub rotation: LogRotation,
+}
+
+/// Keeps the background file writer running, dropping it flushes whatever is still buffered
+pub struct LoggingGuard {
+    _file_writer: Option<WorkerGuard>,
+}
+
+impl LoggingConfig {
+    /// Read RUST_LOG, LOG_FORMAT, LOG_DIR and LOG_ROTATION
+    pub fn from_env() -> Result<Self, String> {
+        let var = |key: &str| std::env::var(key).ok().filter(|value| !value.trim().is_empty());
+
+        Ok(Self {
+            filter: var("RUST_LOG"),
+            format: var("LOG_FORMAT").map(|v| v.parse()).transpose()?.unwrap_or_default(),
+            directory: var("LOG_DIR").map(PathBuf::from),
+            rotation: var("LOG_ROTATION").map(|v| v.parse()).transpose()?.unwrap_or_default(),
+        })
+    }
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "text" | "pretty" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => Err(format!(
+                "Unknown LOG_FORMAT '{}', expected 'text' or 'json'",
+                other
+            )),
+        }
+    }
+}
+
+impl std::str::FromStr for LogRotation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "hourly" => Ok(Self::Hourly),
+            "daily" => Ok(Self::Daily),
+            "never" => Ok(Self::Never),
+            other => Err(format!(
+                "Unknown LOG_ROTATION '{}', expected 'hourly', 'daily' or 'never'",
+                other
+            )),
+        }
+    }
+}
+
+impl From<LogRotation> for Rotation {
+    fn from(rotation: LogRotation) -> Self {
+        match rotation {
+            LogRotation::Hourly => Rotation::HOURLY,
+            LogRotation::Daily => Rotation::DAILY,
+            LogRotation::Never => Rotation::NEVER,
+        }
+    }
+}
+
+/// Install the global subscriber: stdout plus an optional rotating file, both behind the same filter
+/// Keep the returned guard alive until exit so file output is flushed
+pub fn setup_logging(config: &LoggingConfig) -> Result<LoggingGuard, String> {
+    let filter = EnvFilter::try_new(config.filter.as_deref().unwrap_or(DEFAULT_FILTER))
+        .map_err(|e| format!("Invalid RUST_LOG filter: {}", e))?;
+
+    let mut layers = vec![format_layer(config.format, std::io::stdout, true)];
+
+    let file_writer = match &config.directory {
+        Some(directory) => {
+            let appender = RollingFileAppender::builder()
+                .rotation(config.rotation.into())
+                .filename_prefix(LOG_FILE_PREFIX)
+                .build(directory)
+                .map_err(|e| format!("Failed to open log directory {:?}: {}", directory, e))?;
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            layers.push(format_layer(config.format, writer, false));
+            Some(guard)
+        }
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(layers)
+        .with(filter)
+        .try_init()
+        .map_err(|e| format!("Failed to set default tracing subscriber: {}", e))?;
+
+    Ok(LoggingGuard {
+        _file_writer: file_writer,
+    })
+}
+
+fn format_layer<W>(format: LogFormat, writer: W, ansi: bool) -> Box<dyn Layer<Registry> + Send + Sync>
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    match format {
+        LogFormat::Text => fmt::layer().with_writer(writer).with_ansi(ansi).boxed(),
+        LogFormat::Json => fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_writer(writer)
+            .boxed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_and_rotations_parse_case_insensitively() {
+        assert_eq!("JSON".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert_eq!(" text ".parse::<LogFormat>(), Ok(LogFormat::Text));
+        assert!("xml".parse::<LogFormat>().is_err());
+
+        assert_eq!("Hourly".parse::<LogRotation>(), Ok(LogRotation::Hourly));
+        assert_eq!("never".parse::<LogRotation>(), Ok(LogRotation::Never));
+        assert!("weekly".parse::<LogRotation>().is_err());
+    }
+}
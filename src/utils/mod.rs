@@ -1,5 +1,9 @@
 pub mod logger;
 pub mod modal_storage;
+pub mod rate_limiter;
+pub mod shutdown;
 
 pub use logger::*;
-pub use modal_storage::*;
\ No newline at end of file
+pub use modal_storage::*;
+pub use rate_limiter::*;
+pub use shutdown::*;
@@ -1,3 +1,4 @@
+use crate::domain::value_objects::MentionTarget;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -10,8 +11,8 @@ pub struct TaskModalMetadata {
     pub task_type: String,
     pub notification_method: String,
     pub channel_id: Option<u64>,
-    pub mention: Option<String>,
-    pub created_at: Instant,
+    pub mentions: Vec<MentionTarget>,
+    pub timezone: Option<String>,
 }
 
 impl TaskModalMetadata {
@@ -19,27 +20,38 @@ impl TaskModalMetadata {
         task_type: String,
         notification_method: String,
         channel_id: Option<u64>,
-        mention: Option<String>,
+        mentions: Vec<MentionTarget>,
+        timezone: Option<String>,
     ) -> Self {
         Self {
             task_type,
             notification_method,
             channel_id,
-            mention,
-            created_at: Instant::now(),
+            mentions,
+            timezone,
         }
     }
 }
 
-/// Temporary storage for modal metadata with automatic expiration
-/// Prevents memory leaks by automatically cleaning up stale entries
+/// Slots offered by /meeting_finder, kept until the user picks one
+#[derive(Clone, Debug)]
+pub struct MeetingProposal {
+    pub owner_id: u64,
+    pub guild_id: u64,
+    pub channel_id: u64,
+    pub participants: Vec<u64>,
+    pub title: String,
+}
+
+/// Temporary storage for data a later interaction (modal submit, menu pick) needs, with automatic
+/// expiration. Prevents memory leaks by automatically cleaning up stale entries
 #[derive(Clone)]
-pub struct ModalStorage {
-    storage: Arc<Mutex<HashMap<String, TaskModalMetadata>>>,
+pub struct ModalStorage<T = TaskModalMetadata> {
+    storage: Arc<Mutex<HashMap<String, (Instant, T)>>>,
     ttl: Duration,
 }
 
-impl ModalStorage {
+impl<T: Clone> ModalStorage<T> {
     /// Create a new ModalStorage with a Time-To-Live (TTL) for entries
     pub fn new(ttl: Duration) -> Self {
         Self {
@@ -49,25 +61,25 @@ impl ModalStorage {
     }
 
     /// Store metadata with a unique ID
-    pub async fn store(&self, id: String, metadata: TaskModalMetadata) {
+    pub async fn store(&self, id: String, metadata: T) {
         let mut storage = self.storage.lock().await;
-        storage.insert(id, metadata);
+        storage.insert(id, (Instant::now(), metadata));
     }
 
     /// Retrieve metadata by ID and remove it from storage
     /// Returns None if the ID doesn't exist or has expired
-    pub async fn retrieve(&self, id: &str) -> Option<TaskModalMetadata> {
+    pub async fn retrieve(&self, id: &str) -> Option<T> {
         let mut storage = self.storage.lock().await;
         
-        if let Some(metadata) = storage.get(id) {
+        if let Some((created_at, _)) = storage.get(id) {
             // Check if expired
-            if metadata.created_at.elapsed() > self.ttl {
+            if created_at.elapsed() > self.ttl {
                 storage.remove(id);
                 return None;
             }
             
             // Remove and return (one-time use)
-            storage.remove(id)
+            storage.remove(id).map(|(_, metadata)| metadata)
         } else {
             None
         }
@@ -76,7 +88,7 @@ impl ModalStorage {
     /// Clean up expired entries (can be called periodically)
     pub async fn cleanup_expired(&self) {
         let mut storage = self.storage.lock().await;
-        storage.retain(|_, metadata| metadata.created_at.elapsed() <= self.ttl);
+        storage.retain(|_, (created_at, _)| created_at.elapsed() <= self.ttl);
     }
 
     /// Get the number of stored entries (for debugging/monitoring)
@@ -85,6 +97,12 @@ impl ModalStorage {
         let storage = self.storage.lock().await;
         storage.len()
     }
+
+    /// Check if no entries are stored
+    #[allow(dead_code)]
+    pub async fn is_empty(&self) -> bool {
+        self.storage.lock().await.is_empty()
+    }
 }
 
 /// Generate a unique short ID for modal custom_id
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Per-user token bucket: `burst` interactions right away, then `per_minute` on average
+pub struct RateLimiter {
+    buckets: Mutex<HashMap<u64, Bucket>>,
+    burst: f64,
+    per_second: f64,
+}
+
+impl RateLimiter {
+    pub fn new(per_minute: u32, burst: u32) -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+            burst: f64::from(burst.max(1)),
+            per_second: f64::from(per_minute.max(1)) / 60.0,
+        }
+    }
+
+    /// Take one token for `user_id`, or return how long until the next one is available
+    pub fn try_acquire(&self, user_id: u64, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(user_id).or_insert(Bucket {
+            tokens: self.burst,
+            refilled_at: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * self.per_second).min(self.burst);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.per_second,
+            ))
+        }
+    }
+
+    /// Forget users whose bucket has refilled completely, they behave like new ones
+    pub fn cleanup(&self, now: Instant) {
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.retain(|_, bucket| {
+            let elapsed = now.saturating_duration_since(bucket.refilled_at);
+            bucket.tokens + elapsed.as_secs_f64() * self.per_second < self.burst
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_a_burst_then_refills_over_time() {
+        let limiter = RateLimiter::new(6, 2);
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire(1, start).is_ok());
+        assert!(limiter.try_acquire(1, start).is_ok());
+        let retry_after = limiter.try_acquire(1, start).unwrap_err();
+        assert_eq!(retry_after.as_secs(), 10);
+
+        // Other users have their own bucket
+        assert!(limiter.try_acquire(2, start).is_ok());
+
+        let later = start + Duration::from_secs(10);
+        assert!(limiter.try_acquire(1, later).is_ok());
+        assert!(limiter.try_acquire(1, later).is_err());
+
+        limiter.cleanup(start + Duration::from_secs(60));
+        assert!(limiter.buckets.lock().unwrap().is_empty());
+    }
+}
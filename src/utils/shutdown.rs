@@ -0,0 +1,239 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::{Notify, watch};
+
+/// Stages of a shutdown, in the order they happen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ShutdownPhase {
+    /// Normal operation
+    Running,
+    /// No new work is accepted, in-flight dispatches are allowed to finish
+    Draining,
+    /// The drain deadline passed, in-flight work must hand back what it holds and stop
+    Aborting,
+}
+
+/// Shared shutdown state: the current phase plus a count of in-flight dispatches
+/// Cloning is cheap, every clone observes the same state
+#[derive(Clone)]
+pub struct Shutdown {
+    phase: Arc<watch::Sender<ShutdownPhase>>,
+    in_flight: Arc<InFlight>,
+}
+
+struct InFlight {
+    count: AtomicUsize,
+    idle: Notify,
+}
+
+/// Marks one dispatch as in flight until dropped
+pub struct InFlightGuard {
+    in_flight: Arc<InFlight>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self {
+            phase: Arc::new(watch::Sender::new(ShutdownPhase::Running)),
+            in_flight: Arc::new(InFlight {
+                count: AtomicUsize::new(0),
+                idle: Notify::new(),
+            }),
+        }
+    }
+
+    pub fn phase(&self) -> ShutdownPhase {
+        *self.phase.borrow()
+    }
+
+    /// True once shutdown has started, whatever the phase
+    pub fn is_draining(&self) -> bool {
+        self.phase() >= ShutdownPhase::Draining
+    }
+
+    /// Stop accepting new work (no-op if shutdown already started)
+    pub fn begin_draining(&self) {
+        self.advance_to(ShutdownPhase::Draining);
+    }
+
+    /// Tell in-flight work to give up, used once the drain deadline has passed
+    pub fn abort(&self) {
+        self.advance_to(ShutdownPhase::Aborting);
+    }
+
+    fn advance_to(&self, phase: ShutdownPhase) {
+        self.phase.send_if_modified(|current| {
+            if *current < phase {
+                *current = phase;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// Resolves once shutdown has started
+    pub async fn draining(&self) {
+        self.wait_for(ShutdownPhase::Draining).await;
+    }
+
+    /// Resolves once in-flight work has been told to abort
+    pub async fn aborting(&self) {
+        self.wait_for(ShutdownPhase::Aborting).await;
+    }
+
+    async fn wait_for(&self, phase: ShutdownPhase) {
+        let mut receiver = self.phase.subscribe();
+        // The sender lives as long as self, so wait_for can only fail if it is dropped
+        let _ = receiver.wait_for(|current| *current >= phase).await;
+    }
+
+    /// Register a new dispatch, or None if shutdown already started and it should be rejected
+    pub fn try_track(&self) -> Option<InFlightGuard> {
+        // Count first so a concurrent wait_idle can't see zero while we are still deciding
+        self.in_flight.count.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlightGuard {
+            in_flight: self.in_flight.clone(),
+        };
+
+        if self.is_draining() {
+            return None;
+        }
+        Some(guard)
+    }
+
+    /// Number of dispatches currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.count.load(Ordering::SeqCst)
+    }
+
+    /// Wait until no dispatch is in flight
+    pub async fn wait_idle(&self) {
+        loop {
+            let notified = self.in_flight.idle.notified();
+            if self.in_flight() == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.in_flight.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.in_flight.idle.notify_waiters();
+        }
+    }
+}
+
+/// Wait for Ctrl+C, or SIGTERM on unix, and return the signal's name
+pub async fn wait_for_signal() -> &'static str {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => "SIGINT",
+                    _ = sigterm.recv() => "SIGTERM",
+                }
+            }
+            Err(_) => {
+                let _ = tokio::signal::ctrl_c().await;
+                "SIGINT"
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+        "Ctrl+C"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn new_work_is_rejected_once_draining() {
+        let shutdown = Shutdown::new();
+        let guard = shutdown.try_track();
+        assert!(guard.is_some());
+        assert_eq!(shutdown.in_flight(), 1);
+
+        shutdown.begin_draining();
+        assert!(shutdown.try_track().is_none());
+        assert_eq!(shutdown.in_flight(), 1);
+
+        drop(guard);
+        assert_eq!(shutdown.in_flight(), 0);
+    }
+
+    #[test]
+    fn phases_only_move_forward() {
+        let shutdown = Shutdown::new();
+        shutdown.abort();
+        shutdown.begin_draining();
+        assert_eq!(shutdown.phase(), ShutdownPhase::Aborting);
+        assert!(shutdown.is_draining());
+    }
+
+    #[tokio::test]
+    async fn wait_idle_returns_when_the_last_guard_drops() {
+        let shutdown = Shutdown::new();
+        let first = shutdown.try_track().unwrap();
+        let second = shutdown.try_track().unwrap();
+
+        let waiter = {
+            let shutdown = shutdown.clone();
+            tokio::spawn(async move { shutdown.wait_idle().await })
+        };
+
+        drop(first);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+
+        drop(second);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("wait_idle should return once idle")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn phase_waiters_wake_up() {
+        let shutdown = Shutdown::new();
+        let draining = {
+            let shutdown = shutdown.clone();
+            tokio::spawn(async move { shutdown.draining().await })
+        };
+        let aborting = {
+            let shutdown = shutdown.clone();
+            tokio::spawn(async move { shutdown.aborting().await })
+        };
+
+        shutdown.begin_draining();
+        tokio::time::timeout(Duration::from_secs(1), draining)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!aborting.is_finished());
+
+        shutdown.abort();
+        tokio::time::timeout(Duration::from_secs(1), aborting)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+}